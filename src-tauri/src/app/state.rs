@@ -15,7 +15,7 @@ impl AppState {
     pub fn new(db: DbConnection, window: WebviewWindow<Wry>) -> Self {
         Self {
             db,
-            renderer_manager: Arc::new(Mutex::new(RendererManager::new())),
+            renderer_manager: Arc::new(Mutex::new(RendererManager::new(db.clone()))),
             window,
         }
     }
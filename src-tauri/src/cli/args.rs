@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+
+use crate::core::export::{ExportFormat, DEFAULT_JPEG_QUALITY};
+use crate::renderer::DEFAULT_HDR_PEAK_NITS;
+
+pub(super) const USAGE: &str = "\
+Usage: extents-cli render [options] <inputs>... --out <dir>
+
+Develops RAW and raster files headlessly with the Extents processing graph.
+
+Options:
+  --out <dir>          Directory to write rendered files into (required)
+  --recipe <file>      Apply this .exts sidecar to every input instead of
+                       each file's own sidecar
+  --format <format>    jpeg, png, tiff16, exr, or avif (default: jpeg)
+  --quality <1-100>    JPEG quality (default: 92)
+  --bad-pixel-db       Read per-camera bad pixel maps from the app database
+  -h, --help           Print this help
+";
+
+/// Parsed command-line invocation.
+pub(super) enum CliCommand {
+    Render(RenderArgs),
+    Help,
+}
+
+/// Arguments for the `render` subcommand.
+pub(super) struct RenderArgs {
+    pub inputs: Vec<String>,
+    pub output_dir: PathBuf,
+    pub recipe_path: Option<PathBuf>,
+    pub format: ExportFormat,
+    pub bad_pixel_db: bool,
+}
+
+/// Parses process arguments, excluding the program name.
+pub(super) fn parse_args(args: &[String]) -> Result<CliCommand, String> {
+    let mut args = args.iter();
+
+    let subcommand = match args.next() {
+        Some(subcommand) => subcommand.as_str(),
+        None => return Err("Missing subcommand".to_string()),
+    };
+
+    match subcommand {
+        "render" => {}
+        "help" | "-h" | "--help" => return Ok(CliCommand::Help),
+        other => return Err(format!("Unknown subcommand: {other}")),
+    }
+
+    let mut inputs = Vec::new();
+    let mut output_dir = None;
+    let mut recipe_path = None;
+    let mut format_name = None;
+    let mut quality = None;
+    let mut bad_pixel_db = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(CliCommand::Help),
+            "--out" | "-o" => match args.next() {
+                Some(value) => output_dir = Some(PathBuf::from(value)),
+                None => return Err(format!("{arg} requires a directory")),
+            },
+            "--recipe" => match args.next() {
+                Some(value) => recipe_path = Some(PathBuf::from(value)),
+                None => return Err("--recipe requires a sidecar file".to_string()),
+            },
+            "--format" => match args.next() {
+                Some(value) => format_name = Some(value.clone()),
+                None => return Err("--format requires a value".to_string()),
+            },
+            "--quality" => match args.next() {
+                Some(value) => match value.parse::<u8>() {
+                    Ok(value) if (1..=100).contains(&value) => quality = Some(value),
+                    _ => return Err(format!("Invalid JPEG quality: {value}")),
+                },
+                None => return Err("--quality requires a value".to_string()),
+            },
+            "--bad-pixel-db" => bad_pixel_db = true,
+            flag if flag.starts_with('-') => return Err(format!("Unknown option: {flag}")),
+            input => inputs.push(input.to_string()),
+        }
+    }
+
+    let output_dir = match output_dir {
+        Some(output_dir) => output_dir,
+        None => return Err("--out is required".to_string()),
+    };
+
+    if inputs.is_empty() {
+        return Err("No input files given".to_string());
+    }
+
+    let format = match parse_format(format_name.as_deref(), quality) {
+        Ok(format) => format,
+        Err(error) => return Err(error),
+    };
+
+    Ok(CliCommand::Render(RenderArgs {
+        inputs,
+        output_dir,
+        recipe_path,
+        format,
+        bad_pixel_db,
+    }))
+}
+
+fn parse_format(name: Option<&str>, quality: Option<u8>) -> Result<ExportFormat, String> {
+    let format = match name.map(str::to_ascii_lowercase).as_deref() {
+        None | Some("jpeg") | Some("jpg") => ExportFormat::Jpeg {
+            quality: quality.unwrap_or(DEFAULT_JPEG_QUALITY),
+        },
+        Some("png") => ExportFormat::Png,
+        Some("tiff16") | Some("tiff") | Some("tif") => ExportFormat::Tiff16,
+        Some("exr") => ExportFormat::Exr {
+            peak_nits: DEFAULT_HDR_PEAK_NITS as u32,
+        },
+        Some("avif") => ExportFormat::Avif {
+            peak_nits: DEFAULT_HDR_PEAK_NITS as u32,
+        },
+        Some(other) => return Err(format!("Unknown output format: {other}")),
+    };
+
+    if quality.is_some() && !matches!(format, ExportFormat::Jpeg { .. }) {
+        return Err("--quality only applies to JPEG output".to_string());
+    }
+
+    Ok(format)
+}
@@ -0,0 +1,124 @@
+mod args;
+
+use std::path::Path;
+
+use args::{parse_args, CliCommand, RenderArgs, USAGE};
+
+use crate::core::db::connection::DbConnection;
+use crate::core::editing::EditRecipe;
+use crate::core::export::{run_export_job, ExportJobInput};
+use crate::core::sidecar::load_sidecar_file;
+
+const EXIT_SUCCESS: i32 = 0;
+const EXIT_FAILURE: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+
+/// Runs the CLI with process arguments (excluding the program name).
+///
+/// The CLI shares the export pipeline with the desktop app: sources are
+/// decoded with the same readers, developed on an offscreen processing graph,
+/// and encoded by `core::export`. No window or Tauri runtime is created.
+///
+/// Returns the process exit code: 0 when every input rendered, 1 when any
+/// input or the job itself failed, and 2 for invalid usage.
+pub fn run(args: Vec<String>) -> i32 {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            return EXIT_USAGE;
+        }
+    };
+
+    match command {
+        CliCommand::Help => {
+            print!("{USAGE}");
+            EXIT_SUCCESS
+        }
+        CliCommand::Render(render_args) => run_render(render_args),
+    }
+}
+
+fn run_render(render_args: RenderArgs) -> i32 {
+    let recipe_override = match render_args.recipe_path.as_deref() {
+        Some(recipe_path) => match load_recipe_file(recipe_path) {
+            Ok(recipe) => Some(recipe),
+            Err(error) => {
+                eprintln!("error: {error}");
+                return EXIT_FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let input = ExportJobInput {
+        paths: render_args.inputs,
+        output_dir: render_args.output_dir,
+        format: render_args.format,
+        recipe_override,
+        db: if render_args.bad_pixel_db {
+            open_database()
+        } else {
+            None
+        },
+    };
+
+    let result = run_export_job(input, |progress| {
+        let position = progress.index + 1;
+
+        match (&progress.output_path, &progress.error) {
+            (Some(output_path), _) => println!(
+                "[{position}/{}] {} -> {output_path}",
+                progress.total, progress.source_path
+            ),
+            (None, Some(error)) => eprintln!(
+                "[{position}/{}] {} failed: {error}",
+                progress.total, progress.source_path
+            ),
+            (None, None) => {}
+        }
+    });
+
+    match result {
+        Ok(summary) => {
+            println!(
+                "Rendered {} file(s), {} failed",
+                summary.exported, summary.failed
+            );
+
+            if summary.failed == 0 {
+                EXIT_SUCCESS
+            } else {
+                EXIT_FAILURE
+            }
+        }
+        Err(error) => {
+            eprintln!("error: {error:#}");
+            EXIT_FAILURE
+        }
+    }
+}
+
+/// Opens the app database read-only for per-camera bad pixel maps.
+///
+/// The CLI never creates or migrates the database, so it leaves the desktop
+/// app's data untouched. Rendering continues without maps when the database
+/// is unavailable.
+fn open_database() -> Option<DbConnection> {
+    match DbConnection::open_read_only() {
+        Ok(db) => Some(db),
+        Err(error) => {
+            eprintln!("warning: bad pixel maps unavailable: {error}");
+            None
+        }
+    }
+}
+
+fn load_recipe_file(recipe_path: &Path) -> Result<EditRecipe, String> {
+    match load_sidecar_file(recipe_path) {
+        Ok(sidecar) => Ok(sidecar.recipe().clone()),
+        Err(error) => Err(error.to_string()),
+    }
+}
@@ -0,0 +1,29 @@
+use crate::app::AppState;
+use crate::core::db::bad_pixels::{get_camera_bad_pixels, set_camera_bad_pixels, BadPixel};
+use tauri::State;
+
+/// Returns the user-supplied bad pixel map for a camera body.
+#[tauri::command]
+pub fn get_bad_pixel_map(
+    camera_make: String,
+    camera_model: String,
+    state: State<AppState>,
+) -> Result<Vec<BadPixel>, String> {
+    let connection = state.db.connection.lock().map_err(|e| e.to_string())?;
+
+    get_camera_bad_pixels(&connection, &camera_make, &camera_model).map_err(|e| e.to_string())
+}
+
+/// Replaces the bad pixel map for a camera body. Applies to images loaded afterwards.
+#[tauri::command]
+pub fn set_bad_pixel_map(
+    camera_make: String,
+    camera_model: String,
+    pixels: Vec<BadPixel>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let mut connection = state.db.connection.lock().map_err(|e| e.to_string())?;
+
+    set_camera_bad_pixels(&mut *connection, &camera_make, &camera_model, &pixels)
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use tauri::{Emitter, State};
+
+use crate::app::AppState;
+use crate::core::export::{run_export_job, ExportFormat, ExportJobInput};
+
+/// Export request for a batch of source images.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportImagesRequest {
+    paths: Vec<String>,
+    output_dir: String,
+    format: ExportFormat,
+}
+
+#[tauri::command]
+pub fn export_images(
+    request: ExportImagesRequest,
+    app_handle: tauri::AppHandle,
+    state: State<AppState>,
+) -> Result<(), String> {
+    if request.paths.is_empty() {
+        return Err("Export requires at least one source path".to_string());
+    }
+
+    if request.output_dir.is_empty() {
+        return Err("Export requires an output directory".to_string());
+    }
+
+    log::info!(
+        "Starting export of {} image(s) to {}",
+        request.paths.len(),
+        request.output_dir
+    );
+
+    let progress_handle = app_handle.clone();
+    let complete_handle = app_handle.clone();
+    let error_handle = app_handle.clone();
+
+    let input = ExportJobInput {
+        paths: request.paths,
+        output_dir: PathBuf::from(request.output_dir),
+        format: request.format,
+        recipe_override: None,
+        db: Some(state.db.clone()),
+    };
+
+    tauri::async_runtime::spawn(async move {
+        let result = tauri::async_runtime::spawn_blocking(move || {
+            run_export_job(input, |progress| {
+                progress_handle.emit("export-progress", progress).ok();
+            })
+        })
+        .await;
+
+        match result {
+            Ok(Ok(summary)) => {
+                complete_handle.emit("export-complete", summary).ok();
+            }
+            Ok(Err(err)) => {
+                error_handle.emit("export-error", format!("{err:#}")).ok();
+            }
+            Err(err) => {
+                error_handle.emit("export-error", err.to_string()).ok();
+            }
+        }
+    });
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use crate::app::AppState;
+use crate::core::db::edit_history::{get_edit_history, record_edit, redo_edit, undo_edit};
+use crate::core::editing::{EditHistorySummary, EditRecipe};
+use tauri::State;
+
+/// Returns the undo history recorded for an image.
+#[tauri::command]
+pub fn get_edit_history_summary(
+    path: String,
+    state: State<AppState>,
+) -> Result<EditHistorySummary, String> {
+    let connection = state.db.connection.lock().map_err(|e| e.to_string())?;
+
+    match get_edit_history(&connection, &path) {
+        Ok(Some(history)) => Ok(history.summary()),
+        Ok(None) => Ok(EditHistorySummary::empty()),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+/// Records the current recipe of an image as a named history entry.
+#[tauri::command]
+pub fn record_edit_history(
+    path: String,
+    recipe: EditRecipe,
+    state: State<AppState>,
+) -> Result<EditHistorySummary, String> {
+    let connection = state.db.connection.lock().map_err(|e| e.to_string())?;
+
+    record_edit(&connection, &path, &recipe).map_err(|e| e.to_string())
+}
+
+/// Undoes the latest applied edit of an image, returning the restored recipe.
+#[tauri::command]
+pub fn undo_edit_history(
+    path: String,
+    state: State<AppState>,
+) -> Result<Option<EditRecipe>, String> {
+    let connection = state.db.connection.lock().map_err(|e| e.to_string())?;
+
+    undo_edit(&connection, &path).map_err(|e| e.to_string())
+}
+
+/// Redoes the next undone edit of an image, returning the restored recipe.
+#[tauri::command]
+pub fn redo_edit_history(
+    path: String,
+    state: State<AppState>,
+) -> Result<Option<EditRecipe>, String> {
+    let connection = state.db.connection.lock().map_err(|e| e.to_string())?;
+
+    redo_edit(&connection, &path).map_err(|e| e.to_string())
+}
@@ -0,0 +1,10 @@
+use crate::core::look::{load_look_file, LookFile};
+use std::path::Path;
+
+/// Validates a `.cube` look and returns the path and hash to store in a recipe.
+#[tauri::command]
+pub fn read_look_file(path: String) -> Result<LookFile, String> {
+    load_look_file(Path::new(&path), None)
+        .map(|(file, _)| file)
+        .map_err(|error| format!("{:#}", error))
+}
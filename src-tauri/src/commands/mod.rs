@@ -1,8 +1,12 @@
 pub mod annotations;
+pub mod bad_pixels;
 pub mod exif;
+pub mod export;
 pub mod file;
+pub mod history;
 pub mod image;
 pub mod inspection;
+pub mod look;
 pub mod renderer;
 pub mod scanner;
 pub mod settings;
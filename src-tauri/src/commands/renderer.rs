@@ -1,4 +1,5 @@
 use crate::app::AppState;
+use crate::core::editing::{ColorBand, ColorRange, MaskPoint, WhiteBalance};
 use crate::renderer::{InspectionSnapshot, RenderState, RendererManager};
 use log::{info, warn};
 use tauri::State;
@@ -137,3 +138,104 @@ pub fn get_renderer_inspection(
 
     Ok(manager.inspection_snapshot())
 }
+
+/// Returns a custom white balance that neutralizes the patch at a normalized image position.
+#[tauri::command]
+pub fn sample_white_balance(
+    x: f32,
+    y: f32,
+    state: State<AppState>,
+) -> Result<WhiteBalance, String> {
+    let manager = RendererManager::lock(&state.renderer_manager)?;
+
+    manager.sample_neutral_white_balance(x, y)
+}
+
+/// Returns the color mixer band under a normalized image position, or `None` for neutral colors.
+#[tauri::command]
+pub fn sample_color_band(
+    x: f32,
+    y: f32,
+    state: State<AppState>,
+) -> Result<Option<ColorBand>, String> {
+    let manager = RendererManager::lock(&state.renderer_manager)?;
+
+    manager.sample_color_band(x, y)
+}
+
+/// Maps a surface pixel position to a normalized source image position, or
+/// `None` outside the displayed image.
+#[tauri::command]
+pub fn source_position_at_viewport(
+    x: f32,
+    y: f32,
+    state: State<AppState>,
+) -> Result<Option<MaskPoint>, String> {
+    let manager = RendererManager::lock(&state.renderer_manager)?;
+
+    manager.source_position_at_viewport(x, y)
+}
+
+/// Builds a mask color range from the color at a normalized source position.
+#[tauri::command]
+pub fn sample_mask_color(x: f32, y: f32, state: State<AppState>) -> Result<ColorRange, String> {
+    let manager = RendererManager::lock(&state.renderer_manager)?;
+
+    manager.sample_mask_color(MaskPoint { x, y })
+}
+
+/// Finds a source for a retouch spot at normalized source coordinates by
+/// matching the surroundings of nearby patches.
+#[tauri::command]
+pub fn find_spot_source(
+    x: f32,
+    y: f32,
+    radius: f32,
+    state: State<AppState>,
+) -> Result<Option<MaskPoint>, String> {
+    let manager = RendererManager::lock(&state.renderer_manager)?;
+
+    manager.find_spot_source(MaskPoint { x, y }, radius)
+}
+
+/// Shows the capture sharpening mask in place of the developed image.
+#[tauri::command]
+pub fn set_sharpening_mask_preview(enabled: bool, state: State<AppState>) {
+    match RendererManager::lock(&state.renderer_manager) {
+        Ok(mut manager) => manager.set_sharpening_mask_preview(enabled),
+        Err(error) => warn!("{error}"),
+    }
+}
+
+/// Tints one local adjustment mask over the image for the Inspector, or turns
+/// the overlay off when `index` is `None`.
+#[tauri::command]
+pub fn set_local_mask_overlay(index: Option<usize>, state: State<AppState>) {
+    match RendererManager::lock(&state.renderer_manager) {
+        Ok(mut manager) => manager.set_local_mask_overlay(index),
+        Err(error) => warn!("{error}"),
+    }
+}
+
+/// Shows a high-pass, inverted view of the image that makes sensor dust stand
+/// out. The recipe and exports are unaffected.
+#[tauri::command]
+pub fn set_spot_visualization(enabled: bool, state: State<AppState>) {
+    match RendererManager::lock(&state.renderer_manager) {
+        Ok(mut manager) => manager.set_spot_visualization(enabled),
+        Err(error) => warn!("{error}"),
+    }
+}
+
+/// Requests HDR presentation at a display peak in nits, returning whether the
+/// surface supports it or fell back to SDR.
+#[tauri::command]
+pub fn set_hdr_output(
+    enabled: bool,
+    peak_nits: f32,
+    state: State<AppState>,
+) -> Result<bool, String> {
+    let mut manager = RendererManager::lock(&state.renderer_manager)?;
+
+    manager.set_hdr_output(enabled, peak_nits)
+}
@@ -0,0 +1,124 @@
+use rusqlite::{params, Connection};
+use std::error::Error;
+use tracing::info;
+
+use crate::core::db::connection::DbConnection;
+use crate::core::db::util::now_timestamp;
+
+/// One defective photosite in full-sensor RAW coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BadPixel {
+    pub x: u32,
+    pub y: u32,
+}
+
+/// Initializes the per-camera bad pixel map table.
+pub fn init_bad_pixels_table(connection: &Connection) -> Result<(), Box<dyn Error>> {
+    connection.execute(
+        "
+        CREATE TABLE IF NOT EXISTS camera_bad_pixels (
+            camera_make TEXT NOT NULL,
+            camera_model TEXT NOT NULL,
+            x INTEGER NOT NULL,
+            y INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL,
+            PRIMARY KEY (camera_make, camera_model, x, y)
+        );
+        ",
+        [],
+    )?;
+
+    Ok(())
+}
+
+/// Returns the user-supplied bad pixel map for one camera body.
+pub fn get_camera_bad_pixels(
+    connection: &Connection,
+    camera_make: &str,
+    camera_model: &str,
+) -> Result<Vec<BadPixel>, Box<dyn Error>> {
+    let mut statement = connection.prepare(
+        "
+        SELECT x, y FROM camera_bad_pixels
+        WHERE camera_make = ?1 AND camera_model = ?2
+        ORDER BY y, x
+        ",
+    )?;
+
+    let rows = statement.query_map(params![camera_make, camera_model], |row| {
+        Ok(BadPixel {
+            x: row.get(0)?,
+            y: row.get(1)?,
+        })
+    })?;
+
+    let mut results = Vec::new();
+
+    for row in rows {
+        results.push(row?);
+    }
+
+    Ok(results)
+}
+
+/// Replaces the bad pixel map for one camera body.
+///
+/// An empty `pixels` slice clears the map.
+pub fn set_camera_bad_pixels(
+    connection: &mut Connection,
+    camera_make: &str,
+    camera_model: &str,
+    pixels: &[BadPixel],
+) -> Result<(), Box<dyn Error>> {
+    let timestamp = now_timestamp();
+    let transaction = connection.transaction()?;
+
+    transaction.execute(
+        "DELETE FROM camera_bad_pixels WHERE camera_make = ?1 AND camera_model = ?2",
+        params![camera_make, camera_model],
+    )?;
+
+    {
+        let mut statement = transaction.prepare(
+            "
+            INSERT OR IGNORE INTO camera_bad_pixels (camera_make, camera_model, x, y, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ",
+        )?;
+
+        for pixel in pixels {
+            statement.execute(params![
+                camera_make,
+                camera_model,
+                pixel.x,
+                pixel.y,
+                timestamp
+            ])?;
+        }
+    }
+
+    transaction.commit()?;
+
+    info!(
+        "[bad_pixels] persisted map camera={} {} count={}",
+        camera_make,
+        camera_model,
+        pixels.len()
+    );
+
+    Ok(())
+}
+
+/// Loads the bad pixel map for one camera body from a shared connection.
+pub fn load_camera_bad_pixels(
+    db: &DbConnection,
+    camera_make: &str,
+    camera_model: &str,
+) -> Result<Vec<BadPixel>, Box<dyn Error>> {
+    let connection = db
+        .connection
+        .lock()
+        .map_err(|error| std::io::Error::new(std::io::ErrorKind::Other, error.to_string()))?;
+
+    get_camera_bad_pixels(&connection, camera_make, camera_model)
+}
@@ -1,12 +1,17 @@
-use rusqlite::Connection;
+use rusqlite::{Connection, OpenFlags};
 use std::error::Error;
 use std::sync::{Arc, Mutex};
 use tracing::info;
 
 use super::annotations::init_annotations_table;
+use super::bad_pixels::init_bad_pixels_table;
 use super::checkpoints::init_checkpoints_table;
+use super::edit_history::init_edit_history_table;
 use super::exif::init_exif_table;
 
+/// File name of the app database inside the cache directory.
+const DB_FILE_NAME: &str = "extents.db";
+
 /// Shared SQLite connection wrapper used by Tauri command handlers.
 #[derive(Clone)]
 pub struct DbConnection {
@@ -16,18 +21,14 @@ pub struct DbConnection {
 impl DbConnection {
     /// Opens the app database and applies table migrations.
     pub fn init_db_connection() -> Result<Self, Box<dyn Error>> {
-        let cache_dir = dirs::cache_dir()
-            .unwrap_or_else(|| std::env::temp_dir())
-            .join("com.extents.cache");
+        let cache_dir = db_cache_dir();
 
         match std::fs::create_dir_all(&cache_dir) {
             Ok(()) => {}
             Err(error) => return Err(Box::new(error)),
         }
 
-        let db_path = cache_dir.join("extents.db");
-
-        let connection = match Connection::open(db_path) {
+        let connection = match Connection::open(cache_dir.join(DB_FILE_NAME)) {
             Ok(connection) => connection,
             Err(error) => return Err(Box::new(error)),
         };
@@ -43,6 +44,28 @@ impl DbConnection {
             connection: Arc::new(Mutex::new(connection)),
         })
     }
+
+    /// Opens the existing app database read-only, without creating it or
+    /// applying migrations, for tools that only look data up.
+    pub fn open_read_only() -> Result<Self, Box<dyn Error>> {
+        let db_path = db_cache_dir().join(DB_FILE_NAME);
+        let flags = OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+
+        let connection = match Connection::open_with_flags(db_path, flags) {
+            Ok(connection) => connection,
+            Err(error) => return Err(Box::new(error)),
+        };
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+}
+
+fn db_cache_dir() -> std::path::PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(|| std::env::temp_dir())
+        .join("com.extents.cache")
 }
 
 /// Applies all table migrations required by the app.
@@ -62,6 +85,16 @@ fn run_migrations(connection: &Connection) -> Result<(), Box<dyn Error>> {
         Err(error) => return Err(error),
     }
 
+    match init_bad_pixels_table(connection) {
+        Ok(()) => {}
+        Err(error) => return Err(error),
+    }
+
+    match init_edit_history_table(connection) {
+        Ok(()) => {}
+        Err(error) => return Err(error),
+    }
+
     info!("Migrations applied");
 
     Ok(())
@@ -0,0 +1,137 @@
+use rusqlite::{params, Connection, OptionalExtension};
+use std::error::Error;
+use tracing::info;
+
+use crate::core::db::util::{json_to_sql_error, now_timestamp};
+use crate::core::editing::{EditHistory, EditHistorySummary, EditRecipe};
+
+/// Histories of images left unedited for this long are deleted at startup.
+const EDIT_HISTORY_RETENTION_SECONDS: i64 = 90 * 24 * 60 * 60;
+
+/// Initializes the per-image edit history table and drops stale histories.
+pub fn init_edit_history_table(connection: &Connection) -> Result<(), Box<dyn Error>> {
+    connection.execute(
+        "
+        CREATE TABLE IF NOT EXISTS edit_history (
+            image_path TEXT PRIMARY KEY,
+            history_json TEXT NOT NULL,
+            updated_at INTEGER NOT NULL
+        );
+        ",
+        [],
+    )?;
+
+    let removed = connection.execute(
+        "DELETE FROM edit_history WHERE updated_at < ?1",
+        params![now_timestamp() - EDIT_HISTORY_RETENTION_SECONDS],
+    )?;
+
+    if removed > 0 {
+        info!("[edit_history] compacted stale histories count={}", removed);
+    }
+
+    Ok(())
+}
+
+/// Returns the stored edit history for one image, if any.
+pub fn get_edit_history(
+    connection: &Connection,
+    image_path: &str,
+) -> Result<Option<EditHistory>, Box<dyn Error>> {
+    let history = connection
+        .query_row(
+            "SELECT history_json FROM edit_history WHERE image_path = ?1",
+            params![image_path],
+            |row| {
+                let history_json: String = row.get(0)?;
+
+                serde_json::from_str(&history_json).map_err(json_to_sql_error)
+            },
+        )
+        .optional()?;
+
+    Ok(history)
+}
+
+/// Replaces the stored edit history for one image.
+pub fn set_edit_history(
+    connection: &Connection,
+    image_path: &str,
+    history: &EditHistory,
+) -> Result<(), Box<dyn Error>> {
+    let history_json = serde_json::to_string(history)?;
+
+    connection.execute(
+        "
+        INSERT INTO edit_history (image_path, history_json, updated_at)
+        VALUES (?1, ?2, ?3)
+        ON CONFLICT(image_path) DO UPDATE SET
+            history_json = excluded.history_json,
+            updated_at = excluded.updated_at
+        ",
+        params![image_path, history_json, now_timestamp()],
+    )?;
+
+    Ok(())
+}
+
+/// Records `recipe` as the latest state of an image and returns the updated
+/// history.
+///
+/// The first recipe recorded for an image becomes its baseline without an
+/// entry, so the frontend records the loaded recipe before any edit.
+pub fn record_edit(
+    connection: &Connection,
+    image_path: &str,
+    recipe: &EditRecipe,
+) -> Result<EditHistorySummary, Box<dyn Error>> {
+    let Some(mut history) = get_edit_history(connection, image_path)? else {
+        let history = EditHistory::new(recipe);
+
+        set_edit_history(connection, image_path, &history)?;
+
+        return Ok(history.summary());
+    };
+
+    if history.record(recipe, now_timestamp())? {
+        set_edit_history(connection, image_path, &history)?;
+    }
+
+    Ok(history.summary())
+}
+
+/// Steps an image's history back one entry and returns the restored recipe.
+pub fn undo_edit(
+    connection: &Connection,
+    image_path: &str,
+) -> Result<Option<EditRecipe>, Box<dyn Error>> {
+    let Some(mut history) = get_edit_history(connection, image_path)? else {
+        return Ok(None);
+    };
+
+    let recipe = history.undo()?;
+
+    if recipe.is_some() {
+        set_edit_history(connection, image_path, &history)?;
+    }
+
+    Ok(recipe)
+}
+
+/// Reapplies an image's next undone entry and returns the restored recipe.
+pub fn redo_edit(
+    connection: &Connection,
+    image_path: &str,
+) -> Result<Option<EditRecipe>, Box<dyn Error>> {
+    let Some(mut history) = get_edit_history(connection, image_path)? else {
+        return Ok(None);
+    };
+
+    let recipe = history.redo()?;
+
+    if recipe.is_some() {
+        set_edit_history(connection, image_path, &history)?;
+    }
+
+    Ok(recipe)
+}
@@ -1,5 +1,7 @@
 pub mod annotations;
+pub mod bad_pixels;
 pub mod checkpoints;
 pub mod connection;
+pub mod edit_history;
 pub mod exif;
 pub mod util;
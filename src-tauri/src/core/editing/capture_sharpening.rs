@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// Capture sharpening settings persisted in an edit recipe.
+///
+/// Applies to Bayer RAW sources only, restoring the softness left by the
+/// anti-aliasing filter and demosaic. Sharpening runs on linear luminance
+/// before any tone or color adjustment.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CaptureSharpening {
+    /// Sharpening strength in `0.0..=1.0`; `0.0` disables the stage.
+    pub amount: f32,
+    /// Gaussian radius in pixels, in `0.5..=2.0`.
+    pub radius: f32,
+    /// Relative detail in `0.0..=1.0` below which texture is treated as noise.
+    pub threshold: f32,
+}
+
+impl Default for CaptureSharpening {
+    fn default() -> Self {
+        Self {
+            amount: 0.3,
+            radius: 0.8,
+            threshold: 0.2,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// Number of hue bands in the color mixer.
+pub const COLOR_BAND_COUNT: usize = 8;
+
+/// Hue band controlled by one color mixer column, in hue order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorBand {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Aqua,
+    Blue,
+    Purple,
+    Magenta,
+}
+
+impl ColorBand {
+    /// All bands in the order used by [`ColorMixer`] arrays.
+    pub const ALL: [ColorBand; COLOR_BAND_COUNT] = [
+        ColorBand::Red,
+        ColorBand::Orange,
+        ColorBand::Yellow,
+        ColorBand::Green,
+        ColorBand::Aqua,
+        ColorBand::Blue,
+        ColorBand::Purple,
+        ColorBand::Magenta,
+    ];
+}
+
+/// Per-hue color mixer settings persisted in an edit recipe.
+///
+/// Each array is indexed in [`ColorBand::ALL`] order. Values are normalized to
+/// `-1.0..=1.0` with `0.0` as neutral.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColorMixer {
+    /// Rotates the band toward its neighbouring hues.
+    pub hue: [f32; COLOR_BAND_COUNT],
+    pub saturation: [f32; COLOR_BAND_COUNT],
+    pub luminance: [f32; COLOR_BAND_COUNT],
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+/// Largest straighten angle in either direction, in degrees.
+pub const MAX_STRAIGHTEN_DEGREES: f32 = 45.0;
+
+/// Crop and orientation settings persisted in an edit recipe.
+///
+/// Quarter turns are applied first, then flips, both in the displayed frame.
+/// The crop rectangle is laid out in that oriented frame and the image is
+/// straightened about the crop center, so rotating or flipping never moves
+/// the crop relative to what the user sees.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Geometry {
+    /// Crop in normalized oriented-image coordinates, or `None` to use the
+    /// source's default crop.
+    pub crop: Option<CropRect>,
+    pub aspect_ratio: CropAspectRatio,
+    /// Clockwise straighten angle in `-45.0..=45.0` degrees.
+    pub straighten_degrees: f32,
+    /// Clockwise quarter turns in `0..=3`.
+    pub quarter_turns: u8,
+    pub flip_horizontal: bool,
+    pub flip_vertical: bool,
+}
+
+/// Axis-aligned rectangle in normalized `0.0..=1.0` image coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CropRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl CropRect {
+    /// Returns the rectangle covering the whole image.
+    pub fn full() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            width: 1.0,
+            height: 1.0,
+        }
+    }
+}
+
+/// Aspect ratio the crop is locked to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum CropAspectRatio {
+    /// The crop keeps whatever shape it was drawn with.
+    #[default]
+    Free,
+    /// The crop keeps the oriented image's aspect ratio.
+    Original,
+    /// The crop keeps a fixed `width:height` ratio in the oriented frame.
+    Fixed { width: f32, height: f32 },
+}
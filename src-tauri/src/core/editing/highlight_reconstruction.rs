@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// Highlight reconstruction mode persisted in an edit recipe.
+///
+/// Applies to RAW sources only. Clipped photosites are repaired on the sensor
+/// mosaic before demosaic so partially clipped highlights stop turning magenta
+/// once white balance scales the unclipped channels. New recipes default to
+/// [`HighlightReconstruction::Blend`]; sidecars saved before the setting
+/// existed load as [`HighlightReconstruction::Off`] so they render unchanged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HighlightReconstruction {
+    /// Leaves clipped photosites untouched.
+    Off,
+    /// Clamps every channel to the lowest white-balanced clip level, giving neutral
+    /// but flat highlights.
+    Clip,
+    /// Blends clipped photosites toward the brightest nearby sample in proportion
+    /// to how much of the neighbourhood is clipped.
+    #[default]
+    Blend,
+    /// Rebuilds clipped photosites from unclipped channels in the neighbourhood,
+    /// recovering detail above the clip point where at least one channel survives.
+    Inpaint,
+}
+
+impl HighlightReconstruction {
+    /// Returns the mode for sidecars that predate highlight reconstruction.
+    pub fn unset() -> Self {
+        Self::Off
+    }
+}
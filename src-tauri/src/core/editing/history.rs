@@ -0,0 +1,311 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::recipe::EditRecipe;
+
+/// Largest number of undoable entries kept per image; older entries are
+/// dropped first.
+pub const MAX_HISTORY_ENTRIES: usize = 100;
+
+/// Changes to the same recipe fields recorded within this many seconds of the
+/// previous entry are folded into it, so one slider drag is one undo step.
+const COALESCE_WINDOW_SECONDS: i64 = 2;
+
+/// Linear undo/redo history for one image's edit recipe.
+///
+/// Entries store only the top-level recipe fields they changed, as JSON
+/// before and after values, so a long history of slider edits stays small.
+/// Recording after an undo discards the entries that could have been redone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditHistory {
+    recipe: EditRecipe,
+    entries: Vec<RecipeDelta>,
+    position: usize,
+}
+
+/// One named change between two recipes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecipeDelta {
+    label: String,
+    recorded_at: i64,
+    before: Map<String, Value>,
+    after: Map<String, Value>,
+}
+
+/// History entries and the undo position shown to the frontend.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditHistorySummary {
+    pub entries: Vec<EditHistoryEntry>,
+    /// Number of entries currently applied; entries at and after this index
+    /// can be redone.
+    pub position: usize,
+}
+
+/// Display information for one history entry.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditHistoryEntry {
+    pub label: String,
+    pub recorded_at: i64,
+}
+
+impl EditHistory {
+    /// Starts an empty history whose current state is `recipe`.
+    pub fn new(recipe: &EditRecipe) -> Self {
+        Self {
+            recipe: recipe.clone(),
+            entries: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Records the change from the current state to `recipe`.
+    ///
+    /// Returns `false` when nothing changed. A change to the same fields as
+    /// the latest entry within the coalescing window replaces that entry, and
+    /// the entry is removed when the fields return to their earlier values.
+    pub fn record(
+        &mut self,
+        recipe: &EditRecipe,
+        recorded_at: i64,
+    ) -> Result<bool, serde_json::Error> {
+        let current_fields = match recipe_fields(&self.recipe) {
+            Ok(fields) => fields,
+            Err(error) => return Err(error),
+        };
+        let fields = match recipe_fields(recipe) {
+            Ok(fields) => fields,
+            Err(error) => return Err(error),
+        };
+        let (before, after) = changed_fields(&current_fields, &fields);
+
+        if after.is_empty() {
+            return Ok(false);
+        }
+
+        self.entries.truncate(self.position);
+        self.recipe = recipe.clone();
+
+        if let Some(latest) = self.entries.last_mut() {
+            let same_fields = latest.after.len() == after.len()
+                && after.keys().all(|key| latest.after.contains_key(key));
+
+            if same_fields && recorded_at - latest.recorded_at <= COALESCE_WINDOW_SECONDS {
+                latest.after = after;
+                latest.recorded_at = recorded_at;
+
+                if fields_match(&latest.before, &latest.after) {
+                    self.entries.pop();
+                } else {
+                    latest.label = delta_label(&latest.before, &latest.after);
+                }
+
+                self.position = self.entries.len();
+
+                return Ok(true);
+            }
+        }
+
+        self.entries.push(RecipeDelta {
+            label: delta_label(&before, &after),
+            recorded_at,
+            before,
+            after,
+        });
+
+        if self.entries.len() > MAX_HISTORY_ENTRIES {
+            let excess = self.entries.len() - MAX_HISTORY_ENTRIES;
+
+            self.entries.drain(..excess);
+        }
+
+        self.position = self.entries.len();
+
+        Ok(true)
+    }
+
+    /// Steps back one entry and returns the restored recipe, or `None` when
+    /// there is nothing to undo.
+    pub fn undo(&mut self) -> Result<Option<EditRecipe>, serde_json::Error> {
+        if self.position == 0 {
+            return Ok(None);
+        }
+
+        self.position -= 1;
+
+        let fields = self.entries[self.position].before.clone();
+
+        self.apply(fields).map(Some)
+    }
+
+    /// Reapplies the next undone entry and returns the restored recipe, or
+    /// `None` when there is nothing to redo.
+    pub fn redo(&mut self) -> Result<Option<EditRecipe>, serde_json::Error> {
+        if self.position == self.entries.len() {
+            return Ok(None);
+        }
+
+        let fields = self.entries[self.position].after.clone();
+
+        self.position += 1;
+
+        self.apply(fields).map(Some)
+    }
+
+    /// Returns the entry labels and undo position for display.
+    pub fn summary(&self) -> EditHistorySummary {
+        EditHistorySummary {
+            entries: self
+                .entries
+                .iter()
+                .map(|entry| EditHistoryEntry {
+                    label: entry.label.clone(),
+                    recorded_at: entry.recorded_at,
+                })
+                .collect(),
+            position: self.position,
+        }
+    }
+
+    fn apply(&mut self, fields: Map<String, Value>) -> Result<EditRecipe, serde_json::Error> {
+        let mut recipe = match recipe_fields(&self.recipe) {
+            Ok(recipe) => recipe,
+            Err(error) => return Err(error),
+        };
+
+        recipe.extend(fields);
+
+        self.recipe = match serde_json::from_value(Value::Object(recipe)) {
+            Ok(recipe) => recipe,
+            Err(error) => return Err(error),
+        };
+
+        Ok(self.recipe.clone())
+    }
+}
+
+impl EditHistorySummary {
+    /// Returns an empty summary for an image with no recorded history.
+    pub fn empty() -> Self {
+        Self {
+            entries: Vec::new(),
+            position: 0,
+        }
+    }
+}
+
+fn recipe_fields(recipe: &EditRecipe) -> Result<Map<String, Value>, serde_json::Error> {
+    match serde_json::to_value(recipe) {
+        Ok(Value::Object(fields)) => Ok(fields),
+        Ok(_) => Ok(Map::new()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Returns the earlier and later values of every top-level field that differs.
+fn changed_fields(
+    before: &Map<String, Value>,
+    after: &Map<String, Value>,
+) -> (Map<String, Value>, Map<String, Value>) {
+    let mut changed_before = Map::new();
+    let mut changed_after = Map::new();
+
+    for (key, value) in after {
+        let previous = before.get(key).cloned().unwrap_or(Value::Null);
+
+        if !values_match(&previous, value) {
+            changed_before.insert(key.clone(), previous);
+            changed_after.insert(key.clone(), value.clone());
+        }
+    }
+
+    (changed_before, changed_after)
+}
+
+fn fields_match(left: &Map<String, Value>, right: &Map<String, Value>) -> bool {
+    left.len() == right.len()
+        && left.iter().all(|(key, value)| {
+            right
+                .get(key)
+                .is_some_and(|other| values_match(value, other))
+        })
+}
+
+/// Compares recipe values at `f32` precision, since persisted JSON numbers may
+/// not parse back to the exact `f64` they were written from.
+fn values_match(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => match (left.as_f64(), right.as_f64()) {
+            (Some(left), Some(right)) => left as f32 == right as f32,
+            _ => left == right,
+        },
+        (Value::Array(left), Value::Array(right)) => {
+            left.len() == right.len()
+                && left
+                    .iter()
+                    .zip(right)
+                    .all(|(left, right)| values_match(left, right))
+        }
+        (Value::Object(left), Value::Object(right)) => fields_match(left, right),
+        _ => left == right,
+    }
+}
+
+/// Names a change for the history list, e.g. "Exposure +0.30" for a single
+/// slider or "Tone Curve, Look" for a few fields.
+fn delta_label(before: &Map<String, Value>, after: &Map<String, Value>) -> String {
+    if after.len() == 1 {
+        if let Some((key, value)) = after.iter().next() {
+            let previous = before.get(key).and_then(Value::as_f64);
+
+            if let (Some(previous), Some(value)) = (previous, value.as_f64()) {
+                return slider_label(key, value - previous);
+            }
+        }
+    }
+
+    let names: Vec<&str> = after.keys().map(|key| field_name(key)).collect();
+
+    if names.len() <= 2 {
+        names.join(", ")
+    } else {
+        format!("{} Settings", names.len())
+    }
+}
+
+/// Formats a slider change the way the edit panel displays it: exposure in
+/// stops, normalized tone and color controls in percent.
+fn slider_label(key: &str, delta: f64) -> String {
+    if key == "exposure_ev" {
+        return format!("{} {delta:+.2}", field_name(key));
+    }
+
+    format!("{} {:+.0}", field_name(key), delta * 100.0)
+}
+
+fn field_name(key: &str) -> &str {
+    match key {
+        "white_balance" => "White Balance",
+        "highlight_reconstruction" => "Highlight Reconstruction",
+        "lens_correction" => "Lens Correction",
+        "noise_reduction" => "Noise Reduction",
+        "capture_sharpening" => "Sharpening",
+        "exposure_ev" => "Exposure",
+        "contrast" => "Contrast",
+        "highlights" => "Highlights",
+        "shadows" => "Shadows",
+        "whites" => "Whites",
+        "blacks" => "Blacks",
+        "saturation" => "Saturation",
+        "vibrance" => "Vibrance",
+        "tone_curve" => "Tone Curve",
+        "color_mixer" => "Color Mixer",
+        "look" => "Look",
+        "tone_mapping" => "Tone Mapping",
+        "geometry" => "Crop & Rotate",
+        "local_adjustments" => "Masking",
+        "retouch_spots" => "Spot Removal",
+        _ => key,
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// Lens correction settings persisted in an edit recipe.
+///
+/// The toggles apply the matching lens profile's calibrated corrections. The
+/// manual amounts are added on top of the profile, or used alone when no
+/// profile matches. Amounts are normalized to `-1.0..=1.0` with `0.0` as
+/// neutral.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LensCorrection {
+    pub distortion: bool,
+    pub chromatic_aberration: bool,
+    pub vignetting: bool,
+    /// Positive values remove barrel distortion, negative values remove pincushion.
+    pub distortion_amount: f32,
+    /// Scales the red channel radially relative to green.
+    pub chromatic_aberration_red: f32,
+    /// Scales the blue channel radially relative to green.
+    pub chromatic_aberration_blue: f32,
+    /// Positive values brighten the corners, negative values darken them.
+    pub vignetting_amount: f32,
+}
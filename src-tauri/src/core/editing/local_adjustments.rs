@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+
+/// Largest number of local adjustments applied to one image.
+///
+/// Each adjustment's mask occupies one channel of the graph's RGBA mask
+/// texture, so extra entries in a recipe are ignored by the renderer.
+pub const MAX_LOCAL_ADJUSTMENTS: usize = 4;
+
+/// Masked tone and color deltas persisted in an edit recipe.
+///
+/// Deltas use the same ranges as the matching global controls and are applied
+/// on top of the globally adjusted image, weighted by the mask.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LocalAdjustment {
+    pub name: String,
+    pub enabled: bool,
+    pub mask: Mask,
+    /// Tonal and color limits that further restrict `mask`.
+    pub range: MaskRange,
+    pub exposure_ev: f32,
+    pub contrast: f32,
+    /// Warms (positive) or cools (negative) the masked area.
+    pub temperature: f32,
+    /// Shifts the masked area toward magenta (positive) or green (negative).
+    pub tint: f32,
+    pub saturation: f32,
+}
+
+impl Default for LocalAdjustment {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            enabled: true,
+            mask: Mask::default(),
+            range: MaskRange::default(),
+            exposure_ev: 0.0,
+            contrast: 0.0,
+            temperature: 0.0,
+            tint: 0.0,
+            saturation: 0.0,
+        }
+    }
+}
+
+/// Vector mask shape.
+///
+/// Positions are normalized `0.0..=1.0` coordinates in the uncropped,
+/// unrotated source image, so masks stay attached to image content when the
+/// geometry changes. Radii are fractions of the longer source edge.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum Mask {
+    /// Full strength before `start`, fading to nothing past `end`.
+    Linear { start: MaskPoint, end: MaskPoint },
+    /// Elliptical mask, full strength inside and fading over `feather`.
+    Radial {
+        center: MaskPoint,
+        radius_x: f32,
+        radius_y: f32,
+        /// Clockwise ellipse rotation in degrees.
+        rotation_degrees: f32,
+        /// Fraction of the radius used for the falloff, in `0.0..=1.0`.
+        feather: f32,
+        /// Applies the adjustment outside the ellipse instead of inside.
+        invert: bool,
+    },
+    /// Painted strokes applied in order.
+    Brush { strokes: Vec<BrushStroke> },
+}
+
+impl Default for Mask {
+    fn default() -> Self {
+        Self::Radial {
+            center: MaskPoint { x: 0.5, y: 0.5 },
+            radius_x: 0.25,
+            radius_y: 0.25,
+            rotation_degrees: 0.0,
+            feather: 0.5,
+            invert: false,
+        }
+    }
+}
+
+/// Normalized source image position.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaskPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// One painted brush stroke.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BrushStroke {
+    pub points: Vec<MaskPoint>,
+    /// Brush radius as a fraction of the longer source edge.
+    pub radius: f32,
+    /// Fraction of the radius used for the falloff, in `0.0..=1.0`.
+    pub feather: f32,
+    /// Removes mask coverage instead of adding it.
+    pub erase: bool,
+}
+
+impl Default for BrushStroke {
+    fn default() -> Self {
+        Self {
+            points: Vec::new(),
+            radius: 0.02,
+            feather: 0.5,
+            erase: false,
+        }
+    }
+}
+
+/// Range refinements multiplied into a mask.
+///
+/// Ranges are measured on the working-space image before global adjustments,
+/// so they select the same pixels however the image is later graded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MaskRange {
+    pub luminance: Option<LuminanceRange>,
+    pub color: Option<ColorRange>,
+}
+
+/// Keeps pixels whose OkLab lightness falls within `min..=max`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LuminanceRange {
+    pub min: f32,
+    pub max: f32,
+    /// Lightness width of the falloff outside each end of the range.
+    pub feather: f32,
+}
+
+impl Default for LuminanceRange {
+    fn default() -> Self {
+        Self {
+            min: 0.0,
+            max: 1.0,
+            feather: 0.1,
+        }
+    }
+}
+
+/// Keeps pixels close in OkLCh hue and chroma to a sampled color.
+///
+/// The sampled hue and chroma are stored alongside the sample position so a
+/// sidecar reproduces the same selection without re-reading the image.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColorRange {
+    pub sample: MaskPoint,
+    pub hue_degrees: f32,
+    pub chroma: f32,
+    /// Hue distance in degrees at which the selection reaches zero.
+    pub hue_range_degrees: f32,
+    /// Chroma distance at which the selection reaches zero.
+    pub chroma_range: f32,
+    /// Fraction of the range used for the falloff, in `0.0..=1.0`.
+    pub feather: f32,
+}
+
+impl Default for ColorRange {
+    fn default() -> Self {
+        Self {
+            sample: MaskPoint { x: 0.5, y: 0.5 },
+            hue_degrees: 0.0,
+            chroma: 0.0,
+            hue_range_degrees: 30.0,
+            chroma_range: 0.1,
+            feather: 0.5,
+        }
+    }
+}
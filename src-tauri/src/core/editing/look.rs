@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Creative look settings persisted in an edit recipe.
+///
+/// A look is a `.cube` 3D LUT referenced by path. The content hash recorded
+/// when the look was chosen is checked on load so an edited or replaced file
+/// is reported instead of silently changing the image.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Look {
+    pub path: Option<String>,
+    /// BLAKE3 hex digest of the `.cube` file contents.
+    pub hash: Option<String>,
+    pub placement: LookPlacement,
+    pub interpolation: LookInterpolation,
+    /// Blend between the unmodified image at `0.0` and the full look at `1.0`.
+    pub amount: f32,
+}
+
+impl Default for Look {
+    fn default() -> Self {
+        Self {
+            path: None,
+            hash: None,
+            placement: LookPlacement::default(),
+            interpolation: LookInterpolation::default(),
+            amount: 1.0,
+        }
+    }
+}
+
+/// Where in the output transform a look is applied.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LookPlacement {
+    /// Linear Rec.2020 scene values before tone mapping, mapped through the
+    /// LUT domain.
+    Working,
+    /// sRGB-encoded display values after tone mapping and gamut compression.
+    #[default]
+    Display,
+}
+
+/// How the look LUT is sampled between lattice points.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LookInterpolation {
+    Trilinear,
+    #[default]
+    Tetrahedral,
+}
@@ -1,3 +1,32 @@
+mod capture_sharpening;
+mod color_mixer;
+mod geometry;
+mod highlight_reconstruction;
+mod history;
+mod lens_correction;
+mod local_adjustments;
+mod look;
+mod noise_reduction;
 mod recipe;
+mod retouch;
+mod tone_curve;
+mod tone_mapping;
+mod white_balance;
 
+pub use capture_sharpening::CaptureSharpening;
+pub use color_mixer::{ColorBand, ColorMixer, COLOR_BAND_COUNT};
+pub use geometry::{CropAspectRatio, CropRect, Geometry, MAX_STRAIGHTEN_DEGREES};
+pub use highlight_reconstruction::HighlightReconstruction;
+pub use history::{EditHistory, EditHistoryEntry, EditHistorySummary, MAX_HISTORY_ENTRIES};
+pub use lens_correction::LensCorrection;
+pub use local_adjustments::{
+    BrushStroke, ColorRange, LocalAdjustment, LuminanceRange, Mask, MaskPoint, MaskRange,
+    MAX_LOCAL_ADJUSTMENTS,
+};
+pub use look::{Look, LookInterpolation, LookPlacement};
+pub use noise_reduction::NoiseReduction;
 pub use recipe::EditRecipe;
+pub use retouch::{RetouchMode, RetouchSpot, MAX_RETOUCH_SPOTS};
+pub use tone_curve::{CurvePoint, ParametricCurve, ToneCurve};
+pub use tone_mapping::{ToneMapper, ToneMapping};
+pub use white_balance::WhiteBalance;
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Noise reduction settings persisted in an edit recipe.
+///
+/// Applies to RAW sources only. Amounts are offsets from the ISO-derived
+/// default strength, and detail values are offsets from a balanced default,
+/// all normalized to `-1.0..=1.0` with `0.0` keeping the default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NoiseReduction {
+    /// Strength of the edge-aware luminance filter.
+    pub luminance: f32,
+    /// How much fine luminance texture is restored after filtering.
+    pub luminance_detail: f32,
+    /// Strength of the opponent-space chroma filter.
+    pub chroma: f32,
+    /// How much chroma variation is kept as real color detail.
+    pub chroma_detail: f32,
+}
@@ -1,5 +1,43 @@
+use super::capture_sharpening::CaptureSharpening;
+use super::color_mixer::ColorMixer;
+use super::geometry::Geometry;
+use super::highlight_reconstruction::HighlightReconstruction;
+use super::lens_correction::LensCorrection;
+use super::local_adjustments::LocalAdjustment;
+use super::look::Look;
+use super::noise_reduction::NoiseReduction;
+use super::retouch::RetouchSpot;
+use super::tone_curve::ToneCurve;
+use super::tone_mapping::ToneMapping;
+use super::white_balance::WhiteBalance;
+
 /// User-editable processing parameters applied to a canonical pipeline image.
+///
+/// Tone and color controls are normalized to `-1.0..=1.0` with `0.0` as the
+/// neutral value. Missing fields deserialize to neutral so older sidecars load
+/// and render as they did when saved.
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct EditRecipe {
+    pub white_balance: WhiteBalance,
+    #[serde(default = "HighlightReconstruction::unset")]
+    pub highlight_reconstruction: HighlightReconstruction,
+    pub lens_correction: LensCorrection,
+    pub noise_reduction: NoiseReduction,
+    pub capture_sharpening: CaptureSharpening,
     pub exposure_ev: f32,
+    pub contrast: f32,
+    pub highlights: f32,
+    pub shadows: f32,
+    pub whites: f32,
+    pub blacks: f32,
+    pub saturation: f32,
+    pub vibrance: f32,
+    pub tone_curve: ToneCurve,
+    pub color_mixer: ColorMixer,
+    pub look: Look,
+    pub tone_mapping: ToneMapping,
+    pub geometry: Geometry,
+    pub local_adjustments: Vec<LocalAdjustment>,
+    pub retouch_spots: Vec<RetouchSpot>,
 }
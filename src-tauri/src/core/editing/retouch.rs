@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use super::local_adjustments::MaskPoint;
+
+/// Largest number of retouch spots applied to one image.
+///
+/// Spots are uploaded in a fixed-size uniform block, so extra entries in a
+/// recipe are ignored by the renderer.
+pub const MAX_RETOUCH_SPOTS: usize = 64;
+
+/// One spot removal persisted in an edit recipe.
+///
+/// Positions use the same normalized source coordinates as local adjustment
+/// masks, and the radius is a fraction of the longer source edge.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetouchSpot {
+    /// Center of the area copied from.
+    pub source: MaskPoint,
+    /// Center of the area being repaired.
+    pub destination: MaskPoint,
+    pub radius: f32,
+    /// Fraction of the radius used for the falloff, in `0.0..=1.0`.
+    pub feather: f32,
+    pub mode: RetouchMode,
+}
+
+impl Default for RetouchSpot {
+    fn default() -> Self {
+        Self {
+            source: MaskPoint { x: 0.5, y: 0.5 },
+            destination: MaskPoint { x: 0.5, y: 0.5 },
+            radius: 0.02,
+            feather: 0.5,
+            mode: RetouchMode::default(),
+        }
+    }
+}
+
+/// How a retouch spot fills its destination.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetouchMode {
+    /// Copies source pixels unchanged.
+    Clone,
+    /// Copies source detail and blends its tone and color into the
+    /// destination's surroundings.
+    #[default]
+    Heal,
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+/// Tone curve settings persisted in an edit recipe.
+///
+/// Curves operate on a perceptual encoding of the working-space values where
+/// `0.0` is black and `1.0` is diffuse white. The parametric curve is applied
+/// first, then the RGB point curve, then the per-channel point curves. An
+/// empty point list leaves that curve neutral.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ToneCurve {
+    pub parametric: ParametricCurve,
+    pub rgb: Vec<CurvePoint>,
+    pub red: Vec<CurvePoint>,
+    pub green: Vec<CurvePoint>,
+    pub blue: Vec<CurvePoint>,
+}
+
+/// Region-based tone curve with adjustable split points.
+///
+/// Region amounts are normalized to `-1.0..=1.0` with `0.0` as neutral. Split
+/// points are in curve input space and separate shadows from darks, darks from
+/// lights, and lights from highlights.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParametricCurve {
+    pub shadows: f32,
+    pub darks: f32,
+    pub lights: f32,
+    pub highlights: f32,
+    pub shadow_split: f32,
+    pub midtone_split: f32,
+    pub highlight_split: f32,
+}
+
+impl Default for ParametricCurve {
+    fn default() -> Self {
+        Self {
+            shadows: 0.0,
+            darks: 0.0,
+            lights: 0.0,
+            highlights: 0.0,
+            shadow_split: 0.25,
+            midtone_split: 0.5,
+            highlight_split: 0.75,
+        }
+    }
+}
+
+/// Point curve control point with both axes in `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CurvePoint {
+    pub x: f32,
+    pub y: f32,
+}
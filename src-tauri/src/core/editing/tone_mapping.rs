@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+/// Scene-to-display tone mapping settings persisted in an edit recipe.
+///
+/// Applies only to sources rendered with the tone-map-to-SDR display intent;
+/// direct SDR sources are already display-referred.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ToneMapping {
+    pub mapper: ToneMapper,
+    /// Filmic midtone contrast in `-1.0..=1.0`; `0.0` is neutral.
+    pub contrast: f32,
+    /// Filmic toe in `-1.0..=1.0`; positive values deepen shadows and negative
+    /// values lift them.
+    pub toe: f32,
+}
+
+/// Curve used to compress scene luminance into display range.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToneMapper {
+    /// Fitted ACES RRT+ODT curve on luminance with a highlight chroma rolloff.
+    #[default]
+    AcesFitted,
+    /// Per-channel log sigmoid in an inset gamut, desaturating bright colors
+    /// toward white instead of skewing their hue.
+    Agx,
+    /// Luminance sigmoid anchored at middle grey with adjustable contrast and toe.
+    Filmic,
+    /// Luminance `x / (1 + x)` compression.
+    Reinhard,
+    /// No compression; values above display white are clipped.
+    Clip,
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// White balance selection persisted in an edit recipe.
+///
+/// Temperature and tint describe the scene illuminant to neutralize. Custom
+/// multipliers are source-space channel gains normalized to green, as produced
+/// by the neutral-patch eyedropper.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum WhiteBalance {
+    #[default]
+    AsShot,
+    Temperature {
+        temperature_k: f32,
+        tint: f32,
+    },
+    Custom {
+        multipliers: [f32; 3],
+    },
+}
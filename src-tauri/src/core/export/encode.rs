@@ -0,0 +1,329 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use avif_serialize::constants::{
+    ColorPrimaries as AvifColorPrimaries, MatrixCoefficients as AvifMatrixCoefficients,
+    TransferCharacteristics as AvifTransferCharacteristics,
+};
+use avif_serialize::Aviffy;
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::openexr::OpenExrEncoder;
+use image::codecs::png::PngEncoder;
+use image::{ExtendedColorType, ImageEncoder};
+use rav1e::prelude::{
+    ChromaSampling, ChromaticityPoint, ColorDescription, ColorPrimaries, Config, ContentLight,
+    Context as Av1Context, EncoderConfig, EncoderStatus, MasteringDisplay, MatrixCoefficients,
+    PixelRange, TransferCharacteristics,
+};
+use tiff::encoder::{colortype, TiffEncoder};
+use tiff::tags::Tag;
+
+use super::icc::srgb_icc_profile;
+use super::settings::ExportFormat;
+use crate::renderer::{RenderedImage, SDR_REFERENCE_WHITE_NITS};
+
+/// Bit depth of the PQ-encoded AVIF color planes.
+const AVIF_BIT_DEPTH: u8 = 10;
+
+/// AV1 quantizer for AVIF export, low enough that gradients in the extended
+/// highlights do not band.
+const AVIF_QUANTIZER: u8 = 40;
+
+/// rav1e speed preset for AVIF export.
+const AVIF_SPEED: u8 = 6;
+
+/// Absolute luminance encoded by a PQ signal of `1.0`.
+const PQ_PEAK_NITS: f32 = 10_000.0;
+
+/// Darkest luminance of the mastering display reported in AVIF metadata.
+const MASTERING_MIN_NITS: f32 = 0.0001;
+
+/// Encodes a rendered image for its export format and writes it to disk.
+///
+/// Rendered texels are linear display-referred values from the output
+/// transform. Integer formats are sRGB-encoded and embed the matching sRGB ICC
+/// profile; EXR keeps the linear extended-range values as written, and AVIF
+/// PQ-encodes them in BT.2020.
+pub fn write_export_image(image: &RenderedImage, format: ExportFormat, path: &Path) -> Result<()> {
+    if image.width() == 0 || image.height() == 0 {
+        return Err(anyhow!("cannot export an empty rendered image"));
+    }
+
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create export file: {}", path.display()))?;
+    let mut writer = BufWriter::new(file);
+
+    match format {
+        ExportFormat::Jpeg { quality } => write_jpeg(&mut writer, image, quality),
+        ExportFormat::Png => write_png(&mut writer, image),
+        ExportFormat::Tiff16 => write_tiff16(&mut writer, image),
+        ExportFormat::Exr { .. } => write_exr(&mut writer, image),
+        ExportFormat::Avif { peak_nits } => write_avif(&mut writer, image, peak_nits),
+    }
+    .with_context(|| format!("Failed to encode export file: {}", path.display()))?;
+
+    writer
+        .flush()
+        .with_context(|| format!("Failed to flush export file: {}", path.display()))
+}
+
+fn write_jpeg<W: Write>(writer: &mut W, image: &RenderedImage, quality: u8) -> Result<()> {
+    let mut encoder = JpegEncoder::new_with_quality(writer, quality.clamp(1, 100));
+    let rgb: Vec<u8> = image
+        .texels()
+        .chunks_exact(4)
+        .flat_map(|texel| {
+            [
+                linear_to_srgb_u8(texel[0]),
+                linear_to_srgb_u8(texel[1]),
+                linear_to_srgb_u8(texel[2]),
+            ]
+        })
+        .collect();
+
+    if let Err(error) = encoder.set_icc_profile(srgb_icc_profile()) {
+        return Err(anyhow!("JPEG encoder rejected ICC profile: {error}"));
+    }
+
+    encoder.write_image(&rgb, image.width(), image.height(), ExtendedColorType::Rgb8)?;
+
+    Ok(())
+}
+
+fn write_png<W: Write>(writer: &mut W, image: &RenderedImage) -> Result<()> {
+    let mut encoder = PngEncoder::new(writer);
+    let rgba: Vec<u8> = image
+        .texels()
+        .chunks_exact(4)
+        .flat_map(|texel| {
+            [
+                linear_to_srgb_u8(texel[0]),
+                linear_to_srgb_u8(texel[1]),
+                linear_to_srgb_u8(texel[2]),
+                unit_to_u8(texel[3]),
+            ]
+        })
+        .collect();
+
+    if let Err(error) = encoder.set_icc_profile(srgb_icc_profile()) {
+        return Err(anyhow!("PNG encoder rejected ICC profile: {error}"));
+    }
+
+    encoder.write_image(
+        &rgba,
+        image.width(),
+        image.height(),
+        ExtendedColorType::Rgba8,
+    )?;
+
+    Ok(())
+}
+
+fn write_tiff16<W: Write + std::io::Seek>(writer: &mut W, image: &RenderedImage) -> Result<()> {
+    let mut encoder = TiffEncoder::new(writer)?;
+    let rgb: Vec<u16> = image
+        .texels()
+        .chunks_exact(4)
+        .flat_map(|texel| {
+            [
+                linear_to_srgb_u16(texel[0]),
+                linear_to_srgb_u16(texel[1]),
+                linear_to_srgb_u16(texel[2]),
+            ]
+        })
+        .collect();
+    let icc_profile = srgb_icc_profile();
+    let mut tiff_image = encoder.new_image::<colortype::RGB16>(image.width(), image.height())?;
+
+    tiff_image
+        .encoder()
+        .write_tag(Tag::IccProfile, icc_profile.as_slice())?;
+    tiff_image.write_data(&rgb)?;
+
+    Ok(())
+}
+
+fn write_exr<W: Write + std::io::Seek>(writer: &mut W, image: &RenderedImage) -> Result<()> {
+    let encoder = OpenExrEncoder::new(writer);
+
+    encoder.write_image(
+        bytemuck::cast_slice(image.texels()),
+        image.width(),
+        image.height(),
+        ExtendedColorType::Rgba32F,
+    )?;
+
+    Ok(())
+}
+
+/// Writes a 10-bit full-range BT.2020 PQ AVIF with CICP 9/16/9.
+///
+/// Extended-range linear Rec.709 texels are placed at SDR reference white
+/// nits, converted to BT.2020 and PQ-encoded up to `peak_nits`. The mastering
+/// display and content light levels travel as AV1 metadata so viewers tone map
+/// from the right peak. Alpha is not written.
+fn write_avif<W: Write>(writer: &mut W, image: &RenderedImage, peak_nits: u32) -> Result<()> {
+    let width = image.width() as usize;
+    let height = image.height() as usize;
+    let peak_nits = (peak_nits as f32).clamp(SDR_REFERENCE_WHITE_NITS, PQ_PEAK_NITS);
+    let mut planes: [Vec<u8>; 3] = std::array::from_fn(|_| Vec::with_capacity(width * height * 2));
+    let mut max_content_light = 0.0_f32;
+    let mut frame_light_sum = 0.0_f64;
+
+    for texel in image.texels().chunks_exact(4) {
+        let nits = linear_rec709_to_rec2020([texel[0], texel[1], texel[2]])
+            .map(|value| (value * SDR_REFERENCE_WHITE_NITS).clamp(0.0, peak_nits));
+        let brightest = nits[0].max(nits[1]).max(nits[2]);
+
+        max_content_light = max_content_light.max(brightest);
+        frame_light_sum += f64::from(brightest);
+
+        let encoded = bt2020_ycbcr(nits.map(pq_encode));
+
+        for (plane, value) in planes.iter_mut().zip(encoded) {
+            plane.extend_from_slice(&unit_to_u10(value).to_le_bytes());
+        }
+    }
+
+    let max_frame_average_light = frame_light_sum / (width * height) as f64;
+    let config = Config::new().with_encoder_config(EncoderConfig {
+        width,
+        height,
+        bit_depth: usize::from(AVIF_BIT_DEPTH),
+        chroma_sampling: ChromaSampling::Cs444,
+        pixel_range: PixelRange::Full,
+        color_description: Some(ColorDescription {
+            color_primaries: ColorPrimaries::BT2020,
+            transfer_characteristics: TransferCharacteristics::SMPTE2084,
+            matrix_coefficients: MatrixCoefficients::BT2020NCL,
+        }),
+        mastering_display: Some(bt2020_mastering_display(peak_nits)),
+        content_light: Some(ContentLight {
+            max_content_light_level: max_content_light.ceil() as u16,
+            max_frame_average_light_level: max_frame_average_light.ceil() as u16,
+        }),
+        still_picture: true,
+        quantizer: usize::from(AVIF_QUANTIZER),
+        min_quantizer: AVIF_QUANTIZER,
+        ..EncoderConfig::with_speed_preset(AVIF_SPEED)
+    });
+    let mut context: Av1Context<u16> = config.new_context()?;
+    let mut frame = context.new_frame();
+
+    for (plane, data) in frame.planes.iter_mut().zip(&planes) {
+        plane.copy_from_raw_u8(data, width * 2, 2);
+    }
+
+    context.send_frame(frame)?;
+    context.flush();
+
+    let mut color = Vec::new();
+
+    loop {
+        match context.receive_packet() {
+            Ok(mut packet) => color.append(&mut packet.data),
+            Err(EncoderStatus::Encoded) => continue,
+            Err(EncoderStatus::LimitReached) => break,
+            Err(error) => return Err(anyhow!("AV1 encoder failed: {error}")),
+        }
+    }
+
+    Aviffy::new()
+        .set_color_primaries(AvifColorPrimaries::Bt2020)
+        .set_transfer_characteristics(AvifTransferCharacteristics::Smpte2084)
+        .set_matrix_coefficients(AvifMatrixCoefficients::Bt2020Ncl)
+        .set_full_color_range(true)
+        .write(
+            writer,
+            &color,
+            None,
+            image.width(),
+            image.height(),
+            AVIF_BIT_DEPTH,
+        )?;
+
+    Ok(())
+}
+
+/// Describes a BT.2020 D65 mastering display with the export peak, using the
+/// AV1 fixed-point encodings.
+fn bt2020_mastering_display(peak_nits: f32) -> MasteringDisplay {
+    let point = |x: f32, y: f32| ChromaticityPoint {
+        x: (x * 65536.0).round() as u16,
+        y: (y * 65536.0).round() as u16,
+    };
+
+    MasteringDisplay {
+        primaries: [
+            point(0.708, 0.292),
+            point(0.170, 0.797),
+            point(0.131, 0.046),
+        ],
+        white_point: point(0.3127, 0.3290),
+        max_luminance: (peak_nits * 256.0).round() as u32,
+        min_luminance: (MASTERING_MIN_NITS * 16384.0).round() as u32,
+    }
+}
+
+fn linear_rec709_to_rec2020(color: [f32; 3]) -> [f32; 3] {
+    let [red, green, blue] = color;
+
+    [
+        (0.6274 * red) + (0.3293 * green) + (0.0433 * blue),
+        (0.0691 * red) + (0.9195 * green) + (0.0114 * blue),
+        (0.0164 * red) + (0.0880 * green) + (0.8956 * blue),
+    ]
+}
+
+/// Applies the SMPTE ST 2084 (PQ) inverse EOTF to absolute luminance in nits.
+fn pq_encode(nits: f32) -> f32 {
+    let m1 = 0.159_301_757_812_5;
+    let m2 = 78.843_75;
+    let c1 = 0.835_937_5;
+    let c2 = 18.851_562_5;
+    let c3 = 18.687_5;
+    let luminance = (nits / PQ_PEAK_NITS).clamp(0.0, 1.0).powf(m1);
+
+    ((c1 + (c2 * luminance)) / (1.0 + (c3 * luminance))).powf(m2)
+}
+
+/// Converts non-linear BT.2020 R'G'B' into full-range Y'CbCr with chroma
+/// centred on `0.5`.
+fn bt2020_ycbcr(color: [f32; 3]) -> [f32; 3] {
+    let [red, green, blue] = color;
+    let luma = (0.2627 * red) + (0.6780 * green) + (0.0593 * blue);
+
+    [
+        luma,
+        ((blue - luma) / 1.8814) + 0.5,
+        ((red - luma) / 1.4746) + 0.5,
+    ]
+}
+
+fn unit_to_u10(value: f32) -> u16 {
+    ((value.clamp(0.0, 1.0) * 1023.0) + 0.5) as u16
+}
+
+fn srgb_encode(value: f32) -> f32 {
+    let value = value.clamp(0.0, 1.0);
+
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        (1.055 * value.powf(1.0 / 2.4)) - 0.055
+    }
+}
+
+fn linear_to_srgb_u8(value: f32) -> u8 {
+    unit_to_u8(srgb_encode(value))
+}
+
+fn linear_to_srgb_u16(value: f32) -> u16 {
+    ((srgb_encode(value).clamp(0.0, 1.0) * 65535.0) + 0.5) as u16
+}
+
+fn unit_to_u8(value: f32) -> u8 {
+    ((value.clamp(0.0, 1.0) * 255.0) + 0.5) as u8
+}
@@ -0,0 +1,179 @@
+/// ICC profile header size in bytes.
+const HEADER_SIZE: usize = 128;
+
+/// Number of entries used for sampled tone-response curves.
+const TRC_ENTRY_COUNT: usize = 1024;
+
+/// CIE D50 profile connection space white in XYZ.
+const D50_WHITE: [f64; 3] = [0.9642, 1.0, 0.8249];
+
+/// Bradford-adapted sRGB primaries relative to the D50 connection space.
+///
+/// Columns are the red, green, and blue colorant XYZ values written to the
+/// `rXYZ`, `gXYZ`, and `bXYZ` tags.
+#[allow(clippy::excessive_precision)]
+const SRGB_COLORANTS_D50: [[f64; 3]; 3] = [
+    [0.4360747, 0.2225045, 0.0139322],
+    [0.3850649, 0.7168786, 0.0971045],
+    [0.1430804, 0.0606169, 0.7141733],
+];
+
+/// Builds an ICC v2 display profile describing sRGB-encoded output.
+///
+/// Exported files are encoded with the sRGB transfer function after the output
+/// transform, so the embedded profile uses the same primaries and a sampled
+/// sRGB curve. Version 2 keeps the profile readable by older tools.
+pub fn srgb_icc_profile() -> Vec<u8> {
+    build_rgb_display_profile("Extents sRGB", SRGB_COLORANTS_D50, srgb_decode)
+}
+
+/// Serializes a matrix/TRC RGB display profile.
+fn build_rgb_display_profile(
+    description: &str,
+    colorants: [[f64; 3]; 3],
+    decode: fn(f64) -> f64,
+) -> Vec<u8> {
+    let trc = curve_tag(decode);
+    let tags: Vec<(&[u8; 4], Vec<u8>)> = vec![
+        (b"desc", text_description_tag(description)),
+        (b"cprt", text_tag("No copyright, use freely")),
+        (b"wtpt", xyz_tag(D50_WHITE)),
+        (b"rXYZ", xyz_tag(colorants[0])),
+        (b"gXYZ", xyz_tag(colorants[1])),
+        (b"bXYZ", xyz_tag(colorants[2])),
+        (b"rTRC", trc.clone()),
+        (b"gTRC", trc.clone()),
+        (b"bTRC", trc),
+    ];
+
+    let tag_table_size = 4 + (tags.len() * 12);
+    let mut tag_table = Vec::with_capacity(tag_table_size);
+    let mut tag_data = Vec::new();
+    let mut offset = HEADER_SIZE + tag_table_size;
+
+    tag_table.extend_from_slice(&(tags.len() as u32).to_be_bytes());
+
+    for (signature, data) in &tags {
+        tag_table.extend_from_slice(*signature);
+        tag_table.extend_from_slice(&(offset as u32).to_be_bytes());
+        tag_table.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+        tag_data.extend_from_slice(data);
+
+        while tag_data.len() % 4 != 0 {
+            tag_data.push(0);
+        }
+
+        offset = HEADER_SIZE + tag_table_size + tag_data.len();
+    }
+
+    let profile_size = HEADER_SIZE + tag_table.len() + tag_data.len();
+    let mut profile = profile_header(profile_size as u32);
+
+    profile.extend_from_slice(&tag_table);
+    profile.extend_from_slice(&tag_data);
+
+    profile
+}
+
+fn profile_header(profile_size: u32) -> Vec<u8> {
+    let mut header = Vec::with_capacity(HEADER_SIZE);
+
+    header.extend_from_slice(&profile_size.to_be_bytes());
+    header.extend_from_slice(&[0; 4]);
+    header.extend_from_slice(&0x0210_0000u32.to_be_bytes());
+    header.extend_from_slice(b"mntr");
+    header.extend_from_slice(b"RGB ");
+    header.extend_from_slice(b"XYZ ");
+
+    for date_part in [2026u16, 1, 1, 0, 0, 0] {
+        header.extend_from_slice(&date_part.to_be_bytes());
+    }
+
+    header.extend_from_slice(b"acsp");
+    header.extend_from_slice(&[0; 4]);
+    header.extend_from_slice(&[0; 4]);
+    header.extend_from_slice(&[0; 4]);
+    header.extend_from_slice(&[0; 4]);
+    header.extend_from_slice(&[0; 8]);
+    header.extend_from_slice(&0u32.to_be_bytes());
+
+    for component in D50_WHITE {
+        header.extend_from_slice(&s15_fixed16(component).to_be_bytes());
+    }
+
+    header.extend_from_slice(&[0; 4]);
+    header.resize(HEADER_SIZE, 0);
+
+    header
+}
+
+fn text_description_tag(text: &str) -> Vec<u8> {
+    let mut data = Vec::new();
+
+    data.extend_from_slice(b"desc");
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&((text.len() + 1) as u32).to_be_bytes());
+    data.extend_from_slice(text.as_bytes());
+    data.push(0);
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&[0; 2]);
+    data.push(0);
+    data.extend_from_slice(&[0; 67]);
+
+    data
+}
+
+fn text_tag(text: &str) -> Vec<u8> {
+    let mut data = Vec::new();
+
+    data.extend_from_slice(b"text");
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(text.as_bytes());
+    data.push(0);
+
+    data
+}
+
+fn xyz_tag(xyz: [f64; 3]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(20);
+
+    data.extend_from_slice(b"XYZ ");
+    data.extend_from_slice(&[0; 4]);
+
+    for component in xyz {
+        data.extend_from_slice(&s15_fixed16(component).to_be_bytes());
+    }
+
+    data
+}
+
+fn curve_tag(decode: fn(f64) -> f64) -> Vec<u8> {
+    let mut data = Vec::with_capacity(12 + (TRC_ENTRY_COUNT * 2));
+
+    data.extend_from_slice(b"curv");
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&(TRC_ENTRY_COUNT as u32).to_be_bytes());
+
+    for index in 0..TRC_ENTRY_COUNT {
+        let encoded = index as f64 / (TRC_ENTRY_COUNT - 1) as f64;
+        let linear = decode(encoded).clamp(0.0, 1.0);
+
+        data.extend_from_slice(&((linear * 65535.0).round() as u16).to_be_bytes());
+    }
+
+    data
+}
+
+fn s15_fixed16(value: f64) -> i32 {
+    (value * 65536.0).round() as i32
+}
+
+fn srgb_decode(encoded: f64) -> f64 {
+    if encoded <= 0.04045 {
+        encoded / 12.92
+    } else {
+        ((encoded + 0.055) / 1.055).powf(2.4)
+    }
+}
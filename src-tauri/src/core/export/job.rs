@@ -0,0 +1,177 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use log::{info, warn};
+use serde::Serialize;
+
+use super::encode::write_export_image;
+use super::settings::ExportFormat;
+use crate::core::db::connection::DbConnection;
+use crate::core::editing::EditRecipe;
+use crate::core::sidecar::load_sidecar;
+use crate::renderer::OffscreenRenderer;
+
+/// Source files and output settings for one export run.
+pub struct ExportJobInput {
+    pub paths: Vec<String>,
+    pub output_dir: PathBuf,
+    pub format: ExportFormat,
+    /// Recipe applied to every source instead of each file's own sidecar.
+    pub recipe_override: Option<EditRecipe>,
+    /// Database used to look up per-camera bad pixel maps.
+    pub db: Option<DbConnection>,
+}
+
+/// Per-file export result reported while a job is running.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportProgress {
+    pub index: usize,
+    pub total: usize,
+    pub source_path: String,
+    pub output_path: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Final counts for a completed export run.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSummary {
+    pub exported: usize,
+    pub failed: usize,
+}
+
+/// Renders and writes every source in an export job.
+///
+/// Each file is decoded, developed with its sidecar recipe (or the job's
+/// recipe override) on an offscreen processing graph, and encoded at full
+/// resolution. A failure for one file is reported through `on_progress` and
+/// does not stop the remaining files.
+pub fn run_export_job(
+    input: ExportJobInput,
+    mut on_progress: impl FnMut(&ExportProgress),
+) -> Result<ExportSummary> {
+    fs::create_dir_all(&input.output_dir).with_context(|| {
+        format!(
+            "Failed to create export directory {}",
+            input.output_dir.display()
+        )
+    })?;
+
+    let mut renderer = match OffscreenRenderer::new(input.db.clone()) {
+        Ok(renderer) => renderer,
+        Err(error) => return Err(error),
+    };
+
+    info!(
+        "[export] Exporting {} file(s) to {} using {}",
+        input.paths.len(),
+        input.output_dir.display(),
+        renderer.adapter_name()
+    );
+
+    let total = input.paths.len();
+    let mut summary = ExportSummary::default();
+
+    for (index, source_path) in input.paths.iter().enumerate() {
+        let result = export_one(
+            &mut renderer,
+            source_path,
+            &input.output_dir,
+            input.format,
+            input.recipe_override.as_ref(),
+        );
+
+        let progress = match result {
+            Ok(output_path) => {
+                summary.exported += 1;
+
+                ExportProgress {
+                    index,
+                    total,
+                    source_path: source_path.clone(),
+                    output_path: Some(output_path.to_string_lossy().to_string()),
+                    error: None,
+                }
+            }
+            Err(error) => {
+                warn!("[export] Failed to export {}: {:#}", source_path, error);
+                summary.failed += 1;
+
+                ExportProgress {
+                    index,
+                    total,
+                    source_path: source_path.clone(),
+                    output_path: None,
+                    error: Some(format!("{error:#}")),
+                }
+            }
+        };
+
+        on_progress(&progress);
+    }
+
+    Ok(summary)
+}
+
+fn export_one(
+    renderer: &mut OffscreenRenderer,
+    source_path: &str,
+    output_dir: &Path,
+    format: ExportFormat,
+    recipe_override: Option<&EditRecipe>,
+) -> Result<PathBuf> {
+    let recipe = match recipe_override {
+        Some(recipe) => recipe.clone(),
+        None => match load_recipe(source_path) {
+            Ok(recipe) => recipe,
+            Err(error) => return Err(error),
+        },
+    };
+
+    let rendered = match renderer.render_path(source_path, &recipe, format.display_output()) {
+        Ok(rendered) => rendered,
+        Err(error) => return Err(error),
+    };
+
+    let output_path = match unique_output_path(source_path, output_dir, format) {
+        Ok(output_path) => output_path,
+        Err(error) => return Err(error),
+    };
+
+    match write_export_image(&rendered, format, &output_path) {
+        Ok(()) => Ok(output_path),
+        Err(error) => Err(error),
+    }
+}
+
+fn load_recipe(source_path: &str) -> Result<EditRecipe> {
+    match load_sidecar(source_path) {
+        Ok(sidecar) => Ok(sidecar.recipe().clone()),
+        Err(error) => Err(anyhow!(error.to_string())),
+    }
+}
+
+/// Builds an output path from the source stem without overwriting existing files.
+fn unique_output_path(
+    source_path: &str,
+    output_dir: &Path,
+    format: ExportFormat,
+) -> Result<PathBuf> {
+    let stem = match Path::new(source_path).file_stem() {
+        Some(stem) => stem.to_string_lossy().to_string(),
+        None => return Err(anyhow!("Export source has no file name: {}", source_path)),
+    };
+
+    let extension = format.extension();
+    let mut candidate = output_dir.join(format!("{stem}.{extension}"));
+    let mut suffix = 1;
+
+    while candidate.exists() {
+        candidate = output_dir.join(format!("{stem}-{suffix}.{extension}"));
+        suffix += 1;
+    }
+
+    Ok(candidate)
+}
@@ -0,0 +1,9 @@
+mod encode;
+mod icc;
+mod job;
+mod settings;
+
+pub use encode::write_export_image;
+pub use icc::srgb_icc_profile;
+pub use job::{run_export_job, ExportJobInput, ExportProgress, ExportSummary};
+pub use settings::{ExportFormat, DEFAULT_JPEG_QUALITY};
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+use crate::renderer::{DisplayOutput, DEFAULT_HDR_PEAK_NITS};
+
+/// JPEG quality used when a request does not specify one.
+pub const DEFAULT_JPEG_QUALITY: u8 = 92;
+
+/// File format and encoding options for exported images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ExportFormat {
+    /// 8-bit baseline JPEG with a 1-100 quality setting.
+    Jpeg {
+        #[serde(default = "default_jpeg_quality")]
+        quality: u8,
+    },
+    /// 8-bit RGBA PNG.
+    Png,
+    /// 16-bit-per-channel RGB TIFF.
+    Tiff16,
+    /// 32-bit float linear RGBA OpenEXR rendered for an HDR display peak.
+    ///
+    /// Values are extended-range linear Rec.709 where `1.0` is SDR reference
+    /// white, so highlights above SDR white survive up to `peak_nits`.
+    Exr {
+        #[serde(default = "default_hdr_peak_nits")]
+        peak_nits: u32,
+    },
+    /// 10-bit PQ-encoded BT.2020 AVIF rendered for an HDR display peak.
+    ///
+    /// Tagged with CICP 9/16/9 and carries mastering display and content
+    /// light metadata for `peak_nits`, so HDR viewers show it at that peak.
+    Avif {
+        #[serde(default = "default_hdr_peak_nits")]
+        peak_nits: u32,
+    },
+}
+
+impl ExportFormat {
+    /// Returns the file extension written for this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Jpeg { .. } => "jpg",
+            Self::Png => "png",
+            Self::Tiff16 => "tif",
+            Self::Exr { .. } => "exr",
+            Self::Avif { .. } => "avif",
+        }
+    }
+
+    /// Returns the MIME type written for this format.
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Jpeg { .. } => "image/jpeg",
+            Self::Png => "image/png",
+            Self::Tiff16 => "image/tiff",
+            Self::Exr { .. } => "image/x-exr",
+            Self::Avif { .. } => "image/avif",
+        }
+    }
+
+    /// Returns the display output the image should be rendered for.
+    pub fn display_output(self) -> DisplayOutput {
+        match self {
+            Self::Exr { peak_nits } | Self::Avif { peak_nits } => DisplayOutput::Hdr {
+                peak_nits: peak_nits as f32,
+            },
+            Self::Jpeg { .. } | Self::Png | Self::Tiff16 => DisplayOutput::Sdr,
+        }
+    }
+}
+
+impl Default for ExportFormat {
+    fn default() -> Self {
+        Self::Jpeg {
+            quality: DEFAULT_JPEG_QUALITY,
+        }
+    }
+}
+
+fn default_jpeg_quality() -> u8 {
+    DEFAULT_JPEG_QUALITY
+}
+
+fn default_hdr_peak_nits() -> u32 {
+    DEFAULT_HDR_PEAK_NITS as u32
+}
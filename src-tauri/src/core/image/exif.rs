@@ -21,6 +21,7 @@ pub struct ExifMetadata {
     pub shutter_speed: Option<f32>,
     pub aperture: Option<f32>,
     pub focal_length: Option<f32>,
+    pub focal_length_35mm: Option<f32>,
     pub exposure_bias: Option<f32>,
     pub white_balance: Option<u16>,
     pub metering_mode: Option<u16>,
@@ -73,6 +74,7 @@ fn extract_raw_exif_metadata(path: &str) -> Result<ExifMetadata> {
     exif.shutter_speed = metadata.exif.exposure_time.map(rational_to_f32);
     exif.aperture = metadata.exif.fnumber.map(rational_to_f32);
     exif.focal_length = metadata.exif.focal_length.map(rational_to_f32);
+    exif.focal_length_35mm = read_focal_length_35mm_from_path(path);
     exif.exposure_bias = metadata.exif.exposure_bias.map(srational_to_f32);
     exif.white_balance = metadata.exif.white_balance;
     exif.metering_mode = metadata.exif.metering_mode;
@@ -125,6 +127,7 @@ fn extract_raster_exif_metadata(path: &str) -> Result<ExifMetadata> {
         metadata.shutter_speed = read_exif_rational(exif, Tag::ExposureTime);
         metadata.aperture = read_exif_rational(exif, Tag::FNumber);
         metadata.focal_length = read_exif_rational(exif, Tag::FocalLength);
+        metadata.focal_length_35mm = read_focal_length_35mm(exif);
         metadata.exposure_bias = read_exif_srational(exif, Tag::ExposureBiasValue);
         metadata.white_balance = read_exif_uint(exif, Tag::WhiteBalance).map(|value| value as u16);
         metadata.metering_mode = read_exif_uint(exif, Tag::MeteringMode).map(|value| value as u16);
@@ -178,6 +181,23 @@ fn read_dimensions_from_exif_path(path: &str) -> Option<(u32, u32)> {
     }
 }
 
+/// Reads the 35mm-equivalent focal length straight from a RAW container,
+/// since the RAW decoder's EXIF block does not carry it.
+fn read_focal_length_35mm_from_path(path: &str) -> Option<f32> {
+    let file = File::open(path).ok()?;
+    let mut reader = BufReader::new(file);
+    let exif = Reader::new().read_from_container(&mut reader).ok()?;
+
+    read_focal_length_35mm(&exif)
+}
+
+/// Returns the 35mm-equivalent focal length, treating `0` as unknown.
+fn read_focal_length_35mm(exif: &exif::Exif) -> Option<f32> {
+    read_exif_uint(exif, Tag::FocalLengthIn35mmFilm)
+        .filter(|value| *value > 0)
+        .map(|value| value as f32)
+}
+
 fn read_raw_dimensions(rawfile: &RawSource, decoder: &dyn Decoder) -> Option<(u32, u32)> {
     let raw_image = decoder
         .raw_image(rawfile, &RawDecodeParams::default(), true)
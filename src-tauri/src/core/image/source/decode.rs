@@ -1,6 +1,10 @@
+use std::path::Path;
+
 use anyhow::{Context, Result};
 use image::RgbaImage;
+use log::warn;
 
+use super::dng_opcodes::{read_dng_opcodes, DngOpcode};
 use super::{ImageSource, RasterSource, RawSource};
 use crate::core::image::decode::is_supported_raw_extension;
 use crate::core::image::orientation::{
@@ -76,12 +80,37 @@ fn decode_raw_source(path: &str) -> Result<RawSource> {
         Err(error) => return Err(error),
     };
 
-    match RawSource::from_raw_image(&raw_image, orientation) {
+    let dng_opcodes = decode_dng_opcodes(path);
+
+    match RawSource::from_raw_image(&raw_image, orientation, dng_opcodes) {
         Ok(source) => Ok(source),
         Err(error) => Err(error),
     }
 }
 
+/// Reads DNG opcode lists, returning no opcodes for other RAW formats.
+///
+/// Opcode lists refine development but are not required to show the image, so
+/// malformed lists are logged and ignored.
+fn decode_dng_opcodes(path: &str) -> Vec<DngOpcode> {
+    let is_dng = Path::new(path)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("dng"));
+
+    if !is_dng {
+        return Vec::new();
+    }
+
+    match read_dng_opcodes(path) {
+        Ok(dng_opcodes) => dng_opcodes,
+        Err(error) => {
+            warn!("Failed to read DNG opcode lists for {path}: {error:#}");
+            Vec::new()
+        }
+    }
+}
+
 /// Decodes a RAW file into rawler's decoded RAW image representation.
 fn decode_raw_file(path: &str) -> Result<rawler::RawImage> {
     let raw_image_result = rawler::decode_file(path);
@@ -0,0 +1,650 @@
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use anyhow::{anyhow, Context, Result};
+
+/// TIFF tag listing the offsets of child image directories.
+const TAG_SUB_IFDS: u16 = 330;
+/// TIFF tag distinguishing full-resolution images from previews.
+const TAG_NEW_SUBFILE_TYPE: u16 = 254;
+/// DNG opcode list applied to the raw image as read from the file.
+const TAG_OPCODE_LIST_1: u16 = 51008;
+/// DNG opcode list applied after linearization and black level subtraction.
+const TAG_OPCODE_LIST_2: u16 = 51009;
+/// DNG opcode list applied after demosaic.
+const TAG_OPCODE_LIST_3: u16 = 51022;
+
+/// Upper bound on directories visited, guarding against cyclic offsets.
+const MAX_IMAGE_DIRECTORIES: usize = 64;
+
+const OPCODE_WARP_RECTILINEAR: u32 = 1;
+const OPCODE_FIX_BAD_PIXELS_CONSTANT: u32 = 4;
+const OPCODE_FIX_BAD_PIXELS_LIST: u32 = 5;
+const OPCODE_GAIN_MAP: u32 = 9;
+
+/// Which DNG processing stage an opcode list belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DngOpcodeStage {
+    /// OpcodeList1: raw sensor values, in full sensor coordinates.
+    Raw,
+    /// OpcodeList2: linearized values, in active-area coordinates.
+    Linearized,
+    /// OpcodeList3: demosaiced values, in active-area coordinates.
+    Demosaiced,
+}
+
+impl DngOpcodeStage {
+    /// Returns the DNG tag name of this stage's opcode list.
+    pub fn list_name(self) -> &'static str {
+        match self {
+            Self::Raw => "OpcodeList1",
+            Self::Linearized => "OpcodeList2",
+            Self::Demosaiced => "OpcodeList3",
+        }
+    }
+}
+
+/// One opcode parsed from a DNG opcode list.
+#[derive(Debug, Clone)]
+pub struct DngOpcode {
+    stage: DngOpcodeStage,
+    optional: bool,
+    operation: DngOperation,
+}
+
+impl DngOpcode {
+    /// Returns the processing stage whose opcode list carried this opcode.
+    pub fn stage(&self) -> DngOpcodeStage {
+        self.stage
+    }
+
+    /// Returns whether the file marks this opcode as safe to skip.
+    pub fn optional(&self) -> bool {
+        self.optional
+    }
+
+    /// Returns the decoded operation.
+    pub fn operation(&self) -> &DngOperation {
+        &self.operation
+    }
+
+    /// Returns the DNG specification name of this opcode.
+    pub fn name(&self) -> String {
+        match &self.operation {
+            DngOperation::WarpRectilinear(_) => "WarpRectilinear".to_string(),
+            DngOperation::FixBadPixelsConstant { .. } => "FixBadPixelsConstant".to_string(),
+            DngOperation::FixBadPixelsList { .. } => "FixBadPixelsList".to_string(),
+            DngOperation::GainMap(_) => "GainMap".to_string(),
+            DngOperation::Unsupported { id } => format!("Opcode {id}"),
+        }
+    }
+}
+
+/// Decoded parameters of a DNG opcode.
+#[derive(Debug, Clone)]
+pub enum DngOperation {
+    WarpRectilinear(DngWarpRectilinear),
+    /// Photosites whose raw value equals `constant` are defective.
+    FixBadPixelsConstant {
+        constant: u32,
+    },
+    /// Listed photosites and rectangles are defective.
+    FixBadPixelsList {
+        points: Vec<DngPoint>,
+        rects: Vec<DngRect>,
+    },
+    GainMap(DngGainMap),
+    /// An opcode this renderer does not implement.
+    Unsupported {
+        id: u32,
+    },
+}
+
+/// A pixel position in opcode stage coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DngPoint {
+    pub row: u32,
+    pub column: u32,
+}
+
+/// A half-open rectangle in opcode stage coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DngRect {
+    pub top: u32,
+    pub left: u32,
+    pub bottom: u32,
+    pub right: u32,
+}
+
+/// Radial and tangential distortion coefficients for one image plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DngWarpPlane {
+    pub radial: [f64; 4],
+    pub tangential: [f64; 2],
+}
+
+/// WarpRectilinear parameters.
+///
+/// The optical center is relative to the stage image, where `(0, 0)` is the
+/// top-left corner and `(1, 1)` the bottom-right.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DngWarpRectilinear {
+    pub planes: Vec<DngWarpPlane>,
+    pub center: [f64; 2],
+}
+
+/// GainMap parameters: a grid of gains applied to photosites in `area`.
+///
+/// Map points are placed at relative image positions starting at `origin`
+/// and stepping by `spacing`, in `(vertical, horizontal)` order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DngGainMap {
+    pub area: DngRect,
+    pub plane: u32,
+    pub planes: u32,
+    pub row_pitch: u32,
+    pub column_pitch: u32,
+    pub points: [u32; 2],
+    pub spacing: [f64; 2],
+    pub origin: [f64; 2],
+    pub map_planes: u32,
+    pub gains: Vec<f32>,
+}
+
+impl DngGainMap {
+    /// Returns the bilinearly interpolated gain at a relative image position.
+    pub fn gain_at(&self, relative_row: f64, relative_column: f64, plane: u32) -> f32 {
+        let map_plane = plane.min(self.map_planes.saturating_sub(1)) as usize;
+        let map_row = map_coordinate(
+            relative_row,
+            self.origin[0],
+            self.spacing[0],
+            self.points[0],
+        );
+        let map_column = map_coordinate(
+            relative_column,
+            self.origin[1],
+            self.spacing[1],
+            self.points[1],
+        );
+
+        let row_0 = map_row.floor() as usize;
+        let column_0 = map_column.floor() as usize;
+        let row_1 = (row_0 + 1).min(self.points[0] as usize - 1);
+        let column_1 = (column_0 + 1).min(self.points[1] as usize - 1);
+        let row_weight = (map_row - row_0 as f64) as f32;
+        let column_weight = (map_column - column_0 as f64) as f32;
+
+        let top = lerp(
+            self.gain(row_0, column_0, map_plane),
+            self.gain(row_0, column_1, map_plane),
+            column_weight,
+        );
+        let bottom = lerp(
+            self.gain(row_1, column_0, map_plane),
+            self.gain(row_1, column_1, map_plane),
+            column_weight,
+        );
+
+        lerp(top, bottom, row_weight)
+    }
+
+    fn gain(&self, row: usize, column: usize, plane: usize) -> f32 {
+        let index = (((row * self.points[1] as usize) + column) * self.map_planes as usize) + plane;
+
+        self.gains[index]
+    }
+}
+
+/// Reads and parses the opcode lists of a DNG file's main raw image.
+///
+/// Returns an empty list for files without opcode lists. Opcodes are returned
+/// in list order, so callers can apply them in the order DNG specifies.
+pub fn read_dng_opcodes(path: &str) -> Result<Vec<DngOpcode>> {
+    let file = match File::open(path)
+        .with_context(|| format!("Failed to open DNG file for opcode lists: {path}"))
+    {
+        Ok(file) => file,
+        Err(error) => return Err(error),
+    };
+
+    let mut reader = match TiffReader::new(BufReader::new(file)) {
+        Ok(reader) => reader,
+        Err(error) => return Err(error),
+    };
+
+    let Some(entries) = (match reader.find_main_image_directory() {
+        Ok(entries) => entries,
+        Err(error) => return Err(error),
+    }) else {
+        return Ok(Vec::new());
+    };
+
+    let mut opcodes = Vec::new();
+
+    for (tag, stage) in [
+        (TAG_OPCODE_LIST_1, DngOpcodeStage::Raw),
+        (TAG_OPCODE_LIST_2, DngOpcodeStage::Linearized),
+        (TAG_OPCODE_LIST_3, DngOpcodeStage::Demosaiced),
+    ] {
+        let Some(entry) = entries.iter().find(|entry| entry.tag == tag) else {
+            continue;
+        };
+
+        let bytes = match reader.entry_bytes(entry) {
+            Ok(bytes) => bytes,
+            Err(error) => return Err(error),
+        };
+
+        match parse_opcode_list(&bytes, stage) {
+            Ok(list) => opcodes.extend(list),
+            Err(error) => return Err(error.context(format!("invalid DNG {}", stage.list_name()))),
+        }
+    }
+
+    Ok(opcodes)
+}
+
+/// Parses one big-endian DNG opcode list.
+fn parse_opcode_list(bytes: &[u8], stage: DngOpcodeStage) -> Result<Vec<DngOpcode>> {
+    let mut cursor = OpcodeCursor::new(bytes);
+    let count = match cursor.u32() {
+        Ok(count) => count,
+        Err(error) => return Err(error),
+    };
+    let mut opcodes = Vec::new();
+
+    for _ in 0..count {
+        let id = cursor.u32()?;
+        let _dng_version = cursor.u32()?;
+        let flags = cursor.u32()?;
+        let parameter_bytes = cursor.u32()? as usize;
+        let parameters = cursor.bytes(parameter_bytes)?;
+
+        let operation = match parse_operation(id, parameters) {
+            Ok(operation) => operation,
+            Err(error) => return Err(error),
+        };
+
+        opcodes.push(DngOpcode {
+            stage,
+            optional: flags & 1 != 0,
+            operation,
+        });
+    }
+
+    Ok(opcodes)
+}
+
+fn parse_operation(id: u32, parameters: &[u8]) -> Result<DngOperation> {
+    let mut cursor = OpcodeCursor::new(parameters);
+
+    match id {
+        OPCODE_WARP_RECTILINEAR => {
+            let plane_count = cursor.u32()?;
+            let mut planes = Vec::with_capacity(cursor.capacity_for(plane_count, 48)?);
+
+            for _ in 0..plane_count {
+                planes.push(DngWarpPlane {
+                    radial: [cursor.f64()?, cursor.f64()?, cursor.f64()?, cursor.f64()?],
+                    tangential: [cursor.f64()?, cursor.f64()?],
+                });
+            }
+
+            let center = [cursor.f64()?, cursor.f64()?];
+
+            Ok(DngOperation::WarpRectilinear(DngWarpRectilinear {
+                planes,
+                center,
+            }))
+        }
+        OPCODE_FIX_BAD_PIXELS_CONSTANT => {
+            let constant = cursor.u32()?;
+            let _bayer_phase = cursor.u32()?;
+
+            Ok(DngOperation::FixBadPixelsConstant { constant })
+        }
+        OPCODE_FIX_BAD_PIXELS_LIST => {
+            let _bayer_phase = cursor.u32()?;
+            let point_count = cursor.u32()?;
+            let rect_count = cursor.u32()?;
+            let mut points = Vec::with_capacity(cursor.capacity_for(point_count, 8)?);
+            let mut rects = Vec::with_capacity(cursor.capacity_for(rect_count, 16)?);
+
+            for _ in 0..point_count {
+                points.push(DngPoint {
+                    row: cursor.u32()?,
+                    column: cursor.u32()?,
+                });
+            }
+
+            for _ in 0..rect_count {
+                rects.push(cursor.rect()?);
+            }
+
+            Ok(DngOperation::FixBadPixelsList { points, rects })
+        }
+        OPCODE_GAIN_MAP => {
+            let area = cursor.rect()?;
+            let plane = cursor.u32()?;
+            let planes = cursor.u32()?;
+            let row_pitch = cursor.u32()?;
+            let column_pitch = cursor.u32()?;
+            let points = [cursor.u32()?, cursor.u32()?];
+            let spacing = [cursor.f64()?, cursor.f64()?];
+            let origin = [cursor.f64()?, cursor.f64()?];
+            let map_planes = cursor.u32()?;
+
+            if points[0] == 0 || points[1] == 0 || map_planes == 0 {
+                return Err(anyhow!("GainMap has an empty gain grid"));
+            }
+
+            let gain_count = u64::from(points[0]) * u64::from(points[1]) * u64::from(map_planes);
+            let mut gains = Vec::with_capacity(cursor.capacity_for(gain_count, 4)?);
+
+            for _ in 0..gain_count {
+                gains.push(cursor.f32()?);
+            }
+
+            Ok(DngOperation::GainMap(DngGainMap {
+                area,
+                plane,
+                planes,
+                row_pitch: row_pitch.max(1),
+                column_pitch: column_pitch.max(1),
+                points,
+                spacing,
+                origin,
+                map_planes,
+                gains,
+            }))
+        }
+        id => Ok(DngOperation::Unsupported { id }),
+    }
+}
+
+/// Maps a relative image position onto a clamped fractional gain map index.
+fn map_coordinate(relative: f64, origin: f64, spacing: f64, points: u32) -> f64 {
+    let index = if spacing > 0.0 {
+        (relative - origin) / spacing
+    } else {
+        0.0
+    };
+
+    index.clamp(0.0, f64::from(points - 1))
+}
+
+fn lerp(from: f32, to: f32, weight: f32) -> f32 {
+    from + ((to - from) * weight)
+}
+
+/// Big-endian reader over opcode list bytes.
+struct OpcodeCursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> OpcodeCursor<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8]> {
+        let end = match self.position.checked_add(length) {
+            Some(end) if end <= self.bytes.len() => end,
+            _ => return Err(anyhow!("opcode data ends early")),
+        };
+        let bytes = &self.bytes[self.position..end];
+
+        self.position = end;
+
+        Ok(bytes)
+    }
+
+    /// Returns `count` as a vector capacity after checking that that many
+    /// `item_size`-byte items fit in the remaining data, so untrusted counts
+    /// cannot request huge allocations.
+    fn capacity_for(&self, count: impl Into<u64>, item_size: u64) -> Result<usize> {
+        let count = count.into();
+        let remaining = (self.bytes.len() - self.position) as u64;
+
+        match count.checked_mul(item_size) {
+            Some(length) if length <= remaining => Ok(count as usize),
+            _ => Err(anyhow!("opcode data ends early")),
+        }
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let bytes = self.bytes(4)?;
+
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn f32(&mut self) -> Result<f32> {
+        self.u32().map(f32::from_bits)
+    }
+
+    fn f64(&mut self) -> Result<f64> {
+        let bytes = self.bytes(8)?;
+        let mut value = [0; 8];
+
+        value.copy_from_slice(bytes);
+
+        Ok(f64::from_be_bytes(value))
+    }
+
+    fn rect(&mut self) -> Result<DngRect> {
+        Ok(DngRect {
+            top: self.u32()?,
+            left: self.u32()?,
+            bottom: self.u32()?,
+            right: self.u32()?,
+        })
+    }
+}
+
+/// One TIFF directory entry with its value or value offset still encoded.
+#[derive(Debug, Clone, Copy)]
+struct IfdEntry {
+    tag: u16,
+    field_type: u16,
+    count: u32,
+    value: [u8; 4],
+}
+
+/// Minimal TIFF directory reader for locating DNG opcode lists.
+struct TiffReader<R> {
+    reader: R,
+    big_endian: bool,
+    length: u64,
+}
+
+impl<R: Read + Seek> TiffReader<R> {
+    fn new(mut reader: R) -> Result<Self> {
+        let mut byte_order = [0; 2];
+
+        if let Err(error) = reader.read_exact(&mut byte_order) {
+            return Err(anyhow!("failed to read TIFF header: {error}"));
+        }
+
+        let big_endian = match &byte_order {
+            b"II" => false,
+            b"MM" => true,
+            _ => return Err(anyhow!("file is not a TIFF container")),
+        };
+        let length = match reader.seek(SeekFrom::End(0)) {
+            Ok(length) => length,
+            Err(error) => return Err(anyhow!("failed to seek TIFF data: {error}")),
+        };
+
+        Ok(Self {
+            reader,
+            big_endian,
+            length,
+        })
+    }
+
+    /// Returns the entries of the first full-resolution image directory.
+    ///
+    /// DNGs store the raw image either in IFD0 or in a SubIFD under a preview,
+    /// so directories are visited depth-first in file order.
+    fn find_main_image_directory(&mut self) -> Result<Option<Vec<IfdEntry>>> {
+        let first_offset = self.read_u32_at(4)?;
+        let mut pending = vec![first_offset];
+        let mut visited = 0;
+
+        while let Some(offset) = pending.pop() {
+            if offset == 0 || visited >= MAX_IMAGE_DIRECTORIES {
+                continue;
+            }
+
+            visited += 1;
+
+            let (entries, next_offset) = self.read_directory(offset)?;
+            let is_main_image = match entries
+                .iter()
+                .find(|entry| entry.tag == TAG_NEW_SUBFILE_TYPE)
+            {
+                Some(entry) => self.entry_u32(entry, 0)? == 0,
+                None => true,
+            };
+            let has_opcodes = entries.iter().any(|entry| {
+                matches!(
+                    entry.tag,
+                    TAG_OPCODE_LIST_1 | TAG_OPCODE_LIST_2 | TAG_OPCODE_LIST_3
+                )
+            });
+
+            if is_main_image && has_opcodes {
+                return Ok(Some(entries));
+            }
+
+            pending.push(next_offset);
+
+            if let Some(sub_ifds) = entries.iter().find(|entry| entry.tag == TAG_SUB_IFDS) {
+                for index in (0..sub_ifds.count).rev() {
+                    pending.push(self.entry_u32(sub_ifds, index)?);
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn read_directory(&mut self, offset: u32) -> Result<(Vec<IfdEntry>, u32)> {
+        let entry_count = self.read_u16_at(u64::from(offset))?;
+        let mut entries = Vec::with_capacity(entry_count as usize);
+
+        for index in 0..u64::from(entry_count) {
+            let entry_offset = u64::from(offset) + 2 + (index * 12);
+            let mut bytes = [0; 12];
+
+            self.read_exact_at(entry_offset, &mut bytes)?;
+
+            entries.push(IfdEntry {
+                tag: self.decode_u16([bytes[0], bytes[1]]),
+                field_type: self.decode_u16([bytes[2], bytes[3]]),
+                count: self.decode_u32([bytes[4], bytes[5], bytes[6], bytes[7]]),
+                value: [bytes[8], bytes[9], bytes[10], bytes[11]],
+            });
+        }
+
+        let next_offset =
+            self.read_u32_at(u64::from(offset) + 2 + (u64::from(entry_count) * 12))?;
+
+        Ok((entries, next_offset))
+    }
+
+    /// Returns the raw value bytes of an entry, following its offset when needed.
+    ///
+    /// Values that would extend past the end of the file are rejected before
+    /// anything is allocated for them.
+    fn entry_bytes(&mut self, entry: &IfdEntry) -> Result<Vec<u8>> {
+        let length = field_type_size(entry.field_type) as u64 * u64::from(entry.count);
+
+        if length <= 4 {
+            return Ok(entry.value[..length as usize].to_vec());
+        }
+
+        let offset = self.decode_u32(entry.value);
+
+        if u64::from(offset) + length > self.length {
+            return Err(anyhow!(
+                "TIFF tag {} value extends past the end of the file",
+                entry.tag
+            ));
+        }
+
+        let mut bytes = vec![0; length as usize];
+
+        self.read_exact_at(u64::from(offset), &mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Reads one SHORT, LONG, or IFD value from an entry.
+    fn entry_u32(&mut self, entry: &IfdEntry, index: u32) -> Result<u32> {
+        let bytes = self.entry_bytes(entry)?;
+        let size = field_type_size(entry.field_type);
+        let start = index as usize * size;
+
+        match (size, bytes.get(start..start + size)) {
+            (2, Some(value)) => Ok(u32::from(self.decode_u16([value[0], value[1]]))),
+            (4, Some(value)) => Ok(self.decode_u32([value[0], value[1], value[2], value[3]])),
+            _ => Err(anyhow!("TIFF tag {} is not an integer value", entry.tag)),
+        }
+    }
+
+    fn read_u16_at(&mut self, offset: u64) -> Result<u16> {
+        let mut bytes = [0; 2];
+
+        self.read_exact_at(offset, &mut bytes)?;
+
+        Ok(self.decode_u16(bytes))
+    }
+
+    fn read_u32_at(&mut self, offset: u64) -> Result<u32> {
+        let mut bytes = [0; 4];
+
+        self.read_exact_at(offset, &mut bytes)?;
+
+        Ok(self.decode_u32(bytes))
+    }
+
+    fn read_exact_at(&mut self, offset: u64, bytes: &mut [u8]) -> Result<()> {
+        if let Err(error) = self.reader.seek(SeekFrom::Start(offset)) {
+            return Err(anyhow!("failed to seek TIFF data: {error}"));
+        }
+
+        match self.reader.read_exact(bytes) {
+            Ok(()) => Ok(()),
+            Err(error) => Err(anyhow!("failed to read TIFF data: {error}")),
+        }
+    }
+
+    fn decode_u16(&self, bytes: [u8; 2]) -> u16 {
+        if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        }
+    }
+
+    fn decode_u32(&self, bytes: [u8; 4]) -> u32 {
+        if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        }
+    }
+}
+
+/// Returns the byte size of one value of a TIFF field type.
+fn field_type_size(field_type: u16) -> usize {
+    match field_type {
+        3 | 8 => 2,
+        4 | 9 | 11 | 13 => 4,
+        5 | 10 | 12 => 8,
+        _ => 1,
+    }
+}
@@ -1,8 +1,13 @@
 mod decode;
+mod dng_opcodes;
 mod raster;
 mod raw;
 
 pub use decode::decode_source_from_path;
+pub use dng_opcodes::{
+    DngGainMap, DngOpcode, DngOpcodeStage, DngOperation, DngPoint, DngRect, DngWarpPlane,
+    DngWarpRectilinear,
+};
 pub use raster::{RasterSamples, RasterSource};
 pub use raw::{RawCfaPattern, RawColorMatrixAnchor, RawLevels, RawRect, RawSamples, RawSource};
 
@@ -3,20 +3,22 @@ use rawler::imgop::xyz::Illuminant;
 use rawler::rawimage::RawPhotometricInterpretation;
 use rawler::{RawImage, RawImageData};
 
+use super::dng_opcodes::DngOpcode;
 use crate::core::image::orientation::Orientation;
 use crate::core::image::ImageDimensions;
 
 /// CPU-side RAW sensor payload intended for GPU RAW development upload.
 ///
-/// This is intentionally narrow. The first GPU RAW development target is a
-/// one-plane, 2x2 RGB CFA image. Other RAW layouts should be added explicitly
-/// instead of being forced through this shape.
+/// This is intentionally narrow. GPU RAW development targets one-plane RGB CFA
+/// images with either a 2x2 Bayer or a 6x6 X-Trans pattern, and linear RGB
+/// images with three samples per pixel. Other RAW layouts should be added
+/// explicitly instead of being forced through this shape.
 #[derive(Debug, Clone)]
 pub struct RawSource {
     samples: RawSamples,
     dimensions: ImageDimensions,
     bits_per_sample: u32,
-    cfa: RawCfaPattern,
+    cfa: Option<RawCfaPattern>,
     black_levels: RawLevels,
     white_levels: RawLevels,
     active_area: Option<RawRect>,
@@ -27,30 +29,46 @@ pub struct RawSource {
     orientation: Option<Orientation>,
     camera_make: String,
     camera_model: String,
+    dng_opcodes: Vec<DngOpcode>,
 }
 
 impl RawSource {
     /// Builds a GPU-oriented RAW sensor source from rawler's decoded RAW image.
-    pub fn from_raw_image(raw_image: &RawImage, orientation: Option<Orientation>) -> Result<Self> {
-        if raw_image.cpp != 1 {
-            return Err(anyhow!(
-                "GPU RAW source currently supports one sample per photosite, got cpp={}",
-                raw_image.cpp
-            ));
-        }
-
+    ///
+    /// `dng_opcodes` carries the parsed DNG opcode lists, or is empty for
+    /// non-DNG files.
+    pub fn from_raw_image(
+        raw_image: &RawImage,
+        orientation: Option<Orientation>,
+        dng_opcodes: Vec<DngOpcode>,
+    ) -> Result<Self> {
         let cfa = match &raw_image.photometric {
+            RawPhotometricInterpretation::LinearRaw if raw_image.cpp == 3 => None,
+            RawPhotometricInterpretation::LinearRaw => {
+                return Err(anyhow!(
+                    "GPU RAW source currently supports three-sample linear RGB, got cpp={}",
+                    raw_image.cpp
+                ));
+            }
+            RawPhotometricInterpretation::Cfa(_) if raw_image.cpp != 1 => {
+                return Err(anyhow!(
+                    "GPU RAW source currently supports one sample per CFA photosite, got cpp={}",
+                    raw_image.cpp
+                ));
+            }
             RawPhotometricInterpretation::Cfa(config)
-                if config.cfa.is_rgb() && config.cfa.width == 2 && config.cfa.height == 2 =>
+                if config.cfa.is_rgb()
+                    && ((config.cfa.width == 2 && config.cfa.height == 2)
+                        || (config.cfa.width == 6 && config.cfa.height == 6)) =>
             {
                 match RawCfaPattern::from_rawler_config(config) {
-                    Ok(cfa) => cfa,
+                    Ok(cfa) => Some(cfa),
                     Err(error) => return Err(error),
                 }
             }
             RawPhotometricInterpretation::Cfa(config) => {
                 return Err(anyhow!(
-                    "GPU RAW source currently supports 2x2 RGB CFA patterns, got {} ({}x{})",
+                    "GPU RAW source currently supports 2x2 and 6x6 RGB CFA patterns, got {} ({}x{})",
                     config.cfa,
                     config.cfa.width,
                     config.cfa.height
@@ -58,7 +76,7 @@ impl RawSource {
             }
             photometric => {
                 return Err(anyhow!(
-                    "GPU RAW source currently requires CFA RAW data, got {photometric:?}"
+                    "GPU RAW source currently requires CFA or linear RAW data, got {photometric:?}"
                 ));
             }
         };
@@ -76,18 +94,22 @@ impl RawSource {
             Err(error) => return Err(error),
         };
 
-        let samples = RawSamples::from_raw_image_data(&raw_image.data);
+        let samples = match cfa {
+            Some(_) => RawSamples::from_raw_image_data(&raw_image.data),
+            None => RawSamples::linear_rgb_from_raw_image_data(&raw_image.data),
+        };
         let expected_sample_count = match dimensions.pixel_count() {
-            Ok(expected_sample_count) => expected_sample_count,
+            Ok(pixel_count) => pixel_count * raw_image.cpp,
             Err(error) => return Err(error),
         };
 
         if samples.len() != expected_sample_count {
             return Err(anyhow!(
-                "RAW sensor sample count {} does not match dimensions {}x{}",
+                "RAW sensor sample count {} does not match dimensions {}x{} with cpp={}",
                 samples.len(),
                 dimensions.width(),
-                dimensions.height()
+                dimensions.height(),
+                raw_image.cpp
             ));
         }
 
@@ -123,6 +145,17 @@ impl RawSource {
             Err(error) => return Err(error),
         };
 
+        let metadata_black_levels = RawLevels::from_rawler_black_level(&raw_image.blacklevel);
+        let black_levels = if metadata_black_levels.is_zero() {
+            cfa.as_ref()
+                .and_then(|cfa| {
+                    RawLevels::from_black_areas(&samples, dimensions, cfa, &black_areas)
+                })
+                .unwrap_or(metadata_black_levels)
+        } else {
+            metadata_black_levels
+        };
+
         let color_matrix_anchors = match extract_camera_color_matrix_anchors(raw_image) {
             Ok(color_matrix_anchors) => color_matrix_anchors,
             Err(error) => return Err(error),
@@ -133,7 +166,7 @@ impl RawSource {
             dimensions,
             bits_per_sample,
             cfa,
-            black_levels: RawLevels::from_rawler_black_level(&raw_image.blacklevel),
+            black_levels,
             white_levels: RawLevels::from_rawler_white_level(&raw_image.whitelevel),
             active_area,
             crop_area,
@@ -143,6 +176,7 @@ impl RawSource {
             orientation,
             camera_make: raw_image.clean_make.clone(),
             camera_model: raw_image.clean_model.clone(),
+            dng_opcodes,
         })
     }
 
@@ -161,12 +195,15 @@ impl RawSource {
         self.bits_per_sample
     }
 
-    /// Returns the repeating CFA pattern.
-    pub fn cfa(&self) -> &RawCfaPattern {
-        &self.cfa
+    /// Returns the repeating CFA pattern, or `None` for linear RGB sources.
+    pub fn cfa(&self) -> Option<&RawCfaPattern> {
+        self.cfa.as_ref()
     }
 
     /// Returns black levels aligned to the 2x2 CFA positions.
+    ///
+    /// When the metadata reports no black level, these are measured from the
+    /// masked `black_areas` instead.
     pub fn black_levels(&self) -> RawLevels {
         self.black_levels
     }
@@ -215,6 +252,11 @@ impl RawSource {
     pub fn camera_model(&self) -> &str {
         &self.camera_model
     }
+
+    /// Returns the DNG opcodes parsed from the source file, in list order.
+    pub fn dng_opcodes(&self) -> &[DngOpcode] {
+        &self.dng_opcodes
+    }
 }
 
 /// RAW sensor sample storage before GPU upload.
@@ -222,6 +264,8 @@ impl RawSource {
 pub enum RawSamples {
     IntegerU16(Vec<u16>),
     Float32(Vec<f32>),
+    /// Interleaved RGB samples, three per pixel, in sensor code units.
+    LinearRgb(Vec<f32>),
 }
 
 impl RawSamples {
@@ -230,6 +274,7 @@ impl RawSamples {
         match self {
             Self::IntegerU16(samples) => samples.len(),
             Self::Float32(samples) => samples.len(),
+            Self::LinearRgb(samples) => samples.len(),
         }
     }
 
@@ -244,19 +289,48 @@ impl RawSamples {
             RawImageData::Float(samples) => Self::Float32(samples.clone()),
         }
     }
+
+    fn linear_rgb_from_raw_image_data(data: &RawImageData) -> Self {
+        match data {
+            RawImageData::Integer(samples) => {
+                Self::LinearRgb(samples.iter().map(|sample| f32::from(*sample)).collect())
+            }
+            RawImageData::Float(samples) => Self::LinearRgb(samples.clone()),
+        }
+    }
 }
 
-/// A 2x2 RGB color filter array pattern.
+/// Largest supported CFA pattern: the 6x6 Fujifilm X-Trans layout.
+const MAX_CFA_PATTERN_LEN: usize = 36;
+
+/// A 2x2 Bayer or 6x6 X-Trans RGB color filter array pattern.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RawCfaPattern {
-    pattern: [u32; 4],
+    width: u32,
+    height: u32,
+    pattern: [u32; MAX_CFA_PATTERN_LEN],
     plane_colors: [u32; 3],
 }
 
 impl RawCfaPattern {
-    /// Returns the 2x2 CFA pattern in row-major order.
-    pub fn pattern(&self) -> [u32; 4] {
-        self.pattern
+    /// Returns the repeating pattern width in photosites.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the repeating pattern height in photosites.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the CFA pattern in row-major order.
+    pub fn pattern(&self) -> &[u32] {
+        &self.pattern[..(self.width * self.height) as usize]
+    }
+
+    /// Returns the CFA color code at a sensor coordinate.
+    pub fn color_at(&self, x: u32, y: u32) -> u32 {
+        self.pattern[((y % self.height) * self.width + (x % self.width)) as usize]
     }
 
     /// Returns the source plane colors in rawler's CFA color codes.
@@ -272,50 +346,145 @@ impl RawCfaPattern {
             ));
         }
 
+        let width = config.cfa.width as u32;
+        let height = config.cfa.height as u32;
         let pattern_values = config.cfa.flat_pattern();
-        if pattern_values.len() != 4 {
+        if pattern_values.len() != (width * height) as usize
+            || pattern_values.len() > MAX_CFA_PATTERN_LEN
+        {
             return Err(anyhow!(
-                "GPU RAW source expected four CFA pattern samples, got {}",
+                "GPU RAW source expected {} CFA pattern samples, got {}",
+                width * height,
                 pattern_values.len()
             ));
         }
 
+        let mut pattern = [0; MAX_CFA_PATTERN_LEN];
+        for (slot, value) in pattern.iter_mut().zip(pattern_values.iter()) {
+            *slot = u32::from(*value);
+        }
+
         let plane_colors = config.colors.plane_colors::<3>().map(|color| color as u32);
 
         Ok(Self {
-            pattern: [
-                u32::from(pattern_values[0]),
-                u32::from(pattern_values[1]),
-                u32::from(pattern_values[2]),
-                u32::from(pattern_values[3]),
-            ],
+            width,
+            height,
+            pattern,
             plane_colors,
         })
     }
 }
 
 /// Four sensor levels aligned to the 2x2 CFA positions.
+///
+/// Linear RGB sources have no CFA, so their levels are stored per plane in red,
+/// green, blue order with the fourth slot repeating green.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RawLevels {
     values: [f32; 4],
 }
 
 impl RawLevels {
-    /// Returns the levels in 2x2 CFA row-major order.
+    /// Returns the levels in 2x2 CFA row-major order, or red, green, blue
+    /// order for linear RGB sources.
     pub fn values(self) -> [f32; 4] {
         self.values
     }
 
     fn from_rawler_black_level(black_level: &rawler::rawimage::BlackLevel) -> Self {
-        Self {
-            values: black_level.as_bayer_array(),
-        }
+        let levels: Vec<f32> = black_level
+            .levels
+            .iter()
+            .map(|level| level.as_f32())
+            .collect();
+
+        Self::from_slots(&levels)
     }
 
     fn from_rawler_white_level(white_level: &rawler::rawimage::WhiteLevel) -> Self {
-        Self {
-            values: white_level.as_bayer_array(),
+        let levels: Vec<f32> = white_level.0.iter().map(|level| *level as f32).collect();
+
+        Self::from_slots(&levels)
+    }
+
+    /// Expands decoder levels to four slots: one per 2x2 CFA position, one per
+    /// linear RGB plane, or a single level shared by every slot.
+    fn from_slots(levels: &[f32]) -> Self {
+        let values = match *levels {
+            [top_left, top_right, bottom_left, bottom_right] => {
+                [top_left, top_right, bottom_left, bottom_right]
+            }
+            [red, green, blue] => [red, green, blue, green],
+            [level, ..] => [level; 4],
+            [] => [0.0; 4],
+        };
+
+        Self { values }
+    }
+
+    fn is_zero(self) -> bool {
+        self.values.iter().all(|value| *value == 0.0)
+    }
+
+    /// Averages masked black-reference photosites per 2x2 CFA position.
+    ///
+    /// Larger patterns such as X-Trans do not repeat on 2x2 parity, so their
+    /// photosites are averaged into one level shared by every position.
+    /// Returns `None` for linear RGB samples or when any position has no
+    /// masked photosite inside the sensor.
+    fn from_black_areas(
+        samples: &RawSamples,
+        dimensions: ImageDimensions,
+        cfa: &RawCfaPattern,
+        black_areas: &[RawRect],
+    ) -> Option<Self> {
+        let width = dimensions.width();
+        let height = dimensions.height();
+        let per_position = cfa.width() == 2 && cfa.height() == 2;
+        let mut sums = [0.0_f64; 4];
+        let mut counts = [0_u64; 4];
+
+        for area in black_areas {
+            let right = area.x.saturating_add(area.width).min(width);
+            let bottom = area.y.saturating_add(area.height).min(height);
+
+            for y in area.y..bottom {
+                for x in area.x..right {
+                    let index = (y as usize * width as usize) + x as usize;
+                    let sample = match samples {
+                        RawSamples::IntegerU16(samples) => f64::from(samples[index]),
+                        RawSamples::Float32(samples) => f64::from(samples[index]),
+                        RawSamples::LinearRgb(_) => return None,
+                    };
+                    let slot = if per_position {
+                        (((y % 2) * 2) + (x % 2)) as usize
+                    } else {
+                        0
+                    };
+
+                    sums[slot] += sample;
+                    counts[slot] += 1;
+                }
+            }
+        }
+
+        if !per_position {
+            if counts[0] == 0 {
+                return None;
+            }
+
+            let mean = (sums[0] / counts[0] as f64) as f32;
+
+            return Some(Self { values: [mean; 4] });
         }
+
+        if counts.contains(&0) {
+            return None;
+        }
+
+        Some(Self {
+            values: std::array::from_fn(|slot| (sums[slot] / counts[slot] as f64) as f32),
+        })
     }
 }
 
@@ -0,0 +1,267 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+use log::{info, warn};
+
+use super::lensfun::{parse_lensfun_database, LensfunCamera, LensfunDatabase, LensfunLens};
+use super::profile::{LensCalibration, LensProfile};
+use crate::core::image::exif::ExifMetadata;
+
+const LENSFUN_SYSTEM_DIRS: [&str; 2] = [
+    "/usr/share/lensfun/version_1",
+    "/usr/local/share/lensfun/version_1",
+];
+
+static LENS_DATABASE: OnceLock<LensDatabase> = OnceLock::new();
+
+/// Lens profiles and camera crop factors loaded from local lensfun-compatible
+/// XML databases.
+#[derive(Debug, Default)]
+pub struct LensDatabase {
+    lenses: Vec<LensfunLens>,
+    cameras: Vec<LensfunCamera>,
+}
+
+impl LensDatabase {
+    /// Returns the process-wide database, loading it on first use.
+    pub fn shared() -> &'static LensDatabase {
+        LENS_DATABASE.get_or_init(|| Self::load_from_dirs(&lens_profile_dirs()))
+    }
+
+    /// Loads every `.xml` file in the given directories.
+    ///
+    /// Directories are read in order and unreadable files are skipped, so user
+    /// profiles listed first take precedence over system lensfun profiles for
+    /// the same lens.
+    pub fn load_from_dirs(dirs: &[PathBuf]) -> Self {
+        let mut lenses = Vec::new();
+        let mut cameras = Vec::new();
+
+        for dir in dirs {
+            let entries = match fs::read_dir(dir) {
+                Ok(entries) => entries,
+                Err(_) => continue,
+            };
+
+            let mut paths: Vec<PathBuf> = entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|extension| extension == "xml"))
+                .collect();
+
+            paths.sort();
+
+            for path in paths {
+                match load_database_file(&path) {
+                    Ok(mut database) => {
+                        lenses.append(&mut database.lenses);
+                        cameras.append(&mut database.cameras);
+                    }
+                    Err(error) => warn!(
+                        "Skipping lens profile database {}: {:#}",
+                        path.display(),
+                        error
+                    ),
+                }
+            }
+        }
+
+        info!(
+            "Loaded {} lens profiles and {} camera entries",
+            lenses.len(),
+            cameras.len()
+        );
+
+        Self { lenses, cameras }
+    }
+
+    /// Returns the crop factor lensfun lists for a camera make and model.
+    pub fn camera_crop_factor(&self, make: Option<&str>, model: &str) -> Option<f32> {
+        let model_key = normalize_name(model);
+        let make_key = make.map(normalize_name).filter(|make| !make.is_empty());
+
+        if model_key.is_empty() {
+            return None;
+        }
+
+        self.cameras
+            .iter()
+            .find(|camera| {
+                let maker_key = normalize_name(&camera.maker);
+
+                normalize_name(&camera.model) == model_key
+                    && make_key.as_ref().map_or(true, |make| {
+                        make.contains(&maker_key) || maker_key.contains(make)
+                    })
+            })
+            .map(|camera| camera.crop_factor)
+    }
+
+    /// Finds the profile that best matches a lens make and model from EXIF.
+    ///
+    /// Profiles from a different maker than the EXIF lens make are skipped.
+    /// Among the rest, an exact model or alias match wins. Otherwise every word of the EXIF
+    /// model must appear in the profile name, and the profile with the fewest
+    /// extra words is chosen.
+    pub fn find(&self, lens_make: Option<&str>, lens_model: &str) -> Option<&LensProfile> {
+        let model_key = normalize_name(lens_model);
+
+        if model_key.is_empty() {
+            return None;
+        }
+
+        let make_key = lens_make
+            .map(normalize_name)
+            .filter(|make| !make.is_empty());
+        let matches_make = |lens: &&LensfunLens| {
+            let maker_key = normalize_name(&lens.profile.maker);
+
+            make_key.as_ref().map_or(true, |make| {
+                make.contains(&maker_key) || maker_key.contains(make)
+            })
+        };
+        let exact = self.lenses.iter().filter(matches_make).find(|lens| {
+            normalize_name(&lens.profile.model) == model_key
+                || lens
+                    .aliases
+                    .iter()
+                    .any(|alias| normalize_name(alias) == model_key)
+        });
+
+        if let Some(lens) = exact {
+            return Some(&lens.profile);
+        }
+
+        let model_tokens = name_tokens(lens_model);
+        let mut best: Option<(usize, &LensProfile)> = None;
+
+        for lens in self.lenses.iter().filter(matches_make) {
+            let mut profile_tokens = name_tokens(&lens.profile.maker);
+            profile_tokens.extend(name_tokens(&lens.profile.model));
+
+            if !model_tokens
+                .iter()
+                .all(|token| profile_tokens.contains(token))
+            {
+                continue;
+            }
+
+            let extra_tokens = profile_tokens
+                .iter()
+                .filter(|token| !model_tokens.contains(token))
+                .count();
+
+            if best.map_or(true, |(best_extra, _)| extra_tokens < best_extra) {
+                best = Some((extra_tokens, &lens.profile));
+            }
+        }
+
+        best.map(|(_, profile)| profile)
+    }
+}
+
+/// Resolves lens corrections for a capture from its EXIF lens and exposure data.
+///
+/// The capture's crop factor comes from the EXIF 35mm-equivalent focal length,
+/// then from the lensfun entry for the camera body. When neither is known the
+/// capture is assumed to match the profile's calibration sensor.
+pub fn resolve_lens_calibration(exif: &ExifMetadata) -> Option<LensCalibration> {
+    let lens_model = exif.lens_model.as_deref()?;
+    let database = LensDatabase::shared();
+    let profile = database.find(exif.lens_make.as_deref(), lens_model)?;
+    let camera_crop_factor = capture_crop_factor(database, exif, profile);
+
+    Some(profile.calibration_at(exif.focal_length, exif.aperture, camera_crop_factor))
+}
+
+/// Returns the capture sensor's crop factor, logging which fallback was used
+/// when EXIF does not record it.
+fn capture_crop_factor(database: &LensDatabase, exif: &ExifMetadata, profile: &LensProfile) -> f32 {
+    if let Some(crop_factor) = exif_crop_factor(exif) {
+        return crop_factor;
+    }
+
+    let camera_model = exif.model.as_deref().unwrap_or_default();
+
+    if let Some(crop_factor) = database.camera_crop_factor(exif.make.as_deref(), camera_model) {
+        info!("Using lensfun crop factor {crop_factor} for {camera_model}: EXIF has no 35mm focal length");
+
+        return crop_factor;
+    }
+
+    warn!(
+        "Crop factor unknown for {camera_model}: assuming the {} profile's crop factor {}",
+        profile.model(),
+        profile.crop_factor()
+    );
+
+    profile.crop_factor()
+}
+
+/// Derives the capture sensor's crop factor from the 35mm-equivalent and real
+/// focal lengths.
+fn exif_crop_factor(exif: &ExifMetadata) -> Option<f32> {
+    let focal_length = exif.focal_length.filter(|value| *value > 0.0)?;
+    let focal_length_35mm = exif.focal_length_35mm.filter(|value| *value > 0.0)?;
+
+    Some(focal_length_35mm / focal_length)
+}
+
+/// Returns the directories searched for lens profiles, user profiles first.
+fn lens_profile_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    if let Some(data_dir) = dirs::data_dir() {
+        dirs.push(data_dir.join("com.extents").join("lenses"));
+    }
+
+    dirs.extend(LENSFUN_SYSTEM_DIRS.iter().map(PathBuf::from));
+
+    dirs
+}
+
+fn load_database_file(path: &Path) -> Result<LensfunDatabase> {
+    let xml =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    parse_lensfun_database(&xml)
+}
+
+fn normalize_name(name: &str) -> String {
+    name_tokens(name).join(" ")
+}
+
+/// Splits a lens name into lowercase words, separating letters from digits so
+/// `EF50mm` and `EF 50mm` compare equal.
+fn name_tokens(name: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut previous_is_digit = None;
+
+    for character in name.chars().flat_map(char::to_lowercase) {
+        if !(character.is_alphanumeric() || character == '.' || character == '/') {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            previous_is_digit = None;
+            continue;
+        }
+
+        let is_digit = character.is_ascii_digit() || character == '.';
+
+        if previous_is_digit == Some(false) && is_digit && !current.is_empty() {
+            tokens.push(std::mem::take(&mut current));
+        }
+
+        current.push(character);
+        previous_is_digit = Some(is_digit);
+    }
+
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
@@ -0,0 +1,299 @@
+use anyhow::{Context, Result};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use super::profile::{FocalCalibration, LensProfile, VignettingCalibration};
+
+/// A rectilinear lens read from a lensfun database file, with every model
+/// name it is listed under.
+#[derive(Debug, Clone)]
+pub(super) struct LensfunLens {
+    pub(super) profile: LensProfile,
+    pub(super) aliases: Vec<String>,
+}
+
+/// A camera body read from a lensfun database file.
+#[derive(Debug, Clone)]
+pub(super) struct LensfunCamera {
+    pub(super) maker: String,
+    pub(super) model: String,
+    pub(super) crop_factor: f32,
+}
+
+/// The lens and camera entries of one lensfun database file.
+#[derive(Debug, Default)]
+pub(super) struct LensfunDatabase {
+    pub(super) lenses: Vec<LensfunLens>,
+    pub(super) cameras: Vec<LensfunCamera>,
+}
+
+#[derive(Default)]
+struct CameraBuilder {
+    makers: Vec<(bool, String)>,
+    models: Vec<(bool, String)>,
+    crop_factor: Option<f32>,
+}
+
+#[derive(Default)]
+struct LensBuilder {
+    makers: Vec<(bool, String)>,
+    models: Vec<(bool, String)>,
+    crop_factor: Option<f32>,
+    rectilinear: bool,
+    distortion: Vec<FocalCalibration<5>>,
+    chromatic_aberration: Vec<FocalCalibration<6>>,
+    vignetting: Vec<VignettingCalibration>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum TextField {
+    Maker(bool),
+    Model(bool),
+    CropFactor,
+    Type,
+}
+
+/// Parses the `<lens>` and `<camera>` entries of a lensfun XML database.
+///
+/// Cameras are kept only for their crop factor. Mount entries are ignored, as
+/// are fisheye and other non-rectilinear lenses and calibration models the
+/// correction stage does not implement.
+pub(super) fn parse_lensfun_database(xml: &str) -> Result<LensfunDatabase> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut database = LensfunDatabase::default();
+    let mut lens: Option<LensBuilder> = None;
+    let mut camera: Option<CameraBuilder> = None;
+    let mut text_field: Option<TextField> = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .with_context(|| format!("Invalid lensfun XML at byte {}", reader.buffer_position()))?;
+
+        match event {
+            Event::Start(element) => match element.name().as_ref() {
+                b"lens" => lens = Some(LensBuilder::new()),
+                b"camera" => camera = Some(CameraBuilder::default()),
+                name => {
+                    if let Some(lens) = lens.as_mut() {
+                        text_field = text_field_for(name, &element);
+                        lens.read_calibration(name, &element);
+                    } else if camera.is_some() {
+                        text_field = text_field_for(name, &element);
+                    }
+                }
+            },
+            Event::Empty(element) => {
+                if let Some(lens) = lens.as_mut() {
+                    lens.read_calibration(element.name().as_ref(), &element);
+                }
+            }
+            Event::Text(text) => {
+                let Some(field) = text_field else {
+                    continue;
+                };
+                let text = String::from_utf8_lossy(&text);
+
+                if let Some(lens) = lens.as_mut() {
+                    lens.read_text(field, text.trim());
+                } else if let Some(camera) = camera.as_mut() {
+                    camera.read_text(field, text.trim());
+                }
+            }
+            Event::End(element) => {
+                text_field = None;
+
+                match element.name().as_ref() {
+                    b"lens" => {
+                        if let Some(parsed) = lens.take().and_then(LensBuilder::build) {
+                            database.lenses.push(parsed);
+                        }
+                    }
+                    b"camera" => {
+                        if let Some(parsed) = camera.take().and_then(CameraBuilder::build) {
+                            database.cameras.push(parsed);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(database)
+}
+
+fn text_field_for(name: &[u8], element: &BytesStart<'_>) -> Option<TextField> {
+    let localized = attribute(element, b"lang").is_some();
+
+    match name {
+        b"maker" => Some(TextField::Maker(localized)),
+        b"model" => Some(TextField::Model(localized)),
+        b"cropfactor" => Some(TextField::CropFactor),
+        b"type" => Some(TextField::Type),
+        _ => None,
+    }
+}
+
+fn attribute(element: &BytesStart<'_>, key: &[u8]) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attribute| attribute.key.as_ref() == key)
+        .map(|attribute| String::from_utf8_lossy(&attribute.value).into_owned())
+}
+
+fn numeric_attribute(element: &BytesStart<'_>, key: &[u8]) -> Option<f32> {
+    attribute(element, key).and_then(|value| value.trim().parse().ok())
+}
+
+impl CameraBuilder {
+    fn read_text(&mut self, field: TextField, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        match field {
+            TextField::Maker(localized) => self.makers.push((localized, text.to_string())),
+            TextField::Model(localized) => self.models.push((localized, text.to_string())),
+            TextField::CropFactor => self.crop_factor = text.parse().ok(),
+            TextField::Type => {}
+        }
+    }
+
+    /// Builds the camera, skipping entries without a usable crop factor.
+    fn build(self) -> Option<LensfunCamera> {
+        let crop_factor = self.crop_factor.filter(|value| *value > 0.0)?;
+
+        Some(LensfunCamera {
+            maker: primary_name(&self.makers)?,
+            model: primary_name(&self.models)?,
+            crop_factor,
+        })
+    }
+}
+
+impl LensBuilder {
+    fn new() -> Self {
+        Self {
+            rectilinear: true,
+            ..Self::default()
+        }
+    }
+
+    fn read_text(&mut self, field: TextField, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        match field {
+            TextField::Maker(localized) => self.makers.push((localized, text.to_string())),
+            TextField::Model(localized) => self.models.push((localized, text.to_string())),
+            TextField::CropFactor => self.crop_factor = text.parse().ok(),
+            TextField::Type => self.rectilinear = text == "rectilinear",
+        }
+    }
+
+    fn read_calibration(&mut self, name: &[u8], element: &BytesStart<'_>) {
+        let Some(focal_length) = numeric_attribute(element, b"focal") else {
+            return;
+        };
+        let model = attribute(element, b"model").unwrap_or_default();
+        let value = |key: &[u8]| numeric_attribute(element, key).unwrap_or(0.0);
+
+        match (name, model.as_str()) {
+            (b"distortion", "poly3") => {
+                let k1 = value(b"k1");
+
+                self.distortion.push(FocalCalibration {
+                    focal_length,
+                    coefficients: [1.0 - k1, 0.0, k1, 0.0, 0.0],
+                });
+            }
+            (b"distortion", "poly5") => self.distortion.push(FocalCalibration {
+                focal_length,
+                coefficients: [1.0, 0.0, value(b"k1"), 0.0, value(b"k2")],
+            }),
+            (b"distortion", "ptlens") => {
+                let (a, b, c) = (value(b"a"), value(b"b"), value(b"c"));
+
+                self.distortion.push(FocalCalibration {
+                    focal_length,
+                    coefficients: [1.0 - a - b - c, c, b, a, 0.0],
+                });
+            }
+            (b"tca", "linear") => self.chromatic_aberration.push(FocalCalibration {
+                focal_length,
+                coefficients: [value(b"kr"), 0.0, 0.0, value(b"kb"), 0.0, 0.0],
+            }),
+            (b"tca", "poly3") => {
+                let scale = |key: &[u8]| numeric_attribute(element, key).unwrap_or(1.0);
+
+                self.chromatic_aberration.push(FocalCalibration {
+                    focal_length,
+                    coefficients: [
+                        scale(b"vr"),
+                        value(b"cr"),
+                        value(b"br"),
+                        scale(b"vb"),
+                        value(b"cb"),
+                        value(b"bb"),
+                    ],
+                });
+            }
+            (b"vignetting", "pa") => {
+                let Some(aperture) = numeric_attribute(element, b"aperture") else {
+                    return;
+                };
+
+                self.vignetting.push(VignettingCalibration {
+                    focal_length,
+                    aperture,
+                    distance: numeric_attribute(element, b"distance").unwrap_or(f32::MAX),
+                    coefficients: [value(b"k1"), value(b"k2"), value(b"k3")],
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn build(self) -> Option<LensfunLens> {
+        if !self.rectilinear {
+            return None;
+        }
+
+        let maker = primary_name(&self.makers)?;
+        let model = primary_name(&self.models)?;
+        let aliases = self
+            .models
+            .into_iter()
+            .map(|(_, name)| name)
+            .filter(|name| *name != model)
+            .collect();
+
+        Some(LensfunLens {
+            profile: LensProfile {
+                maker,
+                model,
+                crop_factor: self.crop_factor.unwrap_or(1.0),
+                distortion: self.distortion,
+                chromatic_aberration: self.chromatic_aberration,
+                vignetting: self.vignetting,
+            },
+            aliases,
+        })
+    }
+}
+
+/// Prefers the untranslated name, falling back to the first localized one.
+fn primary_name(names: &[(bool, String)]) -> Option<String> {
+    names
+        .iter()
+        .find(|(localized, _)| !localized)
+        .or_else(|| names.first())
+        .map(|(_, name)| name.clone())
+}
@@ -0,0 +1,6 @@
+mod database;
+mod lensfun;
+mod profile;
+
+pub use database::{resolve_lens_calibration, LensDatabase};
+pub use profile::{LensCalibration, LensProfile};
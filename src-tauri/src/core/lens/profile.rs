@@ -0,0 +1,222 @@
+/// Calibrated correction data for one lens, read from a lens profile database.
+///
+/// Distortion and chromatic aberration models are stored in a unified
+/// polynomial form so calibrations recorded with different lensfun models can
+/// be interpolated against each other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LensProfile {
+    pub(super) maker: String,
+    pub(super) model: String,
+    pub(super) crop_factor: f32,
+    pub(super) distortion: Vec<FocalCalibration<5>>,
+    pub(super) chromatic_aberration: Vec<FocalCalibration<6>>,
+    pub(super) vignetting: Vec<VignettingCalibration>,
+}
+
+/// Coefficients calibrated at one focal length.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct FocalCalibration<const N: usize> {
+    pub(super) focal_length: f32,
+    pub(super) coefficients: [f32; N],
+}
+
+/// `pa` vignetting coefficients calibrated at one focal length, aperture, and distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct VignettingCalibration {
+    pub(super) focal_length: f32,
+    pub(super) aperture: f32,
+    pub(super) distance: f32,
+    pub(super) coefficients: [f32; 3],
+}
+
+/// Lens corrections resolved for one capture's focal length and aperture.
+///
+/// Distortion maps a corrected radius `r` to the captured radius
+/// `r * (c0 + c1 r + c2 r^2 + c3 r^3 + c4 r^4)`, with `r = 1` at half the
+/// shorter image side. Chromatic aberration scales the red and blue captured
+/// radius by `v + c r + b r^2` relative to green. Vignetting is the `pa` model
+/// `1 + k1 r^2 + k2 r^4 + k3 r^6`, with `r = 1` at the image corner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LensCalibration {
+    pub lens_model: String,
+    pub distortion: Option<[f32; 5]>,
+    pub chromatic_aberration: Option<[[f32; 3]; 2]>,
+    pub vignetting: Option<[f32; 3]>,
+}
+
+impl LensProfile {
+    /// Returns the lens maker as written in the profile database.
+    pub fn maker(&self) -> &str {
+        &self.maker
+    }
+
+    /// Returns the lens model as written in the profile database.
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Returns the crop factor of the sensor the lens was calibrated on.
+    pub fn crop_factor(&self) -> f32 {
+        self.crop_factor
+    }
+
+    /// Resolves corrections for a capture by interpolating between calibrations.
+    ///
+    /// Without a focal length only single-focal profiles resolve, and without
+    /// an aperture vignetting is left uncorrected. Vignetting uses the farthest
+    /// calibrated focus distance, since capture focus distance is not known.
+    /// Coefficients are rescaled from the calibration sensor's normalized
+    /// radius to the capture sensor's, given its `camera_crop_factor`.
+    pub fn calibration_at(
+        &self,
+        focal_length: Option<f32>,
+        aperture: Option<f32>,
+        camera_crop_factor: f32,
+    ) -> LensCalibration {
+        let radius_scale = self.crop_factor / camera_crop_factor;
+
+        LensCalibration {
+            lens_model: self.model.clone(),
+            distortion: interpolate_focal(&self.distortion, focal_length)
+                .map(|coefficients| scale_radius_powers(coefficients, radius_scale)),
+            chromatic_aberration: interpolate_focal(&self.chromatic_aberration, focal_length).map(
+                |coefficients| {
+                    [
+                        scale_radius_powers(
+                            [coefficients[0], coefficients[1], coefficients[2]],
+                            radius_scale,
+                        ),
+                        scale_radius_powers(
+                            [coefficients[3], coefficients[4], coefficients[5]],
+                            radius_scale,
+                        ),
+                    ]
+                },
+            ),
+            vignetting: aperture
+                .and_then(|aperture| self.vignetting_at(focal_length, aperture))
+                .map(|coefficients| {
+                    let [k1, k2, k3] = coefficients;
+                    let scale = radius_scale * radius_scale;
+
+                    [k1 * scale, k2 * scale.powi(2), k3 * scale.powi(3)]
+                }),
+        }
+    }
+
+    fn vignetting_at(&self, focal_length: Option<f32>, aperture: f32) -> Option<[f32; 3]> {
+        let mut by_focal: Vec<FocalCalibration<3>> = Vec::new();
+
+        for calibration in &self.vignetting {
+            if by_focal
+                .iter()
+                .any(|entry| entry.focal_length == calibration.focal_length)
+            {
+                continue;
+            }
+
+            let by_aperture =
+                farthest_distance_by_aperture(&self.vignetting, calibration.focal_length);
+
+            if let Some(coefficients) = interpolate_by(&by_aperture, aperture) {
+                by_focal.push(FocalCalibration {
+                    focal_length: calibration.focal_length,
+                    coefficients,
+                });
+            }
+        }
+
+        interpolate_focal(&by_focal, focal_length)
+    }
+}
+
+/// Rewrites a polynomial whose coefficient `i` multiplies `r^i` so evaluating
+/// it at `r` gives the original polynomial at `scale * r`.
+fn scale_radius_powers<const N: usize>(coefficients: [f32; N], scale: f32) -> [f32; N] {
+    std::array::from_fn(|index| coefficients[index] * scale.powi(index as i32))
+}
+
+/// Returns one vignetting calibration per aperture at a focal length, keeping
+/// the farthest focus distance for each aperture.
+fn farthest_distance_by_aperture(
+    calibrations: &[VignettingCalibration],
+    focal_length: f32,
+) -> Vec<FocalCalibration<3>> {
+    let mut by_aperture: Vec<(f32, VignettingCalibration)> = Vec::new();
+
+    for calibration in calibrations
+        .iter()
+        .filter(|calibration| calibration.focal_length == focal_length)
+    {
+        match by_aperture
+            .iter_mut()
+            .find(|(aperture, _)| *aperture == calibration.aperture)
+        {
+            Some((_, existing)) if existing.distance >= calibration.distance => {}
+            Some((_, existing)) => *existing = *calibration,
+            None => by_aperture.push((calibration.aperture, *calibration)),
+        }
+    }
+
+    by_aperture
+        .into_iter()
+        .map(|(aperture, calibration)| FocalCalibration {
+            focal_length: aperture,
+            coefficients: calibration.coefficients,
+        })
+        .collect()
+}
+
+/// Interpolates focal calibrations, treating a missing focal length as usable
+/// only when the profile was calibrated at a single focal length.
+fn interpolate_focal<const N: usize>(
+    calibrations: &[FocalCalibration<N>],
+    focal_length: Option<f32>,
+) -> Option<[f32; N]> {
+    match focal_length {
+        Some(focal_length) => interpolate_by(calibrations, focal_length),
+        None if calibrations.len() == 1 => Some(calibrations[0].coefficients),
+        None => None,
+    }
+}
+
+/// Linearly interpolates coefficients between the two calibrations bracketing
+/// `position`, clamping to the nearest calibration outside the calibrated range.
+fn interpolate_by<const N: usize>(
+    calibrations: &[FocalCalibration<N>],
+    position: f32,
+) -> Option<[f32; N]> {
+    let mut below: Option<&FocalCalibration<N>> = None;
+    let mut above: Option<&FocalCalibration<N>> = None;
+
+    for calibration in calibrations {
+        if calibration.focal_length <= position
+            && below.map_or(true, |below| calibration.focal_length > below.focal_length)
+        {
+            below = Some(calibration);
+        }
+
+        if calibration.focal_length >= position
+            && above.map_or(true, |above| calibration.focal_length < above.focal_length)
+        {
+            above = Some(calibration);
+        }
+    }
+
+    match (below, above) {
+        (Some(below), Some(above)) if above.focal_length > below.focal_length => {
+            let weight =
+                (position - below.focal_length) / (above.focal_length - below.focal_length);
+            let mut coefficients = below.coefficients;
+
+            for (coefficient, above_coefficient) in coefficients.iter_mut().zip(above.coefficients)
+            {
+                *coefficient += (above_coefficient - *coefficient) * weight;
+            }
+
+            Some(coefficients)
+        }
+        (Some(calibration), _) | (None, Some(calibration)) => Some(calibration.coefficients),
+        (None, None) => None,
+    }
+}
@@ -0,0 +1,182 @@
+use anyhow::{anyhow, Result};
+use log::warn;
+
+/// Smallest supported lattice size per axis.
+const MIN_LUT_SIZE: u32 = 2;
+
+/// Largest supported lattice size per axis. 129 covers every common grading
+/// export while keeping the uploaded texture under 40 MB.
+const MAX_LUT_SIZE: u32 = 129;
+
+/// Parsed `.cube` 3D lookup table.
+///
+/// Entries are stored in file order, with red changing fastest, then green,
+/// then blue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubeLut {
+    pub title: Option<String>,
+    pub size: u32,
+    pub domain_min: [f32; 3],
+    pub domain_max: [f32; 3],
+    pub table: Vec<[f32; 3]>,
+}
+
+/// Parses Adobe/Resolve `.cube` text into a validated 3D lookup table.
+///
+/// 1D LUTs and shaper LUTs are rejected because the look stage only samples a
+/// 3D lattice. Other unknown uppercase keywords, such as Resolve's
+/// `LUT_IN_VIDEO_RANGE`, are skipped with a warning.
+pub fn parse_cube_lut(text: &str) -> Result<CubeLut> {
+    let mut title = None;
+    let mut size = None;
+    let mut domain_min = [0.0; 3];
+    let mut domain_max = [1.0; 3];
+    let mut table = Vec::new();
+
+    for (index, raw_line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = raw_line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (keyword, rest) = match line.split_once(char::is_whitespace) {
+            Some((keyword, rest)) => (keyword, rest.trim()),
+            None => (line, ""),
+        };
+
+        match keyword {
+            "TITLE" => {
+                title = Some(rest.trim_matches('"').to_string());
+            }
+            "LUT_3D_SIZE" => {
+                if size.is_some() {
+                    return Err(anyhow!("line {line_number}: LUT_3D_SIZE is declared twice"));
+                }
+
+                let parsed: u32 = match rest.parse() {
+                    Ok(parsed) => parsed,
+                    Err(_) => {
+                        return Err(anyhow!(
+                            "line {line_number}: invalid LUT_3D_SIZE \"{rest}\""
+                        ))
+                    }
+                };
+
+                if !(MIN_LUT_SIZE..=MAX_LUT_SIZE).contains(&parsed) {
+                    return Err(anyhow!(
+                        "line {line_number}: LUT_3D_SIZE {parsed} is outside {MIN_LUT_SIZE}..={MAX_LUT_SIZE}"
+                    ));
+                }
+
+                size = Some(parsed);
+                table.reserve((parsed as usize).pow(3));
+            }
+            "LUT_1D_SIZE" => {
+                return Err(anyhow!(
+                    "line {line_number}: 1D LUTs are not supported as looks"
+                ));
+            }
+            "DOMAIN_MIN" => {
+                domain_min = parse_triplet(rest, line_number)?;
+            }
+            "DOMAIN_MAX" => {
+                domain_max = parse_triplet(rest, line_number)?;
+            }
+            "LUT_3D_INPUT_RANGE" => {
+                let [min, max] = parse_pair(rest, line_number)?;
+
+                domain_min = [min; 3];
+                domain_max = [max; 3];
+            }
+            _ if is_uppercase_keyword(keyword) => {
+                warn!("Ignoring unknown .cube keyword {keyword} on line {line_number}");
+            }
+            _ if keyword.starts_with(|character: char| character.is_ascii_alphabetic()) => {
+                return Err(anyhow!("line {line_number}: unknown keyword {keyword}"));
+            }
+            _ => {
+                if size.is_none() {
+                    return Err(anyhow!(
+                        "line {line_number}: table data appears before LUT_3D_SIZE"
+                    ));
+                }
+
+                table.push(parse_triplet(line, line_number)?);
+            }
+        }
+    }
+
+    let Some(size) = size else {
+        return Err(anyhow!("missing LUT_3D_SIZE"));
+    };
+
+    for channel in 0..3 {
+        if domain_min[channel] >= domain_max[channel] {
+            return Err(anyhow!(
+                "domain minimum {} is not below maximum {} for channel {channel}",
+                domain_min[channel],
+                domain_max[channel]
+            ));
+        }
+    }
+
+    let expected_entries = (size as usize).pow(3);
+
+    if table.len() != expected_entries {
+        return Err(anyhow!(
+            "expected {expected_entries} table entries for a {size}x{size}x{size} LUT, found {}",
+            table.len()
+        ));
+    }
+
+    Ok(CubeLut {
+        title,
+        size,
+        domain_min,
+        domain_max,
+        table,
+    })
+}
+
+/// Returns whether `text` looks like a `.cube` keyword such as `LUT_3D_SIZE`.
+fn is_uppercase_keyword(text: &str) -> bool {
+    text.starts_with(|character: char| character.is_ascii_uppercase())
+        && text.chars().all(|character| {
+            character.is_ascii_uppercase() || character.is_ascii_digit() || character == '_'
+        })
+}
+
+fn parse_triplet(text: &str, line_number: usize) -> Result<[f32; 3]> {
+    let values = parse_values(text, line_number)?;
+
+    match values.as_slice() {
+        [red, green, blue] => Ok([*red, *green, *blue]),
+        _ => Err(anyhow!(
+            "line {line_number}: expected 3 values, found {}",
+            values.len()
+        )),
+    }
+}
+
+fn parse_pair(text: &str, line_number: usize) -> Result<[f32; 2]> {
+    let values = parse_values(text, line_number)?;
+
+    match values.as_slice() {
+        [min, max] => Ok([*min, *max]),
+        _ => Err(anyhow!(
+            "line {line_number}: expected 2 values, found {}",
+            values.len()
+        )),
+    }
+}
+
+fn parse_values(text: &str, line_number: usize) -> Result<Vec<f32>> {
+    text.split_whitespace()
+        .map(|value| match value.parse::<f32>() {
+            Ok(parsed) if parsed.is_finite() => Ok(parsed),
+            _ => Err(anyhow!("line {line_number}: invalid number \"{value}\"")),
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+
+use super::cube::{parse_cube_lut, CubeLut};
+
+/// Summary of a `.cube` look returned to the frontend when one is chosen.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LookFile {
+    pub path: String,
+    pub hash: String,
+    pub title: Option<String>,
+    pub size: u32,
+}
+
+/// Reads, hashes and parses a `.cube` look.
+///
+/// When `expected_hash` is given the file contents must still match it, so a
+/// recipe never silently picks up a look that was edited after it was chosen.
+pub fn load_look_file(path: &Path, expected_hash: Option<&str>) -> Result<(LookFile, CubeLut)> {
+    let bytes = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let hash = blake3::hash(&bytes).to_hex().to_string();
+
+    if let Some(expected_hash) = expected_hash {
+        if !expected_hash.eq_ignore_ascii_case(&hash) {
+            return Err(anyhow!(
+                "{} changed since the look was applied",
+                path.display()
+            ));
+        }
+    }
+
+    let text = match std::str::from_utf8(&bytes) {
+        Ok(text) => text,
+        Err(_) => return Err(anyhow!("{} is not a text .cube file", path.display())),
+    };
+
+    let lut =
+        parse_cube_lut(text).with_context(|| format!("invalid .cube file {}", path.display()))?;
+
+    let file = LookFile {
+        path: path.to_string_lossy().into_owned(),
+        hash,
+        title: lut.title.clone(),
+        size: lut.size,
+    };
+
+    Ok((file, lut))
+}
@@ -0,0 +1,5 @@
+mod cube;
+mod loader;
+
+pub use cube::{parse_cube_lut, CubeLut};
+pub use loader::{load_look_file, LookFile};
@@ -1,6 +1,9 @@
 pub mod cache;
 pub mod db;
 pub mod editing;
+pub mod export;
 pub mod image;
 pub mod inspection;
+pub mod lens;
+pub mod look;
 pub mod sidecar;
@@ -177,11 +177,18 @@ pub fn load_sidecar(path: &str) -> Result<Sidecar, SidecarError> {
         return Sidecar::from_recipe(path, &EditRecipe::default());
     }
 
-    let sidecar_json = match fs::read_to_string(&sidecar_path) {
+    load_sidecar_file(&sidecar_path)
+}
+
+/// Loads a `.exts` sidecar document from an explicit file path.
+///
+/// Unlike `load_sidecar`, a missing file is an error rather than a default.
+pub fn load_sidecar_file(sidecar_path: &Path) -> Result<Sidecar, SidecarError> {
+    let sidecar_json = match fs::read_to_string(sidecar_path) {
         Ok(sidecar_json) => sidecar_json,
         Err(source) => {
             return Err(SidecarError::ReadFailed {
-                path: sidecar_path.clone(),
+                path: sidecar_path.to_path_buf(),
                 source,
             });
         }
@@ -191,7 +198,7 @@ pub fn load_sidecar(path: &str) -> Result<Sidecar, SidecarError> {
         Ok(sidecar) => sidecar,
         Err(source) => {
             return Err(SidecarError::ParseFailed {
-                path: sidecar_path,
+                path: sidecar_path.to_path_buf(),
                 source,
             });
         }
@@ -0,0 +1,5 @@
+fn main() {
+    let args = std::env::args().skip(1).collect();
+
+    std::process::exit(extents_lib::cli::run(args));
+}
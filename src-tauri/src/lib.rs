@@ -1,4 +1,5 @@
 pub mod app;
+pub mod cli;
 pub mod commands;
 pub mod core;
 pub mod renderer;
@@ -106,6 +107,15 @@ pub fn run() {
             commands::renderer::clear_renderer,
             commands::renderer::set_render_state,
             commands::renderer::get_renderer_inspection,
+            commands::renderer::sample_white_balance,
+            commands::renderer::sample_color_band,
+            commands::renderer::source_position_at_viewport,
+            commands::renderer::sample_mask_color,
+            commands::renderer::find_spot_source,
+            commands::renderer::set_sharpening_mask_preview,
+            commands::renderer::set_local_mask_overlay,
+            commands::renderer::set_spot_visualization,
+            commands::renderer::set_hdr_output,
             // Inspection Commands
             commands::inspection::capture_inspection_checkpoint_artifact,
             commands::inspection::create_inspection_checkpoint_set,
@@ -120,6 +130,13 @@ pub fn run() {
             commands::annotations::get_annotations,
             // Exif Commands
             commands::exif::get_exif_metadata,
+            // Bad Pixel Map Commands
+            commands::bad_pixels::get_bad_pixel_map,
+            commands::bad_pixels::set_bad_pixel_map,
+            // Export Commands
+            commands::export::export_images,
+            // Look Commands
+            commands::look::read_look_file,
             // Settings Commands
             commands::settings::get_cache_size,
             commands::settings::clear_cache,
@@ -127,6 +144,11 @@ pub fn run() {
             commands::sidecar::load_sidecar,
             commands::sidecar::save_sidecar,
             commands::sidecar::sync_sidecar,
+            // Edit History Commands
+            commands::history::get_edit_history_summary,
+            commands::history::record_edit_history,
+            commands::history::undo_edit_history,
+            commands::history::redo_edit_history,
         ])
         // Running the application
         .run(tauri::generate_context!())
@@ -42,6 +42,62 @@ impl GpuContext {
             queue,
         })
     }
+
+    /// Initializes a GPU context for offscreen full-resolution processing.
+    ///
+    /// Offscreen work never presents to a surface, but it does need to process
+    /// sensor-sized textures and readback buffers, so the device requests the
+    /// adapter's own limits instead of the conservative wgpu defaults. When no
+    /// hardware adapter is available (headless CI, servers), a software adapter
+    /// is requested instead.
+    pub fn new_offscreen() -> Result<Self> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+
+        let adapter = match pollster::block_on(instance.request_adapter(
+            &wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                force_fallback_adapter: false,
+                compatible_surface: None,
+            },
+        )) {
+            Ok(adapter) => adapter,
+            Err(hardware_error) => {
+                log::warn!(
+                        "[gpu] No hardware adapter for offscreen work ({hardware_error}); trying software fallback"
+                    );
+
+                match pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+                    power_preference: wgpu::PowerPreference::LowPower,
+                    force_fallback_adapter: true,
+                    compatible_surface: None,
+                }))
+                .context("failed to request offscreen GPU adapter")
+                {
+                    Ok(adapter) => adapter,
+                    Err(error) => return Err(error),
+                }
+            }
+        };
+
+        let (device, queue) =
+            match pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+                label: Some("Offscreen Device"),
+                required_limits: adapter.limits(),
+                ..Default::default()
+            }))
+            .context("failed to request offscreen GPU device")
+            {
+                Ok(device_and_queue) => device_and_queue,
+                Err(error) => return Err(error),
+            };
+
+        Ok(Self {
+            instance,
+            adapter,
+            device,
+            queue,
+        })
+    }
 }
 
 /// Window-backed presentation state for the renderer surface.
@@ -51,6 +107,8 @@ impl GpuContext {
 pub struct SurfaceContext {
     surface: wgpu::Surface<'static>,
     config: wgpu::SurfaceConfiguration,
+    sdr_format: wgpu::TextureFormat,
+    hdr_format: Option<wgpu::TextureFormat>,
 }
 
 impl SurfaceContext {
@@ -85,6 +143,14 @@ impl SurfaceContext {
             None => return Err(anyhow!("renderer surface exposes no supported formats")),
         };
 
+        // Half-float surfaces are presented as extended-range linear sRGB, which
+        // is how compositors accept HDR content from wgpu.
+        let hdr_format = surface_capabilities
+            .formats
+            .iter()
+            .copied()
+            .find(|f| *f == wgpu::TextureFormat::Rgba16Float);
+
         let present_mode = match surface_capabilities.present_modes.first().copied() {
             Some(present_mode) => present_mode,
             None => {
@@ -112,7 +178,12 @@ impl SurfaceContext {
 
         surface.configure(&gpu.device, &config);
 
-        Ok(Self { surface, config })
+        Ok(Self {
+            surface,
+            config,
+            sdr_format: surface_format,
+            hdr_format,
+        })
     }
 
     /// Returns the texture format used by the window surface.
@@ -120,6 +191,33 @@ impl SurfaceContext {
         self.config.format
     }
 
+    /// Returns whether the surface is configured for extended-range output.
+    pub fn is_hdr(&self) -> bool {
+        self.hdr_format == Some(self.config.format)
+    }
+
+    /// Switches between the SDR and extended-range surface formats.
+    ///
+    /// Returns whether the surface is presenting extended range afterwards,
+    /// which is `false` when HDR was requested but the adapter does not expose
+    /// a half-float surface format.
+    pub fn set_hdr(&mut self, gpu: &GpuContext, enabled: bool) -> bool {
+        let format = match (enabled, self.hdr_format) {
+            (true, Some(hdr_format)) => hdr_format,
+            _ => self.sdr_format,
+        };
+
+        if format != self.config.format {
+            self.config.format = format;
+
+            if self.config.width > 0 && self.config.height > 0 {
+                self.surface.configure(&gpu.device, &self.config);
+            }
+        }
+
+        self.is_hdr()
+    }
+
     /// Returns the current configured surface width.
     pub fn width(&self) -> u32 {
         self.config.width
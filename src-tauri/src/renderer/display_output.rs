@@ -0,0 +1,26 @@
+/// Luminance of SDR reference white, which display output always encodes as `1.0`.
+pub const SDR_REFERENCE_WHITE_NITS: f32 = 203.0;
+
+/// Peak luminance used when HDR output is requested without a display peak.
+pub const DEFAULT_HDR_PEAK_NITS: f32 = 1000.0;
+
+/// Dynamic range targeted by the output transform.
+///
+/// HDR output is extended-range linear Rec.709 where `1.0` is SDR reference
+/// white, so display-referred SDR sources look the same on both paths and only
+/// tone-mapped scene-referred sources use the extra headroom.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DisplayOutput {
+    Sdr,
+    Hdr { peak_nits: f32 },
+}
+
+impl DisplayOutput {
+    /// Returns peak display luminance relative to SDR reference white.
+    pub fn headroom(self) -> f32 {
+        match self {
+            Self::Sdr => 1.0,
+            Self::Hdr { peak_nits } => (peak_nits / SDR_REFERENCE_WHITE_NITS).max(1.0),
+        }
+    }
+}
@@ -6,16 +6,18 @@ use tauri::async_runtime;
 use super::input::build_input_from_path;
 use super::input::Input;
 use super::manager::{RendererManager, RendererManagerHandle};
+use crate::core::db::connection::DbConnection;
 
 /// Builds input from a path and sets it only if the request is still active.
 pub(crate) fn set_requested_input_from_path(
     path: &str,
     request_id: u64,
     renderer_manager: &RendererManagerHandle,
+    db: &DbConnection,
 ) -> Result<(), String> {
     let build_start = Instant::now();
 
-    let input = match build_input_from_path(path) {
+    let input = match build_input_from_path(path, Some(db)) {
         Ok(input) => input,
         Err(error) => return Err(error.to_string()),
     };
@@ -31,11 +33,12 @@ pub(crate) async fn swap_requested_input(
     path: String,
     request_id: u64,
     renderer_manager: RendererManagerHandle,
+    db: DbConnection,
 ) -> Result<(), String> {
     let input_result = async_runtime::spawn_blocking(move || {
         let build_start = Instant::now();
 
-        build_input_from_path(&path).map(|input| (input, elapsed_ms(build_start)))
+        build_input_from_path(&path, Some(&db)).map(|input| (input, elapsed_ms(build_start)))
     })
     .await;
 
@@ -64,6 +67,7 @@ pub(crate) fn spawn_full_image_load(
     path: String,
     request_id: u64,
     renderer_manager: RendererManagerHandle,
+    db: DbConnection,
 ) {
     let renderer_for_task = renderer_manager.clone();
     let cloned_path_for_logging = path.clone();
@@ -77,7 +81,7 @@ pub(crate) fn spawn_full_image_load(
         let input_result = async_runtime::spawn_blocking(move || {
             let build_start = Instant::now();
 
-            build_input_from_path(&path).map(|input| (input, elapsed_ms(build_start)))
+            build_input_from_path(&path, Some(&db)).map(|input| (input, elapsed_ms(build_start)))
         })
         .await;
 
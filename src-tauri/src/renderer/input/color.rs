@@ -0,0 +1,202 @@
+/// A three-by-three color transform matrix.
+pub(super) type ColorMatrix3 = [[f32; 3]; 3];
+
+/// Converts CIE XYZ to linear sRGB primaries using the sRGB D65 white point.
+#[allow(clippy::excessive_precision)]
+pub(super) const XYZ_TO_LINEAR_SRGB: ColorMatrix3 = [
+    [3.2404542, -1.5371385, -0.4985314],
+    [-0.9692660, 1.8760108, 0.0415560],
+    [0.0556434, -0.2040259, 1.0572252],
+];
+
+/// Converts linear Rec.2020 RGB to linear sRGB primaries.
+///
+/// This is the inverse of the sRGB-to-Rec.2020 transform used by the raster
+/// development shader.
+#[allow(clippy::excessive_precision)]
+pub(super) const LINEAR_REC2020_TO_LINEAR_SRGB: ColorMatrix3 = [
+    [1.6604910, -0.5876411, -0.0728499],
+    [-0.1245505, 1.1328999, -0.0083494],
+    [-0.0181508, -0.1005789, 1.1187297],
+];
+
+/// Lowest correlated color temperature accepted by the Planckian approximation.
+const MIN_TEMPERATURE_K: f32 = 1_667.0;
+
+/// Highest correlated color temperature accepted by the Planckian approximation.
+const MAX_TEMPERATURE_K: f32 = 25_000.0;
+
+/// Distance from the Planckian locus, in CIE 1960 uv, reached at full tint.
+const TINT_DUV_RANGE: f32 = 0.025;
+
+/// Bisection steps used when solving chromaticity back to temperature.
+const TEMPERATURE_SOLVE_ITERATIONS: usize = 40;
+
+/// Applies a three-by-three matrix to a color vector.
+pub(super) fn transform_color(matrix: ColorMatrix3, color: [f32; 3]) -> [f32; 3] {
+    [
+        (matrix[0][0] * color[0]) + (matrix[0][1] * color[1]) + (matrix[0][2] * color[2]),
+        (matrix[1][0] * color[0]) + (matrix[1][1] * color[1]) + (matrix[1][2] * color[2]),
+        (matrix[2][0] * color[0]) + (matrix[2][1] * color[1]) + (matrix[2][2] * color[2]),
+    ]
+}
+
+/// Converts XYZ to xy chromaticity, returning `None` for non-positive sums.
+pub(super) fn xyz_to_xy(xyz: [f32; 3]) -> Option<[f32; 2]> {
+    let sum = xyz[0] + xyz[1] + xyz[2];
+
+    if !sum.is_finite() || sum <= f32::EPSILON {
+        return None;
+    }
+
+    Some([xyz[0] / sum, xyz[1] / sum])
+}
+
+/// Converts an xy chromaticity to XYZ with unit luminance.
+pub(super) fn xy_to_xyz(xy: [f32; 2]) -> [f32; 3] {
+    let [x, y] = xy;
+
+    [x / y, 1.0, (1.0 - x - y) / y]
+}
+
+/// Returns the chromaticity of an illuminant described by temperature and tint.
+///
+/// Temperature selects a point on the Planckian locus. Tint offsets that point
+/// perpendicular to the locus in CIE 1960 uv. Positive tint describes a greener
+/// illuminant (above the locus), so neutralizing it shifts the image toward
+/// magenta; negative tint does the opposite.
+pub(super) fn temperature_tint_to_xy(temperature_k: f32, tint: f32) -> [f32; 2] {
+    let temperature_k = temperature_k.clamp(MIN_TEMPERATURE_K, MAX_TEMPERATURE_K);
+    let tint = tint.clamp(-1.0, 1.0);
+
+    let uv = xy_to_uv(planckian_xy(temperature_k));
+
+    if tint == 0.0 {
+        return uv_to_xy(uv);
+    }
+
+    let normal = locus_normal(temperature_k);
+    let duv = tint * TINT_DUV_RANGE;
+
+    uv_to_xy([uv[0] + (normal[0] * duv), uv[1] + (normal[1] * duv)])
+}
+
+/// Returns the temperature and tint whose chromaticity is closest to `xy`.
+///
+/// This inverts `temperature_tint_to_xy` by bisecting in mired space for the
+/// locus point whose tangent is perpendicular to the offset from `xy`, then
+/// measuring the signed distance from the locus as tint.
+pub(super) fn xy_to_temperature_tint(xy: [f32; 2]) -> (f32, f32) {
+    let uv = xy_to_uv(xy);
+    let mut warm_mired = 1.0e6 / MIN_TEMPERATURE_K;
+    let mut cool_mired = 1.0e6 / MAX_TEMPERATURE_K;
+
+    let warm_offset = locus_tangent_offset(uv, warm_mired);
+    let cool_offset = locus_tangent_offset(uv, cool_mired);
+
+    let mired = if warm_offset.signum() == cool_offset.signum() {
+        if warm_offset.abs() < cool_offset.abs() {
+            warm_mired
+        } else {
+            cool_mired
+        }
+    } else {
+        for _ in 0..TEMPERATURE_SOLVE_ITERATIONS {
+            let middle_mired = (warm_mired + cool_mired) * 0.5;
+
+            if locus_tangent_offset(uv, middle_mired).signum() == warm_offset.signum() {
+                warm_mired = middle_mired;
+            } else {
+                cool_mired = middle_mired;
+            }
+        }
+
+        (warm_mired + cool_mired) * 0.5
+    };
+
+    let temperature_k = 1.0e6 / mired;
+    let locus_uv = xy_to_uv(planckian_xy(temperature_k));
+    let normal = locus_normal(temperature_k);
+    let duv = ((uv[0] - locus_uv[0]) * normal[0]) + ((uv[1] - locus_uv[1]) * normal[1]);
+
+    (temperature_k, (duv / TINT_DUV_RANGE).clamp(-1.0, 1.0))
+}
+
+/// Returns the projection of `uv - locus(mired)` onto the locus tangent.
+///
+/// The sign flips where the perpendicular from `uv` meets the locus.
+fn locus_tangent_offset(uv: [f32; 2], mired: f32) -> f32 {
+    let temperature_k = 1.0e6 / mired;
+    let locus_uv = xy_to_uv(planckian_xy(temperature_k));
+    let normal = locus_normal(temperature_k);
+    let tangent = [normal[1], -normal[0]];
+
+    ((uv[0] - locus_uv[0]) * tangent[0]) + ((uv[1] - locus_uv[1]) * tangent[1])
+}
+
+/// Returns the unit normal to the Planckian locus in CIE 1960 uv.
+///
+/// The normal points above the locus, toward green.
+fn locus_normal(temperature_k: f32) -> [f32; 2] {
+    let step = (temperature_k * 0.01).max(1.0);
+    let warmer = xy_to_uv(planckian_xy((temperature_k - step).max(MIN_TEMPERATURE_K)));
+    let cooler = xy_to_uv(planckian_xy((temperature_k + step).min(MAX_TEMPERATURE_K)));
+    let tangent = [cooler[0] - warmer[0], cooler[1] - warmer[1]];
+    let tangent_length = ((tangent[0] * tangent[0]) + (tangent[1] * tangent[1])).sqrt();
+
+    if tangent_length <= f32::EPSILON {
+        return [0.0, 1.0];
+    }
+
+    let normal = [-tangent[1] / tangent_length, tangent[0] / tangent_length];
+
+    if normal[1] < 0.0 {
+        [-normal[0], -normal[1]]
+    } else {
+        normal
+    }
+}
+
+/// Approximates the Planckian locus chromaticity for a color temperature.
+///
+/// Uses the Kim et al. cubic spline fit, valid from 1667 K to 25000 K.
+fn planckian_xy(temperature_k: f32) -> [f32; 2] {
+    let t = f64::from(temperature_k);
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let x = if t <= 4_000.0 {
+        (-0.266_123_9e9 / t3) - (0.234_358_9e6 / t2) + (0.877_695_6e3 / t) + 0.179_910
+    } else {
+        (-3.025_846_9e9 / t3) + (2.107_037_9e6 / t2) + (0.222_634_7e3 / t) + 0.240_390
+    };
+
+    let x2 = x * x;
+    let x3 = x2 * x;
+
+    let y = if t <= 2_222.0 {
+        (-1.106_381_4 * x3) - (1.348_110_20 * x2) + (2.185_558_32 * x) - 0.202_196_83
+    } else if t <= 4_000.0 {
+        (-0.954_947_6 * x3) - (1.374_185_93 * x2) + (2.091_370_15 * x) - 0.167_488_67
+    } else {
+        (3.081_758_0 * x3) - (5.873_386_70 * x2) + (3.751_129_97 * x) - 0.370_014_83
+    };
+
+    [x as f32, y as f32]
+}
+
+/// Converts CIE 1931 xy chromaticity to CIE 1960 uv.
+fn xy_to_uv(xy: [f32; 2]) -> [f32; 2] {
+    let [x, y] = xy;
+    let denominator = (-2.0 * x) + (12.0 * y) + 3.0;
+
+    [(4.0 * x) / denominator, (6.0 * y) / denominator]
+}
+
+/// Converts CIE 1960 uv chromaticity to CIE 1931 xy.
+fn uv_to_xy(uv: [f32; 2]) -> [f32; 2] {
+    let [u, v] = uv;
+    let denominator = (2.0 * u) - (8.0 * v) + 4.0;
+
+    [(3.0 * u) / denominator, (2.0 * v) / denominator]
+}
@@ -1,11 +1,22 @@
+mod color;
+mod profile;
 mod raster;
 mod raw;
+mod white_balance;
 
 use anyhow::Result;
+use log::warn;
 
+use super::display_output::DisplayOutput;
 use super::processing_graph::DevelopmentParameters;
+use crate::core::db::bad_pixels::{load_camera_bad_pixels, BadPixel};
+use crate::core::db::connection::DbConnection;
+use crate::core::editing::CropRect;
 use crate::core::image::source::{decode_source_from_path, ImageSource};
-use crate::core::image::ImageDimensions;
+use crate::core::image::{extract_exif_metadata, ExifMetadata, ImageDimensions};
+use crate::core::lens::{resolve_lens_calibration, LensCalibration};
+
+pub(in crate::renderer) use white_balance::{ResolvedWhiteBalance, WhiteBalanceCalibration};
 
 /// Renderer-ready input built from source-domain image data.
 ///
@@ -16,8 +27,12 @@ pub(super) struct Input {
     image: InputImage,
     development_source: DevelopmentSource,
     development_parameters: DevelopmentParameters,
+    white_balance: WhiteBalanceCalibration,
     output_transform: OutputTransformSettings,
     source_metadata: SourceMetadata,
+    lens_calibration: Option<LensCalibration>,
+    iso: Option<u32>,
+    default_crop: Option<CropRect>,
 }
 
 impl Input {
@@ -26,6 +41,7 @@ impl Input {
         image: InputImage,
         development_source: DevelopmentSource,
         development_parameters: DevelopmentParameters,
+        white_balance: WhiteBalanceCalibration,
         output_transform: OutputTransformSettings,
         source_metadata: SourceMetadata,
     ) -> Self {
@@ -33,11 +49,36 @@ impl Input {
             image,
             development_source,
             development_parameters,
+            white_balance,
             output_transform,
             source_metadata,
+            lens_calibration: None,
+            iso: None,
+            default_crop: None,
         }
     }
 
+    /// Attaches the lens profile corrections resolved for the source capture.
+    pub(in crate::renderer) fn with_lens_calibration(
+        mut self,
+        lens_calibration: Option<LensCalibration>,
+    ) -> Self {
+        self.lens_calibration = lens_calibration;
+        self
+    }
+
+    /// Attaches the capture ISO used to pick default noise reduction strength.
+    pub(in crate::renderer) fn with_iso(mut self, iso: Option<u32>) -> Self {
+        self.iso = iso;
+        self
+    }
+
+    /// Attaches the camera's recommended crop in normalized source coordinates.
+    pub(in crate::renderer) fn with_default_crop(mut self, default_crop: Option<CropRect>) -> Self {
+        self.default_crop = default_crop;
+        self
+    }
+
     /// Returns the CPU-side source payload to upload into graph resources.
     pub(super) fn image(&self) -> &InputImage {
         &self.image
@@ -53,6 +94,11 @@ impl Input {
         self.development_parameters
     }
 
+    /// Returns the calibration used to resolve recipe white balance for this source.
+    pub(super) fn white_balance(&self) -> &WhiteBalanceCalibration {
+        &self.white_balance
+    }
+
     /// Returns how this input should be transformed for display.
     pub(super) fn output_transform(&self) -> OutputTransformSettings {
         self.output_transform
@@ -62,6 +108,21 @@ impl Input {
     pub(super) fn source_metadata(&self) -> &SourceMetadata {
         &self.source_metadata
     }
+
+    /// Returns lens profile corrections matched from the source EXIF, if any.
+    pub(super) fn lens_calibration(&self) -> Option<&LensCalibration> {
+        self.lens_calibration.as_ref()
+    }
+
+    /// Returns the capture ISO from the source EXIF, if recorded.
+    pub(super) fn iso(&self) -> Option<u32> {
+        self.iso
+    }
+
+    /// Returns the crop used while the recipe has none, if the source recommends one.
+    pub(super) fn default_crop(&self) -> Option<CropRect> {
+        self.default_crop
+    }
 }
 
 /// CPU-side texel payload used for renderer source upload.
@@ -106,6 +167,8 @@ impl InputImage {
 pub(in crate::renderer) enum DevelopmentSource {
     RasterSrgb,
     RawBayer2x2,
+    RawXTrans6x6,
+    RawLinearRgb,
 }
 
 /// Controls how graph output should be rendered for display.
@@ -113,6 +176,7 @@ pub(in crate::renderer) enum DevelopmentSource {
 pub(super) enum DisplayIntent {
     DirectSdr,
     ToneMapToSdr,
+    ToneMapToHdr,
 }
 
 /// Output-transform settings selected for renderer input display.
@@ -120,6 +184,7 @@ pub(super) enum DisplayIntent {
 pub(super) struct OutputTransformSettings {
     display_intent: DisplayIntent,
     base_exposure_ev: f32,
+    display_headroom: f32,
 }
 
 /// Source-domain metadata retained for renderer inspection.
@@ -153,13 +218,26 @@ pub(in crate::renderer) struct RawSourceMetadata {
     pub(in crate::renderer) bits_per_sample: u32,
     pub(in crate::renderer) sensor_dimensions: ImageDimensions,
     pub(in crate::renderer) crop_area: SourceRect,
-    pub(in crate::renderer) cfa_pattern: [u32; 4],
+    pub(in crate::renderer) cfa_width: u32,
+    pub(in crate::renderer) cfa_height: u32,
+    pub(in crate::renderer) cfa_pattern: Vec<u32>,
     pub(in crate::renderer) source_black_levels: [f32; 4],
     pub(in crate::renderer) source_white_levels: [f32; 4],
     pub(in crate::renderer) normalized_black_levels: [f32; 4],
     pub(in crate::renderer) normalized_white_levels: [f32; 4],
     pub(in crate::renderer) as_shot_white_balance: [f32; 4],
     pub(in crate::renderer) headroom_white_balance: [f32; 3],
+    pub(in crate::renderer) mapped_bad_pixels: usize,
+    pub(in crate::renderer) dng_opcodes: Vec<DngOpcodeStatus>,
+}
+
+/// One DNG opcode found in the source file and whether development applies it.
+#[derive(Debug, Clone)]
+pub(in crate::renderer) struct DngOpcodeStatus {
+    pub(in crate::renderer) list: &'static str,
+    pub(in crate::renderer) name: String,
+    pub(in crate::renderer) optional: bool,
+    pub(in crate::renderer) applied: bool,
 }
 
 /// A rectangle in source image coordinates.
@@ -177,6 +255,7 @@ impl OutputTransformSettings {
         Self {
             display_intent: DisplayIntent::DirectSdr,
             base_exposure_ev: 0.0,
+            display_headroom: 1.0,
         }
     }
 
@@ -185,6 +264,23 @@ impl OutputTransformSettings {
         Self {
             display_intent: DisplayIntent::ToneMapToSdr,
             base_exposure_ev,
+            display_headroom: 1.0,
+        }
+    }
+
+    /// Retargets tone-mapped input at the given display output.
+    ///
+    /// Scene-referred input tone-mapped for SDR is mapped into the HDR headroom
+    /// instead. Display-referred input is left unchanged because it has no
+    /// highlight information above SDR white.
+    pub(super) fn for_display_output(self, output: DisplayOutput) -> Self {
+        match (self.display_intent, output) {
+            (DisplayIntent::ToneMapToSdr, DisplayOutput::Hdr { .. }) => Self {
+                display_intent: DisplayIntent::ToneMapToHdr,
+                display_headroom: output.headroom(),
+                ..self
+            },
+            _ => self,
         }
     }
 
@@ -197,17 +293,71 @@ impl OutputTransformSettings {
     pub(super) fn base_exposure_ev(self) -> f32 {
         self.base_exposure_ev
     }
+
+    /// Returns display peak luminance relative to SDR reference white.
+    pub(super) fn display_headroom(self) -> f32 {
+        self.display_headroom
+    }
 }
 
 /// Builds renderer-ready image data from a source image path.
-pub(super) fn build_input_from_path(path: &str) -> Result<Input> {
+///
+/// When a database is available, RAW sources pick up the user bad pixel map
+/// stored for their camera body. Every source is matched against the local
+/// lens profile database using its EXIF lens metadata and carries its EXIF ISO.
+pub(super) fn build_input_from_path(path: &str, db: Option<&DbConnection>) -> Result<Input> {
     let source = match decode_source_from_path(path) {
         Ok(source) => source,
         Err(error) => return Err(error),
     };
 
-    match source {
+    let input = match source {
         ImageSource::Raster(raster) => raster::build_input(raster),
-        ImageSource::Raw(raw) => raw::build_input(raw),
+        ImageSource::Raw(raw) => {
+            let bad_pixels = camera_bad_pixels(db, raw.camera_make(), raw.camera_model());
+
+            raw::build_input(raw, &bad_pixels)
+        }
+    };
+
+    let input = match input {
+        Ok(input) => input,
+        Err(error) => return Err(error),
+    };
+
+    let exif = source_exif_metadata(path);
+
+    Ok(input
+        .with_lens_calibration(exif.as_ref().and_then(resolve_lens_calibration))
+        .with_iso(exif.and_then(|exif| exif.iso)))
+}
+
+/// Reads capture metadata for a source, treating unreadable EXIF as missing.
+fn source_exif_metadata(path: &str) -> Option<ExifMetadata> {
+    match extract_exif_metadata(path) {
+        Ok(exif) => Some(exif),
+        Err(error) => {
+            warn!("Failed to read capture metadata for {path}: {error}");
+            None
+        }
+    }
+}
+
+/// Loads the bad pixel map for a camera body, treating lookup failures as no map.
+fn camera_bad_pixels(
+    db: Option<&DbConnection>,
+    camera_make: &str,
+    camera_model: &str,
+) -> Vec<BadPixel> {
+    let Some(db) = db else {
+        return Vec::new();
+    };
+
+    match load_camera_bad_pixels(db, camera_make, camera_model) {
+        Ok(bad_pixels) => bad_pixels,
+        Err(error) => {
+            warn!("Failed to load bad pixel map for {camera_make} {camera_model}: {error}");
+            Vec::new()
+        }
     }
 }
@@ -0,0 +1,278 @@
+use anyhow::{anyhow, Result};
+use rawler::imgop::matrix::{multiply, normalize, pseudo_inverse};
+
+use super::color::{
+    temperature_tint_to_xy, transform_color, xy_to_temperature_tint, xy_to_xyz, xyz_to_xy,
+    ColorMatrix3,
+};
+use crate::core::image::source::RawColorMatrixAnchor;
+
+/// EXIF `LightSource` code for CIE D65, preferred when no anchor has a known temperature.
+const FALLBACK_ILLUMINANT_CODE: u16 = 21;
+
+/// Chromaticity of D50, the DNG starting point for neutral-to-xy solving.
+const D50_XY: [f32; 2] = [0.3457, 0.3585];
+
+/// Maximum iterations when solving a camera neutral back to a white point.
+const NEUTRAL_SOLVE_ITERATIONS: usize = 30;
+
+/// Convergence threshold, in xy units, for the camera neutral solve.
+const NEUTRAL_SOLVE_TOLERANCE: f32 = 1.0e-7;
+
+/// Converts linear Rec.2020 RGB values to CIE XYZ using Rec.2020's D65 white point.
+///
+/// This defines the current working-space basis used by the GPU development
+/// graph. RAW camera matrices are inverted into this destination space.
+#[allow(clippy::excessive_precision)]
+const REC2020_TO_XYZ_D65: ColorMatrix3 = [
+    [
+        0.63695804830129130,
+        0.14461690358620838,
+        0.16888097516417205,
+    ],
+    [
+        0.26270021201126703,
+        0.67799807151887100,
+        0.05930171646986194,
+    ],
+    [
+        0.00000000000000000,
+        0.02807269304908750,
+        1.06098505771079090,
+    ],
+];
+
+/// Camera color profile built from embedded XYZ-to-camera matrix anchors.
+///
+/// Anchors with a known calibration temperature are interpolated DNG-style:
+/// linearly in inverse temperature between the two anchors that bracket the
+/// scene white point, and clamped to the nearest anchor outside that range.
+/// Sources whose anchors have no usable temperature fall back to one matrix.
+#[derive(Debug, Clone)]
+pub(super) struct CameraColorProfile {
+    anchors: Vec<ProfileAnchor>,
+}
+
+/// One calibration matrix and the correlated color temperature it was measured at.
+#[derive(Debug, Clone, Copy)]
+struct ProfileAnchor {
+    temperature_k: Option<f32>,
+    xyz_to_camera: ColorMatrix3,
+}
+
+/// A camera matrix resolved for one scene white point.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ProfileWhitePoint {
+    pub(super) temperature_k: f32,
+    pub(super) tint: f32,
+    pub(super) xyz_to_camera: ColorMatrix3,
+}
+
+impl CameraColorProfile {
+    /// Builds a profile from RAW color matrix anchors.
+    pub(super) fn from_anchors(anchors: &[RawColorMatrixAnchor]) -> Result<Self> {
+        let mut calibrated: Vec<ProfileAnchor> = anchors
+            .iter()
+            .filter_map(|anchor| {
+                illuminant_temperature_k(anchor.illuminant()).map(|temperature_k| ProfileAnchor {
+                    temperature_k: Some(temperature_k),
+                    xyz_to_camera: flat_xyz_to_camera_matrix(anchor.xyz_to_camera()),
+                })
+            })
+            .collect();
+
+        if !calibrated.is_empty() {
+            calibrated.sort_by(|left, right| left.temperature_k.total_cmp(&right.temperature_k));
+
+            return Ok(Self {
+                anchors: calibrated,
+            });
+        }
+
+        let fallback = anchors
+            .iter()
+            .find(|anchor| anchor.illuminant() == FALLBACK_ILLUMINANT_CODE)
+            .or_else(|| anchors.first());
+
+        match fallback {
+            Some(anchor) => Ok(Self {
+                anchors: vec![ProfileAnchor {
+                    temperature_k: None,
+                    xyz_to_camera: flat_xyz_to_camera_matrix(anchor.xyz_to_camera()),
+                }],
+            }),
+            None => Err(anyhow!(
+                "RAW source requires at least one camera color matrix anchor"
+            )),
+        }
+    }
+
+    /// Resolves the profile for an illuminant given as temperature and tint.
+    pub(super) fn white_point_for_temperature(
+        &self,
+        temperature_k: f32,
+        tint: f32,
+    ) -> ProfileWhitePoint {
+        ProfileWhitePoint {
+            temperature_k,
+            tint,
+            xyz_to_camera: self.xyz_to_camera_at(temperature_k),
+        }
+    }
+
+    /// Resolves the profile for a camera-space neutral.
+    ///
+    /// Follows the DNG procedure: starting from D50, interpolate the matrix at
+    /// the current white point's temperature, map the neutral through its
+    /// inverse to a new white point, and repeat until the chromaticity settles.
+    pub(super) fn white_point_for_camera_neutral(
+        &self,
+        camera_neutral: [f32; 3],
+    ) -> ProfileWhitePoint {
+        let mut xy = D50_XY;
+
+        for _ in 0..NEUTRAL_SOLVE_ITERATIONS {
+            let (temperature_k, _) = xy_to_temperature_tint(xy);
+            let camera_to_xyz = pseudo_inverse(self.xyz_to_camera_at(temperature_k));
+
+            let Some(next_xy) = xyz_to_xy(transform_color(camera_to_xyz, camera_neutral)) else {
+                break;
+            };
+
+            let delta = (next_xy[0] - xy[0]).abs() + (next_xy[1] - xy[1]).abs();
+            xy = next_xy;
+
+            if delta <= NEUTRAL_SOLVE_TOLERANCE {
+                break;
+            }
+        }
+
+        let (temperature_k, tint) = xy_to_temperature_tint(xy);
+
+        ProfileWhitePoint {
+            temperature_k,
+            tint,
+            xyz_to_camera: self.xyz_to_camera_at(temperature_k),
+        }
+    }
+
+    /// Interpolates the XYZ-to-camera matrix at a correlated color temperature.
+    fn xyz_to_camera_at(&self, temperature_k: f32) -> ColorMatrix3 {
+        let first = self.anchors[0];
+        let last = self.anchors[self.anchors.len() - 1];
+
+        let (Some(first_temperature_k), Some(last_temperature_k)) =
+            (first.temperature_k, last.temperature_k)
+        else {
+            return first.xyz_to_camera;
+        };
+
+        if temperature_k <= first_temperature_k {
+            return first.xyz_to_camera;
+        }
+
+        if temperature_k >= last_temperature_k {
+            return last.xyz_to_camera;
+        }
+
+        for pair in self.anchors.windows(2) {
+            let (Some(warm_temperature_k), Some(cool_temperature_k)) =
+                (pair[0].temperature_k, pair[1].temperature_k)
+            else {
+                continue;
+            };
+
+            if temperature_k > cool_temperature_k {
+                continue;
+            }
+
+            let inverse_temperature = 1.0 / temperature_k;
+            let warm_inverse = 1.0 / warm_temperature_k;
+            let cool_inverse = 1.0 / cool_temperature_k;
+            let warm_weight = (inverse_temperature - cool_inverse) / (warm_inverse - cool_inverse);
+
+            return blend_matrices(
+                pair[0].xyz_to_camera,
+                pair[1].xyz_to_camera,
+                warm_weight.clamp(0.0, 1.0),
+            );
+        }
+
+        last.xyz_to_camera
+    }
+}
+
+impl ProfileWhitePoint {
+    /// Returns the illuminant white in camera space, scaled to unit luminance.
+    pub(super) fn camera_neutral(&self) -> [f32; 3] {
+        let white_xyz = xy_to_xyz(temperature_tint_to_xy(self.temperature_k, self.tint));
+
+        transform_color(self.xyz_to_camera, white_xyz)
+    }
+
+    /// Builds the camera-space RGB to linear Rec.2020 working-space matrix.
+    ///
+    /// This composes Rec.2020-to-XYZ with the resolved XYZ-to-camera matrix,
+    /// normalizes the result so camera white maps to working white, then
+    /// inverts it for the development shader.
+    pub(super) fn camera_to_working(&self) -> ColorMatrix3 {
+        let rec2020_to_camera = normalize(multiply(&self.xyz_to_camera, &REC2020_TO_XYZ_D65));
+
+        pseudo_inverse(rec2020_to_camera)
+    }
+}
+
+/// Returns `warm * weight + cool * (1 - weight)` element-wise.
+fn blend_matrices(warm: ColorMatrix3, cool: ColorMatrix3, warm_weight: f32) -> ColorMatrix3 {
+    let cool_weight = 1.0 - warm_weight;
+    let mut blended = [[0.0; 3]; 3];
+
+    for row in 0..3 {
+        for column in 0..3 {
+            blended[row][column] =
+                (warm[row][column] * warm_weight) + (cool[row][column] * cool_weight);
+        }
+    }
+
+    blended
+}
+
+/// Returns the correlated color temperature of an EXIF/DNG `LightSource` code.
+///
+/// Codes without a defined spectrum (unknown, other) return `None`.
+fn illuminant_temperature_k(code: u16) -> Option<f32> {
+    let temperature_k = match code {
+        1 => 5_500.0,  // Daylight
+        2 => 4_200.0,  // Fluorescent
+        3 => 2_850.0,  // Tungsten
+        4 => 5_500.0,  // Flash
+        9 => 5_500.0,  // Fine weather
+        10 => 6_500.0, // Cloudy
+        11 => 7_500.0, // Shade
+        12 => 6_430.0, // Daylight fluorescent
+        13 => 5_000.0, // Day white fluorescent
+        14 => 4_150.0, // Cool white fluorescent
+        15 => 3_450.0, // White fluorescent
+        16 => 2_940.0, // Warm white fluorescent
+        17 => 2_856.0, // Standard light A
+        18 => 4_874.0, // Standard light B
+        19 => 6_774.0, // Standard light C
+        20 => 5_503.0, // D55
+        21 => 6_504.0, // D65
+        22 => 7_504.0, // D75
+        23 => 5_003.0, // D50
+        24 => 3_200.0, // ISO studio tungsten
+        _ => return None,
+    };
+
+    Some(temperature_k)
+}
+
+/// Converts a flat row-major XYZ-to-camera matrix into a three-by-three matrix.
+fn flat_xyz_to_camera_matrix(xyz_to_camera: [f32; 9]) -> ColorMatrix3 {
+    [
+        [xyz_to_camera[0], xyz_to_camera[1], xyz_to_camera[2]],
+        [xyz_to_camera[3], xyz_to_camera[4], xyz_to_camera[5]],
+        [xyz_to_camera[6], xyz_to_camera[7], xyz_to_camera[8]],
+    ]
+}
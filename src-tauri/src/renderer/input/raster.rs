@@ -1,7 +1,10 @@
 use anyhow::Result;
 use image::RgbaImage;
 
-use super::{DevelopmentSource, Input, InputImage, OutputTransformSettings, SourceMetadata};
+use super::{
+    DevelopmentSource, Input, InputImage, OutputTransformSettings, SourceMetadata,
+    WhiteBalanceCalibration,
+};
 use crate::core::image::orientation::{apply_orientation, Orientation};
 use crate::core::image::source::{RasterSamples, RasterSource};
 use crate::core::image::ImageDimensions;
@@ -31,6 +34,7 @@ pub(super) fn build_input(raster: RasterSource) -> Result<Input> {
         image,
         DevelopmentSource::RasterSrgb,
         DevelopmentParameters::from_raster_srgb(),
+        WhiteBalanceCalibration::raster_srgb(),
         OutputTransformSettings::direct_sdr(),
         SourceMetadata::raster(),
     ))
@@ -1,77 +1,85 @@
 use anyhow::{anyhow, Result};
-use rawler::imgop::matrix::{multiply, normalize, pseudo_inverse};
-use rawler::imgop::xyz::Illuminant;
 
+use super::profile::CameraColorProfile;
 use super::{
-    DevelopmentSource, Input, InputImage, OutputTransformSettings, RawSourceMetadata,
-    SourceMetadata, SourceRect,
+    DevelopmentSource, DngOpcodeStatus, Input, InputImage, OutputTransformSettings,
+    RawSourceMetadata, SourceMetadata, SourceRect, WhiteBalanceCalibration,
 };
+use crate::core::db::bad_pixels::BadPixel;
+use crate::core::editing::{CropRect, WhiteBalance};
 use crate::core::image::orientation::Orientation;
-use crate::core::image::source::{RawRect, RawSamples, RawSource};
+use crate::core::image::source::{
+    DngGainMap, DngOpcodeStage, DngOperation, DngPoint, DngRect, DngWarpRectilinear, RawRect,
+    RawSamples, RawSource,
+};
 use crate::core::image::ImageDimensions;
-use crate::renderer::processing_graph::DevelopmentParameters;
-
-/// A three-by-three color transform matrix.
-type ColorMatrix3 = [[f32; 3]; 3];
-
-/// Temporary preference order for choosing an embedded XYZ-to-camera matrix.
-///
-/// This selects a camera profile anchor, not the scene white balance. A more
-/// complete profile builder should interpolate between anchors using the
-/// selected/as-shot neutral instead of permanently preferring one matrix.
-const XYZ_TO_CAMERA_ILLUMINANT_PREFERENCE: [Illuminant; 2] = [Illuminant::D65, Illuminant::A];
+use crate::renderer::processing_graph::{DevelopmentParameters, RawLensWarp};
 
 /// Baseline RAW display placement applied before SDR tone mapping.
 const RAW_DISPLAY_BASE_EXPOSURE_EV: f32 = 1.5;
 
-/// Converts linear Rec.2020 RGB values to CIE XYZ using Rec.2020's D65 white point.
+/// Builds renderer input from a decoded RAW source and its camera's bad pixel map.
 ///
-/// This defines the current working-space basis used by the GPU development
-/// graph. RAW camera matrices are inverted into this destination space.
-#[allow(clippy::excessive_precision)]
-const REC2020_TO_XYZ_D65: ColorMatrix3 = [
-    [
-        0.63695804830129130,
-        0.14461690358620838,
-        0.16888097516417205,
-    ],
-    [
-        0.26270021201126703,
-        0.67799807151887100,
-        0.05930171646986194,
-    ],
-    [
-        0.00000000000000000,
-        0.02807269304908750,
-        1.06098505771079090,
-    ],
-];
-
-/// Builds renderer input from a decoded RAW source.
-pub(super) fn build_input(raw: RawSource) -> Result<Input> {
-    let packed_source = match pack_raw_source_image(&raw) {
+/// Bad pixel maps, including DNG FixBadPixels opcodes, are only applied to
+/// Bayer sources; the X-Trans and linear RGB paths have no correction stage yet.
+pub(super) fn build_input(raw: RawSource, bad_pixels: &[BadPixel]) -> Result<Input> {
+    let development_source = match raw.cfa().map(|cfa| (cfa.width(), cfa.height())) {
+        None => DevelopmentSource::RawLinearRgb,
+        Some((2, 2)) => DevelopmentSource::RawBayer2x2,
+        Some((6, 6)) => DevelopmentSource::RawXTrans6x6,
+        Some((width, height)) => {
+            return Err(anyhow!(
+                "GPU RAW development does not support {}x{} CFA patterns",
+                width,
+                height
+            ))
+        }
+    };
+    let dng_opcodes = plan_dng_opcodes(&raw, development_source);
+    let mut bad_pixels = match development_source {
+        DevelopmentSource::RawBayer2x2 => bad_pixels.to_vec(),
+        _ => Vec::new(),
+    };
+
+    bad_pixels.extend(dng_opcodes.bad_pixels.iter().copied());
+
+    let packed_source = match pack_raw_source_image(&raw, &bad_pixels, &dng_opcodes) {
         Ok(packed_source) => packed_source,
         Err(error) => return Err(error),
     };
 
-    let as_shot_white_balance = raw.white_balance_coefficients();
-    let white_balance = resolve_headroom_white_balance(as_shot_white_balance);
-
-    let camera_to_working = match build_camera_to_working_matrix(&raw) {
-        Ok(camera_to_working) => camera_to_working,
+    let profile = match CameraColorProfile::from_anchors(raw.color_matrix_anchors()) {
+        Ok(profile) => profile,
         Err(error) => return Err(error),
     };
 
-    Ok(Input::new(
-        packed_source.image,
-        DevelopmentSource::RawBayer2x2,
-        DevelopmentParameters::from_raw_bayer_2x2(
-            packed_source.cfa_pattern,
+    let as_shot_white_balance = raw.white_balance_coefficients();
+    let white_balance_calibration = WhiteBalanceCalibration::raw(as_shot_white_balance, profile);
+    let resolved_white_balance = white_balance_calibration.resolve(&WhiteBalance::AsShot);
+
+    let development_parameters = match development_source {
+        DevelopmentSource::RawLinearRgb => DevelopmentParameters::from_raw_linear_rgb(
+            packed_source.black_levels,
+            packed_source.white_levels,
+        ),
+        DevelopmentSource::RawXTrans6x6 => DevelopmentParameters::from_raw_xtrans_6x6(
+            xtrans_cfa_pattern(&packed_source.cfa_pattern),
+            packed_source.black_levels,
+            packed_source.white_levels,
+        ),
+        _ => DevelopmentParameters::from_raw_bayer_2x2(
+            bayer_cfa_pattern(&packed_source.cfa_pattern),
             packed_source.black_levels,
             packed_source.white_levels,
-            white_balance,
-            camera_to_working,
         ),
+    }
+    .with_lens_warp(packed_source.lens_warp);
+
+    Ok(Input::new(
+        packed_source.image,
+        development_source,
+        resolved_white_balance.apply_to(development_parameters),
+        white_balance_calibration,
         OutputTransformSettings::tone_map_to_sdr(RAW_DISPLAY_BASE_EXPOSURE_EV),
         SourceMetadata::raw(RawSourceMetadata {
             camera_make: raw.camera_make().to_string(),
@@ -79,26 +87,34 @@ pub(super) fn build_input(raw: RawSource) -> Result<Input> {
             bits_per_sample: raw.bits_per_sample(),
             sensor_dimensions: raw.dimensions(),
             crop_area: packed_source.crop_area,
+            cfa_width: raw.cfa().map_or(0, |cfa| cfa.width()),
+            cfa_height: raw.cfa().map_or(0, |cfa| cfa.height()),
             cfa_pattern: packed_source.cfa_pattern,
             source_black_levels: packed_source.source_black_levels,
             source_white_levels: packed_source.source_white_levels,
             normalized_black_levels: packed_source.black_levels,
             normalized_white_levels: packed_source.white_levels,
             as_shot_white_balance,
-            headroom_white_balance: white_balance,
+            headroom_white_balance: resolved_white_balance.multipliers(),
+            mapped_bad_pixels: packed_source.mapped_bad_pixels,
+            dng_opcodes: dng_opcodes.statuses,
         }),
-    ))
+    )
+    .with_default_crop(packed_source.default_crop))
 }
 
 /// Packed RAW upload data plus metadata needed by the development shader.
 struct PackedRawSourceImage {
     image: InputImage,
     crop_area: SourceRect,
-    cfa_pattern: [u32; 4],
+    default_crop: Option<CropRect>,
+    cfa_pattern: Vec<u32>,
     source_black_levels: [f32; 4],
     source_white_levels: [f32; 4],
     black_levels: [f32; 4],
     white_levels: [f32; 4],
+    mapped_bad_pixels: usize,
+    lens_warp: Option<RawLensWarp>,
 }
 
 /// Sensor-space rectangle selected for RAW source upload.
@@ -112,11 +128,21 @@ struct RawSourceRect {
 
 /// Packs RAW sensor samples into the renderer source texture payload.
 ///
-/// The current GPU RAW path uploads one scalar sensor sample per texel in the
-/// red channel. Crop and orientation are applied here so the graph sees the same
+/// CFA sources upload one scalar sensor sample per texel in the red channel;
+/// linear RGB sources upload their three samples in the RGB channels. Crop and
+/// orientation are applied here so the graph sees the same
 /// display-oriented dimensions as raster inputs, while CFA metadata is adjusted
-/// to keep shader-side Bayer lookup aligned with the packed samples.
-fn pack_raw_source_image(raw: &RawSource) -> Result<PackedRawSourceImage> {
+/// to keep shader-side CFA lookup aligned with the packed samples.
+///
+/// The green channel marks photosites listed in the camera's bad pixel map or
+/// by DNG FixBadPixels opcodes so the development graph replaces them
+/// regardless of automatic detection. For CFA sources the blue channel carries
+/// the DNG GainMap gain, which normalization multiplies in.
+fn pack_raw_source_image(
+    raw: &RawSource,
+    bad_pixels: &[BadPixel],
+    dng_opcodes: &DngOpcodePlan<'_>,
+) -> Result<PackedRawSourceImage> {
     let crop = match select_raw_source_rect(raw) {
         Ok(crop) => crop,
         Err(error) => return Err(error),
@@ -138,6 +164,7 @@ fn pack_raw_source_image(raw: &RawSource) -> Result<PackedRawSourceImage> {
     let sample_scale = raw_sample_scale(raw);
     let source_width = raw.dimensions().width();
     let mut texels = Vec::with_capacity(pixel_count * 4);
+    let mut constant_bad_pixels = 0;
 
     for y in 0..output_height {
         for x in 0..output_width {
@@ -146,21 +173,65 @@ fn pack_raw_source_image(raw: &RawSource) -> Result<PackedRawSourceImage> {
             let source_x = crop.x + crop_x;
             let source_y = crop.y + crop_y;
             let source_index = ((source_y * source_width) + source_x) as usize;
-            let sample = raw_sample_at(raw.samples(), source_index);
 
-            texels.push(sample / sample_scale);
-            texels.push(0.0);
-            texels.push(0.0);
+            match raw.samples() {
+                RawSamples::LinearRgb(samples) => {
+                    let sample_index = source_index * 3;
+
+                    texels.push(samples[sample_index] / sample_scale);
+                    texels.push(samples[sample_index + 1] / sample_scale);
+                    texels.push(samples[sample_index + 2] / sample_scale);
+                }
+                samples => {
+                    let sample = raw_sample_at(samples, source_index);
+                    let constant_bad_pixel = is_dng_constant_bad_pixel(
+                        raw,
+                        &dng_opcodes.bad_pixel_constants,
+                        sample,
+                        source_x,
+                        source_y,
+                    );
+
+                    if constant_bad_pixel {
+                        constant_bad_pixels += 1;
+                    }
+
+                    texels.push(sample / sample_scale);
+                    texels.push(if constant_bad_pixel { 1.0 } else { 0.0 });
+                    texels.push(dng_gain_at(&dng_opcodes.gain_maps, source_x, source_y));
+                }
+            }
+
             texels.push(1.0);
         }
     }
 
-    let cfa_pattern =
-        oriented_cfa_values_for_crop(raw.cfa().pattern(), crop, output_width, orientation);
-    let source_black_levels =
-        oriented_cfa_values_for_crop(raw.black_levels().values(), crop, output_width, orientation);
-    let source_white_levels =
-        oriented_cfa_values_for_crop(raw.white_levels().values(), crop, output_width, orientation);
+    let mapped_bad_pixels = constant_bad_pixels
+        + mark_bad_pixels(&mut texels, bad_pixels, crop, output_width, orientation);
+    let lens_warp = dng_opcodes
+        .warp
+        .map(|warp| dng_lens_warp(warp, crop, orientation));
+
+    let cfa_pattern = oriented_cfa_pattern_for_crop(raw, crop, output_width, orientation);
+    let (source_black_levels, source_white_levels) = if raw.cfa().is_some() {
+        (
+            oriented_cfa_values_for_crop(
+                raw.black_levels().values(),
+                crop,
+                output_width,
+                orientation,
+            ),
+            oriented_cfa_values_for_crop(
+                raw.white_levels().values(),
+                crop,
+                output_width,
+                orientation,
+            ),
+        )
+    } else {
+        // Linear RGB levels are per plane, so crop and orientation leave them alone.
+        (raw.black_levels().values(), raw.white_levels().values())
+    };
     let black_levels = normalize_levels_for_upload(source_black_levels, sample_scale);
     let white_levels = normalize_levels_for_upload(source_white_levels, sample_scale);
 
@@ -172,37 +243,363 @@ fn pack_raw_source_image(raw: &RawSource) -> Result<PackedRawSourceImage> {
             width: crop.width,
             height: crop.height,
         },
+        default_crop: raw_default_crop(raw, crop, orientation),
         cfa_pattern,
         source_black_levels,
         source_white_levels,
         black_levels,
         white_levels,
+        mapped_bad_pixels,
+        lens_warp,
+    })
+}
+
+/// Flags mapped bad pixels in the packed upload's green channel.
+///
+/// Map entries are full-sensor coordinates; entries outside the uploaded crop
+/// are ignored. Returns how many entries landed inside the crop.
+fn mark_bad_pixels(
+    texels: &mut [f32],
+    bad_pixels: &[BadPixel],
+    crop: RawSourceRect,
+    output_width: u32,
+    orientation: Orientation,
+) -> usize {
+    let mut mapped_bad_pixels = 0;
+
+    for bad_pixel in bad_pixels {
+        if bad_pixel.x < crop.x
+            || bad_pixel.y < crop.y
+            || bad_pixel.x >= crop.x + crop.width
+            || bad_pixel.y >= crop.y + crop.height
+        {
+            continue;
+        }
+
+        let (x, y) = raw_crop_to_oriented_position(
+            bad_pixel.x - crop.x,
+            bad_pixel.y - crop.y,
+            crop.width,
+            crop.height,
+            orientation,
+        );
+        let texel_index = (((y * output_width) + x) as usize * 4) + 1;
+
+        texels[texel_index] = 1.0;
+        mapped_bad_pixels += 1;
+    }
+
+    mapped_bad_pixels
+}
+
+/// DNG opcodes that RAW upload and development will apply.
+///
+/// Gain maps and bad pixels are resolved while packing, and the warp becomes
+/// development parameters. `statuses` records every opcode for the Inspector.
+struct DngOpcodePlan<'a> {
+    gain_maps: Vec<StageGainMap<'a>>,
+    bad_pixel_constants: Vec<BadPixelConstant>,
+    bad_pixels: Vec<BadPixel>,
+    warp: Option<StageWarp<'a>>,
+    statuses: Vec<DngOpcodeStatus>,
+}
+
+/// A GainMap together with the sensor rectangle its coordinates are relative to.
+struct StageGainMap<'a> {
+    gain_map: &'a DngGainMap,
+    image: RawSourceRect,
+}
+
+/// A WarpRectilinear together with the sensor rectangle its center is relative to.
+#[derive(Clone, Copy)]
+struct StageWarp<'a> {
+    warp: &'a DngWarpRectilinear,
+    image: RawSourceRect,
+}
+
+/// A FixBadPixelsConstant value, compared after black subtraction for OpcodeList2.
+struct BadPixelConstant {
+    constant: f32,
+    black_subtracted: bool,
+}
+
+/// Selects which DNG opcodes this source's development path can apply.
+///
+/// GainMap needs a CFA source, since linear sources use the blue upload channel
+/// for samples. FixBadPixels follows bad pixel maps and is Bayer-only. Only the
+/// first OpcodeList3 WarpRectilinear is applied, after camera-to-working.
+fn plan_dng_opcodes(raw: &RawSource, development_source: DevelopmentSource) -> DngOpcodePlan<'_> {
+    let is_cfa = matches!(
+        development_source,
+        DevelopmentSource::RawBayer2x2 | DevelopmentSource::RawXTrans6x6
+    );
+    let is_bayer = development_source == DevelopmentSource::RawBayer2x2;
+    let mut plan = DngOpcodePlan {
+        gain_maps: Vec::new(),
+        bad_pixel_constants: Vec::new(),
+        bad_pixels: Vec::new(),
+        warp: None,
+        statuses: Vec::new(),
+    };
+
+    for opcode in raw.dng_opcodes() {
+        let stage = opcode.stage();
+        let image = dng_stage_image_rect(raw, stage);
+        let before_demosaic = stage != DngOpcodeStage::Demosaiced;
+
+        let applied = match opcode.operation() {
+            DngOperation::GainMap(gain_map) => {
+                let applies = is_cfa && before_demosaic && gain_map.plane == 0;
+
+                if applies {
+                    plan.gain_maps.push(StageGainMap { gain_map, image });
+                }
+
+                applies
+            }
+            DngOperation::FixBadPixelsConstant { constant } => {
+                let applies = is_bayer && before_demosaic;
+
+                if applies {
+                    plan.bad_pixel_constants.push(BadPixelConstant {
+                        constant: *constant as f32,
+                        black_subtracted: stage == DngOpcodeStage::Linearized,
+                    });
+                }
+
+                applies
+            }
+            DngOperation::FixBadPixelsList { points, rects } => {
+                let applies = is_bayer && before_demosaic;
+
+                if applies {
+                    plan.bad_pixels
+                        .extend(dng_bad_pixel_list(points, rects, image));
+                }
+
+                applies
+            }
+            DngOperation::WarpRectilinear(warp) => {
+                let applies =
+                    !before_demosaic && plan.warp.is_none() && matches!(warp.planes.len(), 1 | 3);
+
+                if applies {
+                    plan.warp = Some(StageWarp { warp, image });
+                }
+
+                applies
+            }
+            DngOperation::Unsupported { .. } => false,
+        };
+
+        plan.statuses.push(DngOpcodeStatus {
+            list: stage.list_name(),
+            name: opcode.name(),
+            optional: opcode.optional(),
+            applied,
+        });
+    }
+
+    plan
+}
+
+/// Returns the sensor rectangle an opcode list's coordinates are relative to.
+///
+/// OpcodeList1 addresses the stored sensor image; later lists address the
+/// active area.
+fn dng_stage_image_rect(raw: &RawSource, stage: DngOpcodeStage) -> RawSourceRect {
+    let dimensions = raw.dimensions();
+    let full_sensor = RawSourceRect {
+        x: 0,
+        y: 0,
+        width: dimensions.width(),
+        height: dimensions.height(),
+    };
+
+    match (stage, raw.active_area()) {
+        (DngOpcodeStage::Raw, _) | (_, None) => full_sensor,
+        (_, Some(active_area)) => raw_rect_to_source_rect(active_area),
+    }
+}
+
+/// Converts FixBadPixelsList points and rectangles into full-sensor bad pixels.
+fn dng_bad_pixel_list(
+    points: &[DngPoint],
+    rects: &[DngRect],
+    image: RawSourceRect,
+) -> Vec<BadPixel> {
+    let mut bad_pixels = Vec::with_capacity(points.len());
+
+    for point in points {
+        if point.column < image.width && point.row < image.height {
+            bad_pixels.push(BadPixel {
+                x: image.x + point.column,
+                y: image.y + point.row,
+            });
+        }
+    }
+
+    for rect in rects {
+        for row in rect.top..rect.bottom.min(image.height) {
+            for column in rect.left..rect.right.min(image.width) {
+                bad_pixels.push(BadPixel {
+                    x: image.x + column,
+                    y: image.y + row,
+                });
+            }
+        }
+    }
+
+    bad_pixels
+}
+
+/// Returns whether a sensor sample matches a FixBadPixelsConstant value.
+fn is_dng_constant_bad_pixel(
+    raw: &RawSource,
+    constants: &[BadPixelConstant],
+    sample: f32,
+    source_x: u32,
+    source_y: u32,
+) -> bool {
+    constants.iter().any(|constant| {
+        let value = if constant.black_subtracted {
+            let slot = ((source_y % 2) * 2) + (source_x % 2);
+
+            (sample - raw.black_levels().values()[slot as usize]).max(0.0)
+        } else {
+            sample
+        };
+
+        value.round() == constant.constant
     })
 }
 
+/// Returns the combined DNG GainMap gain for one sensor photosite.
+///
+/// Map points are placed in coordinates relative to each opcode list's image,
+/// so the photosite is converted into that image's unit range before lookup.
+fn dng_gain_at(gain_maps: &[StageGainMap<'_>], source_x: u32, source_y: u32) -> f32 {
+    let mut gain = 1.0;
+
+    for stage_gain_map in gain_maps {
+        let image = stage_gain_map.image;
+        let area = stage_gain_map.gain_map.area;
+
+        if source_x < image.x || source_y < image.y {
+            continue;
+        }
+
+        let row = source_y - image.y;
+        let column = source_x - image.x;
+
+        if row < area.top
+            || row >= area.bottom
+            || column < area.left
+            || column >= area.right
+            || (row - area.top) % stage_gain_map.gain_map.row_pitch != 0
+            || (column - area.left) % stage_gain_map.gain_map.column_pitch != 0
+        {
+            continue;
+        }
+
+        gain *= stage_gain_map.gain_map.gain_at(
+            f64::from(row) / f64::from(image.height),
+            f64::from(column) / f64::from(image.width),
+            0,
+        );
+    }
+
+    gain
+}
+
+/// Converts a WarpRectilinear opcode into lens warp parameters for the upload.
+///
+/// The optical center is moved into oriented upload pixels. The normalization
+/// radius is the distance to the farthest active-area corner, which orientation
+/// does not change, while tangential terms are direction-dependent and are
+/// remapped to the oriented axes.
+fn dng_lens_warp(
+    warp: StageWarp<'_>,
+    crop: RawSourceRect,
+    orientation: Orientation,
+) -> RawLensWarp {
+    let image = warp.image;
+    let center_x = f64::from(image.x) + (warp.warp.center[0] * f64::from(image.width));
+    let center_y = f64::from(image.y) + (warp.warp.center[1] * f64::from(image.height));
+    let mut radius: f64 = 0.0;
+
+    for (corner_x, corner_y) in [
+        (image.x, image.y),
+        (image.x + image.width, image.y),
+        (image.x, image.y + image.height),
+        (image.x + image.width, image.y + image.height),
+    ] {
+        radius = radius.max((f64::from(corner_x) - center_x).hypot(f64::from(corner_y) - center_y));
+    }
+
+    let (oriented_center_x, oriented_center_y) = raw_crop_to_oriented_point(
+        (center_x - f64::from(crop.x)) as f32,
+        (center_y - f64::from(crop.y)) as f32,
+        crop.width as f32,
+        crop.height as f32,
+        orientation,
+    );
+    // Single-plane warps apply the same coefficients to every channel.
+    let plane_at = |index: usize| warp.warp.planes[index.min(warp.warp.planes.len() - 1)];
+    let planes = [plane_at(0), plane_at(1), plane_at(2)];
+
+    RawLensWarp {
+        radial: planes.map(|plane| plane.radial.map(|coefficient| coefficient as f32)),
+        tangential: planes.map(|plane| {
+            oriented_tangential_coefficients(
+                [plane.tangential[0] as f32, plane.tangential[1] as f32],
+                orientation,
+            )
+        }),
+        center: [oriented_center_x, oriented_center_y],
+        radius: radius as f32,
+    }
+}
+
+/// Remaps WarpRectilinear tangential coefficients onto oriented image axes.
+fn oriented_tangential_coefficients(tangential: [f32; 2], orientation: Orientation) -> [f32; 2] {
+    let [kt0, kt1] = tangential;
+
+    match orientation {
+        Orientation::Normal => [kt0, kt1],
+        Orientation::FlipHorizontal => [kt0, -kt1],
+        Orientation::Rotate180 => [-kt0, -kt1],
+        Orientation::FlipVertical => [-kt0, kt1],
+        Orientation::Transpose => [kt1, kt0],
+        Orientation::Rotate90 => [kt1, -kt0],
+        Orientation::Transverse => [-kt1, -kt0],
+        Orientation::Rotate270 => [-kt1, kt0],
+    }
+}
+
 /// Chooses the sensor rectangle to upload for GPU RAW development.
 ///
-/// The recommended crop area is preferred, then the active area, then the full
-/// decoded sensor. The selected rectangle is validated before any source samples
-/// are read from it.
+/// The active area is preferred, then the full decoded sensor. The recommended
+/// crop area is not applied here; it becomes the default recipe crop so the
+/// pixels outside it stay recoverable. The CFA pattern and per-position levels
+/// are re-phased to the rectangle origin, so odd active-area offsets keep the
+/// correct color at each photosite. The selected rectangle is validated before
+/// any source samples are read from it.
 fn select_raw_source_rect(raw: &RawSource) -> Result<RawSourceRect> {
     let dimensions = raw.dimensions();
 
-    let rect = match raw.crop_area() {
+    let rect = match raw.active_area() {
         Some(rect) => raw_rect_to_source_rect(rect),
-        None => match raw.active_area() {
-            Some(rect) => raw_rect_to_source_rect(rect),
-            None => RawSourceRect {
-                x: 0,
-                y: 0,
-                width: dimensions.width(),
-                height: dimensions.height(),
-            },
+        None => RawSourceRect {
+            x: 0,
+            y: 0,
+            width: dimensions.width(),
+            height: dimensions.height(),
         },
     };
 
     if rect.width == 0 || rect.height == 0 {
-        return Err(anyhow!("RAW source crop area is empty"));
+        return Err(anyhow!("RAW source area is empty"));
     }
 
     let right = rect.x.saturating_add(rect.width);
@@ -210,7 +607,7 @@ fn select_raw_source_rect(raw: &RawSource) -> Result<RawSourceRect> {
 
     if right > dimensions.width() || bottom > dimensions.height() {
         return Err(anyhow!(
-            "RAW source crop area {}x{} at {},{} exceeds sensor dimensions {}x{}",
+            "RAW source area {}x{} at {},{} exceeds sensor dimensions {}x{}",
             rect.width,
             rect.height,
             rect.x,
@@ -223,6 +620,56 @@ fn select_raw_source_rect(raw: &RawSource) -> Result<RawSourceRect> {
     Ok(rect)
 }
 
+/// Returns the recommended crop area as a normalized rectangle in the oriented
+/// upload, or `None` when the RAW has no crop or it covers the whole upload.
+fn raw_default_crop(
+    raw: &RawSource,
+    upload: RawSourceRect,
+    orientation: Orientation,
+) -> Option<CropRect> {
+    let crop = raw_rect_to_source_rect(raw.crop_area()?);
+    let left = crop.x.max(upload.x);
+    let top = crop.y.max(upload.y);
+    let right = (crop.x + crop.width).min(upload.x + upload.width);
+    let bottom = (crop.y + crop.height).min(upload.y + upload.height);
+
+    if right <= left || bottom <= top {
+        return None;
+    }
+
+    if left == upload.x
+        && top == upload.y
+        && right == upload.x + upload.width
+        && bottom == upload.y + upload.height
+    {
+        return None;
+    }
+
+    let (first_x, first_y) = raw_crop_to_oriented_point(
+        (left - upload.x) as f32,
+        (top - upload.y) as f32,
+        upload.width as f32,
+        upload.height as f32,
+        orientation,
+    );
+    let (second_x, second_y) = raw_crop_to_oriented_point(
+        (right - upload.x) as f32,
+        (bottom - upload.y) as f32,
+        upload.width as f32,
+        upload.height as f32,
+        orientation,
+    );
+    let (oriented_width, oriented_height) =
+        oriented_raw_dimensions(upload.width, upload.height, orientation);
+
+    Some(CropRect {
+        x: first_x.min(second_x) / oriented_width as f32,
+        y: first_y.min(second_y) / oriented_height as f32,
+        width: (first_x - second_x).abs() / oriented_width as f32,
+        height: (first_y - second_y).abs() / oriented_height as f32,
+    })
+}
+
 /// Converts a source-domain RAW rectangle into the local upload rectangle type.
 fn raw_rect_to_source_rect(rect: RawRect) -> RawSourceRect {
     RawSourceRect {
@@ -270,15 +717,60 @@ fn oriented_to_raw_crop_position(
     }
 }
 
+/// Maps a crop-space coordinate to its position after the display orientation transform.
+///
+/// This is the forward counterpart of `oriented_to_raw_crop_position`.
+fn raw_crop_to_oriented_position(
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    orientation: Orientation,
+) -> (u32, u32) {
+    match orientation {
+        Orientation::Normal => (x, y),
+        Orientation::FlipHorizontal => (width - 1 - x, y),
+        Orientation::Rotate180 => (width - 1 - x, height - 1 - y),
+        Orientation::FlipVertical => (x, height - 1 - y),
+        Orientation::Transpose => (y, x),
+        Orientation::Rotate90 => (height - 1 - y, x),
+        Orientation::Transverse => (height - 1 - y, width - 1 - x),
+        Orientation::Rotate270 => (y, width - 1 - x),
+    }
+}
+
+/// Maps a continuous crop-space point to its position after the display orientation transform.
+///
+/// Unlike `raw_crop_to_oriented_position`, this works on pixel-edge coordinates,
+/// so `width` and `height` are the far crop edges rather than the last pixel.
+fn raw_crop_to_oriented_point(
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    orientation: Orientation,
+) -> (f32, f32) {
+    match orientation {
+        Orientation::Normal => (x, y),
+        Orientation::FlipHorizontal => (width - x, y),
+        Orientation::Rotate180 => (width - x, height - y),
+        Orientation::FlipVertical => (x, height - y),
+        Orientation::Transpose => (y, x),
+        Orientation::Rotate90 => (height - y, x),
+        Orientation::Transverse => (height - y, width - x),
+        Orientation::Rotate270 => (y, width - x),
+    }
+}
+
 /// Resolves the scale used to normalize source samples before GPU upload.
 ///
-/// Integer RAW sources use their reported bit depth. Floating RAW sources use
-/// white level when it appears to be in sensor-code units, otherwise they are
+/// Integer CFA sources use their reported bit depth. Floating and linear RGB
+/// sources use white level when it appears to be in sensor-code units, otherwise they are
 /// treated as already normalized.
 fn raw_sample_scale(raw: &RawSource) -> f32 {
     let scale = match raw.samples() {
         RawSamples::IntegerU16(_) => integer_raw_sample_scale(raw.bits_per_sample()),
-        RawSamples::Float32(_) => {
+        RawSamples::Float32(_) | RawSamples::LinearRgb(_) => {
             let white_max = raw
                 .white_levels()
                 .values()
@@ -315,6 +807,7 @@ fn raw_sample_at(samples: &RawSamples, index: usize) -> f32 {
     match samples {
         RawSamples::IntegerU16(samples) => samples[index] as f32,
         RawSamples::Float32(samples) => samples[index],
+        RawSamples::LinearRgb(samples) => samples[index],
     }
 }
 
@@ -328,9 +821,68 @@ fn normalize_levels_for_upload(levels: [f32; 4], sample_scale: f32) -> [f32; 4]
     ]
 }
 
+/// Rebuilds the repeating CFA color pattern after crop and orientation.
+///
+/// Both supported patterns are square, so the oriented pattern keeps the
+/// source pattern's size. Each oriented cell is mapped back to its sensor
+/// coordinate to look up the original CFA color. Linear RGB sources have no
+/// pattern and return an empty list.
+fn oriented_cfa_pattern_for_crop(
+    raw: &RawSource,
+    crop: RawSourceRect,
+    output_width: u32,
+    orientation: Orientation,
+) -> Vec<u32> {
+    let Some(cfa) = raw.cfa() else {
+        return Vec::new();
+    };
+    let output_height = oriented_raw_dimensions(crop.width, crop.height, orientation).1;
+    let mut pattern = Vec::with_capacity((cfa.width() * cfa.height()) as usize);
+
+    for y in 0..cfa.height() {
+        for x in 0..cfa.width() {
+            let virtual_x = x % output_width.max(1);
+            let virtual_y = y % output_height.max(1);
+            let (crop_x, crop_y) = oriented_to_raw_crop_position(
+                virtual_x,
+                virtual_y,
+                crop.width,
+                crop.height,
+                orientation,
+            );
+
+            pattern.push(cfa.color_at(crop.x + crop_x, crop.y + crop_y));
+        }
+    }
+
+    pattern
+}
+
+/// Packs an oriented 2x2 CFA pattern for Bayer development upload.
+fn bayer_cfa_pattern(pattern: &[u32]) -> [u32; 4] {
+    let mut packed = [0; 4];
+
+    for (slot, color) in packed.iter_mut().zip(pattern.iter()) {
+        *slot = *color;
+    }
+
+    packed
+}
+
+/// Packs an oriented 6x6 CFA pattern for X-Trans development upload.
+fn xtrans_cfa_pattern(pattern: &[u32]) -> [u32; 36] {
+    let mut packed = [0; 36];
+
+    for (slot, color) in packed.iter_mut().zip(pattern.iter()) {
+        *slot = *color;
+    }
+
+    packed
+}
+
 /// Rebuilds 2x2 CFA-aligned values after crop and orientation.
 ///
-/// CFA pattern, black levels, and white levels are all indexed by Bayer slot.
+/// Black and white levels are indexed by 2x2 slot, even for X-Trans sources.
 /// If the source crop starts at an odd coordinate or the image is rotated/flipped,
 /// the slot order changes and must be updated before shader development.
 fn oriented_cfa_values_for_crop<T: Copy>(
@@ -373,78 +925,3 @@ fn cfa_value_for_oriented_position<T: Copy>(
 
     values[source_slot as usize]
 }
-
-/// Resolves as-shot RGB white-balance gains while preserving highlight headroom.
-///
-/// Gains are normalized by the largest valid channel gain so white balance does
-/// not push one channel above the source-normalized range before the display
-/// transform has a chance to compress highlights.
-fn resolve_headroom_white_balance(wb_coefficients: [f32; 4]) -> [f32; 3] {
-    let gains = [wb_coefficients[0], wb_coefficients[1], wb_coefficients[2]];
-
-    if !gains.iter().all(|gain| gain.is_finite() && *gain > 0.0) {
-        return [1.0, 1.0, 1.0];
-    }
-
-    let base_gain = gains.iter().copied().fold(f32::NEG_INFINITY, f32::max);
-
-    if !base_gain.is_finite() || base_gain <= 0.0 {
-        return [1.0, 1.0, 1.0];
-    }
-
-    [
-        gains[0] / base_gain,
-        gains[1] / base_gain,
-        gains[2] / base_gain,
-    ]
-}
-
-/// Builds a camera-space RGB to linear Rec.2020 working-space matrix.
-///
-/// RAW metadata stores XYZ-to-camera matrices. This composes Rec.2020-to-XYZ
-/// with the selected XYZ-to-camera matrix, normalizes the result, then inverts
-/// it so the development shader can transform camera RGB into working RGB.
-fn build_camera_to_working_matrix(raw: &RawSource) -> Result<ColorMatrix3> {
-    let xyz_to_camera = match select_xyz_to_camera_matrix(raw) {
-        Ok(xyz_to_camera) => xyz_to_camera,
-        Err(error) => return Err(error),
-    };
-
-    let rec2020_to_camera = normalize(multiply(&xyz_to_camera, &REC2020_TO_XYZ_D65));
-
-    Ok(pseudo_inverse(rec2020_to_camera))
-}
-
-/// Selects the currently supported camera color-matrix anchor.
-///
-/// This is a fallback selection order, not a final scene-matched profile
-/// calculation. The longer-term path is two-illuminant interpolation.
-fn select_xyz_to_camera_matrix(raw: &RawSource) -> Result<ColorMatrix3> {
-    for preferred_illuminant in XYZ_TO_CAMERA_ILLUMINANT_PREFERENCE {
-        let preferred_illuminant_code = u16::from(preferred_illuminant);
-
-        if let Some(anchor) = raw
-            .color_matrix_anchors()
-            .iter()
-            .find(|anchor| anchor.illuminant() == preferred_illuminant_code)
-        {
-            return Ok(flat_xyz_to_camera_matrix(anchor.xyz_to_camera()));
-        }
-    }
-
-    match raw.color_matrix_anchors().first() {
-        Some(anchor) => Ok(flat_xyz_to_camera_matrix(anchor.xyz_to_camera())),
-        None => Err(anyhow!(
-            "RAW source requires at least one camera color matrix anchor"
-        )),
-    }
-}
-
-/// Converts a flat row-major XYZ-to-camera matrix into a three-by-three matrix.
-fn flat_xyz_to_camera_matrix(xyz_to_camera: [f32; 9]) -> ColorMatrix3 {
-    [
-        [xyz_to_camera[0], xyz_to_camera[1], xyz_to_camera[2]],
-        [xyz_to_camera[3], xyz_to_camera[4], xyz_to_camera[5]],
-        [xyz_to_camera[6], xyz_to_camera[7], xyz_to_camera[8]],
-    ]
-}
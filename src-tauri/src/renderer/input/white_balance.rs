@@ -0,0 +1,281 @@
+use rawler::imgop::matrix::pseudo_inverse;
+
+use super::color::{
+    temperature_tint_to_xy, transform_color, xy_to_xyz, ColorMatrix3,
+    LINEAR_REC2020_TO_LINEAR_SRGB, XYZ_TO_LINEAR_SRGB,
+};
+use super::profile::{CameraColorProfile, ProfileWhitePoint};
+use crate::core::editing::WhiteBalance;
+use crate::renderer::processing_graph::DevelopmentParameters;
+
+/// Source-specific data needed to resolve a recipe white balance into
+/// development multipliers.
+///
+/// Multipliers are applied in the source's balance space: camera RGB for RAW
+/// input and linear sRGB for raster input. Recipe multipliers are stored
+/// normalized to green; the development upload may renormalize them.
+#[derive(Debug, Clone)]
+pub(in crate::renderer) struct WhiteBalanceCalibration {
+    as_shot_multipliers: [f32; 3],
+    balance_space: BalanceSpace,
+}
+
+/// Color space white-balance multipliers are applied in.
+#[derive(Debug, Clone)]
+enum BalanceSpace {
+    /// Camera RGB, mapped to working space by a profile matched to the white point.
+    Camera(CameraColorProfile),
+    /// Linear sRGB with a fixed working-space transform.
+    LinearSrgb,
+}
+
+/// A recipe white balance resolved against one source's calibration.
+///
+/// For RAW input this includes the camera-to-working matrix interpolated for
+/// the resolved scene white point, so development multipliers and color
+/// matrix always describe the same illuminant.
+#[derive(Debug, Clone, Copy)]
+pub(in crate::renderer) struct ResolvedWhiteBalance {
+    multipliers: [f32; 3],
+    camera_to_working: Option<ColorMatrix3>,
+    working_to_balance: ColorMatrix3,
+    white_point: Option<(f32, f32)>,
+}
+
+impl WhiteBalanceCalibration {
+    /// Builds calibration for camera-space RAW development.
+    ///
+    /// RAW multipliers are normalized by their largest channel before upload
+    /// so white balance never pushes a channel above the sensor clip point.
+    pub(super) fn raw(as_shot_coefficients: [f32; 4], profile: CameraColorProfile) -> Self {
+        let as_shot_multipliers = match normalize_to_green([
+            as_shot_coefficients[0],
+            as_shot_coefficients[1],
+            as_shot_coefficients[2],
+        ]) {
+            Some(multipliers) => multipliers,
+            None => [1.0, 1.0, 1.0],
+        };
+
+        Self {
+            as_shot_multipliers,
+            balance_space: BalanceSpace::Camera(profile),
+        }
+    }
+
+    /// Builds calibration for display-referred raster development.
+    ///
+    /// Raster input is already balanced, so As Shot is neutral and temperature
+    /// is interpreted relative to the sRGB D65 white point.
+    pub(super) fn raster_srgb() -> Self {
+        Self {
+            as_shot_multipliers: [1.0, 1.0, 1.0],
+            balance_space: BalanceSpace::LinearSrgb,
+        }
+    }
+
+    /// Resolves a recipe white balance into development multipliers and, for
+    /// RAW input, the matching camera-to-working matrix.
+    pub(in crate::renderer) fn resolve(
+        &self,
+        white_balance: &WhiteBalance,
+    ) -> ResolvedWhiteBalance {
+        match &self.balance_space {
+            BalanceSpace::Camera(profile) => self.resolve_camera(profile, white_balance),
+            BalanceSpace::LinearSrgb => self.resolve_linear_srgb(white_balance),
+        }
+    }
+
+    /// Resolves a white balance for RAW input through the camera color profile.
+    ///
+    /// Temperature white balance selects the profile matrix directly. As Shot
+    /// and custom multipliers describe a camera neutral instead, which is solved
+    /// back to a white point before the matrix is interpolated.
+    fn resolve_camera(
+        &self,
+        profile: &CameraColorProfile,
+        white_balance: &WhiteBalance,
+    ) -> ResolvedWhiteBalance {
+        let (white_point, multipliers) = match self.temperature_white_point(profile, white_balance)
+        {
+            Some(white_point) => {
+                let multipliers = match neutral_multipliers(white_point.camera_neutral()) {
+                    Some(multipliers) => multipliers,
+                    None => self.as_shot_multipliers,
+                };
+
+                (white_point, multipliers)
+            }
+            None => {
+                let multipliers = self.recipe_multipliers(white_balance);
+                let white_point =
+                    profile.white_point_for_camera_neutral(neutral_for_multipliers(multipliers));
+
+                (white_point, multipliers)
+            }
+        };
+
+        let development_multipliers = match normalize_to_max_channel(multipliers) {
+            Some(multipliers) => multipliers,
+            None => [1.0, 1.0, 1.0],
+        };
+
+        let camera_to_working = white_point.camera_to_working();
+
+        ResolvedWhiteBalance {
+            multipliers: development_multipliers,
+            camera_to_working: Some(camera_to_working),
+            working_to_balance: pseudo_inverse(camera_to_working),
+            white_point: Some((white_point.temperature_k, white_point.tint)),
+        }
+    }
+
+    /// Resolves a white balance for raster input in linear sRGB.
+    fn resolve_linear_srgb(&self, white_balance: &WhiteBalance) -> ResolvedWhiteBalance {
+        let multipliers = match white_balance {
+            WhiteBalance::Temperature {
+                temperature_k,
+                tint,
+            } if temperature_k.is_finite() && tint.is_finite() => {
+                let illuminant_xyz = xy_to_xyz(temperature_tint_to_xy(*temperature_k, *tint));
+
+                match neutral_multipliers(transform_color(XYZ_TO_LINEAR_SRGB, illuminant_xyz)) {
+                    Some(multipliers) => multipliers,
+                    None => self.as_shot_multipliers,
+                }
+            }
+            _ => self.recipe_multipliers(white_balance),
+        };
+
+        ResolvedWhiteBalance {
+            multipliers,
+            camera_to_working: None,
+            working_to_balance: LINEAR_REC2020_TO_LINEAR_SRGB,
+            white_point: None,
+        }
+    }
+
+    /// Returns the profile white point for temperature white balance.
+    fn temperature_white_point(
+        &self,
+        profile: &CameraColorProfile,
+        white_balance: &WhiteBalance,
+    ) -> Option<ProfileWhitePoint> {
+        match white_balance {
+            WhiteBalance::Temperature {
+                temperature_k,
+                tint,
+            } if temperature_k.is_finite() && tint.is_finite() => {
+                Some(profile.white_point_for_temperature(*temperature_k, *tint))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns green-normalized multipliers for As Shot or custom white balance.
+    fn recipe_multipliers(&self, white_balance: &WhiteBalance) -> [f32; 3] {
+        let multipliers = match white_balance {
+            WhiteBalance::Custom { multipliers } => normalize_to_green(*multipliers),
+            WhiteBalance::AsShot | WhiteBalance::Temperature { .. } => None,
+        };
+
+        multipliers.unwrap_or(self.as_shot_multipliers)
+    }
+}
+
+impl ResolvedWhiteBalance {
+    /// Returns development parameters using these multipliers and color matrix.
+    pub(in crate::renderer) fn apply_to(
+        &self,
+        parameters: DevelopmentParameters,
+    ) -> DevelopmentParameters {
+        let parameters = parameters.with_white_balance(self.multipliers);
+
+        match self.camera_to_working {
+            Some(camera_to_working) => parameters.with_camera_to_working(camera_to_working),
+            None => parameters,
+        }
+    }
+
+    /// Returns the multipliers uploaded for development.
+    pub(in crate::renderer) fn multipliers(&self) -> [f32; 3] {
+        self.multipliers
+    }
+
+    /// Returns the scene white point as temperature and tint, when the source has a profile.
+    pub(in crate::renderer) fn white_point(&self) -> Option<(f32, f32)> {
+        self.white_point
+    }
+
+    /// Computes a custom white balance that neutralizes a sampled patch.
+    ///
+    /// `working_sample` is the mean working-space color of a patch developed
+    /// with this white balance. The patch is mapped back into balance space,
+    /// the applied gains are divided out, and the result is inverted so that
+    /// the patch develops to neutral.
+    pub(in crate::renderer) fn neutral_patch_white_balance(
+        &self,
+        working_sample: [f32; 3],
+    ) -> Option<WhiteBalance> {
+        let balanced = transform_color(self.working_to_balance, working_sample);
+
+        let multipliers = [
+            self.multipliers[0] / balanced[0],
+            self.multipliers[1] / balanced[1],
+            self.multipliers[2] / balanced[2],
+        ];
+
+        normalize_to_green(multipliers).map(|multipliers| WhiteBalance::Custom { multipliers })
+    }
+}
+
+/// Returns green-normalized multipliers that map a balance-space neutral to white.
+fn neutral_multipliers(neutral: [f32; 3]) -> Option<[f32; 3]> {
+    normalize_to_green([1.0 / neutral[0], 1.0 / neutral[1], 1.0 / neutral[2]])
+}
+
+/// Returns the balance-space neutral that multipliers map to white.
+fn neutral_for_multipliers(multipliers: [f32; 3]) -> [f32; 3] {
+    [
+        1.0 / multipliers[0],
+        1.0 / multipliers[1],
+        1.0 / multipliers[2],
+    ]
+}
+
+/// Scales multipliers so the green channel gain is one.
+fn normalize_to_green(multipliers: [f32; 3]) -> Option<[f32; 3]> {
+    if !multipliers
+        .iter()
+        .all(|gain| gain.is_finite() && *gain > 0.0)
+    {
+        return None;
+    }
+
+    Some([
+        multipliers[0] / multipliers[1],
+        1.0,
+        multipliers[2] / multipliers[1],
+    ])
+}
+
+/// Scales multipliers so the largest channel gain is one.
+fn normalize_to_max_channel(multipliers: [f32; 3]) -> Option<[f32; 3]> {
+    if !multipliers
+        .iter()
+        .all(|gain| gain.is_finite() && *gain > 0.0)
+    {
+        return None;
+    }
+
+    let base_gain = multipliers
+        .iter()
+        .copied()
+        .fold(f32::NEG_INFINITY, f32::max);
+
+    Some([
+        multipliers[0] / base_gain,
+        multipliers[1] / base_gain,
+        multipliers[2] / base_gain,
+    ])
+}
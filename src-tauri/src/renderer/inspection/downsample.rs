@@ -1,4 +1,5 @@
 use anyhow::Result;
+use wgpu::util::DeviceExt;
 
 /// Downsamples a display output texture before CPU readback.
 pub(in crate::renderer) fn downsample_display_texture(
@@ -136,3 +137,167 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
 
     Ok(target_texture)
 }
+
+/// Box-filters a region of an RGBA32F image texture into a smaller RGBA32F
+/// texture for CPU readback.
+///
+/// Each output texel averages every source texel it covers, so small
+/// features are not aliased away.
+#[allow(clippy::too_many_arguments)]
+pub(in crate::renderer) fn downsample_image_texture_region(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    source_texture: &wgpu::Texture,
+    origin: (u32, u32),
+    region_width: u32,
+    region_height: u32,
+    width: u32,
+    height: u32,
+) -> Result<wgpu::Texture> {
+    let target_texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Downsampled Image Region Texture"),
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba32Float,
+        usage: wgpu::TextureUsages::STORAGE_BINDING | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let source_view = source_texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let target_view = target_texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let region_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("Image Region Downsample Parameters Buffer"),
+        contents: bytemuck::cast_slice(&[origin.0, origin.1, region_width, region_height]),
+        usage: wgpu::BufferUsages::UNIFORM,
+    });
+    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("Image Region Downsample Shader"),
+        source: wgpu::ShaderSource::Wgsl(
+            r#"
+struct Region {
+  origin: vec2<u32>,
+  size: vec2<u32>,
+};
+
+@group(0) @binding(0) var source_texture: texture_2d<f32>;
+@group(0) @binding(1) var<uniform> region: Region;
+@group(0) @binding(2) var output_texture: texture_storage_2d<rgba32float, write>;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_dimensions = textureDimensions(output_texture);
+
+  if (global_id.x >= output_dimensions.x || global_id.y >= output_dimensions.y) {
+    return;
+  }
+
+  let start = region.origin + (global_id.xy * region.size) / output_dimensions;
+  let end = max(
+    region.origin + ((global_id.xy + vec2<u32>(1u)) * region.size) / output_dimensions,
+    start + vec2<u32>(1u)
+  );
+  var sum = vec4<f32>(0.0);
+
+  for (var y = start.y; y < end.y; y += 1u) {
+    for (var x = start.x; x < end.x; x += 1u) {
+      sum += textureLoad(source_texture, vec2<i32>(i32(x), i32(y)), 0);
+    }
+  }
+
+  let count = f32((end.x - start.x) * (end.y - start.y));
+
+  textureStore(output_texture, vec2<i32>(global_id.xy), sum / count);
+}
+"#
+            .into(),
+        ),
+    });
+    let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("Image Region Downsample Bind Group Layout"),
+        entries: &[
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 2,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::StorageTexture {
+                    access: wgpu::StorageTextureAccess::WriteOnly,
+                    format: wgpu::TextureFormat::Rgba32Float,
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                },
+                count: None,
+            },
+        ],
+    });
+    let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("Image Region Downsample Pipeline Layout"),
+        bind_group_layouts: &[&bind_group_layout],
+        push_constant_ranges: &[],
+    });
+    let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+        label: Some("Image Region Downsample Pipeline"),
+        layout: Some(&pipeline_layout),
+        module: &shader,
+        entry_point: Some("main"),
+        compilation_options: wgpu::PipelineCompilationOptions::default(),
+        cache: None,
+    });
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("Image Region Downsample Bind Group"),
+        layout: &bind_group_layout,
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&source_view),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: region_buffer.as_entire_binding(),
+            },
+            wgpu::BindGroupEntry {
+                binding: 2,
+                resource: wgpu::BindingResource::TextureView(&target_view),
+            },
+        ],
+    });
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("Image Region Downsample Encoder"),
+    });
+
+    {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Image Region Downsample Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(width.div_ceil(16), height.div_ceil(16), 1);
+    }
+
+    queue.submit(std::iter::once(encoder.finish()));
+
+    Ok(target_texture)
+}
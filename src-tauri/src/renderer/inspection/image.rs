@@ -1,4 +1,4 @@
-use super::super::input::RawSourceMetadata;
+use super::super::input::{DngOpcodeStatus, RawSourceMetadata};
 
 /// Source image details for the active renderer input.
 #[derive(Debug, Clone, serde::Serialize)]
@@ -9,6 +9,29 @@ pub struct ImageInspection {
     pub height: u32,
     pub has_transparency: bool,
     pub raw: Option<RawImageInspection>,
+    pub dng_opcodes: Vec<DngOpcodeInspection>,
+}
+
+/// One DNG opcode from the source file and whether development applied it.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DngOpcodeInspection {
+    pub list: String,
+    pub name: String,
+    pub optional: bool,
+    pub applied: bool,
+}
+
+impl DngOpcodeInspection {
+    /// Builds DNG opcode inspection data from RAW source metadata.
+    pub(in crate::renderer) fn from_status(status: &DngOpcodeStatus) -> Self {
+        Self {
+            list: status.list.to_string(),
+            name: status.name.clone(),
+            optional: status.optional,
+            applied: status.applied,
+        }
+    }
 }
 
 /// RAW-specific source details for the active renderer input.
@@ -20,13 +43,14 @@ pub struct RawImageInspection {
     pub bits_per_sample: u32,
     pub sensor_dimensions: DimensionsInspection,
     pub crop_area: RectInspection,
-    pub cfa: CfaPatternInspection,
+    pub cfa: Option<CfaPatternInspection>,
     pub source_black_levels: [f32; 4],
     pub source_white_levels: [f32; 4],
     pub normalized_black_levels: [f32; 4],
     pub normalized_white_levels: [f32; 4],
     pub as_shot_white_balance: [f32; 4],
     pub headroom_white_balance: [f32; 3],
+    pub mapped_bad_pixels: usize,
 }
 
 impl RawImageInspection {
@@ -46,13 +70,20 @@ impl RawImageInspection {
                 width: metadata.crop_area.width,
                 height: metadata.crop_area.height,
             },
-            cfa: CfaPatternInspection::from_bayer_cfa_pattern(metadata.cfa_pattern),
+            cfa: (!metadata.cfa_pattern.is_empty()).then(|| {
+                CfaPatternInspection::from_cfa_pattern(
+                    metadata.cfa_width,
+                    metadata.cfa_height,
+                    &metadata.cfa_pattern,
+                )
+            }),
             source_black_levels: metadata.source_black_levels,
             source_white_levels: metadata.source_white_levels,
             normalized_black_levels: metadata.normalized_black_levels,
             normalized_white_levels: metadata.normalized_white_levels,
             as_shot_white_balance: metadata.as_shot_white_balance,
             headroom_white_balance: metadata.headroom_white_balance,
+            mapped_bad_pixels: metadata.mapped_bad_pixels,
         }
     }
 }
@@ -82,22 +113,21 @@ pub struct CfaPatternInspection {
     pub name: String,
     pub width: u32,
     pub height: u32,
-    pub cells: [CfaCellInspection; 4],
+    pub cells: Vec<CfaCellInspection>,
 }
 
 impl CfaPatternInspection {
-    fn from_bayer_cfa_pattern(pattern: [u32; 4]) -> Self {
-        let cells = [
-            CfaCellInspection::from_color_code(pattern[0]),
-            CfaCellInspection::from_color_code(pattern[1]),
-            CfaCellInspection::from_color_code(pattern[2]),
-            CfaCellInspection::from_color_code(pattern[3]),
-        ];
+    fn from_cfa_pattern(width: u32, height: u32, pattern: &[u32]) -> Self {
+        let cells: Vec<CfaCellInspection> = pattern
+            .iter()
+            .copied()
+            .map(CfaCellInspection::from_color_code)
+            .collect();
 
         Self {
-            name: cfa_pattern_name(&cells),
-            width: 2,
-            height: 2,
+            name: cfa_pattern_name(width, height, &cells),
+            width,
+            height,
             cells,
         }
     }
@@ -129,8 +159,12 @@ fn cfa_color_label(code: u32) -> &'static str {
     }
 }
 
-fn cfa_pattern_name(cells: &[CfaCellInspection; 4]) -> String {
-    let mut name = String::with_capacity(4);
+fn cfa_pattern_name(width: u32, height: u32, cells: &[CfaCellInspection]) -> String {
+    if width == 6 && height == 6 {
+        return "X-Trans".to_string();
+    }
+
+    let mut name = String::with_capacity(cells.len());
 
     for cell in cells {
         name.push_str(&cell.label);
@@ -8,8 +8,12 @@ mod texture;
 mod timing;
 
 pub(in crate::renderer) use capture::capture_output_png;
-pub use image::{ImageInspection, RawImageInspection};
+pub(in crate::renderer) use downsample::downsample_image_texture_region;
+pub use image::{DngOpcodeInspection, ImageInspection, RawImageInspection};
 pub use pipeline::PipelineInspection;
+pub(in crate::renderer) use readback::{
+    read_display_texture_as_rgba_f32, read_image_texture_region_rgba_f32,
+};
 pub use snapshot::InspectionSnapshot;
 pub use texture::{TextureInspection, TextureResourceInspection};
 pub use timing::TimingInspection;
@@ -6,6 +6,17 @@ pub struct PipelineInspection {
     pub display_intent: String,
     pub base_exposure_ev: Option<f32>,
     pub user_exposure_ev: f32,
+    pub white_balance: String,
+    pub white_balance_multipliers: Option<[f32; 3]>,
+    pub highlight_reconstruction: String,
+    pub noise_reduction: String,
+    pub capture_sharpening: String,
+    pub sharpening_mask_preview: bool,
+    pub local_mask_overlay: Option<usize>,
+    pub spot_visualization: bool,
+    pub lens_profile: String,
+    pub tone_mapper: String,
+    pub look: String,
 }
 
 impl Default for PipelineInspection {
@@ -15,6 +26,17 @@ impl Default for PipelineInspection {
             display_intent: "-".to_string(),
             base_exposure_ev: None,
             user_exposure_ev: 0.0,
+            white_balance: "-".to_string(),
+            white_balance_multipliers: None,
+            highlight_reconstruction: "-".to_string(),
+            noise_reduction: "-".to_string(),
+            capture_sharpening: "-".to_string(),
+            sharpening_mask_preview: false,
+            local_mask_overlay: None,
+            spot_visualization: false,
+            lens_profile: "-".to_string(),
+            tone_mapper: "ACES Fitted".to_string(),
+            look: "None".to_string(),
         }
     }
 }
@@ -11,26 +11,114 @@ pub(in crate::renderer) fn read_display_texture_as_rgba8(
     width: u32,
     height: u32,
 ) -> Result<Vec<u8>> {
-    let source_bytes_per_row = width * 8;
+    let mut rgba = Vec::with_capacity((width as usize) * (height as usize) * 4);
+
+    read_display_texture(device, queue, texture, width, height, |texel| {
+        rgba.push(linear_srgb_to_u8(texel[0]));
+        rgba.push(linear_srgb_to_u8(texel[1]));
+        rgba.push(linear_srgb_to_u8(texel[2]));
+        rgba.push(alpha_to_u8(texel[3]));
+    })?;
+
+    Ok(rgba)
+}
+
+/// Reads an RGBA16F display texture into linear display-referred `f32` texels.
+///
+/// Unlike the checkpoint readback this keeps the full half-float precision so
+/// callers can choose their own output encoding and bit depth.
+pub(in crate::renderer) fn read_display_texture_as_rgba_f32(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    width: u32,
+    height: u32,
+) -> Result<Vec<f32>> {
+    let mut texels = Vec::with_capacity((width as usize) * (height as usize) * 4);
+
+    read_display_texture(device, queue, texture, width, height, |texel| {
+        texels.extend_from_slice(&texel);
+    })?;
+
+    Ok(texels)
+}
+
+/// Reads a region of an RGBA32F image texture into working-space `f32` texels.
+pub(in crate::renderer) fn read_image_texture_region_rgba_f32(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    origin: (u32, u32),
+    width: u32,
+    height: u32,
+) -> Result<Vec<f32>> {
+    let mut texels = Vec::with_capacity((width as usize) * (height as usize) * 4);
+
+    read_texture_region(device, queue, texture, 16, origin, width, height, |bytes| {
+        texels.push(read_f32(bytes, 0));
+        texels.push(read_f32(bytes, 4));
+        texels.push(read_f32(bytes, 8));
+        texels.push(read_f32(bytes, 12));
+    })?;
+
+    Ok(texels)
+}
+
+/// Copies an RGBA16F texture into a mapped buffer and visits each texel in row-major order.
+fn read_display_texture(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    width: u32,
+    height: u32,
+    mut visit: impl FnMut([f32; 4]),
+) -> Result<()> {
+    read_texture_region(device, queue, texture, 8, (0, 0), width, height, |bytes| {
+        visit([
+            read_f16(bytes, 0),
+            read_f16(bytes, 2),
+            read_f16(bytes, 4),
+            read_f16(bytes, 6),
+        ]);
+    })
+}
+
+/// Copies a texture region into a mapped buffer and visits each texel's bytes in row-major order.
+#[allow(clippy::too_many_arguments)]
+fn read_texture_region(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    bytes_per_texel: u32,
+    origin: (u32, u32),
+    width: u32,
+    height: u32,
+    mut visit: impl FnMut(&[u8]),
+) -> Result<()> {
+    let source_bytes_per_row = width * bytes_per_texel;
     let padded_bytes_per_row = align_to(source_bytes_per_row, wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
     let buffer_size = u64::from(padded_bytes_per_row) * u64::from(height);
 
     let buffer = device.create_buffer(&wgpu::BufferDescriptor {
-        label: Some("Inspection Checkpoint Output Readback Buffer"),
+        label: Some("Texture Readback Buffer"),
         size: buffer_size,
         usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
         mapped_at_creation: false,
     });
 
     let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
-        label: Some("Inspection Checkpoint Output Readback Encoder"),
+        label: Some("Texture Readback Encoder"),
     });
 
     encoder.copy_texture_to_buffer(
         wgpu::TexelCopyTextureInfo {
             texture,
             mip_level: 0,
-            origin: wgpu::Origin3d::ZERO,
+            origin: wgpu::Origin3d {
+                x: origin.0,
+                y: origin.1,
+                z: 0,
+            },
             aspect: wgpu::TextureAspect::All,
         },
         wgpu::TexelCopyBufferInfo {
@@ -61,38 +149,31 @@ pub(in crate::renderer) fn read_display_texture_as_rgba8(
 
     match receiver.recv() {
         Ok(Ok(())) => {}
-        Ok(Err(error)) => return Err(anyhow!("failed to map renderer output texture: {error}")),
+        Ok(Err(error)) => return Err(anyhow!("failed to map renderer texture: {error}")),
         Err(error) => {
             return Err(anyhow!(
-                "failed to receive renderer output readback: {error}"
+                "failed to receive renderer texture readback: {error}"
             ))
         }
     }
 
     let mapped = buffer_slice.get_mapped_range();
-    let mut rgba = Vec::with_capacity((width as usize) * (height as usize) * 4);
+    let texel_size = bytes_per_texel as usize;
 
     for y in 0..height as usize {
         let row_start = y * padded_bytes_per_row as usize;
 
         for x in 0..width as usize {
-            let pixel_start = row_start + (x * 8);
-            let red = read_f16(&mapped, pixel_start);
-            let green = read_f16(&mapped, pixel_start + 2);
-            let blue = read_f16(&mapped, pixel_start + 4);
-            let alpha = read_f16(&mapped, pixel_start + 6);
-
-            rgba.push(linear_srgb_to_u8(red));
-            rgba.push(linear_srgb_to_u8(green));
-            rgba.push(linear_srgb_to_u8(blue));
-            rgba.push(alpha_to_u8(alpha));
+            let texel_start = row_start + (x * texel_size);
+
+            visit(&mapped[texel_start..texel_start + texel_size]);
         }
     }
 
     drop(mapped);
     buffer.unmap();
 
-    Ok(rgba)
+    Ok(())
 }
 
 fn align_to(value: u32, alignment: u32) -> u32 {
@@ -105,6 +186,15 @@ fn read_f16(bytes: &[u8], offset: usize) -> f32 {
     f16::from_bits(bits).to_f32()
 }
 
+fn read_f32(bytes: &[u8], offset: usize) -> f32 {
+    f32::from_le_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
 fn linear_srgb_to_u8(value: f32) -> u8 {
     let value = value.clamp(0.0, 1.0);
     let encoded = if value <= 0.003_130_8 {
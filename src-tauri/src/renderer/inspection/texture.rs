@@ -4,7 +4,12 @@
 pub struct TextureInspection {
     pub source: TextureResourceInspection,
     pub development_output: TextureResourceInspection,
+    pub lens_correction_output: TextureResourceInspection,
+    pub retouch_output: TextureResourceInspection,
     pub adjustment_output: TextureResourceInspection,
+    pub mask: TextureResourceInspection,
+    pub local_adjustment_output: TextureResourceInspection,
+    pub geometry_output: TextureResourceInspection,
     pub display_output: TextureResourceInspection,
     pub surface: TextureResourceInspection,
 }
@@ -20,7 +25,16 @@ impl TextureInspection {
             development_output: TextureResourceInspection::placeholder(
                 "Development Output Texture",
             ),
+            lens_correction_output: TextureResourceInspection::placeholder(
+                "Lens Correction Output Texture",
+            ),
+            retouch_output: TextureResourceInspection::placeholder("Retouch Output Texture"),
             adjustment_output: TextureResourceInspection::placeholder("Adjustment Output Texture"),
+            mask: TextureResourceInspection::placeholder("Mask Texture"),
+            local_adjustment_output: TextureResourceInspection::placeholder(
+                "Local Adjustment Output Texture",
+            ),
+            geometry_output: TextureResourceInspection::placeholder("Geometry Output Texture"),
             display_output: TextureResourceInspection::placeholder("Display Output Texture"),
             surface: TextureResourceInspection::new(
                 "Window Surface",
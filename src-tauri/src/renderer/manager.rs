@@ -10,7 +10,8 @@ use super::image_load::{
 use super::input::Input;
 use super::renderer::Renderer;
 use super::schedule::RenderState;
-use crate::core::editing::EditRecipe;
+use crate::core::db::connection::DbConnection;
+use crate::core::editing::{ColorBand, ColorRange, EditRecipe, MaskPoint, WhiteBalance};
 
 pub type RendererManagerHandle = Arc<Mutex<RendererManager>>;
 
@@ -21,12 +22,15 @@ pub type RendererManagerHandle = Arc<Mutex<RendererManager>>;
 /// request-aware image loads.
 pub struct RendererManager {
     renderer: Option<Renderer>,
+    db: DbConnection,
 }
 
 impl RendererManager {
     /// Creates a renderer manager with no initialized renderer.
-    pub fn new() -> Self {
-        Self { renderer: None }
+    ///
+    /// The database connection supplies per-camera bad pixel maps to image loads.
+    pub fn new(db: DbConnection) -> Self {
+        Self { renderer: None, db }
     }
 
     /// Locks a shared renderer manager handle.
@@ -78,8 +82,9 @@ impl RendererManager {
     ) -> Result<u64, String> {
         let preview_label = preview_path.as_deref().unwrap_or("<none>");
 
-        let request_id = {
+        let (request_id, db) = {
             let mut manager = Self::lock(&handle)?;
+            let db = manager.db.clone();
             let Some(renderer) = manager.renderer.as_mut() else {
                 return Err("Renderer not initialized".to_string());
             };
@@ -97,11 +102,13 @@ impl RendererManager {
                 request_id, defer_full_image_load, preview_label
             );
 
-            request_id
+            (request_id, db)
         };
 
         if let Some(preview_path) = preview_path {
-            if let Err(error) = set_requested_input_from_path(&preview_path, request_id, &handle) {
+            if let Err(error) =
+                set_requested_input_from_path(&preview_path, request_id, &handle, &db)
+            {
                 warn!(
                     "[RendererManager] Failed to load preview texture from {preview_path}: {error}"
                 );
@@ -109,7 +116,7 @@ impl RendererManager {
         }
 
         if !defer_full_image_load {
-            spawn_full_image_load(path, request_id, handle);
+            spawn_full_image_load(path, request_id, handle, db);
         } else {
             info!(
                 "[RendererManager] Deferring full decode for request {}",
@@ -126,13 +133,13 @@ impl RendererManager {
         path: String,
         request_id: u64,
     ) -> Result<(), String> {
-        let should_start = {
+        let (should_start, db) = {
             let manager = Self::lock(&handle)?;
             let Some(renderer) = manager.renderer.as_ref() else {
                 return Err("Renderer not initialized".to_string());
             };
 
-            renderer.is_request_active(request_id)
+            (renderer.is_request_active(request_id), manager.db.clone())
         };
 
         if !should_start {
@@ -143,7 +150,7 @@ impl RendererManager {
             return Ok(());
         }
 
-        spawn_full_image_load(path, request_id, handle);
+        spawn_full_image_load(path, request_id, handle, db);
 
         Ok(())
     }
@@ -154,7 +161,9 @@ impl RendererManager {
         path: String,
         request_id: u64,
     ) -> Result<(), String> {
-        swap_requested_input(path, request_id, handle).await
+        let db = Self::lock(&handle)?.db.clone();
+
+        swap_requested_input(path, request_id, handle, db).await
     }
 
     /// Updates the logical interaction viewport and recalculates image placement.
@@ -210,6 +219,97 @@ impl RendererManager {
         }
     }
 
+    /// Toggles the capture sharpening mask preview and renders.
+    pub fn set_sharpening_mask_preview(&mut self, enabled: bool) {
+        if let Some(renderer) = self.renderer.as_mut() {
+            renderer.set_sharpening_mask_preview(enabled);
+            renderer.render();
+        }
+    }
+
+    /// Sets or clears the Inspector local adjustment mask overlay and renders.
+    pub fn set_local_mask_overlay(&mut self, index: Option<usize>) {
+        if let Some(renderer) = self.renderer.as_mut() {
+            renderer.set_local_mask_overlay(index);
+            renderer.render();
+        }
+    }
+
+    /// Turns the Inspector spot visualization view on or off and renders.
+    pub fn set_spot_visualization(&mut self, enabled: bool) {
+        if let Some(renderer) = self.renderer.as_mut() {
+            renderer.set_spot_visualization(enabled);
+            renderer.render();
+        }
+    }
+
+    /// Switches live presentation between SDR and HDR and renders, returning
+    /// whether HDR output is active after any SDR fallback.
+    pub fn set_hdr_output(&mut self, enabled: bool, peak_nits: f32) -> Result<bool, String> {
+        match self.renderer.as_mut() {
+            Some(renderer) => {
+                let hdr_active = renderer.set_hdr_output(enabled, peak_nits);
+
+                renderer.render();
+
+                Ok(hdr_active)
+            }
+            None => Err("Renderer not initialized".to_string()),
+        }
+    }
+
+    /// Samples a neutral patch at a normalized image position and returns
+    /// the custom white balance that would neutralize it.
+    pub fn sample_neutral_white_balance(&self, x: f32, y: f32) -> Result<WhiteBalance, String> {
+        match self.renderer.as_ref() {
+            Some(renderer) => renderer
+                .sample_neutral_white_balance(x, y)
+                .map_err(|error| error.to_string()),
+            None => Err("Renderer not initialized".to_string()),
+        }
+    }
+
+    /// Returns the color mixer band under a normalized image position.
+    pub fn sample_color_band(&self, x: f32, y: f32) -> Result<Option<ColorBand>, String> {
+        match self.renderer.as_ref() {
+            Some(renderer) => renderer
+                .sample_color_band(x, y)
+                .map_err(|error| error.to_string()),
+            None => Err("Renderer not initialized".to_string()),
+        }
+    }
+
+    /// Maps a surface pixel position to a normalized source image position.
+    pub fn source_position_at_viewport(&self, x: f32, y: f32) -> Result<Option<MaskPoint>, String> {
+        match self.renderer.as_ref() {
+            Some(renderer) => Ok(renderer.source_position_at_viewport(x, y)),
+            None => Err("Renderer not initialized".to_string()),
+        }
+    }
+
+    /// Builds a mask color range from the color at a normalized source position.
+    pub fn sample_mask_color(&self, sample: MaskPoint) -> Result<ColorRange, String> {
+        match self.renderer.as_ref() {
+            Some(renderer) => renderer
+                .sample_mask_color(sample)
+                .map_err(|error| error.to_string()),
+            None => Err("Renderer not initialized".to_string()),
+        }
+    }
+
+    pub fn find_spot_source(
+        &self,
+        destination: MaskPoint,
+        radius: f32,
+    ) -> Result<Option<MaskPoint>, String> {
+        match self.renderer.as_ref() {
+            Some(renderer) => renderer
+                .find_spot_source(destination, radius)
+                .map_err(|error| error.to_string()),
+            None => Err("Renderer not initialized".to_string()),
+        }
+    }
+
     pub(super) fn set_input_for_active_request(
         &mut self,
         request_id: u64,
@@ -1,10 +1,12 @@
 mod context;
+mod display_output;
 mod display_resources;
 mod image_load;
 mod image_request;
 mod input;
 mod inspection;
 mod manager;
+mod offscreen;
 mod pipeline;
 mod processing_graph;
 mod renderer;
@@ -17,8 +19,10 @@ mod viewport;
 
 pub use context::GpuContext;
 pub use context::SurfaceContext;
+pub use display_output::{DisplayOutput, DEFAULT_HDR_PEAK_NITS, SDR_REFERENCE_WHITE_NITS};
 pub use inspection::InspectionSnapshot;
 pub use manager::{RendererManager, RendererManagerHandle};
+pub use offscreen::{OffscreenRenderer, RenderedImage};
 pub use renderer::Renderer;
 pub use schedule::RenderState;
 pub use vertex::Vertex;
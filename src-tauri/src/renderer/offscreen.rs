@@ -0,0 +1,208 @@
+use anyhow::{anyhow, Result};
+use log::info;
+use std::path::Path;
+
+use super::context::GpuContext;
+use super::display_output::DisplayOutput;
+use super::input::{build_input_from_path, Input};
+use super::processing_graph::{ImageProcessingGraph, RawCaptureSharpening, RawNoiseReduction};
+use super::renderer::graph_display_intent;
+use crate::core::db::connection::DbConnection;
+use crate::core::editing::EditRecipe;
+use crate::core::look::load_look_file;
+
+/// Full-resolution display-referred pixels read back from the processing graph.
+///
+/// Texels are linear-light RGBA in the display color space produced by the
+/// output transform. HDR renders exceed `1.0` up to the display headroom.
+/// Callers own the final transfer encoding and bit depth.
+pub struct RenderedImage {
+    width: u32,
+    height: u32,
+    texels: Vec<f32>,
+}
+
+impl RenderedImage {
+    /// Returns the rendered image width.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the rendered image height.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns linear RGBA texels in row-major order.
+    pub fn texels(&self) -> &[f32] {
+        &self.texels
+    }
+}
+
+/// Window-independent owner of a processing graph used for export and batch work.
+///
+/// This runs the same processing graph stages as the live renderer, but against
+/// its own GPU device so it never disturbs the displayed image or requires a
+/// presentation surface.
+pub struct OffscreenRenderer {
+    gpu: GpuContext,
+    processing_graph: ImageProcessingGraph,
+    db: Option<DbConnection>,
+}
+
+impl OffscreenRenderer {
+    /// Creates an offscreen renderer on a newly requested GPU device.
+    ///
+    /// The optional database supplies per-camera bad pixel maps to RAW sources.
+    pub fn new(db: Option<DbConnection>) -> Result<Self> {
+        let gpu = match GpuContext::new_offscreen() {
+            Ok(gpu) => gpu,
+            Err(error) => return Err(error),
+        };
+
+        let processing_graph = ImageProcessingGraph::new(&gpu.device, &gpu.queue);
+
+        Ok(Self {
+            gpu,
+            processing_graph,
+            db,
+        })
+    }
+
+    /// Returns the name of the adapter backing this renderer.
+    pub fn adapter_name(&self) -> String {
+        self.gpu.adapter.get_info().name
+    }
+
+    /// Decodes a source file and renders it at full resolution with a recipe
+    /// for the given display output.
+    pub fn render_path(
+        &mut self,
+        path: &str,
+        recipe: &EditRecipe,
+        output: DisplayOutput,
+    ) -> Result<RenderedImage> {
+        let input = match build_input_from_path(path, self.db.as_ref()) {
+            Ok(input) => input,
+            Err(error) => return Err(error),
+        };
+
+        self.render_input(input, recipe, output)
+    }
+
+    fn render_input(
+        &mut self,
+        input: Input,
+        recipe: &EditRecipe,
+        output: DisplayOutput,
+    ) -> Result<RenderedImage> {
+        let image = input.image();
+        let dimensions = image.dimensions();
+        let output_transform = input.output_transform().for_display_output(output);
+        let development_parameters = input
+            .white_balance()
+            .resolve(&recipe.white_balance)
+            .apply_to(input.development_parameters())
+            .with_highlight_reconstruction(recipe.highlight_reconstruction)
+            .with_noise_reduction(RawNoiseReduction::from_recipe(
+                &recipe.noise_reduction,
+                input.iso(),
+            ))
+            .with_capture_sharpening(RawCaptureSharpening::from_recipe(
+                &recipe.capture_sharpening,
+                false,
+            ));
+
+        info!(
+            "[OffscreenRenderer] Rendering source image ({}x{})",
+            dimensions.width(),
+            dimensions.height()
+        );
+
+        self.check_texture_limits(dimensions.width(), dimensions.height())?;
+
+        let look_lut = match recipe.look.path.as_ref() {
+            Some(path) => match load_look_file(Path::new(path), recipe.look.hash.as_deref()) {
+                Ok((_, lut)) => Some(lut),
+                Err(error) => return Err(error),
+            },
+            None => None,
+        };
+
+        self.processing_graph.update_output_transform(
+            &self.gpu.device,
+            &self.gpu.queue,
+            graph_display_intent(output_transform.display_intent()),
+            output_transform.base_exposure_ev(),
+            output_transform.display_headroom(),
+        );
+        self.processing_graph.update_lens_correction(
+            &self.gpu.device,
+            &self.gpu.queue,
+            input.lens_calibration(),
+            &recipe.lens_correction,
+        );
+        self.processing_graph.update_retouch_spots(
+            &self.gpu.device,
+            &self.gpu.queue,
+            &recipe.retouch_spots,
+        );
+        self.processing_graph
+            .update_adjustments(&self.gpu.device, &self.gpu.queue, recipe);
+        self.processing_graph.update_local_adjustments(
+            &self.gpu.device,
+            &self.gpu.queue,
+            &recipe.local_adjustments,
+        );
+        self.processing_graph.update_tone_mapping(
+            &self.gpu.device,
+            &self.gpu.queue,
+            &recipe.tone_mapping,
+        );
+        self.processing_graph
+            .update_look_lut(&self.gpu.device, &self.gpu.queue, look_lut.as_ref());
+        self.processing_graph
+            .update_look(&self.gpu.device, &self.gpu.queue, &recipe.look);
+        self.processing_graph
+            .set_geometry(&recipe.geometry, input.default_crop());
+        self.processing_graph.upload_source_image(
+            &self.gpu.device,
+            &self.gpu.queue,
+            image.texels(),
+            dimensions.width(),
+            dimensions.height(),
+            input.development_source(),
+            development_parameters,
+        );
+
+        let texels = match self
+            .processing_graph
+            .read_display_output(&self.gpu.device, &self.gpu.queue)
+        {
+            Ok(texels) => texels,
+            Err(error) => return Err(error),
+        };
+
+        Ok(RenderedImage {
+            width: self.processing_graph.output_width(),
+            height: self.processing_graph.output_height(),
+            texels,
+        })
+    }
+
+    /// Rejects sources that cannot fit in one graph texture on this device.
+    fn check_texture_limits(&self, width: u32, height: u32) -> Result<()> {
+        let max_dimension = self.gpu.device.limits().max_texture_dimension_2d;
+
+        if width > max_dimension || height > max_dimension {
+            return Err(anyhow!(
+                "source image {}x{} exceeds the GPU texture limit of {}",
+                width,
+                height,
+                max_dimension
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::core::editing::{ColorBand, ColorMixer, COLOR_BAND_COUNT};
+
+/// OkLCh hue of each color mixer band center in degrees, in [`ColorBand::ALL`] order.
+///
+/// Centers sit on the hues of the matching sRGB reference colors so the bands
+/// line up with what users expect from named colors.
+const COLOR_BAND_HUES_DEGREES: [f32; COLOR_BAND_COUNT] =
+    [29.0, 53.0, 110.0, 142.0, 195.0, 264.0, 294.0, 328.0];
+
+/// OkLab chroma below which a sample is treated as neutral.
+const NEUTRAL_CHROMA: f32 = 0.02;
+
+/// Linear Rec.2020 to OkLab LMS.
+const REC2020_TO_LMS: [[f32; 3]; 3] = [
+    [0.616_688_4, 0.360_159_08, 0.023_043_303],
+    [0.265_140_18, 0.635_856_5, 0.099_030_22],
+    [0.100_150_64, 0.204_004_33, 0.696_324_7],
+];
+
+/// Cube-root LMS to OkLab.
+const LMS_TO_OKLAB: [[f32; 3]; 3] = [
+    [0.210_454_26, 0.793_617_8, -0.004_072_047],
+    [1.977_998_5, -2.428_592_2, 0.450_593_7],
+    [0.025_904_037, 0.782_771_77, -0.808_675_77],
+];
+
+/// Packs eight band values into two `vec4` uniform slots.
+pub(super) fn pack_color_bands(values: &[f32; COLOR_BAND_COUNT]) -> [[f32; 4]; 2] {
+    let value = |index: usize| values[index].clamp(-1.0, 1.0);
+
+    [
+        [value(0), value(1), value(2), value(3)],
+        [value(4), value(5), value(6), value(7)],
+    ]
+}
+
+/// Returns the band center hues in radians packed for the adjustment uniform block.
+pub(super) fn color_band_hues_uniform() -> [[f32; 4]; 2] {
+    let hues = COLOR_BAND_HUES_DEGREES.map(f32::to_radians);
+
+    [
+        [hues[0], hues[1], hues[2], hues[3]],
+        [hues[4], hues[5], hues[6], hues[7]],
+    ]
+}
+
+/// Returns whether every color mixer control is neutral.
+pub(super) fn is_neutral_color_mixer(color_mixer: &ColorMixer) -> bool {
+    color_mixer
+        .hue
+        .iter()
+        .chain(&color_mixer.saturation)
+        .chain(&color_mixer.luminance)
+        .all(|value| *value == 0.0)
+}
+
+/// Returns the color mixer band with the most weight for a linear Rec.2020 color.
+///
+/// Neutral colors return `None` because the mixer leaves them unchanged.
+pub(in crate::renderer) fn dominant_color_band(color: [f32; 3]) -> Option<ColorBand> {
+    let (hue, chroma) = oklch_hue_chroma(color);
+
+    if chroma < NEUTRAL_CHROMA {
+        return None;
+    }
+
+    for (index, start) in COLOR_BAND_HUES_DEGREES.iter().enumerate() {
+        let next_index = (index + 1) % COLOR_BAND_COUNT;
+        let span = (COLOR_BAND_HUES_DEGREES[next_index] - start).rem_euclid(360.0);
+        let offset = (hue - start).rem_euclid(360.0);
+
+        if offset < span {
+            let band = if offset < span * 0.5 {
+                index
+            } else {
+                next_index
+            };
+
+            return Some(ColorBand::ALL[band]);
+        }
+    }
+
+    None
+}
+
+/// Returns the OkLCh hue in degrees and chroma of a linear Rec.2020 color.
+pub(in crate::renderer) fn oklch_hue_chroma(color: [f32; 3]) -> (f32, f32) {
+    let lms = multiply(&REC2020_TO_LMS, color).map(f32::cbrt);
+    let [_, a, b] = multiply(&LMS_TO_OKLAB, lms);
+
+    (b.atan2(a).to_degrees().rem_euclid(360.0), a.hypot(b))
+}
+
+fn multiply(matrix: &[[f32; 3]; 3], vector: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
@@ -0,0 +1,343 @@
+use crate::core::editing::{CropAspectRatio, CropRect, Geometry, MAX_STRAIGHTEN_DEGREES};
+
+/// Recipe geometry resolved against concrete source dimensions.
+///
+/// Holds the output size and the affine map from output pixel-edge
+/// coordinates to source pixel-edge coordinates that the geometry stage
+/// samples with. Straightened crops are shrunk about their center until every
+/// corner lands inside the image, so the output never shows empty corners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(in crate::renderer) struct ResolvedGeometry {
+    output_width: u32,
+    output_height: u32,
+    output_to_source: Affine,
+}
+
+impl ResolvedGeometry {
+    /// Resolves geometry that passes the source through unchanged.
+    pub(in crate::renderer) fn identity(width: u32, height: u32) -> Self {
+        Self {
+            output_width: width.max(1),
+            output_height: height.max(1),
+            output_to_source: Affine::IDENTITY,
+        }
+    }
+
+    /// Resolves recipe geometry for a source image.
+    ///
+    /// `default_crop` is in normalized source coordinates and applies when the
+    /// recipe has no crop of its own.
+    pub(in crate::renderer) fn resolve(
+        geometry: &Geometry,
+        source_width: u32,
+        source_height: u32,
+        default_crop: Option<CropRect>,
+    ) -> Self {
+        let source_width = source_width.max(1) as f32;
+        let source_height = source_height.max(1) as f32;
+        let quarter_turns = geometry.quarter_turns % 4;
+        let (oriented_width, oriented_height) = if quarter_turns % 2 == 0 {
+            (source_width, source_height)
+        } else {
+            (source_height, source_width)
+        };
+        let oriented_to_source = Affine::oriented_to_source(
+            quarter_turns,
+            geometry.flip_horizontal,
+            geometry.flip_vertical,
+            source_width,
+            source_height,
+        );
+
+        let crop = match (geometry.crop, default_crop) {
+            (Some(crop), _) => clamp_crop(crop),
+            (None, Some(default_crop)) => source_crop_to_oriented(
+                clamp_crop(default_crop),
+                oriented_to_source,
+                source_width,
+                source_height,
+                oriented_width,
+                oriented_height,
+            ),
+            (None, None) => CropRect::full(),
+        };
+
+        let center_x = (crop.x + (crop.width * 0.5)) * oriented_width;
+        let center_y = (crop.y + (crop.height * 0.5)) * oriented_height;
+        let (mut crop_width, mut crop_height) = lock_aspect_ratio(
+            crop.width * oriented_width,
+            crop.height * oriented_height,
+            geometry.aspect_ratio,
+            oriented_width / oriented_height,
+        );
+
+        let angle = geometry
+            .straighten_degrees
+            .clamp(-MAX_STRAIGHTEN_DEGREES, MAX_STRAIGHTEN_DEGREES)
+            .to_radians();
+        let (sin, cos) = angle.sin_cos();
+        let scale = straightened_crop_scale(
+            crop_width,
+            crop_height,
+            center_x,
+            center_y,
+            [[cos, sin], [-sin, cos]],
+            oriented_width,
+            oriented_height,
+        );
+
+        crop_width *= scale;
+        crop_height *= scale;
+
+        let output_width = (crop_width.round() as u32).max(1);
+        let output_height = (crop_height.round() as u32).max(1);
+        let half_width = output_width as f32 * 0.5;
+        let half_height = output_height as f32 * 0.5;
+        let output_to_oriented = Affine {
+            rows: [
+                [
+                    cos,
+                    sin,
+                    center_x - (cos * half_width) - (sin * half_height),
+                ],
+                [
+                    -sin,
+                    cos,
+                    center_y + (sin * half_width) - (cos * half_height),
+                ],
+            ],
+        };
+
+        Self {
+            output_width,
+            output_height,
+            output_to_source: oriented_to_source.after(output_to_oriented),
+        }
+    }
+
+    pub(in crate::renderer) fn output_width(&self) -> u32 {
+        self.output_width
+    }
+
+    pub(in crate::renderer) fn output_height(&self) -> u32 {
+        self.output_height
+    }
+
+    /// Returns the rows of the output-to-source affine transform.
+    pub(in crate::renderer) fn output_to_source_rows(&self) -> [[f32; 3]; 2] {
+        self.output_to_source.rows
+    }
+
+    /// Returns whether the map rotates by a non-quarter-turn angle.
+    pub(in crate::renderer) fn is_straightened(&self) -> bool {
+        let [row_x, row_y] = self.output_to_source.rows;
+
+        row_x[0] * row_x[1] != 0.0 || row_y[0] * row_y[1] != 0.0
+    }
+
+    /// Maps an output pixel-edge coordinate to the matching source coordinate.
+    pub(in crate::renderer) fn output_to_source(&self, x: f32, y: f32) -> (f32, f32) {
+        self.output_to_source.apply(x, y)
+    }
+}
+
+/// Two-row affine transform of pixel-edge coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Affine {
+    rows: [[f32; 3]; 2],
+}
+
+impl Affine {
+    const IDENTITY: Self = Self {
+        rows: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+    };
+
+    /// Maps oriented-frame coordinates back into the source frame by undoing
+    /// flips and then clockwise quarter turns.
+    fn oriented_to_source(
+        quarter_turns: u8,
+        flip_horizontal: bool,
+        flip_vertical: bool,
+        source_width: f32,
+        source_height: f32,
+    ) -> Self {
+        let (oriented_width, oriented_height) = if quarter_turns % 2 == 0 {
+            (source_width, source_height)
+        } else {
+            (source_height, source_width)
+        };
+        let unflip = Self {
+            rows: [
+                if flip_horizontal {
+                    [-1.0, 0.0, oriented_width]
+                } else {
+                    [1.0, 0.0, 0.0]
+                },
+                if flip_vertical {
+                    [0.0, -1.0, oriented_height]
+                } else {
+                    [0.0, 1.0, 0.0]
+                },
+            ],
+        };
+        let unrotate = match quarter_turns {
+            1 => Self {
+                rows: [[0.0, 1.0, 0.0], [-1.0, 0.0, source_height]],
+            },
+            2 => Self {
+                rows: [[-1.0, 0.0, source_width], [0.0, -1.0, source_height]],
+            },
+            3 => Self {
+                rows: [[0.0, -1.0, source_width], [1.0, 0.0, 0.0]],
+            },
+            _ => Self::IDENTITY,
+        };
+
+        unrotate.after(unflip)
+    }
+
+    fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        let [row_x, row_y] = self.rows;
+
+        (
+            (row_x[0] * x) + (row_x[1] * y) + row_x[2],
+            (row_y[0] * x) + (row_y[1] * y) + row_y[2],
+        )
+    }
+
+    /// Returns the transform that applies `inner` first and then `self`.
+    fn after(&self, inner: Self) -> Self {
+        let [a, b] = self.rows;
+        let [c, d] = inner.rows;
+
+        Self {
+            rows: [
+                [
+                    (a[0] * c[0]) + (a[1] * d[0]),
+                    (a[0] * c[1]) + (a[1] * d[1]),
+                    (a[0] * c[2]) + (a[1] * d[2]) + a[2],
+                ],
+                [
+                    (b[0] * c[0]) + (b[1] * d[0]),
+                    (b[0] * c[1]) + (b[1] * d[1]),
+                    (b[0] * c[2]) + (b[1] * d[2]) + b[2],
+                ],
+            ],
+        }
+    }
+
+    fn inverse(&self) -> Self {
+        let [[a, b, tx], [c, d, ty]] = self.rows;
+        let determinant = (a * d) - (b * c);
+        let inverse_a = d / determinant;
+        let inverse_b = -b / determinant;
+        let inverse_c = -c / determinant;
+        let inverse_d = a / determinant;
+
+        Self {
+            rows: [
+                [inverse_a, inverse_b, -((inverse_a * tx) + (inverse_b * ty))],
+                [inverse_c, inverse_d, -((inverse_c * tx) + (inverse_d * ty))],
+            ],
+        }
+    }
+}
+
+/// Clamps a normalized crop inside the image, keeping it non-empty.
+fn clamp_crop(crop: CropRect) -> CropRect {
+    let x = crop.x.clamp(0.0, 1.0);
+    let y = crop.y.clamp(0.0, 1.0);
+
+    CropRect {
+        x,
+        y,
+        width: crop.width.clamp(0.0, 1.0 - x).max(f32::EPSILON),
+        height: crop.height.clamp(0.0, 1.0 - y).max(f32::EPSILON),
+    }
+}
+
+/// Carries a normalized source-frame crop into the normalized oriented frame.
+fn source_crop_to_oriented(
+    crop: CropRect,
+    oriented_to_source: Affine,
+    source_width: f32,
+    source_height: f32,
+    oriented_width: f32,
+    oriented_height: f32,
+) -> CropRect {
+    let source_to_oriented = oriented_to_source.inverse();
+    let (first_x, first_y) =
+        source_to_oriented.apply(crop.x * source_width, crop.y * source_height);
+    let (second_x, second_y) = source_to_oriented.apply(
+        (crop.x + crop.width) * source_width,
+        (crop.y + crop.height) * source_height,
+    );
+
+    CropRect {
+        x: first_x.min(second_x) / oriented_width,
+        y: first_y.min(second_y) / oriented_height,
+        width: (first_x - second_x).abs() / oriented_width,
+        height: (first_y - second_y).abs() / oriented_height,
+    }
+}
+
+/// Shrinks one crop side about the center so the crop matches the locked ratio.
+fn lock_aspect_ratio(
+    width: f32,
+    height: f32,
+    aspect_ratio: CropAspectRatio,
+    original_ratio: f32,
+) -> (f32, f32) {
+    let ratio = match aspect_ratio {
+        CropAspectRatio::Free => return (width, height),
+        CropAspectRatio::Original => original_ratio,
+        CropAspectRatio::Fixed { width, height } if width > 0.0 && height > 0.0 => width / height,
+        CropAspectRatio::Fixed { .. } => return (width, height),
+    };
+
+    if width / height > ratio {
+        (height * ratio, height)
+    } else {
+        (width, width / ratio)
+    }
+}
+
+/// Returns the largest scale, at most `1.0`, at which a straightened crop
+/// centered at `(center_x, center_y)` keeps all four corners inside the image.
+fn straightened_crop_scale(
+    crop_width: f32,
+    crop_height: f32,
+    center_x: f32,
+    center_y: f32,
+    rotation: [[f32; 2]; 2],
+    image_width: f32,
+    image_height: f32,
+) -> f32 {
+    let mut scale: f32 = 1.0;
+
+    for (corner_x, corner_y) in [
+        (-0.5 * crop_width, -0.5 * crop_height),
+        (0.5 * crop_width, -0.5 * crop_height),
+        (-0.5 * crop_width, 0.5 * crop_height),
+        (0.5 * crop_width, 0.5 * crop_height),
+    ] {
+        let offset_x = (rotation[0][0] * corner_x) + (rotation[0][1] * corner_y);
+        let offset_y = (rotation[1][0] * corner_x) + (rotation[1][1] * corner_y);
+
+        scale = scale
+            .min(axis_scale_limit(offset_x, center_x, image_width))
+            .min(axis_scale_limit(offset_y, center_y, image_height));
+    }
+
+    scale
+}
+
+fn axis_scale_limit(offset: f32, center: f32, extent: f32) -> f32 {
+    if offset > 0.0 {
+        (extent - center) / offset
+    } else if offset < 0.0 {
+        center / -offset
+    } else {
+        1.0
+    }
+}
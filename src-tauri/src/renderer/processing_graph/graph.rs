@@ -1,28 +1,76 @@
-use super::super::inspection::{capture_output_png, TextureInspection, TextureResourceInspection};
+use super::super::inspection::{
+    capture_output_png, downsample_image_texture_region, read_display_texture_as_rgba_f32,
+    read_image_texture_region_rgba_f32, TextureInspection, TextureResourceInspection,
+};
 use super::super::texture::ImageTexture;
+use super::geometry::ResolvedGeometry;
+use super::look::LookLut;
+use super::masks::{pack_brush_dabs, BrushDabs};
 use super::parameters::{
     AdjustmentParameters, AdjustmentParametersBuffer, DevelopmentParameters,
-    DevelopmentParametersBuffer, OutputTransformParameters, OutputTransformParametersBuffer,
+    DevelopmentParametersBuffer, GeometryParameters, GeometryParametersBuffer,
+    LensCorrectionParameters, LensCorrectionParametersBuffer, LocalAdjustmentParameters,
+    LocalAdjustmentParametersBuffer, MaskParameters, MaskParametersBuffer,
+    OutputTransformParametersBuffer, RetouchParameters, RetouchParametersBuffer,
+};
+use super::retouch::{find_spot_source, SpotSearchRegion};
+use super::stages::{
+    AdjustmentStage, DevelopmentStage, GeometryStage, LensCorrectionStage, LocalAdjustmentStage,
+    MaskStage, OutputTransformStage, RetouchStage,
 };
-use super::stages::{AdjustmentStage, DevelopmentStage, OutputTransformStage};
+use super::tone_curve::ToneCurveLut;
+use crate::core::editing::{
+    CropRect, EditRecipe, Geometry, LensCorrection, LocalAdjustment, Look, MaskPoint, RetouchSpot,
+    ToneMapping,
+};
+use crate::core::lens::LensCalibration;
+use crate::core::look::CubeLut;
 use crate::renderer::input::DevelopmentSource;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use std::path::Path;
 
 /// GPU-side image processing graph for the active renderer image.
 ///
 /// The graph owns source upload, GPU-side image stage execution, and the current
-/// output texture consumed by display resources.
+/// output texture consumed by display resources. Every stage up to local
+/// adjustments works at source resolution; the geometry stage then resamples
+/// into the cropped output size used by the output transform and display.
+/// Retouching repairs the lens-corrected image before any adjustment, and
+/// local adjustment masks are rasterized from the retouched image, so global
+/// adjustments never re-rasterize them.
 pub(in crate::renderer) struct ImageProcessingGraph {
     source_texture: ImageTexture,
     development_output_texture: ImageTexture,
+    lens_correction_output_texture: ImageTexture,
+    retouch_output_texture: ImageTexture,
     adjustment_output_texture: ImageTexture,
+    mask_texture: ImageTexture,
+    local_adjustment_output_texture: ImageTexture,
+    geometry_output_texture: ImageTexture,
     output_texture: ImageTexture,
     development_parameters_buffer: DevelopmentParametersBuffer,
+    lens_correction_parameters_buffer: LensCorrectionParametersBuffer,
+    retouch_parameters_buffer: RetouchParametersBuffer,
     adjustment_parameters_buffer: AdjustmentParametersBuffer,
+    mask_parameters_buffer: MaskParametersBuffer,
+    local_adjustment_parameters_buffer: LocalAdjustmentParametersBuffer,
+    geometry_parameters_buffer: GeometryParametersBuffer,
     output_transform_parameters_buffer: OutputTransformParametersBuffer,
+    tone_curve_lut: ToneCurveLut,
+    look_lut: LookLut,
+    brush_dabs: BrushDabs,
+    local_adjustments: Vec<LocalAdjustment>,
+    local_mask_overlay: Option<usize>,
+    geometry: Geometry,
+    default_crop: Option<CropRect>,
+    resolved_geometry: ResolvedGeometry,
     development_stage: DevelopmentStage,
+    lens_correction_stage: LensCorrectionStage,
+    retouch_stage: RetouchStage,
     adjustment_stage: AdjustmentStage,
+    mask_stage: MaskStage,
+    local_adjustment_stage: LocalAdjustmentStage,
+    geometry_stage: GeometryStage,
     output_transform_stage: OutputTransformStage,
 }
 
@@ -31,11 +79,26 @@ impl ImageProcessingGraph {
     pub(in crate::renderer) fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
         let source_texture = ImageTexture::new_source(device, queue);
         let development_output_texture = ImageTexture::new_development_output(device, queue);
+        let lens_correction_output_texture =
+            ImageTexture::new_lens_correction_output(device, queue);
+        let retouch_output_texture = ImageTexture::new_retouch_output(device, queue);
         let adjustment_output_texture = ImageTexture::new_adjustment_output(device, queue);
+        let mask_texture = ImageTexture::new_mask_output(device, queue);
+        let local_adjustment_output_texture =
+            ImageTexture::new_local_adjustment_output(device, queue);
+        let geometry_output_texture = ImageTexture::new_geometry_output(device, queue);
         let output_texture = ImageTexture::new_display_output(device, queue);
         let development_parameters_buffer = DevelopmentParametersBuffer::new(device);
+        let lens_correction_parameters_buffer = LensCorrectionParametersBuffer::new(device);
+        let retouch_parameters_buffer = RetouchParametersBuffer::new(device);
         let adjustment_parameters_buffer = AdjustmentParametersBuffer::new(device);
+        let mask_parameters_buffer = MaskParametersBuffer::new(device);
+        let local_adjustment_parameters_buffer = LocalAdjustmentParametersBuffer::new(device);
+        let geometry_parameters_buffer = GeometryParametersBuffer::new(device);
         let output_transform_parameters_buffer = OutputTransformParametersBuffer::new(device);
+        let tone_curve_lut = ToneCurveLut::new(device, queue);
+        let look_lut = LookLut::new(device, queue);
+        let brush_dabs = BrushDabs::new(device);
         let development_stage = DevelopmentStage::new(
             device,
             queue,
@@ -44,29 +107,89 @@ impl ImageProcessingGraph {
             development_output_texture.view(),
             development_parameters_buffer.as_entire_binding(),
         );
-        let adjustment_stage = AdjustmentStage::new(
+        let lens_correction_stage = LensCorrectionStage::new(
             device,
             development_output_texture.view(),
+            lens_correction_output_texture.view(),
+            lens_correction_parameters_buffer.as_entire_binding(),
+        );
+        let retouch_stage = RetouchStage::new(
+            device,
+            lens_correction_output_texture.view(),
+            retouch_output_texture.view(),
+            retouch_parameters_buffer.as_entire_binding(),
+        );
+        let adjustment_stage = AdjustmentStage::new(
+            device,
+            retouch_output_texture.view(),
             adjustment_output_texture.view(),
             adjustment_parameters_buffer.as_entire_binding(),
+            tone_curve_lut.view(),
         );
-        let output_transform_stage = OutputTransformStage::new(
+        let mask_stage = MaskStage::new(
+            device,
+            retouch_output_texture.view(),
+            mask_texture.view(),
+            mask_parameters_buffer.as_entire_binding(),
+            brush_dabs.view(),
+        );
+        let local_adjustment_stage = LocalAdjustmentStage::new(
             device,
             adjustment_output_texture.view(),
+            local_adjustment_output_texture.view(),
+            local_adjustment_parameters_buffer.as_entire_binding(),
+            mask_texture.view(),
+        );
+        let geometry_stage = GeometryStage::new(
+            device,
+            local_adjustment_output_texture.view(),
+            geometry_output_texture.view(),
+            geometry_parameters_buffer.as_entire_binding(),
+        );
+        let output_transform_stage = OutputTransformStage::new(
+            device,
+            geometry_output_texture.view(),
             output_texture.view(),
             output_transform_parameters_buffer.as_entire_binding(),
+            look_lut.view(),
         );
 
+        let resolved_geometry =
+            ResolvedGeometry::identity(output_texture.width(), output_texture.height());
+
         Self {
             source_texture,
             development_output_texture,
+            lens_correction_output_texture,
+            retouch_output_texture,
             adjustment_output_texture,
+            mask_texture,
+            local_adjustment_output_texture,
+            geometry_output_texture,
             output_texture,
             development_parameters_buffer,
+            lens_correction_parameters_buffer,
+            retouch_parameters_buffer,
             adjustment_parameters_buffer,
+            mask_parameters_buffer,
+            local_adjustment_parameters_buffer,
+            geometry_parameters_buffer,
             output_transform_parameters_buffer,
+            tone_curve_lut,
+            look_lut,
+            brush_dabs,
+            local_adjustments: Vec::new(),
+            local_mask_overlay: None,
+            geometry: Geometry::default(),
+            default_crop: None,
+            resolved_geometry,
             development_stage,
+            lens_correction_stage,
+            retouch_stage,
             adjustment_stage,
+            mask_stage,
+            local_adjustment_stage,
+            geometry_stage,
             output_transform_stage,
         }
     }
@@ -88,26 +211,206 @@ impl ImageProcessingGraph {
             .update(queue, development_parameters);
         self.development_output_texture
             .resize_empty(device, width, height);
+        self.lens_correction_output_texture
+            .resize_empty(device, width, height);
+        self.retouch_output_texture
+            .resize_empty(device, width, height);
         self.adjustment_output_texture
             .resize_empty(device, width, height);
-        self.output_texture.resize_empty(device, width, height);
+        self.mask_texture.resize_empty(device, width, height);
+        self.local_adjustment_output_texture
+            .resize_empty(device, width, height);
+        self.pack_masks(device, queue);
+        self.resolve_geometry(device, queue);
         self.rebind_stages(device, queue, development_source);
         self.run_full_graph(device, queue);
     }
 
-    /// Updates graph-owned adjustment parameters and reruns the current graph.
+    /// Records the geometry applied by the next source upload.
+    ///
+    /// `default_crop` is the source's own crop, in normalized source
+    /// coordinates, used while the recipe has no crop.
+    pub(in crate::renderer) fn set_geometry(
+        &mut self,
+        geometry: &Geometry,
+        default_crop: Option<CropRect>,
+    ) {
+        self.geometry = *geometry;
+        self.default_crop = default_crop;
+    }
+
+    /// Updates recipe geometry for the current source and reruns the graph from geometry.
+    ///
+    /// Returns whether the output size changed, which replaces the output
+    /// texture that display resources bind.
+    pub(in crate::renderer) fn update_geometry(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        geometry: &Geometry,
+    ) -> bool {
+        let output_size = (self.output_width(), self.output_height());
+
+        self.geometry = *geometry;
+
+        if self.resolve_geometry(device, queue) {
+            self.rebind_output_stages(device);
+        }
+
+        self.run_from_geometry(device, queue);
+
+        output_size != (self.output_width(), self.output_height())
+    }
+
+    /// Maps an output pixel-edge position to the source pixel it was resampled from.
+    ///
+    /// Returns `None` when the position falls outside the source image.
+    pub(in crate::renderer) fn source_pixel_at_output(&self, x: f32, y: f32) -> Option<(u32, u32)> {
+        let (source_x, source_y) = self.resolved_geometry.output_to_source(x, y);
+        let width = self.adjustment_output_texture.width() as f32;
+        let height = self.adjustment_output_texture.height() as f32;
+
+        if !(0.0..width).contains(&source_x) || !(0.0..height).contains(&source_y) {
+            return None;
+        }
+
+        Some((source_x as u32, source_y as u32))
+    }
+
+    /// Maps a normalized output position to a normalized source position.
+    ///
+    /// Returns `None` when the position falls outside the source image.
+    pub(in crate::renderer) fn source_position_at_output(
+        &self,
+        x: f32,
+        y: f32,
+    ) -> Option<(f32, f32)> {
+        let (source_x, source_y) = self.resolved_geometry.output_to_source(
+            x * self.output_width() as f32,
+            y * self.output_height() as f32,
+        );
+        let source_x = source_x / self.adjustment_output_texture.width() as f32;
+        let source_y = source_y / self.adjustment_output_texture.height() as f32;
+
+        if !(0.0..=1.0).contains(&source_x) || !(0.0..=1.0).contains(&source_y) {
+            return None;
+        }
+
+        Some((source_x, source_y))
+    }
+
+    /// Updates graph-owned development parameters and reruns the full graph.
+    pub(in crate::renderer) fn update_development_parameters(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        development_parameters: DevelopmentParameters,
+    ) {
+        self.development_parameters_buffer
+            .update(queue, development_parameters);
+        self.run_full_graph(device, queue);
+    }
+
+    /// Updates graph-owned lens correction parameters and reruns the graph from lens correction.
+    pub(in crate::renderer) fn update_lens_correction(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        calibration: Option<&LensCalibration>,
+        settings: &LensCorrection,
+    ) {
+        let parameters = LensCorrectionParameters::from_lens_correction(calibration, settings);
+
+        self.lens_correction_parameters_buffer
+            .update(queue, parameters);
+        self.run_from_lens_correction(device, queue);
+    }
+
+    /// Updates retouch spots and reruns the graph from retouching.
+    pub(in crate::renderer) fn update_retouch_spots(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        retouch_spots: &[RetouchSpot],
+    ) {
+        self.retouch_parameters_buffer
+            .update(queue, RetouchParameters::from_retouch_spots(retouch_spots));
+        self.run_from_retouch(device, queue);
+    }
+
+    /// Updates graph-owned adjustment parameters and the tone curve lookup, then
+    /// reruns the graph from adjustments.
     pub(in crate::renderer) fn update_adjustments(
         &mut self,
         device: &wgpu::Device,
         queue: &wgpu::Queue,
-        exposure_ev: f32,
+        recipe: &EditRecipe,
     ) {
-        let parameters = AdjustmentParameters::from_exposure_ev(exposure_ev);
+        let parameters = AdjustmentParameters::from_recipe(recipe);
 
+        self.tone_curve_lut.update(queue, &recipe.tone_curve);
         self.adjustment_parameters_buffer.update(queue, parameters);
         self.run_from_adjustments(device, queue);
     }
 
+    /// Updates local adjustments and reruns the graph from the first stage they affect.
+    ///
+    /// Masks are re-rasterized only when a mask shape, range, or enabled state changed;
+    /// delta-only edits rerun from the local adjustment stage.
+    pub(in crate::renderer) fn update_local_adjustments(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        local_adjustments: &[LocalAdjustment],
+    ) {
+        let masks_changed = self.local_adjustments.len() != local_adjustments.len()
+            || self
+                .local_adjustments
+                .iter()
+                .zip(local_adjustments)
+                .any(|(current, next)| {
+                    current.enabled != next.enabled
+                        || current.mask != next.mask
+                        || current.range != next.range
+                });
+
+        self.local_adjustments = local_adjustments.to_vec();
+        self.local_adjustment_parameters_buffer.update(
+            queue,
+            LocalAdjustmentParameters::from_local_adjustments(
+                &self.local_adjustments,
+                self.local_mask_overlay,
+            ),
+        );
+
+        if masks_changed {
+            self.pack_masks(device, queue);
+            self.run_masks(device, queue);
+        }
+
+        self.run_from_local_adjustments(device, queue);
+    }
+
+    /// Tints one local adjustment mask over the unadjusted local adjustment
+    /// input, or restores the adjusted image when `mask_overlay` is `None`.
+    pub(in crate::renderer) fn set_local_mask_overlay(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        mask_overlay: Option<usize>,
+    ) {
+        self.local_mask_overlay = mask_overlay;
+        self.local_adjustment_parameters_buffer.update(
+            queue,
+            LocalAdjustmentParameters::from_local_adjustments(
+                &self.local_adjustments,
+                self.local_mask_overlay,
+            ),
+        );
+
+        self.run_from_local_adjustments(device, queue);
+    }
+
     /// Updates graph-owned output parameters and reruns the output transform stage.
     pub(in crate::renderer) fn update_output_transform(
         &mut self,
@@ -115,9 +418,90 @@ impl ImageProcessingGraph {
         queue: &wgpu::Queue,
         display_intent: u32,
         base_exposure_ev: f32,
+        display_headroom: f32,
+    ) {
+        let parameters = self
+            .output_transform_parameters_buffer
+            .parameters()
+            .with_output_transform(display_intent, base_exposure_ev, display_headroom);
+
+        self.output_transform_parameters_buffer
+            .update(queue, parameters);
+        self.run_output_transform(device, queue);
+    }
+
+    /// Updates the graph-owned tone mapper and reruns the output transform stage.
+    pub(in crate::renderer) fn update_tone_mapping(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        tone_mapping: &ToneMapping,
+    ) {
+        let parameters = self
+            .output_transform_parameters_buffer
+            .parameters()
+            .with_tone_mapping(tone_mapping);
+
+        self.output_transform_parameters_buffer
+            .update(queue, parameters);
+        self.run_output_transform(device, queue);
+    }
+
+    /// Switches the output transform between the rendered image and the spot
+    /// visualization debug view, then reruns the output transform stage.
+    pub(in crate::renderer) fn set_spot_visualization(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        enabled: bool,
+    ) {
+        let parameters = self
+            .output_transform_parameters_buffer
+            .parameters()
+            .with_spot_visualization(enabled);
+
+        self.output_transform_parameters_buffer
+            .update(queue, parameters);
+        self.run_output_transform(device, queue);
+    }
+
+    /// Replaces or clears the look lookup texture without rerunning the graph.
+    ///
+    /// Call [`Self::update_look`] afterwards so the output transform picks up
+    /// the new lattice size and domain.
+    pub(in crate::renderer) fn update_look_lut(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        lut: Option<&CubeLut>,
+    ) {
+        let Some(lut) = lut else {
+            self.look_lut.clear();
+            return;
+        };
+
+        if self.look_lut.upload(device, queue, lut) {
+            self.output_transform_stage.rebind(
+                device,
+                self.geometry_output_texture.view(),
+                self.output_texture.view(),
+                self.output_transform_parameters_buffer.as_entire_binding(),
+                self.look_lut.view(),
+            );
+        }
+    }
+
+    /// Updates graph-owned look parameters and reruns the output transform stage.
+    pub(in crate::renderer) fn update_look(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        look: &Look,
     ) {
-        let parameters =
-            OutputTransformParameters::from_output_transform(display_intent, base_exposure_ev);
+        let parameters = self
+            .output_transform_parameters_buffer
+            .parameters()
+            .with_look(look, &self.look_lut);
 
         self.output_transform_parameters_buffer
             .update(queue, parameters);
@@ -156,6 +540,147 @@ impl ImageProcessingGraph {
         )
     }
 
+    /// Reads the full-resolution display output texture back as linear RGBA texels.
+    pub(in crate::renderer) fn read_display_output(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) -> Result<Vec<f32>> {
+        read_display_texture_as_rgba_f32(
+            device,
+            queue,
+            self.output_texture.texture(),
+            self.output_texture.width(),
+            self.output_texture.height(),
+        )
+    }
+
+    /// Returns the mean working-space color of a square development-output patch.
+    ///
+    /// The patch is centered on `(x, y)` in development-output pixels and is
+    /// clamped to the texture bounds.
+    pub(in crate::renderer) fn sample_development_output(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        x: u32,
+        y: u32,
+        radius: u32,
+    ) -> Result<[f32; 3]> {
+        sample_texture_patch(
+            device,
+            queue,
+            &self.development_output_texture,
+            "development output",
+            x,
+            y,
+            radius,
+        )
+    }
+
+    /// Returns the mean working-space color of a square adjustment-output patch.
+    ///
+    /// The patch is centered on `(x, y)` in adjustment-output pixels and is
+    /// clamped to the texture bounds.
+    pub(in crate::renderer) fn sample_adjustment_output(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        x: u32,
+        y: u32,
+        radius: u32,
+    ) -> Result<[f32; 3]> {
+        sample_texture_patch(
+            device,
+            queue,
+            &self.adjustment_output_texture,
+            "adjustment output",
+            x,
+            y,
+            radius,
+        )
+    }
+
+    /// Returns the mean working-space color of a square retouch-output patch
+    /// centered on a normalized source position.
+    ///
+    /// This is the image the mask stage measures range refinements on.
+    pub(in crate::renderer) fn sample_retouch_output(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        x: f32,
+        y: f32,
+        radius: u32,
+    ) -> Result<[f32; 3]> {
+        let width = self.retouch_output_texture.width();
+        let height = self.retouch_output_texture.height();
+
+        sample_texture_patch(
+            device,
+            queue,
+            &self.retouch_output_texture,
+            "retouch output",
+            ((x * width as f32) as u32).min(width.saturating_sub(1)),
+            ((y * height as f32) as u32).min(height.saturating_sub(1)),
+            radius,
+        )
+    }
+
+    /// Searches around a spot destination for the best-matching source.
+    ///
+    /// The unretouched lens-corrected image is box-filtered on the GPU and
+    /// read back at a reduced size, so the search cost does not grow with the
+    /// spot radius. Returns `None` when no candidate fits inside the image.
+    pub(in crate::renderer) fn find_spot_source(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        destination: MaskPoint,
+        radius: f32,
+    ) -> Result<Option<MaskPoint>> {
+        let image_width = self.lens_correction_output_texture.width();
+        let image_height = self.lens_correction_output_texture.height();
+        let Some(region) = SpotSearchRegion::around(destination, radius, image_width, image_height)
+        else {
+            return Ok(None);
+        };
+
+        let downsampled_texture = match downsample_image_texture_region(
+            device,
+            queue,
+            self.lens_correction_output_texture.texture(),
+            (region.left, region.top),
+            region.width,
+            region.height,
+            region.sample_width,
+            region.sample_height,
+        ) {
+            Ok(texture) => texture,
+            Err(error) => return Err(error),
+        };
+        let texels = match read_image_texture_region_rgba_f32(
+            device,
+            queue,
+            &downsampled_texture,
+            (0, 0),
+            region.sample_width,
+            region.sample_height,
+        ) {
+            Ok(texels) => texels,
+            Err(error) => return Err(error),
+        };
+
+        Ok(find_spot_source(
+            &texels,
+            &region,
+            destination,
+            radius,
+            image_width,
+            image_height,
+        ))
+    }
+
     /// Returns graph texture state for the Inspector.
     pub(in crate::renderer) fn texture_inspection(
         &self,
@@ -166,7 +691,16 @@ impl ImageProcessingGraph {
         TextureInspection {
             source: texture_resource_inspection(&self.source_texture),
             development_output: texture_resource_inspection(&self.development_output_texture),
+            lens_correction_output: texture_resource_inspection(
+                &self.lens_correction_output_texture,
+            ),
+            retouch_output: texture_resource_inspection(&self.retouch_output_texture),
             adjustment_output: texture_resource_inspection(&self.adjustment_output_texture),
+            mask: texture_resource_inspection(&self.mask_texture),
+            local_adjustment_output: texture_resource_inspection(
+                &self.local_adjustment_output_texture,
+            ),
+            geometry_output: texture_resource_inspection(&self.geometry_output_texture),
             display_output: texture_resource_inspection(&self.output_texture),
             surface: TextureResourceInspection::new(
                 "Window Surface",
@@ -193,18 +727,109 @@ impl ImageProcessingGraph {
             self.development_output_texture.width(),
             self.development_output_texture.height(),
         );
-        self.adjustment_stage.rebind(
+        self.lens_correction_stage.rebind(
             device,
             self.development_output_texture.view(),
+            self.lens_correction_output_texture.view(),
+            self.lens_correction_parameters_buffer.as_entire_binding(),
+        );
+        self.retouch_stage.rebind(
+            device,
+            self.lens_correction_output_texture.view(),
+            self.retouch_output_texture.view(),
+            self.retouch_parameters_buffer.as_entire_binding(),
+        );
+        self.adjustment_stage.rebind(
+            device,
+            self.retouch_output_texture.view(),
             self.adjustment_output_texture.view(),
             self.adjustment_parameters_buffer.as_entire_binding(),
+            self.tone_curve_lut.view(),
         );
-        self.output_transform_stage.rebind(
+        self.rebind_mask_stage(device);
+        self.local_adjustment_stage.rebind(
             device,
             self.adjustment_output_texture.view(),
+            self.local_adjustment_output_texture.view(),
+            self.local_adjustment_parameters_buffer.as_entire_binding(),
+            self.mask_texture.view(),
+        );
+        self.rebind_output_stages(device);
+    }
+
+    fn rebind_mask_stage(&mut self, device: &wgpu::Device) {
+        self.mask_stage.rebind(
+            device,
+            self.retouch_output_texture.view(),
+            self.mask_texture.view(),
+            self.mask_parameters_buffer.as_entire_binding(),
+            self.brush_dabs.view(),
+        );
+    }
+
+    /// Packs recorded masks against the current source size and uploads their
+    /// parameters and brush dabs.
+    fn pack_masks(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        let brush_dabs = pack_brush_dabs(
+            &self.local_adjustments,
+            self.mask_texture.width(),
+            self.mask_texture.height(),
+        );
+
+        self.mask_parameters_buffer.update(
+            queue,
+            MaskParameters::from_local_adjustments(&self.local_adjustments, &brush_dabs),
+        );
+
+        if self.brush_dabs.upload(device, queue, &brush_dabs.texels) {
+            self.rebind_mask_stage(device);
+        }
+    }
+
+    /// Rebinds the stages that read or write the cropped output textures.
+    fn rebind_output_stages(&mut self, device: &wgpu::Device) {
+        self.geometry_stage.rebind(
+            device,
+            self.local_adjustment_output_texture.view(),
+            self.geometry_output_texture.view(),
+            self.geometry_parameters_buffer.as_entire_binding(),
+        );
+        self.output_transform_stage.rebind(
+            device,
+            self.geometry_output_texture.view(),
             self.output_texture.view(),
             self.output_transform_parameters_buffer.as_entire_binding(),
+            self.look_lut.view(),
+        );
+    }
+
+    /// Resolves the recorded geometry against the adjustment output and
+    /// uploads its parameters, returning whether the cropped textures were resized.
+    fn resolve_geometry(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) -> bool {
+        let resolved_geometry = ResolvedGeometry::resolve(
+            &self.geometry,
+            self.adjustment_output_texture.width(),
+            self.adjustment_output_texture.height(),
+            self.default_crop,
+        );
+        let width = resolved_geometry.output_width();
+        let height = resolved_geometry.output_height();
+        let resized =
+            width != self.output_texture.width() || height != self.output_texture.height();
+
+        if resized {
+            self.geometry_output_texture
+                .resize_empty(device, width, height);
+            self.output_texture.resize_empty(device, width, height);
+        }
+
+        self.resolved_geometry = resolved_geometry;
+        self.geometry_parameters_buffer.update(
+            queue,
+            GeometryParameters::from_resolved_geometry(&resolved_geometry),
         );
+
+        resized
     }
 
     fn run_full_graph(&self, device: &wgpu::Device, queue: &wgpu::Queue) {
@@ -214,9 +839,39 @@ impl ImageProcessingGraph {
             self.development_output_texture.width(),
             self.development_output_texture.height(),
         );
+        self.run_from_lens_correction(device, queue);
+    }
+
+    fn run_from_lens_correction(&self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        self.lens_correction_stage.run(
+            device,
+            queue,
+            self.lens_correction_output_texture.width(),
+            self.lens_correction_output_texture.height(),
+        );
+        self.run_from_retouch(device, queue);
+    }
+
+    fn run_from_retouch(&self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        self.retouch_stage.run(
+            device,
+            queue,
+            self.retouch_output_texture.width(),
+            self.retouch_output_texture.height(),
+        );
+        self.run_masks(device, queue);
         self.run_from_adjustments(device, queue);
     }
 
+    fn run_masks(&self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        self.mask_stage.run(
+            device,
+            queue,
+            self.mask_texture.width(),
+            self.mask_texture.height(),
+        );
+    }
+
     fn run_from_adjustments(&self, device: &wgpu::Device, queue: &wgpu::Queue) {
         self.adjustment_stage.run(
             device,
@@ -224,6 +879,26 @@ impl ImageProcessingGraph {
             self.adjustment_output_texture.width(),
             self.adjustment_output_texture.height(),
         );
+        self.run_from_local_adjustments(device, queue);
+    }
+
+    fn run_from_local_adjustments(&self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        self.local_adjustment_stage.run(
+            device,
+            queue,
+            self.local_adjustment_output_texture.width(),
+            self.local_adjustment_output_texture.height(),
+        );
+        self.run_from_geometry(device, queue);
+    }
+
+    fn run_from_geometry(&self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        self.geometry_stage.run(
+            device,
+            queue,
+            self.geometry_output_texture.width(),
+            self.geometry_output_texture.height(),
+        );
         self.run_output_transform(device, queue);
     }
 
@@ -245,3 +920,60 @@ fn texture_resource_inspection(texture: &ImageTexture) -> TextureResourceInspect
         texture.height(),
     )
 }
+
+/// Averages a square patch of an image texture, clamped to the texture bounds.
+fn sample_texture_patch(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &ImageTexture,
+    name: &str,
+    x: u32,
+    y: u32,
+    radius: u32,
+) -> Result<[f32; 3]> {
+    let width = texture.width();
+    let height = texture.height();
+
+    if x >= width || y >= height {
+        return Err(anyhow!(
+            "sample position {x},{y} is outside the {width}x{height} {name}"
+        ));
+    }
+
+    let left = x.saturating_sub(radius);
+    let top = y.saturating_sub(radius);
+    let right = (x + radius + 1).min(width);
+    let bottom = (y + radius + 1).min(height);
+
+    let texels = match read_image_texture_region_rgba_f32(
+        device,
+        queue,
+        texture.texture(),
+        (left, top),
+        right - left,
+        bottom - top,
+    ) {
+        Ok(texels) => texels,
+        Err(error) => return Err(error),
+    };
+
+    let mut sum = [0.0f64; 3];
+    let mut count = 0usize;
+
+    for texel in texels.chunks_exact(4) {
+        sum[0] += f64::from(texel[0]);
+        sum[1] += f64::from(texel[1]);
+        sum[2] += f64::from(texel[2]);
+        count += 1;
+    }
+
+    if count == 0 {
+        return Err(anyhow!("{name} sample patch is empty"));
+    }
+
+    Ok([
+        (sum[0] / count as f64) as f32,
+        (sum[1] / count as f64) as f32,
+        (sum[2] / count as f64) as f32,
+    ])
+}
@@ -0,0 +1,162 @@
+use crate::core::look::CubeLut;
+
+/// Lattice size of the identity placeholder bound while no look is loaded.
+const IDENTITY_LUT_SIZE: u32 = 2;
+
+/// Graph-owned 3D lookup texture holding the active `.cube` look.
+///
+/// Texel `(r, g, b)` stores the LUT output for lattice input `(r, g, b)`, so
+/// the file's red-fastest ordering maps directly onto texture x, y, and z. The
+/// texture is recreated only when the lattice size changes.
+pub(super) struct LookLut {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    size: u32,
+    domain_min: [f32; 3],
+    domain_max: [f32; 3],
+    loaded: bool,
+}
+
+impl LookLut {
+    /// Creates the lookup texture initialized with an identity lattice.
+    pub(super) fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        let (texture, view) = create_lut_texture(device, IDENTITY_LUT_SIZE);
+
+        upload_lut(
+            queue,
+            &texture,
+            IDENTITY_LUT_SIZE,
+            &pack_texels(&identity_table(IDENTITY_LUT_SIZE)),
+        );
+
+        Self {
+            texture,
+            view,
+            size: IDENTITY_LUT_SIZE,
+            domain_min: [0.0; 3],
+            domain_max: [1.0; 3],
+            loaded: false,
+        }
+    }
+
+    /// Uploads a parsed look, returning whether the texture was recreated and
+    /// bind groups referencing it must be rebuilt.
+    pub(super) fn upload(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        lut: &CubeLut,
+    ) -> bool {
+        let recreated = lut.size != self.size;
+
+        if recreated {
+            let (texture, view) = create_lut_texture(device, lut.size);
+
+            self.texture = texture;
+            self.view = view;
+            self.size = lut.size;
+        }
+
+        upload_lut(queue, &self.texture, self.size, &pack_texels(&lut.table));
+
+        self.domain_min = lut.domain_min;
+        self.domain_max = lut.domain_max;
+        self.loaded = true;
+
+        recreated
+    }
+
+    /// Marks the look as unloaded so the output transform skips it.
+    pub(super) fn clear(&mut self) {
+        self.loaded = false;
+    }
+
+    /// Returns the lookup texture view bound by the output transform stage.
+    pub(super) fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    pub(super) fn is_loaded(&self) -> bool {
+        self.loaded
+    }
+
+    pub(super) fn size(&self) -> u32 {
+        self.size
+    }
+
+    pub(super) fn domain_min(&self) -> [f32; 3] {
+        self.domain_min
+    }
+
+    pub(super) fn domain_max(&self) -> [f32; 3] {
+        self.domain_max
+    }
+}
+
+fn create_lut_texture(device: &wgpu::Device, size: u32) -> (wgpu::Texture, wgpu::TextureView) {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Look LUT Texture"),
+        size: wgpu::Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: size,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D3,
+        format: wgpu::TextureFormat::Rgba32Float,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    (texture, view)
+}
+
+fn identity_table(size: u32) -> Vec<[f32; 3]> {
+    let scale = 1.0 / (size - 1) as f32;
+    let mut table = Vec::with_capacity((size as usize).pow(3));
+
+    for blue in 0..size {
+        for green in 0..size {
+            for red in 0..size {
+                table.push([
+                    red as f32 * scale,
+                    green as f32 * scale,
+                    blue as f32 * scale,
+                ]);
+            }
+        }
+    }
+
+    table
+}
+
+fn pack_texels(table: &[[f32; 3]]) -> Vec<f32> {
+    table
+        .iter()
+        .flat_map(|&[red, green, blue]| [red, green, blue, 1.0])
+        .collect()
+}
+
+fn upload_lut(queue: &wgpu::Queue, texture: &wgpu::Texture, size: u32, texels: &[f32]) {
+    queue.write_texture(
+        wgpu::TexelCopyTextureInfo {
+            texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        bytemuck::cast_slice(texels),
+        wgpu::TexelCopyBufferLayout {
+            offset: 0,
+            bytes_per_row: Some(16 * size),
+            rows_per_image: Some(size),
+        },
+        wgpu::Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: size,
+        },
+    );
+}
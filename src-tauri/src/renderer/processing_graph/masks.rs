@@ -0,0 +1,304 @@
+use crate::core::editing::{BrushStroke, LocalAdjustment, Mask, MAX_LOCAL_ADJUSTMENTS};
+use std::ops::RangeInclusive;
+
+/// Largest number of brush dabs packed for one image.
+const MAX_BRUSH_DABS: usize = 8192;
+
+/// Smallest square tile, in mask pixels, that dabs are binned into.
+const MIN_BRUSH_TILE_SIZE: u32 = 16;
+
+/// Largest number of per-tile dab references packed for one image. Tiles
+/// double in size until the binned dabs fit, so very large brushes fall back
+/// to coarser tiles instead of growing the table without bound.
+const MAX_BRUSH_TILE_ENTRIES: usize = 1 << 22;
+
+/// Width of the brush table texture in texels, each holding four values.
+const BRUSH_TABLE_WIDTH: u32 = 1024;
+
+/// Dab spacing along a stroke as a fraction of the brush radius.
+const DAB_SPACING: f32 = 0.25;
+
+/// Offset added to a dab's feather to mark it as erasing. Mirrors
+/// `ERASE_OFFSET` in `mask.wgsl`.
+const ERASE_OFFSET: f32 = 2.0;
+
+/// Brush dabs for every local adjustment, binned into square tiles of the mask.
+///
+/// `texels` is the brush table uploaded to [`BrushDabs`]. `tile_grids` holds
+/// the table value index of each brush mask's tile grid, and `tile_size` the
+/// tile edge in mask pixels.
+pub(super) struct PackedBrushDabs {
+    pub(super) texels: Vec<[f32; 4]>,
+    pub(super) tile_grids: [u32; MAX_LOCAL_ADJUSTMENTS],
+    pub(super) tile_size: u32,
+}
+
+/// Graph-owned 2D texture holding the brush table read by the mask stage.
+///
+/// The table is a flat list of values, four per texel, laid out row by row
+/// [`BRUSH_TABLE_WIDTH`] texels wide. It starts with one texel per dab: the
+/// normalized source `x`, `y`, the radius as a fraction of the longer source
+/// edge, and the feather, offset by [`ERASE_OFFSET`] for erasing dabs. Each
+/// brush mask then has a row-major tile grid of `(first, count)` value pairs
+/// pointing at lists of dab texel indices, kept in stroke order so erasing
+/// dabs still apply after the dabs they cover. The texture grows to the next
+/// power of two rows when a recipe needs more than it holds.
+pub(super) struct BrushDabs {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    capacity: u32,
+}
+
+impl BrushDabs {
+    /// Creates a one-row placeholder texture.
+    pub(super) fn new(device: &wgpu::Device) -> Self {
+        let (texture, view) = create_dab_texture(device, 1);
+
+        Self {
+            texture,
+            view,
+            capacity: 1,
+        }
+    }
+
+    /// Uploads the packed brush table, returning whether the texture was
+    /// recreated and bind groups referencing it must be rebuilt.
+    pub(super) fn upload(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        texels: &[[f32; 4]],
+    ) -> bool {
+        if texels.is_empty() {
+            return false;
+        }
+
+        let rows = (texels.len() as u32).div_ceil(BRUSH_TABLE_WIDTH);
+        let recreated = rows > self.capacity;
+
+        if recreated {
+            let capacity = rows.next_power_of_two();
+            let (texture, view) = create_dab_texture(device, capacity);
+
+            self.texture = texture;
+            self.view = view;
+            self.capacity = capacity;
+        }
+
+        let mut padded = texels.to_vec();
+        padded.resize((rows * BRUSH_TABLE_WIDTH) as usize, [0.0; 4]);
+
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            bytemuck::cast_slice(&padded),
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(16 * BRUSH_TABLE_WIDTH),
+                rows_per_image: Some(rows),
+            },
+            wgpu::Extent3d {
+                width: BRUSH_TABLE_WIDTH,
+                height: rows,
+                depth_or_array_layers: 1,
+            },
+        );
+
+        recreated
+    }
+
+    /// Returns the brush table view bound by the mask stage.
+    pub(super) fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+}
+
+/// Resamples every enabled brush mask into evenly spaced dabs and bins them
+/// into the tiles of a `mask_width` by `mask_height` mask.
+///
+/// Spacing is measured in mask pixels so strokes stay continuous on
+/// non-square images. Dabs beyond [`MAX_BRUSH_DABS`] are dropped.
+pub(super) fn pack_brush_dabs(
+    local_adjustments: &[LocalAdjustment],
+    mask_width: u32,
+    mask_height: u32,
+) -> PackedBrushDabs {
+    let mut dabs = Vec::new();
+    let mut ranges = [None; MAX_LOCAL_ADJUSTMENTS];
+
+    for (range, adjustment) in ranges.iter_mut().zip(local_adjustments) {
+        let Mask::Brush { strokes } = &adjustment.mask else {
+            continue;
+        };
+
+        if !adjustment.enabled {
+            continue;
+        }
+
+        let first = dabs.len();
+
+        for stroke in strokes {
+            push_stroke_dabs(&mut dabs, stroke, mask_width, mask_height);
+        }
+
+        dabs.truncate(MAX_BRUSH_DABS);
+        *range = Some((first, dabs.len()));
+    }
+
+    let width = mask_width.max(1);
+    let height = mask_height.max(1);
+    let mut tile_size = MIN_BRUSH_TILE_SIZE;
+
+    while tile_size < width.max(height)
+        && dabs
+            .iter()
+            .map(|dab| {
+                let (columns, rows) = dab_tiles(dab, width, height, tile_size);
+
+                (columns.end() - columns.start() + 1) * (rows.end() - rows.start() + 1)
+            })
+            .sum::<usize>()
+            > MAX_BRUSH_TILE_ENTRIES
+    {
+        tile_size *= 2;
+    }
+
+    let tiles_x = width.div_ceil(tile_size) as usize;
+    let tile_count = tiles_x * height.div_ceil(tile_size) as usize;
+    let mut values: Vec<f32> = dabs.iter().flatten().copied().collect();
+    let mut tile_grids = [0; MAX_LOCAL_ADJUSTMENTS];
+
+    for (tile_grid, range) in tile_grids.iter_mut().zip(ranges) {
+        let Some((first_dab, end_dab)) = range else {
+            continue;
+        };
+
+        let mut tile_dabs = vec![Vec::new(); tile_count];
+
+        for index in first_dab..end_dab {
+            let (columns, rows) = dab_tiles(&dabs[index], width, height, tile_size);
+
+            for tile_y in rows {
+                for tile_x in columns.clone() {
+                    tile_dabs[(tile_y * tiles_x) + tile_x].push(index as f32);
+                }
+            }
+        }
+
+        *tile_grid = values.len() as u32;
+
+        let mut first = values.len() + (tile_count * 2);
+
+        for dab_indices in &tile_dabs {
+            values.extend([first as f32, dab_indices.len() as f32]);
+            first += dab_indices.len();
+        }
+
+        values.extend(tile_dabs.into_iter().flatten());
+    }
+
+    let texels = values
+        .chunks(4)
+        .map(|chunk| {
+            let mut texel = [0.0; 4];
+            texel[..chunk.len()].copy_from_slice(chunk);
+            texel
+        })
+        .collect();
+
+    PackedBrushDabs {
+        texels,
+        tile_grids,
+        tile_size,
+    }
+}
+
+/// Returns the column and row ranges of the tiles a dab's bounding box touches.
+fn dab_tiles(
+    dab: &[f32; 4],
+    width: u32,
+    height: u32,
+    tile_size: u32,
+) -> (RangeInclusive<usize>, RangeInclusive<usize>) {
+    let radius = dab[2] * width.max(height) as f32;
+    let tile_range = |center: f32, edge: u32| {
+        let last = (edge.div_ceil(tile_size) - 1) as f32;
+        let start = ((center - radius) / tile_size as f32)
+            .floor()
+            .clamp(0.0, last);
+        let end = ((center + radius) / tile_size as f32)
+            .floor()
+            .clamp(0.0, last);
+
+        start as usize..=end as usize
+    };
+
+    (
+        tile_range(dab[0] * width as f32, width),
+        tile_range(dab[1] * height as f32, height),
+    )
+}
+
+fn push_stroke_dabs(
+    dabs: &mut Vec<[f32; 4]>,
+    stroke: &BrushStroke,
+    source_width: u32,
+    source_height: u32,
+) {
+    let width = source_width.max(1) as f32;
+    let height = source_height.max(1) as f32;
+    let radius = stroke.radius.max(0.0);
+    let feather = stroke.feather.clamp(0.0, 1.0);
+    let encoded_feather = if stroke.erase {
+        feather + ERASE_OFFSET
+    } else {
+        feather
+    };
+    let spacing = (radius * width.max(height) * DAB_SPACING).max(1.0);
+    let mut dab = |x: f32, y: f32| dabs.push([x, y, radius, encoded_feather]);
+
+    let Some(first) = stroke.points.first() else {
+        return;
+    };
+
+    dab(first.x, first.y);
+
+    for pair in stroke.points.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+        let distance = ((end.x - start.x) * width).hypot((end.y - start.y) * height);
+        let steps = (distance / spacing).ceil().max(1.0) as u32;
+
+        for step in 1..=steps {
+            let t = step as f32 / steps as f32;
+
+            dab(
+                start.x + ((end.x - start.x) * t),
+                start.y + ((end.y - start.y) * t),
+            );
+        }
+    }
+}
+
+fn create_dab_texture(device: &wgpu::Device, capacity: u32) -> (wgpu::Texture, wgpu::TextureView) {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Brush Dab Texture"),
+        size: wgpu::Extent3d {
+            width: BRUSH_TABLE_WIDTH,
+            height: capacity,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba32Float,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    (texture, view)
+}
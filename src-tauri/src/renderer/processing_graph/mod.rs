@@ -1,6 +1,15 @@
+mod color_mixer;
+mod geometry;
 mod graph;
+mod look;
+mod masks;
 mod parameters;
+mod retouch;
 mod stages;
+mod tone_curve;
 
+pub(super) use color_mixer::{dominant_color_band, oklch_hue_chroma};
 pub(super) use graph::ImageProcessingGraph;
-pub(super) use parameters::DevelopmentParameters;
+pub(super) use parameters::{
+    DevelopmentParameters, RawCaptureSharpening, RawLensWarp, RawNoiseReduction,
+};
@@ -1,3 +1,14 @@
+use super::color_mixer::{color_band_hues_uniform, is_neutral_color_mixer, pack_color_bands};
+use super::geometry::ResolvedGeometry;
+use super::look::LookLut;
+use super::masks::PackedBrushDabs;
+use super::tone_curve::is_neutral_tone_curve;
+use crate::core::editing::{
+    CaptureSharpening, EditRecipe, HighlightReconstruction, LensCorrection, LocalAdjustment, Look,
+    LookInterpolation, LookPlacement, Mask, NoiseReduction, RetouchMode, RetouchSpot, ToneMapper,
+    ToneMapping, MAX_LOCAL_ADJUSTMENTS, MAX_RETOUCH_SPOTS,
+};
+use crate::core::lens::LensCalibration;
 use bytemuck::{Pod, Zeroable};
 use wgpu::util::DeviceExt;
 
@@ -6,11 +17,15 @@ use wgpu::util::DeviceExt;
 pub(in crate::renderer) enum DevelopmentParameters {
     Raster(RasterDevelopmentParameters),
     RawBayer(RawBayerDevelopmentParameters),
+    RawXTrans(RawXTransDevelopmentParameters),
+    RawLinear(RawLinearDevelopmentParameters),
 }
 
 /// Development parameters for raster sRGB source input.
 #[derive(Debug, Copy, Clone)]
-pub(in crate::renderer) struct RasterDevelopmentParameters;
+pub(in crate::renderer) struct RasterDevelopmentParameters {
+    white_balance: [f32; 3],
+}
 
 /// Development parameters for one-plane 2x2 Bayer RAW source input.
 #[derive(Debug, Copy, Clone)]
@@ -20,35 +35,362 @@ pub(in crate::renderer) struct RawBayerDevelopmentParameters {
     white_levels: [f32; 4],
     white_balance: [f32; 3],
     camera_to_working: [[f32; 3]; 3],
+    highlight_reconstruction: HighlightReconstruction,
+    noise_reduction: RawNoiseReduction,
+    capture_sharpening: RawCaptureSharpening,
+    lens_warp: Option<RawLensWarp>,
+}
+
+/// Development parameters for one-plane 6x6 X-Trans RAW source input.
+#[derive(Debug, Copy, Clone)]
+pub(in crate::renderer) struct RawXTransDevelopmentParameters {
+    cfa_pattern: [u32; 36],
+    black_levels: [f32; 4],
+    white_levels: [f32; 4],
+    white_balance: [f32; 3],
+    camera_to_working: [[f32; 3]; 3],
+    noise_reduction: RawNoiseReduction,
+    lens_warp: Option<RawLensWarp>,
+}
+
+/// Development parameters for already-demosaiced linear RGB RAW source input.
+#[derive(Debug, Copy, Clone)]
+pub(in crate::renderer) struct RawLinearDevelopmentParameters {
+    black_levels: [f32; 4],
+    white_levels: [f32; 4],
+    white_balance: [f32; 3],
+    camera_to_working: [[f32; 3]; 3],
+    noise_reduction: RawNoiseReduction,
+    lens_warp: Option<RawLensWarp>,
+}
+
+/// Rectilinear lens warp applied to developed RAW working-space data.
+///
+/// Coefficients follow the DNG WarpRectilinear model, one set per RGB plane.
+/// The center is in upload pixel coordinates and `radius` is the distance
+/// that normalizes pixel offsets to the model's unit range.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(in crate::renderer) struct RawLensWarp {
+    pub(in crate::renderer) radial: [[f32; 4]; 3],
+    pub(in crate::renderer) tangential: [[f32; 2]; 3],
+    pub(in crate::renderer) center: [f32; 2],
+    pub(in crate::renderer) radius: f32,
+}
+
+/// ISO at and below which no luminance noise reduction is applied by default.
+const NOISE_REDUCTION_BASE_ISO: f32 = 100.0;
+
+/// Default luminance strength added per stop above the base ISO.
+const LUMINANCE_NOISE_REDUCTION_PER_STOP: f32 = 0.1;
+
+/// Default luminance strength ceiling at very high ISO.
+const LUMINANCE_NOISE_REDUCTION_MAX_DEFAULT: f32 = 0.7;
+
+/// Default chroma strength at the base ISO.
+const CHROMA_NOISE_REDUCTION_BASE: f32 = 0.25;
+
+/// Default chroma strength added per stop above the base ISO.
+const CHROMA_NOISE_REDUCTION_PER_STOP: f32 = 0.08;
+
+/// Default chroma strength ceiling at very high ISO.
+const CHROMA_NOISE_REDUCTION_MAX_DEFAULT: f32 = 0.8;
+
+/// Resolved RAW noise reduction strengths, each in `0.0..=1.0`.
+///
+/// A zero amount disables the matching pass. Detail controls how much of the
+/// filtered-out signal is kept.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub(in crate::renderer) struct RawNoiseReduction {
+    pub(in crate::renderer) luminance: f32,
+    pub(in crate::renderer) luminance_detail: f32,
+    pub(in crate::renderer) chroma: f32,
+    pub(in crate::renderer) chroma_detail: f32,
+}
+
+impl RawNoiseReduction {
+    /// Resolves recipe offsets against ISO-derived default strengths.
+    ///
+    /// Defaults grow with each stop above ISO 100; a missing ISO is treated as
+    /// base ISO, which keeps luminance off and applies light chroma smoothing.
+    pub(in crate::renderer) fn from_recipe(settings: &NoiseReduction, iso: Option<u32>) -> Self {
+        let stops = iso
+            .map(|iso| (iso as f32 / NOISE_REDUCTION_BASE_ISO).max(1.0).log2())
+            .unwrap_or(0.0);
+        let luminance_default =
+            (stops * LUMINANCE_NOISE_REDUCTION_PER_STOP).min(LUMINANCE_NOISE_REDUCTION_MAX_DEFAULT);
+        let chroma_default = (CHROMA_NOISE_REDUCTION_BASE
+            + stops * CHROMA_NOISE_REDUCTION_PER_STOP)
+            .min(CHROMA_NOISE_REDUCTION_MAX_DEFAULT);
+
+        Self {
+            luminance: (luminance_default + settings.luminance.clamp(-1.0, 1.0)).clamp(0.0, 1.0),
+            luminance_detail: detail_from_offset(settings.luminance_detail),
+            chroma: (chroma_default + settings.chroma.clamp(-1.0, 1.0)).clamp(0.0, 1.0),
+            chroma_detail: detail_from_offset(settings.chroma_detail),
+        }
+    }
+}
+
+/// Resolved Bayer capture sharpening settings.
+///
+/// Mask preview replaces the developed image with the sharpening mask so the
+/// Inspector can show which detail the threshold lets through.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub(in crate::renderer) struct RawCaptureSharpening {
+    pub(in crate::renderer) amount: f32,
+    pub(in crate::renderer) radius: f32,
+    pub(in crate::renderer) threshold: f32,
+    pub(in crate::renderer) mask_preview: bool,
+}
+
+impl RawCaptureSharpening {
+    /// Clamps recipe sharpening controls to their supported ranges.
+    pub(in crate::renderer) fn from_recipe(
+        settings: &CaptureSharpening,
+        mask_preview: bool,
+    ) -> Self {
+        Self {
+            amount: settings.amount.clamp(0.0, 1.0),
+            radius: settings.radius.clamp(0.5, 2.0),
+            threshold: settings.threshold.clamp(0.0, 1.0),
+            mask_preview,
+        }
+    }
+}
+
+/// Maps a recipe detail offset around the balanced default to `0.0..=1.0`.
+fn detail_from_offset(offset: f32) -> f32 {
+    0.5 + 0.5 * offset.clamp(-1.0, 1.0)
 }
 
 impl DevelopmentParameters {
     /// Packs neutral development parameters for raster sRGB source input.
     pub(in crate::renderer) fn from_raster_srgb() -> Self {
-        Self::Raster(RasterDevelopmentParameters)
+        Self::Raster(RasterDevelopmentParameters {
+            white_balance: [1.0, 1.0, 1.0],
+        })
     }
 
     /// Packs source-development parameters for a one-plane 2x2 Bayer RAW source.
+    ///
+    /// White balance and camera-to-working matrix start neutral; they are
+    /// replaced once a recipe white balance is resolved against the camera profile.
     pub(in crate::renderer) fn from_raw_bayer_2x2(
         cfa_pattern: [u32; 4],
         black_levels: [f32; 4],
         white_levels: [f32; 4],
-        white_balance: [f32; 3],
-        camera_to_working: [[f32; 3]; 3],
     ) -> Self {
         Self::RawBayer(RawBayerDevelopmentParameters {
             cfa_pattern,
             black_levels,
             white_levels,
-            white_balance,
-            camera_to_working,
+            white_balance: [1.0, 1.0, 1.0],
+            camera_to_working: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            highlight_reconstruction: HighlightReconstruction::default(),
+            noise_reduction: RawNoiseReduction::default(),
+            capture_sharpening: RawCaptureSharpening::default(),
+            lens_warp: None,
+        })
+    }
+
+    /// Packs source-development parameters for a one-plane 6x6 X-Trans RAW source.
+    ///
+    /// Levels stay in 2x2 slot order as reported by the decoder; white balance
+    /// and camera matrix start neutral like the Bayer path.
+    pub(in crate::renderer) fn from_raw_xtrans_6x6(
+        cfa_pattern: [u32; 36],
+        black_levels: [f32; 4],
+        white_levels: [f32; 4],
+    ) -> Self {
+        Self::RawXTrans(RawXTransDevelopmentParameters {
+            cfa_pattern,
+            black_levels,
+            white_levels,
+            white_balance: [1.0, 1.0, 1.0],
+            camera_to_working: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            noise_reduction: RawNoiseReduction::default(),
+            lens_warp: None,
+        })
+    }
+
+    /// Packs source-development parameters for a linear RGB RAW source.
+    ///
+    /// Linear sources skip demosaic but keep sensor levels, white balance, and
+    /// the camera matrix, which start neutral like the CFA paths.
+    pub(in crate::renderer) fn from_raw_linear_rgb(
+        black_levels: [f32; 4],
+        white_levels: [f32; 4],
+    ) -> Self {
+        Self::RawLinear(RawLinearDevelopmentParameters {
+            black_levels,
+            white_levels,
+            white_balance: [1.0, 1.0, 1.0],
+            camera_to_working: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            noise_reduction: RawNoiseReduction::default(),
+            lens_warp: None,
         })
     }
 
+    /// Returns these parameters with development white-balance multipliers replaced.
+    pub(in crate::renderer) fn with_white_balance(self, white_balance: [f32; 3]) -> Self {
+        match self {
+            Self::Raster(_) => Self::Raster(RasterDevelopmentParameters { white_balance }),
+            Self::RawBayer(parameters) => Self::RawBayer(RawBayerDevelopmentParameters {
+                white_balance,
+                ..parameters
+            }),
+            Self::RawXTrans(parameters) => Self::RawXTrans(RawXTransDevelopmentParameters {
+                white_balance,
+                ..parameters
+            }),
+            Self::RawLinear(parameters) => Self::RawLinear(RawLinearDevelopmentParameters {
+                white_balance,
+                ..parameters
+            }),
+        }
+    }
+
+    /// Returns these parameters with the camera-to-working matrix replaced.
+    ///
+    /// Raster input has a fixed working-space transform, so this only affects RAW parameters.
+    pub(in crate::renderer) fn with_camera_to_working(
+        self,
+        camera_to_working: [[f32; 3]; 3],
+    ) -> Self {
+        match self {
+            Self::Raster(parameters) => Self::Raster(parameters),
+            Self::RawBayer(parameters) => Self::RawBayer(RawBayerDevelopmentParameters {
+                camera_to_working,
+                ..parameters
+            }),
+            Self::RawXTrans(parameters) => Self::RawXTrans(RawXTransDevelopmentParameters {
+                camera_to_working,
+                ..parameters
+            }),
+            Self::RawLinear(parameters) => Self::RawLinear(RawLinearDevelopmentParameters {
+                camera_to_working,
+                ..parameters
+            }),
+        }
+    }
+
+    /// Returns these parameters with the RAW highlight reconstruction mode replaced.
+    ///
+    /// Raster input has no sensor clip information and the X-Trans and linear
+    /// paths have no reconstruction stage, so this only affects Bayer RAW parameters.
+    pub(in crate::renderer) fn with_highlight_reconstruction(
+        self,
+        highlight_reconstruction: HighlightReconstruction,
+    ) -> Self {
+        match self {
+            Self::Raster(parameters) => Self::Raster(parameters),
+            Self::RawXTrans(parameters) => Self::RawXTrans(parameters),
+            Self::RawLinear(parameters) => Self::RawLinear(parameters),
+            Self::RawBayer(parameters) => Self::RawBayer(RawBayerDevelopmentParameters {
+                highlight_reconstruction,
+                ..parameters
+            }),
+        }
+    }
+
+    /// Returns these parameters with the capture sharpening settings replaced.
+    ///
+    /// Only the Bayer path has a capture sharpening stage, so this only affects
+    /// Bayer RAW parameters.
+    pub(in crate::renderer) fn with_capture_sharpening(
+        self,
+        capture_sharpening: RawCaptureSharpening,
+    ) -> Self {
+        match self {
+            Self::Raster(parameters) => Self::Raster(parameters),
+            Self::RawXTrans(parameters) => Self::RawXTrans(parameters),
+            Self::RawLinear(parameters) => Self::RawLinear(parameters),
+            Self::RawBayer(parameters) => Self::RawBayer(RawBayerDevelopmentParameters {
+                capture_sharpening,
+                ..parameters
+            }),
+        }
+    }
+
+    /// Returns these parameters with the RAW noise reduction strengths replaced.
+    ///
+    /// Raster input is already processed by its encoder, so this only affects RAW parameters.
+    pub(in crate::renderer) fn with_noise_reduction(
+        self,
+        noise_reduction: RawNoiseReduction,
+    ) -> Self {
+        match self {
+            Self::Raster(parameters) => Self::Raster(parameters),
+            Self::RawBayer(parameters) => Self::RawBayer(RawBayerDevelopmentParameters {
+                noise_reduction,
+                ..parameters
+            }),
+            Self::RawXTrans(parameters) => Self::RawXTrans(RawXTransDevelopmentParameters {
+                noise_reduction,
+                ..parameters
+            }),
+            Self::RawLinear(parameters) => Self::RawLinear(RawLinearDevelopmentParameters {
+                noise_reduction,
+                ..parameters
+            }),
+        }
+    }
+
+    /// Returns these parameters with the RAW lens warp replaced.
+    ///
+    /// Raster input has no geometry correction stage, so this only affects RAW parameters.
+    pub(in crate::renderer) fn with_lens_warp(self, lens_warp: Option<RawLensWarp>) -> Self {
+        match self {
+            Self::Raster(parameters) => Self::Raster(parameters),
+            Self::RawBayer(parameters) => Self::RawBayer(RawBayerDevelopmentParameters {
+                lens_warp,
+                ..parameters
+            }),
+            Self::RawXTrans(parameters) => Self::RawXTrans(RawXTransDevelopmentParameters {
+                lens_warp,
+                ..parameters
+            }),
+            Self::RawLinear(parameters) => Self::RawLinear(RawLinearDevelopmentParameters {
+                lens_warp,
+                ..parameters
+            }),
+        }
+    }
+
+    /// Returns the RAW highlight reconstruction mode, or `None` when the source
+    /// has no reconstruction stage.
+    pub(in crate::renderer) fn highlight_reconstruction(&self) -> Option<HighlightReconstruction> {
+        match self {
+            Self::Raster(_) | Self::RawXTrans(_) | Self::RawLinear(_) => None,
+            Self::RawBayer(parameters) => Some(parameters.highlight_reconstruction),
+        }
+    }
+
+    /// Returns the capture sharpening settings, or `None` when the source has
+    /// no sharpening stage.
+    pub(in crate::renderer) fn capture_sharpening(&self) -> Option<RawCaptureSharpening> {
+        match self {
+            Self::Raster(_) | Self::RawXTrans(_) | Self::RawLinear(_) => None,
+            Self::RawBayer(parameters) => Some(parameters.capture_sharpening),
+        }
+    }
+
+    /// Returns the RAW noise reduction strengths, or `None` for raster sources.
+    pub(in crate::renderer) fn noise_reduction(&self) -> Option<RawNoiseReduction> {
+        match self {
+            Self::Raster(_) => None,
+            Self::RawBayer(parameters) => Some(parameters.noise_reduction),
+            Self::RawXTrans(parameters) => Some(parameters.noise_reduction),
+            Self::RawLinear(parameters) => Some(parameters.noise_reduction),
+        }
+    }
+
     fn to_uniform_block(self) -> DevelopmentUniformBlock {
         match self {
-            Self::Raster(_) => DevelopmentUniformBlock::raster_srgb(),
+            Self::Raster(parameters) => DevelopmentUniformBlock::raster_srgb(parameters),
             Self::RawBayer(parameters) => DevelopmentUniformBlock::raw_bayer(parameters),
+            Self::RawXTrans(parameters) => DevelopmentUniformBlock::raw_xtrans(parameters),
+            Self::RawLinear(parameters) => DevelopmentUniformBlock::raw_linear(parameters),
         }
     }
 }
@@ -74,18 +416,43 @@ struct DevelopmentUniformBlock {
     camera_to_working_red: [f32; 4],
     camera_to_working_green: [f32; 4],
     camera_to_working_blue: [f32; 4],
+    raw_options: [u32; 4],
+    /// 6x6 X-Trans CFA colors in row-major order, packed four per row.
+    cfa_pattern_6x6: [[u32; 4]; 9],
+    /// Radial warp coefficients per RGB plane.
+    lens_warp_radial: [[f32; 4]; 3],
+    /// Tangential warp coefficients per RGB plane in `xy`.
+    lens_warp_tangential: [[f32; 4]; 3],
+    /// `xy`: warp center in pixels, `z`: normalization radius, `w`: enabled flag.
+    lens_warp_center: [f32; 4],
+    /// `x`: luminance amount, `y`: luminance detail, `z`: chroma amount, `w`: chroma detail.
+    noise_reduction: [f32; 4],
+    /// `x`: amount, `y`: radius in pixels, `z`: threshold, `w`: mask preview flag.
+    capture_sharpening: [f32; 4],
 }
 
 impl DevelopmentUniformBlock {
-    fn raster_srgb() -> Self {
+    fn raster_srgb(parameters: RasterDevelopmentParameters) -> Self {
         Self {
             cfa_pattern: [0, 0, 0, 0],
             black_levels: [0.0, 0.0, 0.0, 0.0],
             white_levels: [1.0, 1.0, 1.0, 1.0],
-            white_balance: [1.0, 1.0, 1.0, 0.0],
+            white_balance: [
+                parameters.white_balance[0],
+                parameters.white_balance[1],
+                parameters.white_balance[2],
+                0.0,
+            ],
             camera_to_working_red: [1.0, 0.0, 0.0, 0.0],
             camera_to_working_green: [0.0, 1.0, 0.0, 0.0],
             camera_to_working_blue: [0.0, 0.0, 1.0, 0.0],
+            raw_options: [0, 0, 0, 0],
+            cfa_pattern_6x6: [[0; 4]; 9],
+            lens_warp_radial: [[0.0; 4]; 3],
+            lens_warp_tangential: [[0.0; 4]; 3],
+            lens_warp_center: [0.0; 4],
+            noise_reduction: [0.0; 4],
+            capture_sharpening: [0.0; 4],
         }
     }
 
@@ -118,13 +485,167 @@ impl DevelopmentUniformBlock {
                 parameters.camera_to_working[2][2],
                 0.0,
             ],
+            raw_options: [
+                highlight_reconstruction_mode(parameters.highlight_reconstruction),
+                0,
+                0,
+                0,
+            ],
+            cfa_pattern_6x6: [[0; 4]; 9],
+            lens_warp_radial: [[0.0; 4]; 3],
+            lens_warp_tangential: [[0.0; 4]; 3],
+            lens_warp_center: [0.0; 4],
+            noise_reduction: noise_reduction_uniform(parameters.noise_reduction),
+            capture_sharpening: capture_sharpening_uniform(parameters.capture_sharpening),
         }
+        .with_lens_warp(parameters.lens_warp)
+    }
+
+    fn raw_xtrans(parameters: RawXTransDevelopmentParameters) -> Self {
+        let mut cfa_pattern_6x6 = [[0; 4]; 9];
+
+        for (index, color) in parameters.cfa_pattern.iter().enumerate() {
+            cfa_pattern_6x6[index / 4][index % 4] = *color;
+        }
+
+        Self {
+            cfa_pattern: [0, 0, 0, 0],
+            black_levels: parameters.black_levels,
+            white_levels: parameters.white_levels,
+            white_balance: [
+                parameters.white_balance[0],
+                parameters.white_balance[1],
+                parameters.white_balance[2],
+                0.0,
+            ],
+            camera_to_working_red: [
+                parameters.camera_to_working[0][0],
+                parameters.camera_to_working[0][1],
+                parameters.camera_to_working[0][2],
+                0.0,
+            ],
+            camera_to_working_green: [
+                parameters.camera_to_working[1][0],
+                parameters.camera_to_working[1][1],
+                parameters.camera_to_working[1][2],
+                0.0,
+            ],
+            camera_to_working_blue: [
+                parameters.camera_to_working[2][0],
+                parameters.camera_to_working[2][1],
+                parameters.camera_to_working[2][2],
+                0.0,
+            ],
+            raw_options: [0, 0, 0, 0],
+            cfa_pattern_6x6,
+            lens_warp_radial: [[0.0; 4]; 3],
+            lens_warp_tangential: [[0.0; 4]; 3],
+            lens_warp_center: [0.0; 4],
+            noise_reduction: noise_reduction_uniform(parameters.noise_reduction),
+            capture_sharpening: [0.0; 4],
+        }
+        .with_lens_warp(parameters.lens_warp)
+    }
+
+    fn raw_linear(parameters: RawLinearDevelopmentParameters) -> Self {
+        Self {
+            cfa_pattern: [0, 0, 0, 0],
+            black_levels: parameters.black_levels,
+            white_levels: parameters.white_levels,
+            white_balance: [
+                parameters.white_balance[0],
+                parameters.white_balance[1],
+                parameters.white_balance[2],
+                0.0,
+            ],
+            camera_to_working_red: [
+                parameters.camera_to_working[0][0],
+                parameters.camera_to_working[0][1],
+                parameters.camera_to_working[0][2],
+                0.0,
+            ],
+            camera_to_working_green: [
+                parameters.camera_to_working[1][0],
+                parameters.camera_to_working[1][1],
+                parameters.camera_to_working[1][2],
+                0.0,
+            ],
+            camera_to_working_blue: [
+                parameters.camera_to_working[2][0],
+                parameters.camera_to_working[2][1],
+                parameters.camera_to_working[2][2],
+                0.0,
+            ],
+            raw_options: [0, 0, 0, 0],
+            cfa_pattern_6x6: [[0; 4]; 9],
+            lens_warp_radial: [[0.0; 4]; 3],
+            lens_warp_tangential: [[0.0; 4]; 3],
+            lens_warp_center: [0.0; 4],
+            noise_reduction: noise_reduction_uniform(parameters.noise_reduction),
+            capture_sharpening: [0.0; 4],
+        }
+        .with_lens_warp(parameters.lens_warp)
+    }
+
+    /// Packs an optional lens warp; `None` leaves the warp stage as a copy.
+    fn with_lens_warp(self, lens_warp: Option<RawLensWarp>) -> Self {
+        let Some(lens_warp) = lens_warp else {
+            return self;
+        };
+
+        Self {
+            lens_warp_radial: lens_warp.radial,
+            lens_warp_tangential: lens_warp
+                .tangential
+                .map(|tangential| [tangential[0], tangential[1], 0.0, 0.0]),
+            lens_warp_center: [
+                lens_warp.center[0],
+                lens_warp.center[1],
+                lens_warp.radius,
+                1.0,
+            ],
+            ..self
+        }
+    }
+}
+
+/// Packs resolved noise reduction strengths into the uniform's vector layout.
+fn noise_reduction_uniform(noise_reduction: RawNoiseReduction) -> [f32; 4] {
+    [
+        noise_reduction.luminance,
+        noise_reduction.luminance_detail,
+        noise_reduction.chroma,
+        noise_reduction.chroma_detail,
+    ]
+}
+
+/// Packs capture sharpening settings into the uniform's vector layout.
+fn capture_sharpening_uniform(capture_sharpening: RawCaptureSharpening) -> [f32; 4] {
+    [
+        capture_sharpening.amount,
+        capture_sharpening.radius,
+        capture_sharpening.threshold,
+        if capture_sharpening.mask_preview {
+            1.0
+        } else {
+            0.0
+        },
+    ]
+}
+
+/// Maps a highlight reconstruction mode to the shader's mode constant.
+fn highlight_reconstruction_mode(highlight_reconstruction: HighlightReconstruction) -> u32 {
+    match highlight_reconstruction {
+        HighlightReconstruction::Off => 0,
+        HighlightReconstruction::Clip => 1,
+        HighlightReconstruction::Blend => 2,
+        HighlightReconstruction::Inpaint => 3,
     }
 }
 
 impl Default for DevelopmentUniformBlock {
     fn default() -> Self {
-        Self::raster_srgb()
+        DevelopmentParameters::default().to_uniform_block()
     }
 }
 
@@ -162,24 +683,85 @@ impl DevelopmentParametersBuffer {
 }
 
 /// Graph-owned adjustment parameters consumed by GPU adjustment stages.
+///
+/// Layout mirrors `AdjustmentParameters` in `adjustments.wgsl`.
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Pod, Zeroable)]
 pub(super) struct AdjustmentParameters {
+    /// `x`: exposure in EV, `y`: contrast.
     exposure: [f32; 4],
+    /// `x`: highlights, `y`: shadows, `z`: whites, `w`: blacks.
+    tone: [f32; 4],
+    /// `x`: saturation, `y`: vibrance, `z`: `1.0` when the color mixer is applied.
+    color: [f32; 4],
+    /// `x`: `1.0` when the tone curve lookup texture is applied.
+    tone_curve: [f32; 4],
+    /// Color mixer band center hues in OkLCh radians.
+    color_band_hues: [[f32; 4]; 2],
+    /// Per-band hue shifts.
+    color_mixer_hue: [[f32; 4]; 2],
+    /// Per-band saturation amounts.
+    color_mixer_saturation: [[f32; 4]; 2],
+    /// Per-band luminance amounts.
+    color_mixer_luminance: [[f32; 4]; 2],
 }
 
 impl AdjustmentParameters {
-    /// Packs the current exposure value into a 16-byte uniform block.
-    pub(super) fn from_exposure_ev(exposure_ev: f32) -> Self {
+    /// Packs recipe tone and color controls into the adjustment uniform block.
+    pub(super) fn from_recipe(recipe: &EditRecipe) -> Self {
         Self {
-            exposure: [exposure_ev, 0.0, 0.0, 0.0],
+            exposure: [
+                recipe.exposure_ev,
+                recipe.contrast.clamp(-1.0, 1.0),
+                0.0,
+                0.0,
+            ],
+            tone: [
+                recipe.highlights.clamp(-1.0, 1.0),
+                recipe.shadows.clamp(-1.0, 1.0),
+                recipe.whites.clamp(-1.0, 1.0),
+                recipe.blacks.clamp(-1.0, 1.0),
+            ],
+            color: [
+                recipe.saturation.clamp(-1.0, 1.0),
+                recipe.vibrance.clamp(-1.0, 1.0),
+                if is_neutral_color_mixer(&recipe.color_mixer) {
+                    0.0
+                } else {
+                    1.0
+                },
+                0.0,
+            ],
+            tone_curve: [
+                if is_neutral_tone_curve(&recipe.tone_curve) {
+                    0.0
+                } else {
+                    1.0
+                },
+                0.0,
+                0.0,
+                0.0,
+            ],
+            color_band_hues: color_band_hues_uniform(),
+            color_mixer_hue: pack_color_bands(&recipe.color_mixer.hue),
+            color_mixer_saturation: pack_color_bands(&recipe.color_mixer.saturation),
+            color_mixer_luminance: pack_color_bands(&recipe.color_mixer.luminance),
         }
     }
 }
 
 impl Default for AdjustmentParameters {
     fn default() -> Self {
-        Self { exposure: [0.0; 4] }
+        Self {
+            exposure: [0.0; 4],
+            tone: [0.0; 4],
+            color: [0.0; 4],
+            tone_curve: [0.0; 4],
+            color_band_hues: color_band_hues_uniform(),
+            color_mixer_hue: [[0.0; 4]; 2],
+            color_mixer_saturation: [[0.0; 4]; 2],
+            color_mixer_luminance: [[0.0; 4]; 2],
+        }
     }
 }
 
@@ -216,21 +798,665 @@ impl AdjustmentParametersBuffer {
     }
 }
 
+/// Radial scale added to the red or blue channel per unit of manual chromatic aberration.
+const MANUAL_CHROMATIC_ABERRATION_SCALE: f32 = 0.002;
+
+/// Second-order distortion coefficient removed per unit of manual distortion.
+const MANUAL_DISTORTION_SCALE: f32 = 0.1;
+
+/// Graph-owned lens correction parameters consumed by the lens correction stage.
+///
+/// Layout mirrors `LensCorrectionParameters` in `lens_correction.wgsl`. Profile
+/// corrections and manual overrides are folded into one set of coefficients.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+pub(super) struct LensCorrectionParameters {
+    /// Distortion polynomial `c0..c3`.
+    distortion: [f32; 4],
+    /// `x`: distortion polynomial `c4`.
+    distortion_tail: [f32; 4],
+    /// Red radial scale `v`, `c`, `b` relative to green.
+    chromatic_aberration_red: [f32; 4],
+    /// Blue radial scale `v`, `c`, `b` relative to green.
+    chromatic_aberration_blue: [f32; 4],
+    /// `x..z`: profile `pa` coefficients, `w`: manual corner gain.
+    vignetting: [f32; 4],
+    /// `x`: distortion, `y`: chromatic aberration, `z`: vignetting enabled.
+    options: [u32; 4],
+}
+
+impl LensCorrectionParameters {
+    /// Combines the matched lens profile with the recipe's toggles and manual amounts.
+    pub(super) fn from_lens_correction(
+        calibration: Option<&LensCalibration>,
+        settings: &LensCorrection,
+    ) -> Self {
+        let mut parameters = Self::default();
+
+        let profile_distortion = calibration
+            .and_then(|calibration| calibration.distortion)
+            .filter(|_| settings.distortion);
+        let distortion_amount = settings.distortion_amount.clamp(-1.0, 1.0);
+
+        if profile_distortion.is_some() || distortion_amount != 0.0 {
+            let mut coefficients = profile_distortion.unwrap_or([1.0, 0.0, 0.0, 0.0, 0.0]);
+            coefficients[2] -= distortion_amount * MANUAL_DISTORTION_SCALE;
+
+            parameters.distortion = [
+                coefficients[0],
+                coefficients[1],
+                coefficients[2],
+                coefficients[3],
+            ];
+            parameters.distortion_tail[0] = coefficients[4];
+            parameters.options[0] = 1;
+        }
+
+        let profile_chromatic_aberration = calibration
+            .and_then(|calibration| calibration.chromatic_aberration)
+            .filter(|_| settings.chromatic_aberration);
+        let red_amount = settings.chromatic_aberration_red.clamp(-1.0, 1.0);
+        let blue_amount = settings.chromatic_aberration_blue.clamp(-1.0, 1.0);
+
+        if profile_chromatic_aberration.is_some() || red_amount != 0.0 || blue_amount != 0.0 {
+            let [red, blue] =
+                profile_chromatic_aberration.unwrap_or([[1.0, 0.0, 0.0], [1.0, 0.0, 0.0]]);
+
+            parameters.chromatic_aberration_red = [
+                red[0] + red_amount * MANUAL_CHROMATIC_ABERRATION_SCALE,
+                red[1],
+                red[2],
+                0.0,
+            ];
+            parameters.chromatic_aberration_blue = [
+                blue[0] + blue_amount * MANUAL_CHROMATIC_ABERRATION_SCALE,
+                blue[1],
+                blue[2],
+                0.0,
+            ];
+            parameters.options[1] = 1;
+        }
+
+        let profile_vignetting = calibration
+            .and_then(|calibration| calibration.vignetting)
+            .filter(|_| settings.vignetting);
+        let vignetting_amount = settings.vignetting_amount.clamp(-1.0, 1.0);
+
+        if profile_vignetting.is_some() || vignetting_amount != 0.0 {
+            let coefficients = profile_vignetting.unwrap_or([0.0; 3]);
+
+            parameters.vignetting = [
+                coefficients[0],
+                coefficients[1],
+                coefficients[2],
+                vignetting_amount,
+            ];
+            parameters.options[2] = 1;
+        }
+
+        parameters
+    }
+}
+
+impl Default for LensCorrectionParameters {
+    fn default() -> Self {
+        Self {
+            distortion: [1.0, 0.0, 0.0, 0.0],
+            distortion_tail: [0.0; 4],
+            chromatic_aberration_red: [1.0, 0.0, 0.0, 0.0],
+            chromatic_aberration_blue: [1.0, 0.0, 0.0, 0.0],
+            vignetting: [0.0; 4],
+            options: [0; 4],
+        }
+    }
+}
+
+/// GPU uniform buffer for graph-owned lens correction parameters.
+pub(super) struct LensCorrectionParametersBuffer {
+    parameters: LensCorrectionParameters,
+    buffer: wgpu::Buffer,
+}
+
+impl LensCorrectionParametersBuffer {
+    /// Creates a uniform buffer initialized with lens correction disabled.
+    pub(super) fn new(device: &wgpu::Device) -> Self {
+        let parameters = LensCorrectionParameters::default();
+
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Lens Correction Parameters Buffer"),
+            contents: bytemuck::cast_slice(&[parameters]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        Self { parameters, buffer }
+    }
+
+    /// Updates the live lens correction parameters used by graph stages.
+    pub(super) fn update(&mut self, queue: &wgpu::Queue, parameters: LensCorrectionParameters) {
+        self.parameters = parameters;
+
+        queue.write_buffer(&self.buffer, 0, bytemuck::cast_slice(&[self.parameters]));
+    }
+
+    /// Returns this buffer as a bindable uniform resource.
+    pub(super) fn as_entire_binding(&self) -> wgpu::BindingResource<'_> {
+        self.buffer.as_entire_binding()
+    }
+}
+
+/// Graph-owned geometry parameters consumed by the geometry stage.
+///
+/// Layout mirrors `GeometryParameters` in `geometry.wgsl`. `source_x.xyz` and
+/// `source_y.xyz` are the rows of the affine map from output pixel-edge
+/// coordinates to source pixel-edge coordinates.
+/// `source_x.w` is `1.0` when the map is straightened and needs filtering.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+pub(super) struct GeometryParameters {
+    source_x: [f32; 4],
+    source_y: [f32; 4],
+}
+
+impl GeometryParameters {
+    /// Packs resolved geometry into the stage uniform layout.
+    pub(super) fn from_resolved_geometry(geometry: &ResolvedGeometry) -> Self {
+        let [row_x, row_y] = geometry.output_to_source_rows();
+
+        let filtered = if geometry.is_straightened() { 1.0 } else { 0.0 };
+
+        Self {
+            source_x: [row_x[0], row_x[1], row_x[2], filtered],
+            source_y: [row_y[0], row_y[1], row_y[2], 0.0],
+        }
+    }
+}
+
+impl Default for GeometryParameters {
+    fn default() -> Self {
+        Self {
+            source_x: [1.0, 0.0, 0.0, 0.0],
+            source_y: [0.0, 1.0, 0.0, 0.0],
+        }
+    }
+}
+
+/// GPU uniform buffer for graph-owned geometry parameters.
+pub(super) struct GeometryParametersBuffer {
+    parameters: GeometryParameters,
+    buffer: wgpu::Buffer,
+}
+
+impl GeometryParametersBuffer {
+    /// Creates a uniform buffer initialized with the identity mapping.
+    pub(super) fn new(device: &wgpu::Device) -> Self {
+        let parameters = GeometryParameters::default();
+
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Geometry Parameters Buffer"),
+            contents: bytemuck::cast_slice(&[parameters]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        Self { parameters, buffer }
+    }
+
+    /// Updates the live geometry parameters used by graph stages.
+    pub(super) fn update(&mut self, queue: &wgpu::Queue, parameters: GeometryParameters) {
+        self.parameters = parameters;
+
+        queue.write_buffer(&self.buffer, 0, bytemuck::cast_slice(&[self.parameters]));
+    }
+
+    /// Returns this buffer as a bindable uniform resource.
+    pub(super) fn as_entire_binding(&self) -> wgpu::BindingResource<'_> {
+        self.buffer.as_entire_binding()
+    }
+}
+
+/// Mask kind codes shared with `mask.wgsl`.
+const MASK_KIND_NONE: f32 = 0.0;
+const MASK_KIND_LINEAR: f32 = 1.0;
+const MASK_KIND_RADIAL: f32 = 2.0;
+const MASK_KIND_BRUSH: f32 = 3.0;
+
+/// Graph-owned mask parameters consumed by the mask stage.
+///
+/// Layout mirrors `MaskParameters` in `mask.wgsl`. `info.x` is the number of
+/// masks. Per mask, `shapes` holds the kind, `1.0` when inverted, the feather,
+/// and the clockwise rotation in radians. `points` holds the start and end of
+/// a linear mask, the center and radii of a radial mask, or the brush table
+/// index of a brush mask's tile grid. `info.y` is the brush tile size in mask
+/// pixels.
+///
+/// `luminance_ranges` holds the lightness minimum, maximum, and feather, with
+/// `w` set to `1.0` when the range is active. `color_ranges` holds the sampled
+/// hue in radians, chroma, hue range in radians, and chroma range, and
+/// `color_options` holds the color feather and an active flag.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+pub(super) struct MaskParameters {
+    info: [f32; 4],
+    shapes: [[f32; 4]; MAX_LOCAL_ADJUSTMENTS],
+    points: [[f32; 4]; MAX_LOCAL_ADJUSTMENTS],
+    luminance_ranges: [[f32; 4]; MAX_LOCAL_ADJUSTMENTS],
+    color_ranges: [[f32; 4]; MAX_LOCAL_ADJUSTMENTS],
+    color_options: [[f32; 4]; MAX_LOCAL_ADJUSTMENTS],
+}
+
+impl MaskParameters {
+    /// Packs recipe masks and their brush dab ranges into the stage uniform layout.
+    pub(super) fn from_local_adjustments(
+        local_adjustments: &[LocalAdjustment],
+        brush_dabs: &PackedBrushDabs,
+    ) -> Self {
+        let mut parameters = Self::default();
+        let count = local_adjustments.len().min(MAX_LOCAL_ADJUSTMENTS);
+
+        parameters.info[0] = count as f32;
+        parameters.info[1] = brush_dabs.tile_size as f32;
+
+        for (index, adjustment) in local_adjustments.iter().take(count).enumerate() {
+            if !adjustment.enabled {
+                continue;
+            }
+
+            let (shape, points) = match &adjustment.mask {
+                Mask::Linear { start, end } => (
+                    [MASK_KIND_LINEAR, 0.0, 0.0, 0.0],
+                    [start.x, start.y, end.x, end.y],
+                ),
+                Mask::Radial {
+                    center,
+                    radius_x,
+                    radius_y,
+                    rotation_degrees,
+                    feather,
+                    invert,
+                } => (
+                    [
+                        MASK_KIND_RADIAL,
+                        if *invert { 1.0 } else { 0.0 },
+                        feather.clamp(0.0, 1.0),
+                        rotation_degrees.to_radians(),
+                    ],
+                    [center.x, center.y, radius_x.max(0.0), radius_y.max(0.0)],
+                ),
+                Mask::Brush { .. } => (
+                    [MASK_KIND_BRUSH, 0.0, 0.0, 0.0],
+                    [brush_dabs.tile_grids[index] as f32, 0.0, 0.0, 0.0],
+                ),
+            };
+
+            parameters.shapes[index] = shape;
+            parameters.points[index] = points;
+
+            if let Some(luminance) = adjustment.range.luminance {
+                parameters.luminance_ranges[index] = [
+                    luminance.min,
+                    luminance.max,
+                    luminance.feather.max(0.0),
+                    1.0,
+                ];
+            }
+
+            if let Some(color) = adjustment.range.color {
+                parameters.color_ranges[index] = [
+                    color.hue_degrees.to_radians(),
+                    color.chroma.max(0.0),
+                    color.hue_range_degrees.clamp(0.0, 180.0).to_radians(),
+                    color.chroma_range.max(0.0),
+                ];
+                parameters.color_options[index] = [color.feather.clamp(0.0, 1.0), 1.0, 0.0, 0.0];
+            }
+        }
+
+        parameters
+    }
+}
+
+impl Default for MaskParameters {
+    fn default() -> Self {
+        Self {
+            info: [0.0; 4],
+            shapes: [[MASK_KIND_NONE, 0.0, 0.0, 0.0]; MAX_LOCAL_ADJUSTMENTS],
+            points: [[0.0; 4]; MAX_LOCAL_ADJUSTMENTS],
+            luminance_ranges: [[0.0; 4]; MAX_LOCAL_ADJUSTMENTS],
+            color_ranges: [[0.0; 4]; MAX_LOCAL_ADJUSTMENTS],
+            color_options: [[0.0; 4]; MAX_LOCAL_ADJUSTMENTS],
+        }
+    }
+}
+
+/// GPU uniform buffer for graph-owned mask parameters.
+pub(super) struct MaskParametersBuffer {
+    parameters: MaskParameters,
+    buffer: wgpu::Buffer,
+}
+
+impl MaskParametersBuffer {
+    /// Creates a uniform buffer initialized with no masks.
+    pub(super) fn new(device: &wgpu::Device) -> Self {
+        let parameters = MaskParameters::default();
+
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Mask Parameters Buffer"),
+            contents: bytemuck::cast_slice(&[parameters]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        Self { parameters, buffer }
+    }
+
+    /// Updates the live mask parameters used by graph stages.
+    pub(super) fn update(&mut self, queue: &wgpu::Queue, parameters: MaskParameters) {
+        self.parameters = parameters;
+
+        queue.write_buffer(&self.buffer, 0, bytemuck::cast_slice(&[self.parameters]));
+    }
+
+    /// Returns this buffer as a bindable uniform resource.
+    pub(super) fn as_entire_binding(&self) -> wgpu::BindingResource<'_> {
+        self.buffer.as_entire_binding()
+    }
+}
+
+/// Stops of red/blue gain shift per unit of local temperature.
+const LOCAL_TEMPERATURE_RANGE_EV: f32 = 1.0;
+
+/// Stops of green gain shift per unit of local tint.
+const LOCAL_TINT_RANGE_EV: f32 = 0.5;
+
+/// Graph-owned local adjustment deltas consumed by the local adjustment stage.
+///
+/// Layout mirrors `LocalAdjustmentParameters` in `local_adjustments.wgsl`.
+/// `info.x` is the number of adjustments and `info.y` is the index, plus one,
+/// of the mask tinted over the input for the Inspector overlay, or `0.0` when
+/// the overlay is off. Per adjustment, `tone` holds the
+/// exposure in EV, contrast, and saturation, and `white_balance.rgb` holds
+/// luminance-preserving channel gains.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+pub(super) struct LocalAdjustmentParameters {
+    info: [f32; 4],
+    tone: [[f32; 4]; MAX_LOCAL_ADJUSTMENTS],
+    white_balance: [[f32; 4]; MAX_LOCAL_ADJUSTMENTS],
+}
+
+impl LocalAdjustmentParameters {
+    /// Packs recipe local adjustment deltas and the Inspector mask overlay
+    /// into the stage uniform layout.
+    pub(super) fn from_local_adjustments(
+        local_adjustments: &[LocalAdjustment],
+        mask_overlay: Option<usize>,
+    ) -> Self {
+        let mut parameters = Self::default();
+        let count = local_adjustments.len().min(MAX_LOCAL_ADJUSTMENTS);
+
+        parameters.info[0] = count as f32;
+        parameters.info[1] = match mask_overlay {
+            Some(index) if index < count => (index + 1) as f32,
+            _ => 0.0,
+        };
+
+        for (index, adjustment) in local_adjustments.iter().take(count).enumerate() {
+            let [red, green, blue] =
+                local_white_balance_gains(adjustment.temperature, adjustment.tint);
+
+            parameters.tone[index] = [
+                adjustment.exposure_ev,
+                adjustment.contrast.clamp(-1.0, 1.0),
+                adjustment.saturation.clamp(-1.0, 1.0),
+                0.0,
+            ];
+            parameters.white_balance[index] = [red, green, blue, 0.0];
+        }
+
+        parameters
+    }
+}
+
+impl Default for LocalAdjustmentParameters {
+    fn default() -> Self {
+        Self {
+            info: [0.0; 4],
+            tone: [[0.0; 4]; MAX_LOCAL_ADJUSTMENTS],
+            white_balance: [[1.0, 1.0, 1.0, 0.0]; MAX_LOCAL_ADJUSTMENTS],
+        }
+    }
+}
+
+/// Returns Rec.2020 channel gains for local temperature and tint, normalized
+/// so a neutral grey keeps its luminance.
+fn local_white_balance_gains(temperature: f32, tint: f32) -> [f32; 3] {
+    let temperature = temperature.clamp(-1.0, 1.0) * LOCAL_TEMPERATURE_RANGE_EV * 0.5;
+    let tint = tint.clamp(-1.0, 1.0) * LOCAL_TINT_RANGE_EV;
+    let gains = [
+        (temperature + (tint * 0.5)).exp2(),
+        (-tint).exp2(),
+        (-temperature + (tint * 0.5)).exp2(),
+    ];
+    let luminance = (0.2627 * gains[0]) + (0.6780 * gains[1]) + (0.0593 * gains[2]);
+
+    gains.map(|gain| gain / luminance)
+}
+
+/// GPU uniform buffer for graph-owned local adjustment parameters.
+pub(super) struct LocalAdjustmentParametersBuffer {
+    parameters: LocalAdjustmentParameters,
+    buffer: wgpu::Buffer,
+}
+
+impl LocalAdjustmentParametersBuffer {
+    /// Creates a uniform buffer initialized with no local adjustments.
+    pub(super) fn new(device: &wgpu::Device) -> Self {
+        let parameters = LocalAdjustmentParameters::default();
+
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Local Adjustment Parameters Buffer"),
+            contents: bytemuck::cast_slice(&[parameters]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        Self { parameters, buffer }
+    }
+
+    /// Updates the live local adjustment parameters used by graph stages.
+    pub(super) fn update(&mut self, queue: &wgpu::Queue, parameters: LocalAdjustmentParameters) {
+        self.parameters = parameters;
+
+        queue.write_buffer(&self.buffer, 0, bytemuck::cast_slice(&[self.parameters]));
+    }
+
+    /// Returns this buffer as a bindable uniform resource.
+    pub(super) fn as_entire_binding(&self) -> wgpu::BindingResource<'_> {
+        self.buffer.as_entire_binding()
+    }
+}
+
+/// Retouch mode codes shared with `retouch.wgsl`.
+const RETOUCH_MODE_CLONE: f32 = 0.0;
+const RETOUCH_MODE_HEAL: f32 = 1.0;
+
+/// Graph-owned retouch spots consumed by the retouch stage.
+///
+/// Layout mirrors `RetouchParameters` in `retouch.wgsl`. `info.x` is the
+/// number of spots. Per spot, `positions` holds the normalized destination
+/// and source centers, and `shapes` holds the radius as a fraction of the
+/// longer source edge, the feather, and the retouch mode.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+pub(super) struct RetouchParameters {
+    info: [f32; 4],
+    positions: [[f32; 4]; MAX_RETOUCH_SPOTS],
+    shapes: [[f32; 4]; MAX_RETOUCH_SPOTS],
+}
+
+impl RetouchParameters {
+    /// Packs recipe retouch spots into the stage uniform layout.
+    pub(super) fn from_retouch_spots(retouch_spots: &[RetouchSpot]) -> Self {
+        let mut parameters = Self::default();
+        let count = retouch_spots.len().min(MAX_RETOUCH_SPOTS);
+
+        parameters.info[0] = count as f32;
+
+        for (index, spot) in retouch_spots.iter().take(count).enumerate() {
+            parameters.positions[index] = [
+                spot.destination.x,
+                spot.destination.y,
+                spot.source.x,
+                spot.source.y,
+            ];
+            parameters.shapes[index] = [
+                spot.radius.max(0.0),
+                spot.feather.clamp(0.0, 1.0),
+                match spot.mode {
+                    RetouchMode::Clone => RETOUCH_MODE_CLONE,
+                    RetouchMode::Heal => RETOUCH_MODE_HEAL,
+                },
+                0.0,
+            ];
+        }
+
+        parameters
+    }
+}
+
+impl Default for RetouchParameters {
+    fn default() -> Self {
+        Self {
+            info: [0.0; 4],
+            positions: [[0.0; 4]; MAX_RETOUCH_SPOTS],
+            shapes: [[0.0; 4]; MAX_RETOUCH_SPOTS],
+        }
+    }
+}
+
+/// GPU uniform buffer for graph-owned retouch parameters.
+pub(super) struct RetouchParametersBuffer {
+    parameters: RetouchParameters,
+    buffer: wgpu::Buffer,
+}
+
+impl RetouchParametersBuffer {
+    /// Creates a uniform buffer initialized with no retouch spots.
+    pub(super) fn new(device: &wgpu::Device) -> Self {
+        let parameters = RetouchParameters::default();
+
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Retouch Parameters Buffer"),
+            contents: bytemuck::cast_slice(&[parameters]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        Self { parameters, buffer }
+    }
+
+    /// Updates the live retouch parameters used by graph stages.
+    pub(super) fn update(&mut self, queue: &wgpu::Queue, parameters: RetouchParameters) {
+        self.parameters = parameters;
+
+        queue.write_buffer(&self.buffer, 0, bytemuck::cast_slice(&[self.parameters]));
+    }
+
+    /// Returns this buffer as a bindable uniform resource.
+    pub(super) fn as_entire_binding(&self) -> wgpu::BindingResource<'_> {
+        self.buffer.as_entire_binding()
+    }
+}
+
 /// Graph-owned output transform parameters consumed by the display-output stage.
+///
+/// `display.yzw` hold the look placement, interpolation, and lattice size,
+/// `render.y` holds the look amount, and `render.zw` hold the filmic contrast
+/// and toe. `tone_mapper.x` selects the tone mapping curve and
+/// `display_range.x` is the display peak relative to SDR white.
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Pod, Zeroable)]
 pub(super) struct OutputTransformParameters {
     display: [u32; 4],
     render: [f32; 4],
+    tone_mapper: [u32; 4],
+    display_range: [f32; 4],
+    look_domain_min: [f32; 4],
+    look_domain_max: [f32; 4],
+    debug: [u32; 4],
 }
 
 impl OutputTransformParameters {
-    /// Packs the current output transform settings into a uniform block.
-    pub(super) fn from_output_transform(display_intent: u32, base_exposure_ev: f32) -> Self {
-        Self {
-            display: [display_intent, 0, 0, 0],
-            render: [base_exposure_ev, 0.0, 0.0, 0.0],
-        }
+    /// Replaces the display intent, base exposure, and display headroom while
+    /// keeping the look.
+    pub(super) fn with_output_transform(
+        self,
+        display_intent: u32,
+        base_exposure_ev: f32,
+        display_headroom: f32,
+    ) -> Self {
+        let mut parameters = self;
+
+        parameters.display[0] = display_intent;
+        parameters.render[0] = base_exposure_ev;
+        parameters.display_range[0] = display_headroom.max(1.0);
+
+        parameters
+    }
+
+    /// Replaces the tone mapper and its shaping controls while keeping the look.
+    pub(super) fn with_tone_mapping(self, tone_mapping: &ToneMapping) -> Self {
+        let mut parameters = self;
+
+        parameters.tone_mapper[0] = match tone_mapping.mapper {
+            ToneMapper::AcesFitted => 0,
+            ToneMapper::Agx => 1,
+            ToneMapper::Filmic => 2,
+            ToneMapper::Reinhard => 3,
+            ToneMapper::Clip => 4,
+        };
+        parameters.render[2] = tone_mapping.contrast.clamp(-1.0, 1.0);
+        parameters.render[3] = tone_mapping.toe.clamp(-1.0, 1.0);
+
+        parameters
+    }
+
+    /// Replaces the look settings while keeping the display intent.
+    ///
+    /// The look is disabled when no LUT is loaded or the amount is zero.
+    pub(super) fn with_look(self, look: &Look, lut: &LookLut) -> Self {
+        let mut parameters = self;
+        let enabled = lut.is_loaded() && look.path.is_some() && look.amount > 0.0;
+
+        parameters.display[1] = if enabled {
+            graph_look_placement(look.placement)
+        } else {
+            LOOK_PLACEMENT_NONE
+        };
+        parameters.display[2] = match look.interpolation {
+            LookInterpolation::Trilinear => 0,
+            LookInterpolation::Tetrahedral => 1,
+        };
+        parameters.display[3] = lut.size();
+        parameters.render[1] = look.amount.clamp(0.0, 1.0);
+
+        let [min_red, min_green, min_blue] = lut.domain_min();
+        let [max_red, max_green, max_blue] = lut.domain_max();
+
+        parameters.look_domain_min = [min_red, min_green, min_blue, 0.0];
+        parameters.look_domain_max = [max_red, max_green, max_blue, 0.0];
+
+        parameters
+    }
+
+    /// Switches the spot visualization debug view on or off, keeping every
+    /// rendering setting so the normal view returns unchanged.
+    pub(super) fn with_spot_visualization(self, enabled: bool) -> Self {
+        let mut parameters = self;
+
+        parameters.debug[0] = if enabled {
+            DEBUG_VIEW_VISUALIZE_SPOTS
+        } else {
+            DEBUG_VIEW_NONE
+        };
+
+        parameters
     }
 }
 
@@ -239,10 +1465,26 @@ impl Default for OutputTransformParameters {
         Self {
             display: [0; 4],
             render: [0.0; 4],
+            tone_mapper: [0; 4],
+            display_range: [1.0, 0.0, 0.0, 0.0],
+            look_domain_min: [0.0; 4],
+            look_domain_max: [1.0, 1.0, 1.0, 0.0],
+            debug: [DEBUG_VIEW_NONE, 0, 0, 0],
         }
     }
 }
 
+const LOOK_PLACEMENT_NONE: u32 = 0;
+const DEBUG_VIEW_NONE: u32 = 0;
+const DEBUG_VIEW_VISUALIZE_SPOTS: u32 = 1;
+
+fn graph_look_placement(placement: LookPlacement) -> u32 {
+    match placement {
+        LookPlacement::Working => 1,
+        LookPlacement::Display => 2,
+    }
+}
+
 /// GPU uniform buffer for graph-owned output transform parameters.
 pub(super) struct OutputTransformParametersBuffer {
     parameters: OutputTransformParameters,
@@ -263,6 +1505,11 @@ impl OutputTransformParametersBuffer {
         Self { parameters, buffer }
     }
 
+    /// Returns the live output transform parameters.
+    pub(super) fn parameters(&self) -> OutputTransformParameters {
+        self.parameters
+    }
+
     /// Updates the live output transform parameters used by graph stages.
     pub(super) fn update(&mut self, queue: &wgpu::Queue, parameters: OutputTransformParameters) {
         self.parameters = parameters;
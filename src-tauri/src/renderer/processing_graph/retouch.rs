@@ -0,0 +1,167 @@
+use crate::core::editing::MaskPoint;
+
+/// Longest edge, in texels, of the downsampled region searched for a spot source.
+const SPOT_SEARCH_SIZE: u32 = 128;
+
+/// Candidate sources lie between these multiples of the spot radius from the
+/// destination, so a source never overlaps the spot it repairs.
+const SPOT_SEARCH_MIN_DISTANCE: f32 = 2.5;
+const SPOT_SEARCH_MAX_DISTANCE: f32 = 8.0;
+
+/// Outer radius of the compared context ring as a multiple of the spot radius.
+const SPOT_CONTEXT_RADIUS: f32 = 1.5;
+
+/// Source-pixel region read back for a spot source search, and the
+/// downsampled size it is box-filtered to.
+pub(super) struct SpotSearchRegion {
+    pub(super) left: u32,
+    pub(super) top: u32,
+    pub(super) width: u32,
+    pub(super) height: u32,
+    pub(super) sample_width: u32,
+    pub(super) sample_height: u32,
+}
+
+impl SpotSearchRegion {
+    /// Returns the region around `destination` holding every candidate source
+    /// and its context ring, clamped to the image.
+    ///
+    /// `radius` is a fraction of the longer image edge. Returns `None` when the
+    /// region is empty.
+    pub(super) fn around(
+        destination: MaskPoint,
+        radius: f32,
+        image_width: u32,
+        image_height: u32,
+    ) -> Option<Self> {
+        let radius_pixels = radius.max(0.0) * image_width.max(image_height) as f32;
+        let reach = ((SPOT_SEARCH_MAX_DISTANCE + SPOT_CONTEXT_RADIUS) * radius_pixels).ceil() + 1.0;
+        let center_x = destination.x * image_width as f32;
+        let center_y = destination.y * image_height as f32;
+        let left = (center_x - reach).clamp(0.0, image_width as f32) as u32;
+        let top = (center_y - reach).clamp(0.0, image_height as f32) as u32;
+        let right = (center_x + reach).clamp(0.0, image_width as f32) as u32;
+        let bottom = (center_y + reach).clamp(0.0, image_height as f32) as u32;
+        let width = right.saturating_sub(left);
+        let height = bottom.saturating_sub(top);
+
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let factor = (width.max(height) as f32 / SPOT_SEARCH_SIZE as f32).max(1.0);
+
+        Some(Self {
+            left,
+            top,
+            width,
+            height,
+            sample_width: ((width as f32 / factor).ceil() as u32).max(1),
+            sample_height: ((height as f32 / factor).ceil() as u32).max(1),
+        })
+    }
+}
+
+/// Finds the spot source whose surroundings best match the destination's.
+///
+/// `texels` is the region downsampled to its sample size. The destination's
+/// interior is ignored because it holds the blemish being removed; candidates
+/// are scored by the sum of squared differences over the context ring around
+/// the spot. Returns normalized source coordinates, or `None` when no
+/// candidate fits inside the image.
+pub(super) fn find_spot_source(
+    texels: &[f32],
+    region: &SpotSearchRegion,
+    destination: MaskPoint,
+    radius: f32,
+    image_width: u32,
+    image_height: u32,
+) -> Option<MaskPoint> {
+    let sample_width = region.sample_width as i32;
+    let sample_height = region.sample_height as i32;
+    let scale_x = region.width as f32 / region.sample_width as f32;
+    let scale_y = region.height as f32 / region.sample_height as f32;
+    let radius_samples =
+        (radius.max(0.0) * image_width.max(image_height) as f32 / scale_x.max(scale_y)).max(1.0);
+    let context_radius = (radius_samples * SPOT_CONTEXT_RADIUS).max(radius_samples + 1.5);
+    let ring = context_ring(radius_samples, context_radius);
+    let destination_x =
+        (((destination.x * image_width as f32) - region.left as f32) / scale_x).floor() as i32;
+    let destination_y =
+        (((destination.y * image_height as f32) - region.top as f32) / scale_y).floor() as i32;
+    let texel = |x: i32, y: i32| {
+        let index = ((y * sample_width + x) * 4) as usize;
+
+        [texels[index], texels[index + 1], texels[index + 2]]
+    };
+    let inside = |x: i32, y: i32| (0..sample_width).contains(&x) && (0..sample_height).contains(&y);
+    let destination_ring: Vec<(i32, i32, [f32; 3])> = ring
+        .iter()
+        .filter_map(|&(x, y)| {
+            let (sample_x, sample_y) = (destination_x + x, destination_y + y);
+
+            inside(sample_x, sample_y).then(|| (x, y, texel(sample_x, sample_y)))
+        })
+        .collect();
+
+    if destination_ring.is_empty() {
+        return None;
+    }
+
+    let reach = context_radius.ceil() as i32;
+    let min_distance = radius_samples * SPOT_SEARCH_MIN_DISTANCE;
+    let max_distance = radius_samples * SPOT_SEARCH_MAX_DISTANCE;
+    let mut best_error = f32::INFINITY;
+    let mut best = None;
+
+    for candidate_y in reach..(sample_height - reach) {
+        for candidate_x in reach..(sample_width - reach) {
+            let distance =
+                ((candidate_x - destination_x) as f32).hypot((candidate_y - destination_y) as f32);
+
+            if !(min_distance..=max_distance).contains(&distance) {
+                continue;
+            }
+
+            let mut error = 0.0;
+
+            for (x, y, expected) in &destination_ring {
+                let actual = texel(candidate_x + x, candidate_y + y);
+
+                error += (0..3)
+                    .map(|channel| (actual[channel] - expected[channel]).powi(2))
+                    .sum::<f32>();
+            }
+
+            if error < best_error {
+                best_error = error;
+                best = Some((candidate_x, candidate_y));
+            }
+        }
+    }
+
+    let (source_x, source_y) = best?;
+
+    Some(MaskPoint {
+        x: (region.left as f32 + ((source_x as f32 + 0.5) * scale_x)) / image_width as f32,
+        y: (region.top as f32 + ((source_y as f32 + 0.5) * scale_y)) / image_height as f32,
+    })
+}
+
+/// Returns integer offsets between `inner` and `outer` from the center.
+fn context_ring(inner: f32, outer: f32) -> Vec<(i32, i32)> {
+    let reach = outer.ceil() as i32;
+    let mut ring = Vec::new();
+
+    for y in -reach..=reach {
+        for x in -reach..=reach {
+            let distance = (x as f32).hypot(y as f32);
+
+            if (inner..=outer).contains(&distance) {
+                ring.push((x, y));
+            }
+        }
+    }
+
+    ring
+}
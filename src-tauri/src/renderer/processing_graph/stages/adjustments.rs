@@ -11,26 +11,31 @@ const LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
     pass: "Adjustment Stage Pass",
 };
 
-/// Compute stage that applies graph adjustment parameters to the source image.
+/// Compute stage that applies graph adjustment parameters and the baked tone
+/// curve lookup texture to the source image.
 pub(in crate::renderer::processing_graph) struct AdjustmentStage {
     stage: ImageComputeStage,
 }
 
 impl AdjustmentStage {
-    /// Creates the adjustment stage and binds its initial source, output, and parameters.
+    /// Creates the adjustment stage and binds its initial source, output, parameters,
+    /// and tone curve.
     pub(in crate::renderer::processing_graph) fn new(
         device: &wgpu::Device,
         source_view: &wgpu::TextureView,
         output_view: &wgpu::TextureView,
         adjustment_parameters_binding: wgpu::BindingResource<'_>,
+        tone_curve_view: &wgpu::TextureView,
     ) -> Self {
-        let stage = ImageComputeStage::new(
+        let stage = ImageComputeStage::new_with_lookup(
             device,
             LABELS,
             include_str!("../../../shaders/adjustments.wgsl"),
             source_view,
             output_view,
             adjustment_parameters_binding,
+            tone_curve_view,
+            wgpu::TextureViewDimension::D1,
             IMAGE_TEXTURE_FORMAT,
         );
 
@@ -44,12 +49,14 @@ impl AdjustmentStage {
         source_view: &wgpu::TextureView,
         output_view: &wgpu::TextureView,
         adjustment_parameters_binding: wgpu::BindingResource<'_>,
+        tone_curve_view: &wgpu::TextureView,
     ) {
-        self.stage.rebind(
+        self.stage.rebind_with_lookup(
             device,
             source_view,
             output_view,
             adjustment_parameters_binding,
+            tone_curve_view,
         );
     }
 
@@ -16,6 +16,7 @@ pub(in crate::renderer::processing_graph::stages) struct ImageComputeStageLabels
 ///
 /// This handles the common graph-stage shape used by stages that read one
 /// source texture, write one output texture, and consume one uniform buffer.
+/// Stages may also read one 1D or 3D lookup texture at binding 3.
 pub(in crate::renderer::processing_graph::stages) struct ImageComputeStage {
     pipeline: wgpu::ComputePipeline,
     bind_group_layout: wgpu::BindGroupLayout,
@@ -34,8 +35,60 @@ impl ImageComputeStage {
         parameters_binding: wgpu::BindingResource<'_>,
         storage_format: wgpu::TextureFormat,
     ) -> Self {
-        let bind_group_layout =
-            create_bind_group_layout(device, labels.bind_group_layout, storage_format);
+        Self::create(
+            device,
+            labels,
+            shader_source,
+            source_view,
+            output_view,
+            parameters_binding,
+            None,
+            storage_format,
+        )
+    }
+
+    /// Creates a compute stage that also reads a lookup texture of the given dimension.
+    #[allow(clippy::too_many_arguments)]
+    pub(in crate::renderer::processing_graph::stages) fn new_with_lookup(
+        device: &wgpu::Device,
+        labels: ImageComputeStageLabels,
+        shader_source: &'static str,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        parameters_binding: wgpu::BindingResource<'_>,
+        lookup_view: &wgpu::TextureView,
+        lookup_dimension: wgpu::TextureViewDimension,
+        storage_format: wgpu::TextureFormat,
+    ) -> Self {
+        Self::create(
+            device,
+            labels,
+            shader_source,
+            source_view,
+            output_view,
+            parameters_binding,
+            Some((lookup_view, lookup_dimension)),
+            storage_format,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn create(
+        device: &wgpu::Device,
+        labels: ImageComputeStageLabels,
+        shader_source: &'static str,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        parameters_binding: wgpu::BindingResource<'_>,
+        lookup: Option<(&wgpu::TextureView, wgpu::TextureViewDimension)>,
+        storage_format: wgpu::TextureFormat,
+    ) -> Self {
+        let bind_group_layout = create_bind_group_layout(
+            device,
+            labels.bind_group_layout,
+            storage_format,
+            lookup.map(|(_, dimension)| dimension),
+        );
         let pipeline = create_pipeline(device, &bind_group_layout, labels, shader_source);
         let bind_group = create_bind_group(
             device,
@@ -44,6 +97,7 @@ impl ImageComputeStage {
             source_view,
             output_view,
             parameters_binding,
+            lookup.map(|(view, _)| view),
         );
 
         Self {
@@ -69,6 +123,27 @@ impl ImageComputeStage {
             source_view,
             output_view,
             parameters_binding,
+            None,
+        );
+    }
+
+    /// Rebinds graph resources for a stage created with a lookup texture.
+    pub(in crate::renderer::processing_graph::stages) fn rebind_with_lookup(
+        &mut self,
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        parameters_binding: wgpu::BindingResource<'_>,
+        lookup_view: &wgpu::TextureView,
+    ) {
+        self.bind_group = create_bind_group(
+            device,
+            &self.bind_group_layout,
+            self.labels.bind_group,
+            source_view,
+            output_view,
+            parameters_binding,
+            Some(lookup_view),
         );
     }
 
@@ -106,41 +181,57 @@ fn create_bind_group_layout(
     device: &wgpu::Device,
     label: &'static str,
     output_format: wgpu::TextureFormat,
+    lookup_dimension: Option<wgpu::TextureViewDimension>,
 ) -> wgpu::BindGroupLayout {
-    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
-        label: Some(label),
-        entries: &[
-            wgpu::BindGroupLayoutEntry {
-                binding: 0,
-                visibility: wgpu::ShaderStages::COMPUTE,
-                ty: wgpu::BindingType::Texture {
-                    multisampled: false,
-                    view_dimension: wgpu::TextureViewDimension::D2,
-                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
-                },
-                count: None,
+    let mut entries = vec![
+        wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Texture {
+                multisampled: false,
+                view_dimension: wgpu::TextureViewDimension::D2,
+                sample_type: wgpu::TextureSampleType::Float { filterable: false },
             },
-            wgpu::BindGroupLayoutEntry {
-                binding: 1,
-                visibility: wgpu::ShaderStages::COMPUTE,
-                ty: wgpu::BindingType::StorageTexture {
-                    access: wgpu::StorageTextureAccess::WriteOnly,
-                    format: output_format,
-                    view_dimension: wgpu::TextureViewDimension::D2,
-                },
-                count: None,
+            count: None,
+        },
+        wgpu::BindGroupLayoutEntry {
+            binding: 1,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::StorageTexture {
+                access: wgpu::StorageTextureAccess::WriteOnly,
+                format: output_format,
+                view_dimension: wgpu::TextureViewDimension::D2,
             },
-            wgpu::BindGroupLayoutEntry {
-                binding: 2,
-                visibility: wgpu::ShaderStages::COMPUTE,
-                ty: wgpu::BindingType::Buffer {
-                    ty: wgpu::BufferBindingType::Uniform,
-                    has_dynamic_offset: false,
-                    min_binding_size: None,
-                },
-                count: None,
+            count: None,
+        },
+        wgpu::BindGroupLayoutEntry {
+            binding: 2,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        },
+    ];
+
+    if let Some(lookup_dimension) = lookup_dimension {
+        entries.push(wgpu::BindGroupLayoutEntry {
+            binding: 3,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Texture {
+                multisampled: false,
+                view_dimension: lookup_dimension,
+                sample_type: wgpu::TextureSampleType::Float { filterable: false },
             },
-        ],
+            count: None,
+        });
+    }
+
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some(label),
+        entries: &entries,
     })
 }
 
@@ -178,23 +269,33 @@ fn create_bind_group(
     source_view: &wgpu::TextureView,
     output_view: &wgpu::TextureView,
     parameters_binding: wgpu::BindingResource<'_>,
+    lookup_view: Option<&wgpu::TextureView>,
 ) -> wgpu::BindGroup {
+    let mut entries = vec![
+        wgpu::BindGroupEntry {
+            binding: 0,
+            resource: wgpu::BindingResource::TextureView(source_view),
+        },
+        wgpu::BindGroupEntry {
+            binding: 1,
+            resource: wgpu::BindingResource::TextureView(output_view),
+        },
+        wgpu::BindGroupEntry {
+            binding: 2,
+            resource: parameters_binding,
+        },
+    ];
+
+    if let Some(lookup_view) = lookup_view {
+        entries.push(wgpu::BindGroupEntry {
+            binding: 3,
+            resource: wgpu::BindingResource::TextureView(lookup_view),
+        });
+    }
+
     device.create_bind_group(&wgpu::BindGroupDescriptor {
         label: Some(label),
         layout: bind_group_layout,
-        entries: &[
-            wgpu::BindGroupEntry {
-                binding: 0,
-                resource: wgpu::BindingResource::TextureView(source_view),
-            },
-            wgpu::BindGroupEntry {
-                binding: 1,
-                resource: wgpu::BindingResource::TextureView(output_view),
-            },
-            wgpu::BindGroupEntry {
-                binding: 2,
-                resource: parameters_binding,
-            },
-        ],
+        entries: &entries,
     })
 }
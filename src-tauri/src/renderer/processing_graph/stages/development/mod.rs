@@ -1,18 +1,26 @@
 mod raster;
 mod raw_bayer;
+mod raw_geometry;
+mod raw_linear;
+mod raw_noise_reduction;
+mod raw_xtrans;
 
 use crate::renderer::input::DevelopmentSource;
 use raster::RasterDevelopmentStage;
 use raw_bayer::RawBayerDevelopmentStage;
+use raw_linear::RawLinearDevelopmentStage;
+use raw_xtrans::RawXTransDevelopmentStage;
 
 /// Source-specific development pipeline for uploaded renderer input.
 ///
-/// Raster input and RAW Bayer input have different source-domain semantics, so
+/// Raster input, RAW CFA input, and linear RAW input have different source-domain semantics, so
 /// development is selected at the graph boundary instead of branching inside one
 /// large shader.
 pub(in crate::renderer::processing_graph) enum DevelopmentStage {
     Raster(RasterDevelopmentStage),
     RawBayer(RawBayerDevelopmentStage),
+    RawXTrans(RawXTransDevelopmentStage),
+    RawLinear(RawLinearDevelopmentStage),
 }
 
 impl DevelopmentStage {
@@ -39,6 +47,20 @@ impl DevelopmentStage {
                 output_view,
                 development_parameters_binding,
             )),
+            DevelopmentSource::RawXTrans6x6 => Self::RawXTrans(RawXTransDevelopmentStage::new(
+                device,
+                queue,
+                source_view,
+                output_view,
+                development_parameters_binding,
+            )),
+            DevelopmentSource::RawLinearRgb => Self::RawLinear(RawLinearDevelopmentStage::new(
+                device,
+                queue,
+                source_view,
+                output_view,
+                development_parameters_binding,
+            )),
         }
     }
 
@@ -47,6 +69,8 @@ impl DevelopmentStage {
         match self {
             Self::Raster(_) => DevelopmentSource::RasterSrgb,
             Self::RawBayer(_) => DevelopmentSource::RawBayer2x2,
+            Self::RawXTrans(_) => DevelopmentSource::RawXTrans6x6,
+            Self::RawLinear(_) => DevelopmentSource::RawLinearRgb,
         }
     }
 
@@ -108,6 +132,22 @@ impl DevelopmentStage {
                 width,
                 height,
             ),
+            Self::RawXTrans(stage) => stage.rebind(
+                device,
+                source_view,
+                output_view,
+                development_parameters_binding,
+                width,
+                height,
+            ),
+            Self::RawLinear(stage) => stage.rebind(
+                device,
+                source_view,
+                output_view,
+                development_parameters_binding,
+                width,
+                height,
+            ),
         }
     }
 
@@ -122,6 +162,8 @@ impl DevelopmentStage {
         match self {
             Self::Raster(stage) => stage.run(device, queue, width, height),
             Self::RawBayer(stage) => stage.run(device, queue, width, height),
+            Self::RawXTrans(stage) => stage.run(device, queue, width, height),
+            Self::RawLinear(stage) => stage.run(device, queue, width, height),
         }
     }
 }
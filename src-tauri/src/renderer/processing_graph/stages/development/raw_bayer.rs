@@ -1,4 +1,6 @@
 use super::super::compute::{ImageComputeStage, ImageComputeStageLabels};
+use super::raw_geometry::RawGeometryStage;
+use super::raw_noise_reduction::RawNoiseReductionStage;
 use crate::renderer::texture::{ImageTexture, IMAGE_TEXTURE_FORMAT};
 
 const NORMALIZE_SHADER_SOURCE: &str = concat!(
@@ -6,6 +8,16 @@ const NORMALIZE_SHADER_SOURCE: &str = concat!(
     include_str!("../../../../shaders/development/raw_normalize_bayer.wgsl"),
 );
 
+const BAD_PIXEL_SHADER_SOURCE: &str = concat!(
+    include_str!("../../../../shaders/development/development_bindings.wgsl"),
+    include_str!("../../../../shaders/development/raw_bad_pixel_bayer.wgsl"),
+);
+
+const HIGHLIGHT_RECONSTRUCTION_SHADER_SOURCE: &str = concat!(
+    include_str!("../../../../shaders/development/development_bindings.wgsl"),
+    include_str!("../../../../shaders/development/raw_highlight_reconstruction_bayer.wgsl"),
+);
+
 const DEMOSAIC_SHADER_SOURCE: &str = concat!(
     include_str!("../../../../shaders/development/development_bindings.wgsl"),
     include_str!("../../../../shaders/development/raw_demosaic_bayer.wgsl"),
@@ -16,6 +28,11 @@ const CAMERA_TO_WORKING_SHADER_SOURCE: &str = concat!(
     include_str!("../../../../shaders/development/raw_camera_to_working.wgsl"),
 );
 
+const CAPTURE_SHARPENING_SHADER_SOURCE: &str = concat!(
+    include_str!("../../../../shaders/development/development_bindings.wgsl"),
+    include_str!("../../../../shaders/development/raw_capture_sharpening_bayer.wgsl"),
+);
+
 const NORMALIZE_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
     bind_group_layout: "RAW Normalize Bayer Stage Bind Group Layout",
     pipeline_layout: "RAW Normalize Bayer Stage Pipeline Layout",
@@ -26,6 +43,26 @@ const NORMALIZE_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
     pass: "RAW Normalize Bayer Stage Pass",
 };
 
+const BAD_PIXEL_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "RAW Bad Pixel Correction Stage Bind Group Layout",
+    pipeline_layout: "RAW Bad Pixel Correction Stage Pipeline Layout",
+    shader: "RAW Bad Pixel Correction Stage Shader",
+    pipeline: "RAW Bad Pixel Correction Stage Pipeline",
+    bind_group: "RAW Bad Pixel Correction Stage Bind Group",
+    encoder: "RAW Bad Pixel Correction Stage Encoder",
+    pass: "RAW Bad Pixel Correction Stage Pass",
+};
+
+const HIGHLIGHT_RECONSTRUCTION_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "RAW Highlight Reconstruction Stage Bind Group Layout",
+    pipeline_layout: "RAW Highlight Reconstruction Stage Pipeline Layout",
+    shader: "RAW Highlight Reconstruction Stage Shader",
+    pipeline: "RAW Highlight Reconstruction Stage Pipeline",
+    bind_group: "RAW Highlight Reconstruction Stage Bind Group",
+    encoder: "RAW Highlight Reconstruction Stage Encoder",
+    pass: "RAW Highlight Reconstruction Stage Pass",
+};
+
 const DEMOSAIC_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
     bind_group_layout: "RAW Demosaic Bayer Stage Bind Group Layout",
     pipeline_layout: "RAW Demosaic Bayer Stage Pipeline Layout",
@@ -46,17 +83,38 @@ const CAMERA_TO_WORKING_LABELS: ImageComputeStageLabels = ImageComputeStageLabel
     pass: "RAW Camera To Working Stage Pass",
 };
 
+const CAPTURE_SHARPENING_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "RAW Capture Sharpening Bayer Stage Bind Group Layout",
+    pipeline_layout: "RAW Capture Sharpening Bayer Stage Pipeline Layout",
+    shader: "RAW Capture Sharpening Bayer Stage Shader",
+    pipeline: "RAW Capture Sharpening Bayer Stage Pipeline",
+    bind_group: "RAW Capture Sharpening Bayer Stage Bind Group",
+    encoder: "RAW Capture Sharpening Bayer Stage Encoder",
+    pass: "RAW Capture Sharpening Bayer Stage Pass",
+};
+
 /// Baseline development pipeline for one-plane 2x2 Bayer RAW input.
 ///
-/// This intentionally excludes highlight reconstruction and post-demosaic
-/// recovery so RAW color, exposure, and display behavior can be verified from a
-/// clean baseline before reintroducing highlight-specific logic.
+/// Bad pixel correction replaces hot, dead, and mapped photosites from their
+/// same-color neighbours before any stage reads across them. Highlight
+/// reconstruction then runs on the corrected mosaic, using the clip mask
+/// written by normalization, so demosaic never interpolates between clipped
+/// and unclipped channels of the same highlight. Capture sharpening runs on
+/// the denoised working-space image so it does not amplify noise.
 pub(in crate::renderer::processing_graph) struct RawBayerDevelopmentStage {
     normalized_bayer_texture: ImageTexture,
+    corrected_bayer_texture: ImageTexture,
+    reconstructed_bayer_texture: ImageTexture,
     camera_rgb_texture: ImageTexture,
+    denoised_texture: ImageTexture,
     normalize_stage: ImageComputeStage,
+    bad_pixel_stage: ImageComputeStage,
+    highlight_reconstruction_stage: ImageComputeStage,
     demosaic_stage: ImageComputeStage,
     camera_to_working_stage: ImageComputeStage,
+    noise_reduction_stage: RawNoiseReductionStage,
+    capture_sharpening_stage: ImageComputeStage,
+    geometry_stage: RawGeometryStage,
 }
 
 impl RawBayerDevelopmentStage {
@@ -69,7 +127,11 @@ impl RawBayerDevelopmentStage {
         development_parameters_binding: wgpu::BindingResource<'_>,
     ) -> Self {
         let normalized_bayer_texture = ImageTexture::new_raw_normalized_bayer_output(device, queue);
+        let corrected_bayer_texture = ImageTexture::new_raw_corrected_bayer_output(device, queue);
+        let reconstructed_bayer_texture =
+            ImageTexture::new_raw_reconstructed_bayer_output(device, queue);
         let camera_rgb_texture = ImageTexture::new_raw_camera_rgb_output(device, queue);
+        let denoised_texture = ImageTexture::new_raw_denoised_output(device, queue);
         let normalize_stage = ImageComputeStage::new(
             device,
             NORMALIZE_LABELS,
@@ -79,31 +141,78 @@ impl RawBayerDevelopmentStage {
             development_parameters_binding.clone(),
             IMAGE_TEXTURE_FORMAT,
         );
+        let bad_pixel_stage = ImageComputeStage::new(
+            device,
+            BAD_PIXEL_LABELS,
+            BAD_PIXEL_SHADER_SOURCE,
+            normalized_bayer_texture.view(),
+            corrected_bayer_texture.view(),
+            development_parameters_binding.clone(),
+            IMAGE_TEXTURE_FORMAT,
+        );
+        let highlight_reconstruction_stage = ImageComputeStage::new(
+            device,
+            HIGHLIGHT_RECONSTRUCTION_LABELS,
+            HIGHLIGHT_RECONSTRUCTION_SHADER_SOURCE,
+            corrected_bayer_texture.view(),
+            reconstructed_bayer_texture.view(),
+            development_parameters_binding.clone(),
+            IMAGE_TEXTURE_FORMAT,
+        );
         let demosaic_stage = ImageComputeStage::new(
             device,
             DEMOSAIC_LABELS,
             DEMOSAIC_SHADER_SOURCE,
-            normalized_bayer_texture.view(),
+            reconstructed_bayer_texture.view(),
             camera_rgb_texture.view(),
             development_parameters_binding.clone(),
             IMAGE_TEXTURE_FORMAT,
         );
+        let geometry_stage = RawGeometryStage::new(
+            device,
+            queue,
+            output_view,
+            development_parameters_binding.clone(),
+        );
+        let capture_sharpening_stage = ImageComputeStage::new(
+            device,
+            CAPTURE_SHARPENING_LABELS,
+            CAPTURE_SHARPENING_SHADER_SOURCE,
+            denoised_texture.view(),
+            geometry_stage.input_view(),
+            development_parameters_binding.clone(),
+            IMAGE_TEXTURE_FORMAT,
+        );
+        let noise_reduction_stage = RawNoiseReductionStage::new(
+            device,
+            queue,
+            denoised_texture.view(),
+            development_parameters_binding.clone(),
+        );
         let camera_to_working_stage = ImageComputeStage::new(
             device,
             CAMERA_TO_WORKING_LABELS,
             CAMERA_TO_WORKING_SHADER_SOURCE,
             camera_rgb_texture.view(),
-            output_view,
+            noise_reduction_stage.input_view(),
             development_parameters_binding,
             IMAGE_TEXTURE_FORMAT,
         );
 
         Self {
             normalized_bayer_texture,
+            corrected_bayer_texture,
+            reconstructed_bayer_texture,
             camera_rgb_texture,
+            denoised_texture,
             normalize_stage,
+            bad_pixel_stage,
+            highlight_reconstruction_stage,
             demosaic_stage,
             camera_to_working_stage,
+            noise_reduction_stage,
+            capture_sharpening_stage,
+            geometry_stage,
         }
     }
 
@@ -119,32 +228,78 @@ impl RawBayerDevelopmentStage {
     ) {
         self.normalized_bayer_texture
             .resize_empty(device, width, height);
+        self.corrected_bayer_texture
+            .resize_empty(device, width, height);
+        self.reconstructed_bayer_texture
+            .resize_empty(device, width, height);
         self.camera_rgb_texture.resize_empty(device, width, height);
+        self.denoised_texture.resize_empty(device, width, height);
         self.normalize_stage.rebind(
             device,
             source_view,
             self.normalized_bayer_texture.view(),
             development_parameters_binding.clone(),
         );
-        self.demosaic_stage.rebind(
+        self.bad_pixel_stage.rebind(
             device,
             self.normalized_bayer_texture.view(),
+            self.corrected_bayer_texture.view(),
+            development_parameters_binding.clone(),
+        );
+        self.highlight_reconstruction_stage.rebind(
+            device,
+            self.corrected_bayer_texture.view(),
+            self.reconstructed_bayer_texture.view(),
+            development_parameters_binding.clone(),
+        );
+        self.demosaic_stage.rebind(
+            device,
+            self.reconstructed_bayer_texture.view(),
             self.camera_rgb_texture.view(),
             development_parameters_binding.clone(),
         );
+        self.geometry_stage.rebind(
+            device,
+            output_view,
+            development_parameters_binding.clone(),
+            width,
+            height,
+        );
+        self.capture_sharpening_stage.rebind(
+            device,
+            self.denoised_texture.view(),
+            self.geometry_stage.input_view(),
+            development_parameters_binding.clone(),
+        );
+        self.noise_reduction_stage.rebind(
+            device,
+            self.denoised_texture.view(),
+            development_parameters_binding.clone(),
+            width,
+            height,
+        );
         self.camera_to_working_stage.rebind(
             device,
             self.camera_rgb_texture.view(),
-            output_view,
+            self.noise_reduction_stage.input_view(),
             development_parameters_binding,
         );
     }
 
-    /// Runs RAW normalization, demosaic, and camera-to-working conversion.
+    /// Runs RAW normalization, bad pixel correction, highlight reconstruction,
+    /// demosaic, camera-to-working conversion, noise reduction, capture
+    /// sharpening, and geometry correction.
     pub(super) fn run(&self, device: &wgpu::Device, queue: &wgpu::Queue, width: u32, height: u32) {
         self.normalize_stage.run(device, queue, width, height);
+        self.bad_pixel_stage.run(device, queue, width, height);
+        self.highlight_reconstruction_stage
+            .run(device, queue, width, height);
         self.demosaic_stage.run(device, queue, width, height);
         self.camera_to_working_stage
             .run(device, queue, width, height);
+        self.noise_reduction_stage.run(device, queue, width, height);
+        self.capture_sharpening_stage
+            .run(device, queue, width, height);
+        self.geometry_stage.run(device, queue, width, height);
     }
 }
@@ -0,0 +1,85 @@
+use super::super::compute::{ImageComputeStage, ImageComputeStageLabels};
+use crate::renderer::texture::{ImageTexture, IMAGE_TEXTURE_FORMAT};
+
+const WARP_RECTILINEAR_SHADER_SOURCE: &str = concat!(
+    include_str!("../../../../shaders/development/development_bindings.wgsl"),
+    include_str!("../../../../shaders/development/raw_warp_rectilinear.wgsl"),
+);
+
+const WARP_RECTILINEAR_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "RAW Warp Rectilinear Stage Bind Group Layout",
+    pipeline_layout: "RAW Warp Rectilinear Stage Pipeline Layout",
+    shader: "RAW Warp Rectilinear Stage Shader",
+    pipeline: "RAW Warp Rectilinear Stage Pipeline",
+    bind_group: "RAW Warp Rectilinear Stage Bind Group",
+    encoder: "RAW Warp Rectilinear Stage Encoder",
+    pass: "RAW Warp Rectilinear Stage Pass",
+};
+
+/// Geometry correction shared by the RAW development pipelines.
+///
+/// RAW pipelines write working-space RGB into this stage's input texture
+/// instead of the development output, and the stage resamples it into the
+/// output through the lens warp. Without a warp it is a copy.
+pub(in crate::renderer::processing_graph) struct RawGeometryStage {
+    working_texture: ImageTexture,
+    warp_rectilinear_stage: ImageComputeStage,
+}
+
+impl RawGeometryStage {
+    /// Creates the geometry stage and its working-space input texture.
+    pub(super) fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        output_view: &wgpu::TextureView,
+        development_parameters_binding: wgpu::BindingResource<'_>,
+    ) -> Self {
+        let working_texture = ImageTexture::new_raw_working_output(device, queue);
+        let warp_rectilinear_stage = ImageComputeStage::new(
+            device,
+            WARP_RECTILINEAR_LABELS,
+            WARP_RECTILINEAR_SHADER_SOURCE,
+            working_texture.view(),
+            output_view,
+            development_parameters_binding,
+            IMAGE_TEXTURE_FORMAT,
+        );
+
+        Self {
+            working_texture,
+            warp_rectilinear_stage,
+        }
+    }
+
+    /// Returns the texture view RAW pipelines write working-space RGB into.
+    pub(super) fn input_view(&self) -> &wgpu::TextureView {
+        self.working_texture.view()
+    }
+
+    /// Resizes the working texture and rebinds the warp stage.
+    ///
+    /// Callers must rebind stages writing into `input_view` afterwards, since
+    /// resizing replaces the view.
+    pub(super) fn rebind(
+        &mut self,
+        device: &wgpu::Device,
+        output_view: &wgpu::TextureView,
+        development_parameters_binding: wgpu::BindingResource<'_>,
+        width: u32,
+        height: u32,
+    ) {
+        self.working_texture.resize_empty(device, width, height);
+        self.warp_rectilinear_stage.rebind(
+            device,
+            self.working_texture.view(),
+            output_view,
+            development_parameters_binding,
+        );
+    }
+
+    /// Runs geometry correction into the development output.
+    pub(super) fn run(&self, device: &wgpu::Device, queue: &wgpu::Queue, width: u32, height: u32) {
+        self.warp_rectilinear_stage
+            .run(device, queue, width, height);
+    }
+}
@@ -0,0 +1,147 @@
+use super::super::compute::{ImageComputeStage, ImageComputeStageLabels};
+use super::raw_geometry::RawGeometryStage;
+use super::raw_noise_reduction::RawNoiseReductionStage;
+use crate::renderer::texture::{ImageTexture, IMAGE_TEXTURE_FORMAT};
+
+const NORMALIZE_SHADER_SOURCE: &str = concat!(
+    include_str!("../../../../shaders/development/development_bindings.wgsl"),
+    include_str!("../../../../shaders/development/raw_normalize_linear_rgb.wgsl"),
+);
+
+const CAMERA_TO_WORKING_SHADER_SOURCE: &str = concat!(
+    include_str!("../../../../shaders/development/development_bindings.wgsl"),
+    include_str!("../../../../shaders/development/raw_camera_to_working.wgsl"),
+);
+
+const NORMALIZE_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "RAW Normalize Linear RGB Stage Bind Group Layout",
+    pipeline_layout: "RAW Normalize Linear RGB Stage Pipeline Layout",
+    shader: "RAW Normalize Linear RGB Stage Shader",
+    pipeline: "RAW Normalize Linear RGB Stage Pipeline",
+    bind_group: "RAW Normalize Linear RGB Stage Bind Group",
+    encoder: "RAW Normalize Linear RGB Stage Encoder",
+    pass: "RAW Normalize Linear RGB Stage Pass",
+};
+
+const CAMERA_TO_WORKING_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "RAW Linear Camera To Working Stage Bind Group Layout",
+    pipeline_layout: "RAW Linear Camera To Working Stage Pipeline Layout",
+    shader: "RAW Linear Camera To Working Stage Shader",
+    pipeline: "RAW Linear Camera To Working Stage Pipeline",
+    bind_group: "RAW Linear Camera To Working Stage Bind Group",
+    encoder: "RAW Linear Camera To Working Stage Encoder",
+    pass: "RAW Linear Camera To Working Stage Pass",
+};
+
+/// Development pipeline for already-demosaiced linear RGB RAW input.
+///
+/// Linear DNGs and other multi-sample sources skip demosaic, but still go
+/// through sensor normalization, white balance, and the camera matrix so they
+/// develop in the same scene-referred working space as CFA sources.
+pub(in crate::renderer::processing_graph) struct RawLinearDevelopmentStage {
+    camera_rgb_texture: ImageTexture,
+    normalize_stage: ImageComputeStage,
+    camera_to_working_stage: ImageComputeStage,
+    noise_reduction_stage: RawNoiseReductionStage,
+    geometry_stage: RawGeometryStage,
+}
+
+impl RawLinearDevelopmentStage {
+    /// Creates RAW linear RGB development stages and their intermediate texture.
+    pub(super) fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        development_parameters_binding: wgpu::BindingResource<'_>,
+    ) -> Self {
+        let camera_rgb_texture = ImageTexture::new_raw_camera_rgb_output(device, queue);
+        let normalize_stage = ImageComputeStage::new(
+            device,
+            NORMALIZE_LABELS,
+            NORMALIZE_SHADER_SOURCE,
+            source_view,
+            camera_rgb_texture.view(),
+            development_parameters_binding.clone(),
+            IMAGE_TEXTURE_FORMAT,
+        );
+        let geometry_stage = RawGeometryStage::new(
+            device,
+            queue,
+            output_view,
+            development_parameters_binding.clone(),
+        );
+        let noise_reduction_stage = RawNoiseReductionStage::new(
+            device,
+            queue,
+            geometry_stage.input_view(),
+            development_parameters_binding.clone(),
+        );
+        let camera_to_working_stage = ImageComputeStage::new(
+            device,
+            CAMERA_TO_WORKING_LABELS,
+            CAMERA_TO_WORKING_SHADER_SOURCE,
+            camera_rgb_texture.view(),
+            noise_reduction_stage.input_view(),
+            development_parameters_binding,
+            IMAGE_TEXTURE_FORMAT,
+        );
+
+        Self {
+            camera_rgb_texture,
+            normalize_stage,
+            camera_to_working_stage,
+            noise_reduction_stage,
+            geometry_stage,
+        }
+    }
+
+    /// Rebinds this pipeline after graph texture resources are replaced.
+    pub(super) fn rebind(
+        &mut self,
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        development_parameters_binding: wgpu::BindingResource<'_>,
+        width: u32,
+        height: u32,
+    ) {
+        self.camera_rgb_texture.resize_empty(device, width, height);
+        self.normalize_stage.rebind(
+            device,
+            source_view,
+            self.camera_rgb_texture.view(),
+            development_parameters_binding.clone(),
+        );
+        self.geometry_stage.rebind(
+            device,
+            output_view,
+            development_parameters_binding.clone(),
+            width,
+            height,
+        );
+        self.noise_reduction_stage.rebind(
+            device,
+            self.geometry_stage.input_view(),
+            development_parameters_binding.clone(),
+            width,
+            height,
+        );
+        self.camera_to_working_stage.rebind(
+            device,
+            self.camera_rgb_texture.view(),
+            self.noise_reduction_stage.input_view(),
+            development_parameters_binding,
+        );
+    }
+
+    /// Runs RAW normalization, camera-to-working conversion, noise reduction,
+    /// and geometry correction.
+    pub(super) fn run(&self, device: &wgpu::Device, queue: &wgpu::Queue, width: u32, height: u32) {
+        self.normalize_stage.run(device, queue, width, height);
+        self.camera_to_working_stage
+            .run(device, queue, width, height);
+        self.noise_reduction_stage.run(device, queue, width, height);
+        self.geometry_stage.run(device, queue, width, height);
+    }
+}
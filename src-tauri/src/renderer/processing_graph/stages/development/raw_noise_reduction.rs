@@ -0,0 +1,121 @@
+use super::super::compute::{ImageComputeStage, ImageComputeStageLabels};
+use crate::renderer::texture::{ImageTexture, IMAGE_TEXTURE_FORMAT};
+
+const CHROMA_SHADER_SOURCE: &str = concat!(
+    include_str!("../../../../shaders/development/development_bindings.wgsl"),
+    include_str!("../../../../shaders/development/raw_noise_reduction_chroma.wgsl"),
+);
+
+const LUMINANCE_SHADER_SOURCE: &str = concat!(
+    include_str!("../../../../shaders/development/development_bindings.wgsl"),
+    include_str!("../../../../shaders/development/raw_noise_reduction_luminance.wgsl"),
+);
+
+const CHROMA_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "RAW Chroma Noise Reduction Stage Bind Group Layout",
+    pipeline_layout: "RAW Chroma Noise Reduction Stage Pipeline Layout",
+    shader: "RAW Chroma Noise Reduction Stage Shader",
+    pipeline: "RAW Chroma Noise Reduction Stage Pipeline",
+    bind_group: "RAW Chroma Noise Reduction Stage Bind Group",
+    encoder: "RAW Chroma Noise Reduction Stage Encoder",
+    pass: "RAW Chroma Noise Reduction Stage Pass",
+};
+
+const LUMINANCE_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "RAW Luminance Noise Reduction Stage Bind Group Layout",
+    pipeline_layout: "RAW Luminance Noise Reduction Stage Pipeline Layout",
+    shader: "RAW Luminance Noise Reduction Stage Shader",
+    pipeline: "RAW Luminance Noise Reduction Stage Pipeline",
+    bind_group: "RAW Luminance Noise Reduction Stage Bind Group",
+    encoder: "RAW Luminance Noise Reduction Stage Encoder",
+    pass: "RAW Luminance Noise Reduction Stage Pass",
+};
+
+/// Noise reduction shared by the RAW development pipelines.
+///
+/// RAW pipelines write working-space RGB into this stage's input texture.
+/// Chroma noise is reduced first, then luminance noise, and the result is
+/// written to the output view. Either pass is a copy when its amount is zero.
+pub(in crate::renderer::processing_graph) struct RawNoiseReductionStage {
+    working_texture: ImageTexture,
+    chroma_texture: ImageTexture,
+    chroma_stage: ImageComputeStage,
+    luminance_stage: ImageComputeStage,
+}
+
+impl RawNoiseReductionStage {
+    /// Creates the noise reduction stage and its working-space textures.
+    pub(super) fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        output_view: &wgpu::TextureView,
+        development_parameters_binding: wgpu::BindingResource<'_>,
+    ) -> Self {
+        let working_texture = ImageTexture::new_raw_noise_reduction_input(device, queue);
+        let chroma_texture = ImageTexture::new_raw_chroma_denoised_output(device, queue);
+        let chroma_stage = ImageComputeStage::new(
+            device,
+            CHROMA_LABELS,
+            CHROMA_SHADER_SOURCE,
+            working_texture.view(),
+            chroma_texture.view(),
+            development_parameters_binding.clone(),
+            IMAGE_TEXTURE_FORMAT,
+        );
+        let luminance_stage = ImageComputeStage::new(
+            device,
+            LUMINANCE_LABELS,
+            LUMINANCE_SHADER_SOURCE,
+            chroma_texture.view(),
+            output_view,
+            development_parameters_binding,
+            IMAGE_TEXTURE_FORMAT,
+        );
+
+        Self {
+            working_texture,
+            chroma_texture,
+            chroma_stage,
+            luminance_stage,
+        }
+    }
+
+    /// Returns the texture view RAW pipelines write working-space RGB into.
+    pub(super) fn input_view(&self) -> &wgpu::TextureView {
+        self.working_texture.view()
+    }
+
+    /// Resizes the stage textures and rebinds both passes.
+    ///
+    /// Callers must rebind stages writing into `input_view` afterwards, since
+    /// resizing replaces the view.
+    pub(super) fn rebind(
+        &mut self,
+        device: &wgpu::Device,
+        output_view: &wgpu::TextureView,
+        development_parameters_binding: wgpu::BindingResource<'_>,
+        width: u32,
+        height: u32,
+    ) {
+        self.working_texture.resize_empty(device, width, height);
+        self.chroma_texture.resize_empty(device, width, height);
+        self.chroma_stage.rebind(
+            device,
+            self.working_texture.view(),
+            self.chroma_texture.view(),
+            development_parameters_binding.clone(),
+        );
+        self.luminance_stage.rebind(
+            device,
+            self.chroma_texture.view(),
+            output_view,
+            development_parameters_binding,
+        );
+    }
+
+    /// Runs chroma and then luminance noise reduction.
+    pub(super) fn run(&self, device: &wgpu::Device, queue: &wgpu::Queue, width: u32, height: u32) {
+        self.chroma_stage.run(device, queue, width, height);
+        self.luminance_stage.run(device, queue, width, height);
+    }
+}
@@ -0,0 +1,224 @@
+use super::super::compute::{ImageComputeStage, ImageComputeStageLabels};
+use super::raw_geometry::RawGeometryStage;
+use super::raw_noise_reduction::RawNoiseReductionStage;
+use crate::renderer::texture::{ImageTexture, IMAGE_TEXTURE_FORMAT};
+
+const NORMALIZE_SHADER_SOURCE: &str = concat!(
+    include_str!("../../../../shaders/development/development_bindings.wgsl"),
+    include_str!("../../../../shaders/development/raw_normalize_xtrans.wgsl"),
+);
+
+const GREEN_SHADER_SOURCE: &str = concat!(
+    include_str!("../../../../shaders/development/development_bindings.wgsl"),
+    include_str!("../../../../shaders/development/raw_demosaic_xtrans_green.wgsl"),
+);
+
+const DEMOSAIC_SHADER_SOURCE: &str = concat!(
+    include_str!("../../../../shaders/development/development_bindings.wgsl"),
+    include_str!("../../../../shaders/development/raw_demosaic_xtrans.wgsl"),
+);
+
+const CAMERA_TO_WORKING_SHADER_SOURCE: &str = concat!(
+    include_str!("../../../../shaders/development/development_bindings.wgsl"),
+    include_str!("../../../../shaders/development/raw_camera_to_working.wgsl"),
+);
+
+const NORMALIZE_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "RAW Normalize X-Trans Stage Bind Group Layout",
+    pipeline_layout: "RAW Normalize X-Trans Stage Pipeline Layout",
+    shader: "RAW Normalize X-Trans Stage Shader",
+    pipeline: "RAW Normalize X-Trans Stage Pipeline",
+    bind_group: "RAW Normalize X-Trans Stage Bind Group",
+    encoder: "RAW Normalize X-Trans Stage Encoder",
+    pass: "RAW Normalize X-Trans Stage Pass",
+};
+
+const GREEN_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "RAW X-Trans Green Stage Bind Group Layout",
+    pipeline_layout: "RAW X-Trans Green Stage Pipeline Layout",
+    shader: "RAW X-Trans Green Stage Shader",
+    pipeline: "RAW X-Trans Green Stage Pipeline",
+    bind_group: "RAW X-Trans Green Stage Bind Group",
+    encoder: "RAW X-Trans Green Stage Encoder",
+    pass: "RAW X-Trans Green Stage Pass",
+};
+
+const DEMOSAIC_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "RAW Demosaic X-Trans Stage Bind Group Layout",
+    pipeline_layout: "RAW Demosaic X-Trans Stage Pipeline Layout",
+    shader: "RAW Demosaic X-Trans Stage Shader",
+    pipeline: "RAW Demosaic X-Trans Stage Pipeline",
+    bind_group: "RAW Demosaic X-Trans Stage Bind Group",
+    encoder: "RAW Demosaic X-Trans Stage Encoder",
+    pass: "RAW Demosaic X-Trans Stage Pass",
+};
+
+const CAMERA_TO_WORKING_LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "RAW X-Trans Camera To Working Stage Bind Group Layout",
+    pipeline_layout: "RAW X-Trans Camera To Working Stage Pipeline Layout",
+    shader: "RAW X-Trans Camera To Working Stage Shader",
+    pipeline: "RAW X-Trans Camera To Working Stage Pipeline",
+    bind_group: "RAW X-Trans Camera To Working Stage Bind Group",
+    encoder: "RAW X-Trans Camera To Working Stage Encoder",
+    pass: "RAW X-Trans Camera To Working Stage Pass",
+};
+
+/// Development pipeline for one-plane 6x6 Fujifilm X-Trans RAW input.
+///
+/// Demosaic follows Markesteijn's one-pass structure: green is interpolated
+/// first along four directions and blended by directional gradient, then red
+/// and blue are rebuilt as color differences against that green. Bad pixel
+/// correction and highlight reconstruction are Bayer-only for now.
+pub(in crate::renderer::processing_graph) struct RawXTransDevelopmentStage {
+    normalized_xtrans_texture: ImageTexture,
+    green_texture: ImageTexture,
+    camera_rgb_texture: ImageTexture,
+    normalize_stage: ImageComputeStage,
+    green_stage: ImageComputeStage,
+    demosaic_stage: ImageComputeStage,
+    camera_to_working_stage: ImageComputeStage,
+    noise_reduction_stage: RawNoiseReductionStage,
+    geometry_stage: RawGeometryStage,
+}
+
+impl RawXTransDevelopmentStage {
+    /// Creates RAW X-Trans development stages and their intermediate textures.
+    pub(super) fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        development_parameters_binding: wgpu::BindingResource<'_>,
+    ) -> Self {
+        let normalized_xtrans_texture =
+            ImageTexture::new_raw_normalized_xtrans_output(device, queue);
+        let green_texture = ImageTexture::new_raw_xtrans_green_output(device, queue);
+        let camera_rgb_texture = ImageTexture::new_raw_camera_rgb_output(device, queue);
+        let normalize_stage = ImageComputeStage::new(
+            device,
+            NORMALIZE_LABELS,
+            NORMALIZE_SHADER_SOURCE,
+            source_view,
+            normalized_xtrans_texture.view(),
+            development_parameters_binding.clone(),
+            IMAGE_TEXTURE_FORMAT,
+        );
+        let green_stage = ImageComputeStage::new(
+            device,
+            GREEN_LABELS,
+            GREEN_SHADER_SOURCE,
+            normalized_xtrans_texture.view(),
+            green_texture.view(),
+            development_parameters_binding.clone(),
+            IMAGE_TEXTURE_FORMAT,
+        );
+        let demosaic_stage = ImageComputeStage::new(
+            device,
+            DEMOSAIC_LABELS,
+            DEMOSAIC_SHADER_SOURCE,
+            green_texture.view(),
+            camera_rgb_texture.view(),
+            development_parameters_binding.clone(),
+            IMAGE_TEXTURE_FORMAT,
+        );
+        let geometry_stage = RawGeometryStage::new(
+            device,
+            queue,
+            output_view,
+            development_parameters_binding.clone(),
+        );
+        let noise_reduction_stage = RawNoiseReductionStage::new(
+            device,
+            queue,
+            geometry_stage.input_view(),
+            development_parameters_binding.clone(),
+        );
+        let camera_to_working_stage = ImageComputeStage::new(
+            device,
+            CAMERA_TO_WORKING_LABELS,
+            CAMERA_TO_WORKING_SHADER_SOURCE,
+            camera_rgb_texture.view(),
+            noise_reduction_stage.input_view(),
+            development_parameters_binding,
+            IMAGE_TEXTURE_FORMAT,
+        );
+
+        Self {
+            normalized_xtrans_texture,
+            green_texture,
+            camera_rgb_texture,
+            normalize_stage,
+            green_stage,
+            demosaic_stage,
+            camera_to_working_stage,
+            noise_reduction_stage,
+            geometry_stage,
+        }
+    }
+
+    /// Rebinds this pipeline after graph texture resources are replaced.
+    pub(super) fn rebind(
+        &mut self,
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        development_parameters_binding: wgpu::BindingResource<'_>,
+        width: u32,
+        height: u32,
+    ) {
+        self.normalized_xtrans_texture
+            .resize_empty(device, width, height);
+        self.green_texture.resize_empty(device, width, height);
+        self.camera_rgb_texture.resize_empty(device, width, height);
+        self.normalize_stage.rebind(
+            device,
+            source_view,
+            self.normalized_xtrans_texture.view(),
+            development_parameters_binding.clone(),
+        );
+        self.green_stage.rebind(
+            device,
+            self.normalized_xtrans_texture.view(),
+            self.green_texture.view(),
+            development_parameters_binding.clone(),
+        );
+        self.demosaic_stage.rebind(
+            device,
+            self.green_texture.view(),
+            self.camera_rgb_texture.view(),
+            development_parameters_binding.clone(),
+        );
+        self.geometry_stage.rebind(
+            device,
+            output_view,
+            development_parameters_binding.clone(),
+            width,
+            height,
+        );
+        self.noise_reduction_stage.rebind(
+            device,
+            self.geometry_stage.input_view(),
+            development_parameters_binding.clone(),
+            width,
+            height,
+        );
+        self.camera_to_working_stage.rebind(
+            device,
+            self.camera_rgb_texture.view(),
+            self.noise_reduction_stage.input_view(),
+            development_parameters_binding,
+        );
+    }
+
+    /// Runs RAW normalization, green interpolation, chroma demosaic,
+    /// camera-to-working conversion, noise reduction, and geometry correction.
+    pub(super) fn run(&self, device: &wgpu::Device, queue: &wgpu::Queue, width: u32, height: u32) {
+        self.normalize_stage.run(device, queue, width, height);
+        self.green_stage.run(device, queue, width, height);
+        self.demosaic_stage.run(device, queue, width, height);
+        self.camera_to_working_stage
+            .run(device, queue, width, height);
+        self.noise_reduction_stage.run(device, queue, width, height);
+        self.geometry_stage.run(device, queue, width, height);
+    }
+}
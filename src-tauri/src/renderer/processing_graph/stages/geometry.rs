@@ -0,0 +1,67 @@
+use super::compute::{ImageComputeStage, ImageComputeStageLabels};
+use crate::renderer::texture::IMAGE_TEXTURE_FORMAT;
+
+const LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "Geometry Stage Bind Group Layout",
+    pipeline_layout: "Geometry Stage Pipeline Layout",
+    shader: "Geometry Stage Shader",
+    pipeline: "Geometry Stage Pipeline",
+    bind_group: "Geometry Stage Bind Group",
+    encoder: "Geometry Stage Encoder",
+    pass: "Geometry Stage Pass",
+};
+
+/// Compute stage that resamples adjusted image data through the crop, straighten,
+/// rotation, and flip transform.
+pub(in crate::renderer::processing_graph) struct GeometryStage {
+    stage: ImageComputeStage,
+}
+
+impl GeometryStage {
+    /// Creates the geometry stage and binds its initial source, output, and parameters.
+    pub(in crate::renderer::processing_graph) fn new(
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        geometry_parameters_binding: wgpu::BindingResource<'_>,
+    ) -> Self {
+        let stage = ImageComputeStage::new(
+            device,
+            LABELS,
+            include_str!("../../../shaders/geometry.wgsl"),
+            source_view,
+            output_view,
+            geometry_parameters_binding,
+            IMAGE_TEXTURE_FORMAT,
+        );
+
+        Self { stage }
+    }
+
+    /// Rebinds this stage after graph texture resources are replaced.
+    pub(in crate::renderer::processing_graph) fn rebind(
+        &mut self,
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        geometry_parameters_binding: wgpu::BindingResource<'_>,
+    ) {
+        self.stage.rebind(
+            device,
+            source_view,
+            output_view,
+            geometry_parameters_binding,
+        );
+    }
+
+    /// Runs the geometry compute stage over the current cropped output dimensions.
+    pub(in crate::renderer::processing_graph) fn run(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        width: u32,
+        height: u32,
+    ) {
+        self.stage.run(device, queue, width, height);
+    }
+}
@@ -0,0 +1,66 @@
+use super::compute::{ImageComputeStage, ImageComputeStageLabels};
+use crate::renderer::texture::IMAGE_TEXTURE_FORMAT;
+
+const LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "Lens Correction Stage Bind Group Layout",
+    pipeline_layout: "Lens Correction Stage Pipeline Layout",
+    shader: "Lens Correction Stage Shader",
+    pipeline: "Lens Correction Stage Pipeline",
+    bind_group: "Lens Correction Stage Bind Group",
+    encoder: "Lens Correction Stage Encoder",
+    pass: "Lens Correction Stage Pass",
+};
+
+/// Compute stage that corrects lens distortion, lateral chromatic aberration, and vignetting.
+pub(in crate::renderer::processing_graph) struct LensCorrectionStage {
+    stage: ImageComputeStage,
+}
+
+impl LensCorrectionStage {
+    /// Creates the lens correction stage and binds its initial source, output, and parameters.
+    pub(in crate::renderer::processing_graph) fn new(
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        lens_correction_parameters_binding: wgpu::BindingResource<'_>,
+    ) -> Self {
+        let stage = ImageComputeStage::new(
+            device,
+            LABELS,
+            include_str!("../../../shaders/lens_correction.wgsl"),
+            source_view,
+            output_view,
+            lens_correction_parameters_binding,
+            IMAGE_TEXTURE_FORMAT,
+        );
+
+        Self { stage }
+    }
+
+    /// Rebinds this stage after graph texture resources are replaced.
+    pub(in crate::renderer::processing_graph) fn rebind(
+        &mut self,
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        lens_correction_parameters_binding: wgpu::BindingResource<'_>,
+    ) {
+        self.stage.rebind(
+            device,
+            source_view,
+            output_view,
+            lens_correction_parameters_binding,
+        );
+    }
+
+    /// Runs the lens correction compute stage over the current graph output dimensions.
+    pub(in crate::renderer::processing_graph) fn run(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        width: u32,
+        height: u32,
+    ) {
+        self.stage.run(device, queue, width, height);
+    }
+}
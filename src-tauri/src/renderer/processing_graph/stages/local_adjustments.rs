@@ -0,0 +1,73 @@
+use super::compute::{ImageComputeStage, ImageComputeStageLabels};
+use crate::renderer::texture::IMAGE_TEXTURE_FORMAT;
+
+const LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "Local Adjustment Stage Bind Group Layout",
+    pipeline_layout: "Local Adjustment Stage Pipeline Layout",
+    shader: "Local Adjustment Stage Shader",
+    pipeline: "Local Adjustment Stage Pipeline",
+    bind_group: "Local Adjustment Stage Bind Group",
+    encoder: "Local Adjustment Stage Encoder",
+    pass: "Local Adjustment Stage Pass",
+};
+
+/// Compute stage that blends masked local adjustment deltas over the globally
+/// adjusted image.
+pub(in crate::renderer::processing_graph) struct LocalAdjustmentStage {
+    stage: ImageComputeStage,
+}
+
+impl LocalAdjustmentStage {
+    /// Creates the local adjustment stage and binds its initial source, output,
+    /// parameters, and mask texture.
+    pub(in crate::renderer::processing_graph) fn new(
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        local_adjustment_parameters_binding: wgpu::BindingResource<'_>,
+        mask_view: &wgpu::TextureView,
+    ) -> Self {
+        let stage = ImageComputeStage::new_with_lookup(
+            device,
+            LABELS,
+            include_str!("../../../shaders/local_adjustments.wgsl"),
+            source_view,
+            output_view,
+            local_adjustment_parameters_binding,
+            mask_view,
+            wgpu::TextureViewDimension::D2,
+            IMAGE_TEXTURE_FORMAT,
+        );
+
+        Self { stage }
+    }
+
+    /// Rebinds this stage after graph texture resources are replaced.
+    pub(in crate::renderer::processing_graph) fn rebind(
+        &mut self,
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        local_adjustment_parameters_binding: wgpu::BindingResource<'_>,
+        mask_view: &wgpu::TextureView,
+    ) {
+        self.stage.rebind_with_lookup(
+            device,
+            source_view,
+            output_view,
+            local_adjustment_parameters_binding,
+            mask_view,
+        );
+    }
+
+    /// Runs the local adjustment compute stage over the current graph output dimensions.
+    pub(in crate::renderer::processing_graph) fn run(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        width: u32,
+        height: u32,
+    ) {
+        self.stage.run(device, queue, width, height);
+    }
+}
@@ -0,0 +1,73 @@
+use super::compute::{ImageComputeStage, ImageComputeStageLabels};
+use crate::renderer::texture::IMAGE_TEXTURE_FORMAT;
+
+const LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "Mask Stage Bind Group Layout",
+    pipeline_layout: "Mask Stage Pipeline Layout",
+    shader: "Mask Stage Shader",
+    pipeline: "Mask Stage Pipeline",
+    bind_group: "Mask Stage Bind Group",
+    encoder: "Mask Stage Encoder",
+    pass: "Mask Stage Pass",
+};
+
+/// Compute stage that rasterizes local adjustment masks into one RGBA mask
+/// texture, one mask per channel.
+pub(in crate::renderer::processing_graph) struct MaskStage {
+    stage: ImageComputeStage,
+}
+
+impl MaskStage {
+    /// Creates the mask stage and binds its initial source, mask output, parameters,
+    /// and brush dabs.
+    pub(in crate::renderer::processing_graph) fn new(
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        mask_parameters_binding: wgpu::BindingResource<'_>,
+        brush_dabs_view: &wgpu::TextureView,
+    ) -> Self {
+        let stage = ImageComputeStage::new_with_lookup(
+            device,
+            LABELS,
+            include_str!("../../../shaders/mask.wgsl"),
+            source_view,
+            output_view,
+            mask_parameters_binding,
+            brush_dabs_view,
+            wgpu::TextureViewDimension::D2,
+            IMAGE_TEXTURE_FORMAT,
+        );
+
+        Self { stage }
+    }
+
+    /// Rebinds this stage after graph texture resources are replaced.
+    pub(in crate::renderer::processing_graph) fn rebind(
+        &mut self,
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        mask_parameters_binding: wgpu::BindingResource<'_>,
+        brush_dabs_view: &wgpu::TextureView,
+    ) {
+        self.stage.rebind_with_lookup(
+            device,
+            source_view,
+            output_view,
+            mask_parameters_binding,
+            brush_dabs_view,
+        );
+    }
+
+    /// Runs the mask compute stage over the current mask texture dimensions.
+    pub(in crate::renderer::processing_graph) fn run(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        width: u32,
+        height: u32,
+    ) {
+        self.stage.run(device, queue, width, height);
+    }
+}
@@ -1,8 +1,18 @@
 mod adjustments;
 mod compute;
 mod development;
+mod geometry;
+mod lens_correction;
+mod local_adjustments;
+mod masks;
 mod output_transform;
+mod retouch;
 
 pub(super) use adjustments::AdjustmentStage;
 pub(super) use development::DevelopmentStage;
+pub(super) use geometry::GeometryStage;
+pub(super) use lens_correction::LensCorrectionStage;
+pub(super) use local_adjustments::LocalAdjustmentStage;
+pub(super) use masks::MaskStage;
 pub(super) use output_transform::OutputTransformStage;
+pub(super) use retouch::RetouchStage;
@@ -17,20 +17,24 @@ pub(in crate::renderer::processing_graph) struct OutputTransformStage {
 }
 
 impl OutputTransformStage {
-    /// Creates the output transform stage and binds its initial source, output, and parameters.
+    /// Creates the output transform stage and binds its initial source, output,
+    /// parameters, and look lookup texture.
     pub(in crate::renderer::processing_graph) fn new(
         device: &wgpu::Device,
         source_view: &wgpu::TextureView,
         output_view: &wgpu::TextureView,
         output_transform_parameters_binding: wgpu::BindingResource<'_>,
+        look_view: &wgpu::TextureView,
     ) -> Self {
-        let stage = ImageComputeStage::new(
+        let stage = ImageComputeStage::new_with_lookup(
             device,
             LABELS,
             include_str!("../../../shaders/output_transform.wgsl"),
             source_view,
             output_view,
             output_transform_parameters_binding,
+            look_view,
+            wgpu::TextureViewDimension::D3,
             DISPLAY_TEXTURE_FORMAT,
         );
 
@@ -44,12 +48,14 @@ impl OutputTransformStage {
         source_view: &wgpu::TextureView,
         output_view: &wgpu::TextureView,
         output_transform_parameters_binding: wgpu::BindingResource<'_>,
+        look_view: &wgpu::TextureView,
     ) {
-        self.stage.rebind(
+        self.stage.rebind_with_lookup(
             device,
             source_view,
             output_view,
             output_transform_parameters_binding,
+            look_view,
         );
     }
 
@@ -0,0 +1,62 @@
+use super::compute::{ImageComputeStage, ImageComputeStageLabels};
+use crate::renderer::texture::IMAGE_TEXTURE_FORMAT;
+
+const LABELS: ImageComputeStageLabels = ImageComputeStageLabels {
+    bind_group_layout: "Retouch Stage Bind Group Layout",
+    pipeline_layout: "Retouch Stage Pipeline Layout",
+    shader: "Retouch Stage Shader",
+    pipeline: "Retouch Stage Pipeline",
+    bind_group: "Retouch Stage Bind Group",
+    encoder: "Retouch Stage Encoder",
+    pass: "Retouch Stage Pass",
+};
+
+/// Compute stage that clones or heals recipe retouch spots on the working-space image.
+pub(in crate::renderer::processing_graph) struct RetouchStage {
+    stage: ImageComputeStage,
+}
+
+impl RetouchStage {
+    /// Creates the retouch stage and binds its initial source, output, and parameters.
+    pub(in crate::renderer::processing_graph) fn new(
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        retouch_parameters_binding: wgpu::BindingResource<'_>,
+    ) -> Self {
+        let stage = ImageComputeStage::new(
+            device,
+            LABELS,
+            include_str!("../../../shaders/retouch.wgsl"),
+            source_view,
+            output_view,
+            retouch_parameters_binding,
+            IMAGE_TEXTURE_FORMAT,
+        );
+
+        Self { stage }
+    }
+
+    /// Rebinds this stage after graph texture resources are replaced.
+    pub(in crate::renderer::processing_graph) fn rebind(
+        &mut self,
+        device: &wgpu::Device,
+        source_view: &wgpu::TextureView,
+        output_view: &wgpu::TextureView,
+        retouch_parameters_binding: wgpu::BindingResource<'_>,
+    ) {
+        self.stage
+            .rebind(device, source_view, output_view, retouch_parameters_binding);
+    }
+
+    /// Runs the retouch compute stage over the current graph output dimensions.
+    pub(in crate::renderer::processing_graph) fn run(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        width: u32,
+        height: u32,
+    ) {
+        self.stage.run(device, queue, width, height);
+    }
+}
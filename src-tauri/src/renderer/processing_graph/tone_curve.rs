@@ -0,0 +1,279 @@
+use crate::core::editing::{CurvePoint, ParametricCurve, ToneCurve};
+
+/// Number of entries in the baked tone curve lookup texture.
+const TONE_CURVE_LUT_SIZE: u32 = 1024;
+
+/// Largest output shift a parametric region can apply at full strength.
+const PARAMETRIC_RANGE: f32 = 0.25;
+
+/// Smallest gap kept between parametric split points.
+const MIN_SPLIT_GAP: f32 = 0.05;
+
+/// Graph-owned 1D lookup texture holding the baked tone curve.
+///
+/// Entry `i` maps curve input `i / (size - 1)` to the output of the parametric,
+/// RGB, and per-channel curves, with red, green, and blue stored in the
+/// matching texel channels. The curve is rebaked only when it changes.
+pub(super) struct ToneCurveLut {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    curve: ToneCurve,
+}
+
+impl ToneCurveLut {
+    /// Creates the lookup texture initialized with the neutral curve.
+    pub(super) fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Tone Curve LUT Texture"),
+            size: wgpu::Extent3d {
+                width: TONE_CURVE_LUT_SIZE,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D1,
+            format: wgpu::TextureFormat::Rgba32Float,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let curve = ToneCurve::default();
+
+        upload_lut(queue, &texture, &bake_tone_curve(&curve));
+
+        Self {
+            texture,
+            view,
+            curve,
+        }
+    }
+
+    /// Rebakes and uploads the lookup texture when the curve changed.
+    pub(super) fn update(&mut self, queue: &wgpu::Queue, curve: &ToneCurve) {
+        if *curve == self.curve {
+            return;
+        }
+
+        self.curve = curve.clone();
+
+        upload_lut(queue, &self.texture, &bake_tone_curve(&self.curve));
+    }
+
+    /// Returns the lookup texture view bound by the adjustment stage.
+    pub(super) fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+}
+
+/// Returns whether a tone curve leaves every value unchanged.
+pub(super) fn is_neutral_tone_curve(curve: &ToneCurve) -> bool {
+    let parametric = curve.parametric;
+
+    parametric.shadows == 0.0
+        && parametric.darks == 0.0
+        && parametric.lights == 0.0
+        && parametric.highlights == 0.0
+        && is_neutral_point_curve(&curve.rgb)
+        && is_neutral_point_curve(&curve.red)
+        && is_neutral_point_curve(&curve.green)
+        && is_neutral_point_curve(&curve.blue)
+}
+
+fn is_neutral_point_curve(points: &[CurvePoint]) -> bool {
+    points.len() < 2 || points.iter().all(|point| point.x == point.y)
+}
+
+/// Bakes the composed curve into packed RGBA texels.
+fn bake_tone_curve(curve: &ToneCurve) -> Vec<f32> {
+    let rgb = PointCurve::new(&curve.rgb);
+    let channels = [
+        PointCurve::new(&curve.red),
+        PointCurve::new(&curve.green),
+        PointCurve::new(&curve.blue),
+    ];
+    let mut texels = Vec::with_capacity(TONE_CURVE_LUT_SIZE as usize * 4);
+    let mut previous = 0.0f32;
+
+    for index in 0..TONE_CURVE_LUT_SIZE {
+        let input = index as f32 / (TONE_CURVE_LUT_SIZE - 1) as f32;
+
+        // Keep the parametric curve monotonic so region sliders cannot fold tones.
+        let parametric = evaluate_parametric(&curve.parametric, input).max(previous);
+        previous = parametric;
+
+        let shared = rgb.evaluate(parametric);
+
+        for channel in &channels {
+            texels.push(channel.evaluate(shared));
+        }
+
+        texels.push(1.0);
+    }
+
+    texels
+}
+
+/// Evaluates the parametric curve as the identity plus weighted region bumps.
+///
+/// Each region's bump spans the region and its neighbours, so adjacent regions
+/// blend smoothly and the black and white points stay fixed.
+fn evaluate_parametric(parametric: &ParametricCurve, input: f32) -> f32 {
+    let shadow_split = parametric
+        .shadow_split
+        .clamp(MIN_SPLIT_GAP, 1.0 - 3.0 * MIN_SPLIT_GAP);
+    let midtone_split = parametric
+        .midtone_split
+        .clamp(shadow_split + MIN_SPLIT_GAP, 1.0 - 2.0 * MIN_SPLIT_GAP);
+    let highlight_split = parametric
+        .highlight_split
+        .clamp(midtone_split + MIN_SPLIT_GAP, 1.0 - MIN_SPLIT_GAP);
+    let edges = [0.0, shadow_split, midtone_split, highlight_split, 1.0];
+    let amounts = [
+        parametric.shadows,
+        parametric.darks,
+        parametric.lights,
+        parametric.highlights,
+    ];
+    let mut output = input;
+
+    for (region, amount) in amounts.iter().enumerate() {
+        if *amount == 0.0 {
+            continue;
+        }
+
+        let start = edges[region.saturating_sub(1)];
+        let end = edges[(region + 2).min(edges.len() - 1)];
+        let t = ((input - start) / (end - start)).clamp(0.0, 1.0);
+        let bump = (std::f32::consts::PI * t).sin().powi(2);
+
+        output += amount.clamp(-1.0, 1.0) * PARAMETRIC_RANGE * bump;
+    }
+
+    output.clamp(0.0, 1.0)
+}
+
+/// Monotone cubic interpolation through sorted control points.
+///
+/// Uses Fritsch-Carlson tangents so the curve never overshoots between points.
+/// Inputs outside the first and last points hold the endpoint outputs.
+struct PointCurve {
+    points: Vec<(f32, f32)>,
+    tangents: Vec<f32>,
+}
+
+impl PointCurve {
+    fn new(control_points: &[CurvePoint]) -> Self {
+        let mut points: Vec<(f32, f32)> = control_points
+            .iter()
+            .map(|point| (point.x.clamp(0.0, 1.0), point.y.clamp(0.0, 1.0)))
+            .collect();
+
+        points.sort_by(|left, right| left.0.total_cmp(&right.0));
+        points.dedup_by(|right, left| right.0 - left.0 < f32::EPSILON);
+
+        if points.len() < 2 {
+            return Self {
+                points: Vec::new(),
+                tangents: Vec::new(),
+            };
+        }
+
+        let secants: Vec<f32> = points
+            .windows(2)
+            .map(|pair| (pair[1].1 - pair[0].1) / (pair[1].0 - pair[0].0))
+            .collect();
+        let mut tangents = Vec::with_capacity(points.len());
+
+        tangents.push(secants[0]);
+
+        for index in 1..points.len() - 1 {
+            let before = secants[index - 1];
+            let after = secants[index];
+
+            if before * after <= 0.0 {
+                tangents.push(0.0);
+            } else {
+                tangents.push((before + after) * 0.5);
+            }
+        }
+
+        tangents.push(secants[secants.len() - 1]);
+
+        for (index, secant) in secants.iter().enumerate() {
+            if *secant == 0.0 {
+                tangents[index] = 0.0;
+                tangents[index + 1] = 0.0;
+                continue;
+            }
+
+            let alpha = tangents[index] / secant;
+            let beta = tangents[index + 1] / secant;
+            let magnitude = alpha * alpha + beta * beta;
+
+            if magnitude > 9.0 {
+                let scale = 3.0 / magnitude.sqrt();
+
+                tangents[index] = scale * alpha * secant;
+                tangents[index + 1] = scale * beta * secant;
+            }
+        }
+
+        Self { points, tangents }
+    }
+
+    fn evaluate(&self, input: f32) -> f32 {
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return input;
+        };
+
+        if input <= first.0 {
+            return first.1;
+        }
+
+        if input >= last.0 {
+            return last.1;
+        }
+
+        let segment = self
+            .points
+            .windows(2)
+            .position(|pair| input <= pair[1].0)
+            .unwrap_or(self.points.len() - 2);
+        let (x0, y0) = self.points[segment];
+        let (x1, y1) = self.points[segment + 1];
+        let width = x1 - x0;
+        let t = (input - x0) / width;
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        let value = (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+            + (t3 - 2.0 * t2 + t) * width * self.tangents[segment]
+            + (-2.0 * t3 + 3.0 * t2) * y1
+            + (t3 - t2) * width * self.tangents[segment + 1];
+
+        value.clamp(0.0, 1.0)
+    }
+}
+
+fn upload_lut(queue: &wgpu::Queue, texture: &wgpu::Texture, texels: &[f32]) {
+    queue.write_texture(
+        wgpu::TexelCopyTextureInfo {
+            texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        bytemuck::cast_slice(texels),
+        wgpu::TexelCopyBufferLayout {
+            offset: 0,
+            bytes_per_row: Some(16 * TONE_CURVE_LUT_SIZE),
+            rows_per_image: Some(1),
+        },
+        wgpu::Extent3d {
+            width: TONE_CURVE_LUT_SIZE,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+    );
+}
@@ -1,18 +1,30 @@
 use super::context::{GpuContext, SurfaceContext};
+use super::display_output::DisplayOutput;
 use super::display_resources::DisplayResources;
 use super::image_request::ImageRequest;
 use super::input::{
-    DevelopmentSource, DisplayIntent, Input, OutputTransformSettings, SourceMetadata,
+    DevelopmentSource, DisplayIntent, Input, OutputTransformSettings, ResolvedWhiteBalance,
+    SourceMetadata, WhiteBalanceCalibration,
 };
 use super::inspection::{
-    ImageInspection, InspectionSnapshot, PipelineInspection, RawImageInspection,
+    DngOpcodeInspection, ImageInspection, InspectionSnapshot, PipelineInspection,
+    RawImageInspection,
+};
+use super::processing_graph::{
+    dominant_color_band, oklch_hue_chroma, DevelopmentParameters, ImageProcessingGraph,
+    RawCaptureSharpening, RawNoiseReduction,
 };
-use super::processing_graph::{DevelopmentParameters, ImageProcessingGraph};
 use super::schedule::{RenderSchedule, RenderState};
 use super::viewer::Viewer;
-use crate::core::editing::EditRecipe;
-use anyhow::{Context, Result};
-use log::{error, info};
+use crate::core::editing::{
+    CaptureSharpening, ColorBand, ColorRange, EditRecipe, Geometry, HighlightReconstruction,
+    LensCorrection, LocalAdjustment, Look, LookInterpolation, LookPlacement, MaskPoint,
+    NoiseReduction, RetouchSpot, ToneMapper, ToneMapping, WhiteBalance,
+};
+use crate::core::lens::LensCalibration;
+use crate::core::look::{load_look_file, CubeLut, LookFile};
+use anyhow::{anyhow, Context, Result};
+use log::{error, info, warn};
 use std::path::Path;
 use tauri::async_runtime::JoinHandle;
 use tauri::WebviewWindow;
@@ -27,8 +39,39 @@ pub struct Renderer {
     render_schedule: RenderSchedule,
     inspection: InspectionSnapshot,
     has_image: bool,
+    white_balance: WhiteBalance,
+    highlight_reconstruction: HighlightReconstruction,
+    noise_reduction: NoiseReduction,
+    capture_sharpening: CaptureSharpening,
+    sharpening_mask_preview: bool,
+    iso: Option<u32>,
+    white_balance_calibration: Option<WhiteBalanceCalibration>,
+    resolved_white_balance: Option<ResolvedWhiteBalance>,
+    development_parameters: DevelopmentParameters,
+    lens_correction: LensCorrection,
+    lens_calibration: Option<LensCalibration>,
+    tone_mapping: ToneMapping,
+    geometry: Geometry,
+    retouch_spots: Vec<RetouchSpot>,
+    local_adjustments: Vec<LocalAdjustment>,
+    local_mask_overlay: Option<usize>,
+    spot_visualization: bool,
+    look: Look,
+    look_file: Option<LookFile>,
+    look_error: Option<String>,
+    output_transform: OutputTransformSettings,
+    display_output: DisplayOutput,
 }
 
+/// Half-width, in development-output pixels, of the eyedropper sample patch.
+const NEUTRAL_PATCH_RADIUS: u32 = 4;
+
+/// Half-width, in adjustment-output pixels, of the color mixer targeting patch.
+const COLOR_BAND_PATCH_RADIUS: u32 = 2;
+
+/// Half-width, in retouch-output pixels, of the mask color range sample patch.
+const MASK_COLOR_PATCH_RADIUS: u32 = 2;
+
 impl Renderer {
     pub fn new(window: WebviewWindow) -> Result<Self> {
         let window_size = match window
@@ -75,6 +118,28 @@ impl Renderer {
             render_schedule,
             inspection,
             has_image: false,
+            white_balance: WhiteBalance::AsShot,
+            highlight_reconstruction: HighlightReconstruction::default(),
+            noise_reduction: NoiseReduction::default(),
+            capture_sharpening: CaptureSharpening::default(),
+            sharpening_mask_preview: false,
+            iso: None,
+            white_balance_calibration: None,
+            resolved_white_balance: None,
+            development_parameters: DevelopmentParameters::default(),
+            lens_correction: LensCorrection::default(),
+            lens_calibration: None,
+            tone_mapping: ToneMapping::default(),
+            geometry: Geometry::default(),
+            retouch_spots: Vec::new(),
+            local_adjustments: Vec::new(),
+            local_mask_overlay: None,
+            spot_visualization: false,
+            look: Look::default(),
+            look_file: None,
+            look_error: None,
+            output_transform: OutputTransformSettings::direct_sdr(),
+            display_output: DisplayOutput::Sdr,
         };
 
         renderer.refresh_texture_inspection();
@@ -141,9 +206,22 @@ impl Renderer {
         let image = input.image();
         let dimensions = image.dimensions();
         let development_source = input.development_source();
-        let development_parameters = input.development_parameters();
+        let white_balance_calibration = input.white_balance().clone();
+        let resolved_white_balance = white_balance_calibration.resolve(&self.white_balance);
+
+        self.iso = input.iso();
+
+        let development_parameters = self.with_recipe_development_settings(
+            resolved_white_balance.apply_to(input.development_parameters()),
+        );
         let output_transform = input.output_transform();
 
+        self.white_balance_calibration = Some(white_balance_calibration);
+        self.resolved_white_balance = Some(resolved_white_balance);
+        self.development_parameters = development_parameters;
+        self.lens_calibration = input.lens_calibration().cloned();
+        self.output_transform = output_transform;
+
         self.display_checkboard(image.has_transparency());
         self.update_inspection_for_input(
             dimensions.width(),
@@ -151,10 +229,19 @@ impl Renderer {
             image.has_transparency(),
             development_source,
             input.source_metadata(),
-            output_transform,
+            output_transform.for_display_output(self.display_output),
         );
         self.inspection.timings.input_build_ms = input_build_ms;
-        self.update_output_transform(output_transform);
+        self.update_development_inspection();
+        self.update_output_transform();
+        self.processing_graph.update_lens_correction(
+            &self.gpu.device,
+            &self.gpu.queue,
+            self.lens_calibration.as_ref(),
+            &self.lens_correction,
+        );
+        self.processing_graph
+            .set_geometry(&self.geometry, input.default_crop());
         self.upload_source_image(
             image.texels(),
             dimensions.width(),
@@ -195,24 +282,476 @@ impl Renderer {
 
     /// Updates graph-owned edit parameters and reruns GPU image processing.
     pub fn update_edit_recipe(&mut self, recipe: &EditRecipe) {
-        self.processing_graph.update_adjustments(
+        self.update_development_settings(recipe);
+        self.update_lens_correction(recipe);
+        self.update_retouch_spots(recipe);
+        self.processing_graph
+            .update_adjustments(&self.gpu.device, &self.gpu.queue, recipe);
+        self.update_local_adjustments(recipe);
+        self.update_tone_mapping(recipe);
+        self.update_look(recipe);
+        self.update_geometry(recipe);
+        self.inspection.pipeline.user_exposure_ev = recipe.exposure_ev;
+    }
+
+    /// Applies recipe retouch spots when they changed.
+    fn update_retouch_spots(&mut self, recipe: &EditRecipe) {
+        if recipe.retouch_spots == self.retouch_spots {
+            return;
+        }
+
+        self.retouch_spots = recipe.retouch_spots.clone();
+
+        self.processing_graph.update_retouch_spots(
             &self.gpu.device,
             &self.gpu.queue,
-            recipe.exposure_ev,
+            &self.retouch_spots,
+        );
+    }
+
+    /// Applies recipe masks and their local deltas when they changed.
+    fn update_local_adjustments(&mut self, recipe: &EditRecipe) {
+        if recipe.local_adjustments == self.local_adjustments {
+            return;
+        }
+
+        self.local_adjustments = recipe.local_adjustments.clone();
+
+        self.processing_graph.update_local_adjustments(
+            &self.gpu.device,
+            &self.gpu.queue,
+            &self.local_adjustments,
+        );
+    }
+
+    /// Tints one local adjustment mask over the image in place of the local
+    /// adjustments, or turns the overlay off with `None`.
+    pub fn set_local_mask_overlay(&mut self, index: Option<usize>) {
+        if index == self.local_mask_overlay {
+            return;
+        }
+
+        self.local_mask_overlay = index;
+        self.processing_graph
+            .set_local_mask_overlay(&self.gpu.device, &self.gpu.queue, index);
+        self.inspection.pipeline.local_mask_overlay = index;
+    }
+
+    /// Replaces the displayed image with a high-pass, inverted rendering that
+    /// makes sensor dust stand out, without touching the recipe.
+    pub fn set_spot_visualization(&mut self, enabled: bool) {
+        if enabled == self.spot_visualization {
+            return;
+        }
+
+        self.spot_visualization = enabled;
+        self.processing_graph
+            .set_spot_visualization(&self.gpu.device, &self.gpu.queue, enabled);
+        self.inspection.pipeline.spot_visualization = enabled;
+    }
+
+    /// Applies recipe crop and orientation, refitting the view when the output size changes.
+    fn update_geometry(&mut self, recipe: &EditRecipe) {
+        if recipe.geometry == self.geometry {
+            return;
+        }
+
+        self.geometry = recipe.geometry;
+
+        let resized = self.processing_graph.update_geometry(
+            &self.gpu.device,
+            &self.gpu.queue,
+            &self.geometry,
+        );
+
+        if resized {
+            self.display_resources
+                .bind_image_texture(&self.gpu.device, self.processing_graph.output_view());
+            self.update_vertices();
+            self.refresh_texture_inspection();
+        }
+    }
+
+    /// Applies recipe development settings, rerunning development only when they changed.
+    fn update_development_settings(&mut self, recipe: &EditRecipe) {
+        if recipe.white_balance == self.white_balance
+            && recipe.highlight_reconstruction == self.highlight_reconstruction
+            && recipe.noise_reduction == self.noise_reduction
+            && recipe.capture_sharpening == self.capture_sharpening
+        {
+            return;
+        }
+
+        self.white_balance = recipe.white_balance;
+        self.highlight_reconstruction = recipe.highlight_reconstruction;
+        self.noise_reduction = recipe.noise_reduction;
+        self.capture_sharpening = recipe.capture_sharpening;
+
+        self.refresh_development_parameters();
+    }
+
+    /// Shows the capture sharpening mask in place of the developed image.
+    pub fn set_sharpening_mask_preview(&mut self, enabled: bool) {
+        if enabled == self.sharpening_mask_preview {
+            return;
+        }
+
+        self.sharpening_mask_preview = enabled;
+        self.refresh_development_parameters();
+    }
+
+    /// Re-resolves development parameters from the stored settings and reruns development.
+    fn refresh_development_parameters(&mut self) {
+        let Some(calibration) = &self.white_balance_calibration else {
+            return;
+        };
+
+        let resolved_white_balance = calibration.resolve(&self.white_balance);
+
+        self.resolved_white_balance = Some(resolved_white_balance);
+        self.development_parameters = self.with_recipe_development_settings(
+            resolved_white_balance.apply_to(self.development_parameters),
+        );
+
+        if self.has_image {
+            self.processing_graph.update_development_parameters(
+                &self.gpu.device,
+                &self.gpu.queue,
+                self.development_parameters,
+            );
+        }
+
+        self.update_development_inspection();
+    }
+
+    /// Applies the recipe development settings layered on top of white balance.
+    fn with_recipe_development_settings(
+        &self,
+        development_parameters: DevelopmentParameters,
+    ) -> DevelopmentParameters {
+        development_parameters
+            .with_highlight_reconstruction(self.highlight_reconstruction)
+            .with_noise_reduction(RawNoiseReduction::from_recipe(
+                &self.noise_reduction,
+                self.iso,
+            ))
+            .with_capture_sharpening(RawCaptureSharpening::from_recipe(
+                &self.capture_sharpening,
+                self.sharpening_mask_preview,
+            ))
+    }
+
+    /// Applies recipe lens correction settings, rerunning lens correction only when they changed.
+    fn update_lens_correction(&mut self, recipe: &EditRecipe) {
+        if recipe.lens_correction == self.lens_correction {
+            return;
+        }
+
+        self.lens_correction = recipe.lens_correction;
+
+        if self.has_image {
+            self.processing_graph.update_lens_correction(
+                &self.gpu.device,
+                &self.gpu.queue,
+                self.lens_calibration.as_ref(),
+                &self.lens_correction,
+            );
+        }
+    }
+
+    /// Applies the recipe tone mapper, rerunning the output transform only when it changed.
+    fn update_tone_mapping(&mut self, recipe: &EditRecipe) {
+        if recipe.tone_mapping == self.tone_mapping {
+            return;
+        }
+
+        self.tone_mapping = recipe.tone_mapping;
+
+        self.processing_graph.update_tone_mapping(
+            &self.gpu.device,
+            &self.gpu.queue,
+            &self.tone_mapping,
+        );
+        self.inspection.pipeline.tone_mapper = tone_mapping_label(self.tone_mapping);
+    }
+
+    /// Applies the recipe look, reloading the `.cube` file only when its path or hash changed.
+    fn update_look(&mut self, recipe: &EditRecipe) {
+        if recipe.look == self.look {
+            return;
+        }
+
+        let file_changed = recipe.look.path != self.look.path || recipe.look.hash != self.look.hash;
+
+        self.look = recipe.look.clone();
+
+        if file_changed {
+            let lut = self.load_look();
+
+            self.processing_graph
+                .update_look_lut(&self.gpu.device, &self.gpu.queue, lut.as_ref());
+        }
+
+        self.processing_graph
+            .update_look(&self.gpu.device, &self.gpu.queue, &self.look);
+        self.inspection.pipeline.look = look_label(
+            &self.look,
+            self.look_file.as_ref(),
+            self.look_error.as_deref(),
         );
-        self.inspection.pipeline.user_exposure_ev = recipe.exposure_ev;
+    }
+
+    /// Loads the current look file, recording the loaded file or the failure for the Inspector.
+    fn load_look(&mut self) -> Option<CubeLut> {
+        self.look_file = None;
+        self.look_error = None;
+
+        let path = self.look.path.as_ref()?;
+
+        match load_look_file(Path::new(path), self.look.hash.as_deref()) {
+            Ok((file, lut)) => {
+                info!(
+                    "[Renderer] Loaded {}x{}x{} look {}",
+                    lut.size, lut.size, lut.size, path
+                );
+
+                self.look_file = Some(file);
+
+                Some(lut)
+            }
+            Err(error) => {
+                error!("[Renderer] Failed to load look: {:#}", error);
+
+                self.look_error = Some(format!("{:#}", error));
+
+                None
+            }
+        }
+    }
+
+    /// Computes a custom white balance that neutralizes the patch at a normalized image position.
+    pub fn sample_neutral_white_balance(&self, x: f32, y: f32) -> Result<WhiteBalance> {
+        if !self.has_image {
+            return Err(anyhow!("no image is loaded"));
+        }
+
+        let Some(resolved_white_balance) = self.resolved_white_balance else {
+            return Err(anyhow!("no image is loaded"));
+        };
+
+        let (pixel_x, pixel_y) = match self.source_pixel_at(x, y) {
+            Ok(pixel) => pixel,
+            Err(error) => return Err(error),
+        };
+
+        let sample = match self.processing_graph.sample_development_output(
+            &self.gpu.device,
+            &self.gpu.queue,
+            pixel_x,
+            pixel_y,
+            NEUTRAL_PATCH_RADIUS,
+        ) {
+            Ok(sample) => sample,
+            Err(error) => return Err(error),
+        };
+
+        match resolved_white_balance.neutral_patch_white_balance(sample) {
+            Some(white_balance) => Ok(white_balance),
+            None => Err(anyhow!(
+                "sampled patch is too dark or saturated to compute a white balance"
+            )),
+        }
+    }
+
+    /// Returns the color mixer band under a normalized image position.
+    ///
+    /// The adjusted image is sampled so the band matches what the user sees.
+    /// Returns `None` when the patch is too neutral for the mixer to affect.
+    pub fn sample_color_band(&self, x: f32, y: f32) -> Result<Option<ColorBand>> {
+        if !self.has_image {
+            return Err(anyhow!("no image is loaded"));
+        }
+
+        let (pixel_x, pixel_y) = match self.source_pixel_at(x, y) {
+            Ok(pixel) => pixel,
+            Err(error) => return Err(error),
+        };
+
+        let sample = match self.processing_graph.sample_adjustment_output(
+            &self.gpu.device,
+            &self.gpu.queue,
+            pixel_x,
+            pixel_y,
+            COLOR_BAND_PATCH_RADIUS,
+        ) {
+            Ok(sample) => sample,
+            Err(error) => return Err(error),
+        };
+
+        Ok(dominant_color_band(sample))
+    }
+
+    /// Builds a mask color range from the color at a normalized source position.
+    ///
+    /// The retouched working-space image is sampled because the mask stage
+    /// measures ranges on it. Hue and chroma tolerances use their defaults.
+    pub fn sample_mask_color(&self, sample: MaskPoint) -> Result<ColorRange> {
+        if !self.has_image {
+            return Err(anyhow!("no image is loaded"));
+        }
+
+        if !(0.0..=1.0).contains(&sample.x) || !(0.0..=1.0).contains(&sample.y) {
+            return Err(anyhow!(
+                "sample position {},{} is outside the image",
+                sample.x,
+                sample.y
+            ));
+        }
+
+        let color = match self.processing_graph.sample_retouch_output(
+            &self.gpu.device,
+            &self.gpu.queue,
+            sample.x,
+            sample.y,
+            MASK_COLOR_PATCH_RADIUS,
+        ) {
+            Ok(color) => color,
+            Err(error) => return Err(error),
+        };
+        let (hue_degrees, chroma) = oklch_hue_chroma(color);
+
+        Ok(ColorRange {
+            sample,
+            hue_degrees,
+            chroma,
+            ..ColorRange::default()
+        })
+    }
+
+    /// Searches near a retouch spot destination for a source patch whose
+    /// surroundings match, for spots placed without choosing a source.
+    ///
+    /// `radius` is a fraction of the longer source edge. Returns `None` when no
+    /// candidate source fits inside the image.
+    pub fn find_spot_source(
+        &self,
+        destination: MaskPoint,
+        radius: f32,
+    ) -> Result<Option<MaskPoint>> {
+        if !self.has_image {
+            return Err(anyhow!("no image is loaded"));
+        }
+
+        if !(0.0..=1.0).contains(&destination.x) || !(0.0..=1.0).contains(&destination.y) {
+            return Err(anyhow!(
+                "spot position {},{} is outside the image",
+                destination.x,
+                destination.y
+            ));
+        }
+
+        if !radius.is_finite() || radius <= 0.0 {
+            return Err(anyhow!("spot radius {radius} must be positive"));
+        }
+
+        self.processing_graph.find_spot_source(
+            &self.gpu.device,
+            &self.gpu.queue,
+            destination,
+            radius,
+        )
+    }
+
+    /// Maps a surface pixel position to a normalized position in the uncropped,
+    /// unrotated source image, as used by recipe masks.
+    ///
+    /// Returns `None` when the position is outside the displayed image.
+    pub fn source_position_at_viewport(&self, x: f32, y: f32) -> Option<MaskPoint> {
+        let (image_x, image_y) =
+            self.viewer
+                .image_position_at(x, y, self.surface.width(), self.surface.height())?;
+        let (source_x, source_y) = self
+            .processing_graph
+            .source_position_at_output(image_x, image_y)?;
+
+        Some(MaskPoint {
+            x: source_x,
+            y: source_y,
+        })
+    }
+
+    /// Converts a normalized position in the displayed image into source pixel
+    /// coordinates, undoing crop and orientation.
+    fn source_pixel_at(&self, x: f32, y: f32) -> Result<(u32, u32)> {
+        if !(0.0..=1.0).contains(&x) || !(0.0..=1.0).contains(&y) {
+            return Err(anyhow!("sample position {x},{y} is outside the image"));
+        }
+
+        let width = self.processing_graph.output_width() as f32;
+        let height = self.processing_graph.output_height() as f32;
+
+        match self
+            .processing_graph
+            .source_pixel_at_output(x * width, y * height)
+        {
+            Some(pixel) => Ok(pixel),
+            None => Err(anyhow!("sample position {x},{y} is outside the image")),
+        }
     }
 
     /// Updates active output transform parameters while preserving other display state.
-    fn update_output_transform(&mut self, output_transform: OutputTransformSettings) {
+    fn update_output_transform(&mut self) {
+        let output_transform = self
+            .output_transform
+            .for_display_output(self.display_output);
+
         self.processing_graph.update_output_transform(
             &self.gpu.device,
             &self.gpu.queue,
             graph_display_intent(output_transform.display_intent()),
             output_transform.base_exposure_ev(),
+            output_transform.display_headroom(),
         );
     }
 
+    /// Switches the live view between SDR and extended-range HDR presentation.
+    ///
+    /// Falls back to SDR when the surface has no extended-range format and
+    /// returns whether HDR output is active.
+    pub fn set_hdr_output(&mut self, enabled: bool, peak_nits: f32) -> bool {
+        let hdr_active = self.surface.set_hdr(&self.gpu, enabled);
+
+        if enabled && !hdr_active {
+            warn!("[Renderer] HDR output requested but the surface has no extended-range format; using SDR");
+        }
+
+        self.display_output = if hdr_active {
+            DisplayOutput::Hdr { peak_nits }
+        } else {
+            DisplayOutput::Sdr
+        };
+        self.display_resources = DisplayResources::new(
+            &self.gpu.device,
+            self.surface.format(),
+            self.processing_graph.output_view(),
+        );
+        self.update_vertices();
+
+        if self.has_image {
+            self.update_output_transform();
+            self.inspection.pipeline.display_intent = display_intent_label(
+                self.output_transform
+                    .for_display_output(self.display_output)
+                    .display_intent(),
+            )
+            .to_string();
+        }
+
+        self.refresh_texture_inspection();
+
+        hdr_active
+    }
+
     pub fn clear(&mut self) {
         self.image_request.clear();
 
@@ -368,15 +907,64 @@ impl Renderer {
             raw: source_metadata
                 .raw_metadata()
                 .map(RawImageInspection::from_source_metadata),
+            dng_opcodes: source_metadata
+                .raw_metadata()
+                .map(|raw| {
+                    raw.dng_opcodes
+                        .iter()
+                        .map(DngOpcodeInspection::from_status)
+                        .collect()
+                })
+                .unwrap_or_default(),
         });
         self.inspection.pipeline = PipelineInspection {
             development_source: development_source_label(development_source).to_string(),
             display_intent: display_intent_label(output_transform.display_intent()).to_string(),
             base_exposure_ev: Some(output_transform.base_exposure_ev()),
             user_exposure_ev: self.inspection.pipeline.user_exposure_ev,
+            white_balance: self.inspection.pipeline.white_balance.clone(),
+            white_balance_multipliers: self.inspection.pipeline.white_balance_multipliers,
+            highlight_reconstruction: self.inspection.pipeline.highlight_reconstruction.clone(),
+            noise_reduction: self.inspection.pipeline.noise_reduction.clone(),
+            capture_sharpening: self.inspection.pipeline.capture_sharpening.clone(),
+            sharpening_mask_preview: self.inspection.pipeline.sharpening_mask_preview,
+            local_mask_overlay: self.local_mask_overlay,
+            spot_visualization: self.spot_visualization,
+            lens_profile: self
+                .lens_calibration
+                .as_ref()
+                .map(|calibration| calibration.lens_model.clone())
+                .unwrap_or_else(|| "None".to_string()),
+            tone_mapper: self.inspection.pipeline.tone_mapper.clone(),
+            look: self.inspection.pipeline.look.clone(),
         };
     }
 
+    fn update_development_inspection(&mut self) {
+        self.inspection.pipeline.white_balance =
+            white_balance_label(&self.white_balance, self.resolved_white_balance);
+        self.inspection.pipeline.white_balance_multipliers = self
+            .resolved_white_balance
+            .map(|resolved_white_balance| resolved_white_balance.multipliers());
+        self.inspection.pipeline.highlight_reconstruction = self
+            .development_parameters
+            .highlight_reconstruction()
+            .map(highlight_reconstruction_label)
+            .unwrap_or("-")
+            .to_string();
+        self.inspection.pipeline.noise_reduction = self
+            .development_parameters
+            .noise_reduction()
+            .map(|noise_reduction| noise_reduction_label(noise_reduction, self.iso))
+            .unwrap_or_else(|| "-".to_string());
+        self.inspection.pipeline.capture_sharpening = self
+            .development_parameters
+            .capture_sharpening()
+            .map(capture_sharpening_label)
+            .unwrap_or_else(|| "-".to_string());
+        self.inspection.pipeline.sharpening_mask_preview = self.sharpening_mask_preview;
+    }
+
     fn refresh_texture_inspection(&mut self) {
         self.inspection.textures = self.processing_graph.texture_inspection(
             self.surface.format(),
@@ -386,17 +974,20 @@ impl Renderer {
     }
 }
 
-fn graph_display_intent(intent: DisplayIntent) -> u32 {
+pub(super) fn graph_display_intent(intent: DisplayIntent) -> u32 {
     match intent {
         DisplayIntent::DirectSdr => 0,
         DisplayIntent::ToneMapToSdr => 1,
+        DisplayIntent::ToneMapToHdr => 2,
     }
 }
 
 fn source_kind_label(source: DevelopmentSource) -> &'static str {
     match source {
         DevelopmentSource::RasterSrgb => "Raster",
-        DevelopmentSource::RawBayer2x2 => "RAW",
+        DevelopmentSource::RawBayer2x2
+        | DevelopmentSource::RawXTrans6x6
+        | DevelopmentSource::RawLinearRgb => "RAW",
     }
 }
 
@@ -404,12 +995,116 @@ fn development_source_label(source: DevelopmentSource) -> &'static str {
     match source {
         DevelopmentSource::RasterSrgb => "Raster sRGB",
         DevelopmentSource::RawBayer2x2 => "RAW Bayer 2x2",
+        DevelopmentSource::RawXTrans6x6 => "RAW X-Trans 6x6",
+        DevelopmentSource::RawLinearRgb => "RAW Linear RGB",
+    }
+}
+
+/// Labels a recipe white balance, adding the solved white point for As Shot
+/// and custom multipliers when the source has a camera profile.
+fn white_balance_label(
+    white_balance: &WhiteBalance,
+    resolved_white_balance: Option<ResolvedWhiteBalance>,
+) -> String {
+    let white_point = resolved_white_balance.and_then(|resolved| resolved.white_point());
+
+    match (white_balance, white_point) {
+        (
+            WhiteBalance::Temperature {
+                temperature_k,
+                tint,
+            },
+            _,
+        ) => format!("{temperature_k:.0} K, Tint {tint:+.2}"),
+        (WhiteBalance::AsShot, Some((temperature_k, tint))) => {
+            format!("As Shot ({temperature_k:.0} K, Tint {tint:+.2})")
+        }
+        (WhiteBalance::AsShot, None) => "As Shot".to_string(),
+        (WhiteBalance::Custom { .. }, Some((temperature_k, tint))) => {
+            format!("Custom ({temperature_k:.0} K, Tint {tint:+.2})")
+        }
+        (WhiteBalance::Custom { .. }, None) => "Custom".to_string(),
     }
 }
 
+fn highlight_reconstruction_label(
+    highlight_reconstruction: HighlightReconstruction,
+) -> &'static str {
+    match highlight_reconstruction {
+        HighlightReconstruction::Off => "Off",
+        HighlightReconstruction::Clip => "Clip",
+        HighlightReconstruction::Blend => "Blend",
+        HighlightReconstruction::Inpaint => "Inpaint",
+    }
+}
+
+/// Labels resolved noise reduction strengths with the ISO their defaults came from.
+fn noise_reduction_label(noise_reduction: RawNoiseReduction, iso: Option<u32>) -> String {
+    let strengths = format!(
+        "Luma {:.2}, Chroma {:.2}",
+        noise_reduction.luminance, noise_reduction.chroma
+    );
+
+    match iso {
+        Some(iso) => format!("{strengths} (ISO {iso})"),
+        None => strengths,
+    }
+}
+
+fn capture_sharpening_label(capture_sharpening: RawCaptureSharpening) -> String {
+    if capture_sharpening.amount <= 0.0 {
+        return "Off".to_string();
+    }
+
+    format!(
+        "Amount {:.2}, Radius {:.1} px, Threshold {:.2}",
+        capture_sharpening.amount, capture_sharpening.radius, capture_sharpening.threshold
+    )
+}
+
+fn tone_mapping_label(tone_mapping: ToneMapping) -> String {
+    match tone_mapping.mapper {
+        ToneMapper::AcesFitted => "ACES Fitted".to_string(),
+        ToneMapper::Agx => "AgX".to_string(),
+        ToneMapper::Filmic => format!(
+            "Filmic (Contrast {:+.2}, Toe {:+.2})",
+            tone_mapping.contrast, tone_mapping.toe
+        ),
+        ToneMapper::Reinhard => "Reinhard".to_string(),
+        ToneMapper::Clip => "Clip".to_string(),
+    }
+}
+
+fn look_label(look: &Look, file: Option<&LookFile>, error: Option<&str>) -> String {
+    if let Some(error) = error {
+        return format!("Error: {error}");
+    }
+
+    let Some(file) = file else {
+        return "None".to_string();
+    };
+
+    let name = file.title.as_deref().unwrap_or(&file.path);
+    let placement = match look.placement {
+        LookPlacement::Working => "Working",
+        LookPlacement::Display => "Display",
+    };
+    let interpolation = match look.interpolation {
+        LookInterpolation::Trilinear => "Trilinear",
+        LookInterpolation::Tetrahedral => "Tetrahedral",
+    };
+
+    format!(
+        "{name} ({size}^3, {placement}, {interpolation}, {amount:.0}%)",
+        size = file.size,
+        amount = look.amount * 100.0
+    )
+}
+
 fn display_intent_label(intent: DisplayIntent) -> &'static str {
     match intent {
         DisplayIntent::DirectSdr => "Direct SDR",
         DisplayIntent::ToneMapToSdr => "Tone Map to SDR",
+        DisplayIntent::ToneMapToHdr => "Tone Map to HDR",
     }
 }
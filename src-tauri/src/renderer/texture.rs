@@ -31,8 +31,9 @@ impl ImageTexture {
 
     /// Creates a placeholder output texture for normalized Bayer RAW samples.
     ///
-    /// The red channel stores the normalized sample and the green channel stores
-    /// a clipped-photosite mask produced from CFA-specific sensor white levels.
+    /// The red channel stores the normalized sample, the green channel stores
+    /// a clipped-photosite mask produced from CFA-specific sensor white levels,
+    /// and the blue channel flags photosites from the camera's bad pixel map.
     pub(super) fn new_raw_normalized_bayer_output(
         device: &wgpu::Device,
         queue: &wgpu::Queue,
@@ -40,16 +41,112 @@ impl ImageTexture {
         Self::new_stage_output(device, queue, "RAW Normalized Bayer Texture")
     }
 
+    /// Creates a placeholder output texture for defect-corrected Bayer RAW samples.
+    ///
+    /// This keeps the normalized Bayer layout; the blue channel flags every
+    /// photosite that was replaced, whether mapped or detected.
+    pub(super) fn new_raw_corrected_bayer_output(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) -> Self {
+        Self::new_stage_output(device, queue, "RAW Corrected Bayer Texture")
+    }
+
+    /// Creates a placeholder output texture for highlight-reconstructed Bayer RAW samples.
+    ///
+    /// This keeps the normalized Bayer layout: the red channel stores the
+    /// repaired sample and the green channel keeps the original clip mask.
+    pub(super) fn new_raw_reconstructed_bayer_output(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) -> Self {
+        Self::new_stage_output(device, queue, "RAW Reconstructed Bayer Texture")
+    }
+
+    /// Creates a placeholder output texture for normalized X-Trans RAW samples.
+    ///
+    /// This uses the normalized Bayer layout: sample, clip mask, and mapped
+    /// bad pixel flag in the red, green, and blue channels.
+    pub(super) fn new_raw_normalized_xtrans_output(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) -> Self {
+        Self::new_stage_output(device, queue, "RAW Normalized X-Trans Texture")
+    }
+
+    /// Creates a placeholder output texture for X-Trans samples with interpolated green.
+    ///
+    /// The red channel keeps the normalized sample, the green channel stores
+    /// the full-resolution green estimate, and the blue channel keeps the clip mask.
+    pub(super) fn new_raw_xtrans_green_output(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        Self::new_stage_output(device, queue, "RAW X-Trans Green Texture")
+    }
+
     /// Creates a placeholder output texture for demosaiced camera-space RGB.
+    ///
+    /// Linear RGB sources are normalized straight into this texture.
     pub(super) fn new_raw_camera_rgb_output(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
         Self::new_stage_output(device, queue, "RAW Camera RGB Texture")
     }
 
+    /// Creates a placeholder output texture for working-space RAW data before noise reduction.
+    pub(super) fn new_raw_noise_reduction_input(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) -> Self {
+        Self::new_stage_output(device, queue, "RAW Noise Reduction Input Texture")
+    }
+
+    /// Creates a placeholder output texture for chroma-denoised working-space RAW data.
+    pub(super) fn new_raw_chroma_denoised_output(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) -> Self {
+        Self::new_stage_output(device, queue, "RAW Chroma Denoised Texture")
+    }
+
+    /// Creates a placeholder output texture for denoised working-space RAW data.
+    pub(super) fn new_raw_denoised_output(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        Self::new_stage_output(device, queue, "RAW Denoised Texture")
+    }
+
+    /// Creates a placeholder output texture for working-space RAW data before geometry correction.
+    pub(super) fn new_raw_working_output(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        Self::new_stage_output(device, queue, "RAW Working Texture")
+    }
+
+    /// Creates a placeholder output texture for lens-corrected working-space image data.
+    pub(super) fn new_lens_correction_output(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        Self::new_stage_output(device, queue, "Lens Correction Output Texture")
+    }
+
+    /// Creates a placeholder output texture for retouched working-space image data.
+    pub(super) fn new_retouch_output(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        Self::new_stage_output(device, queue, "Retouch Output Texture")
+    }
+
     /// Creates a placeholder output texture for adjusted working-space image data.
     pub(super) fn new_adjustment_output(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
         Self::new_stage_output(device, queue, "Adjustment Output Texture")
     }
 
+    /// Creates a placeholder texture for rasterized local adjustment masks.
+    ///
+    /// Each channel stores one mask's coverage in `0.0..=1.0`.
+    pub(super) fn new_mask_output(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        Self::new_stage_output(device, queue, "Mask Texture")
+    }
+
+    /// Creates a placeholder output texture for locally adjusted working-space image data.
+    pub(super) fn new_local_adjustment_output(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        Self::new_stage_output(device, queue, "Local Adjustment Output Texture")
+    }
+
+    /// Creates a placeholder output texture for cropped and straightened working-space image data.
+    pub(super) fn new_geometry_output(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        Self::new_stage_output(device, queue, "Geometry Output Texture")
+    }
+
     /// Creates a placeholder output texture for display-ready image data.
     pub(super) fn new_display_output(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
         Self::new(
@@ -71,7 +168,8 @@ impl ImageTexture {
             label,
             wgpu::TextureUsages::TEXTURE_BINDING
                 | wgpu::TextureUsages::STORAGE_BINDING
-                | wgpu::TextureUsages::COPY_DST,
+                | wgpu::TextureUsages::COPY_DST
+                | wgpu::TextureUsages::COPY_SRC,
             IMAGE_TEXTURE_FORMAT,
         )
     }
@@ -133,4 +133,33 @@ impl Viewer {
             checkerboard_enabled: self.checkerboard_enabled,
         })
     }
+
+    /// Maps a surface pixel position to a normalized `0.0..=1.0` position in
+    /// the displayed image, or `None` when it falls outside the image quad.
+    pub(super) fn image_position_at(
+        &self,
+        surface_x: f32,
+        surface_y: f32,
+        surface_width: u32,
+        surface_height: u32,
+    ) -> Option<(f32, f32)> {
+        let transform = self.transform_for_surface(surface_width, surface_height)?;
+
+        if transform.scale <= 0.0 {
+            return None;
+        }
+
+        let ndc_x = (surface_x / surface_width as f32) * 2.0 - 1.0;
+        let ndc_y = 1.0 - (surface_y / surface_height as f32) * 2.0;
+        let quad_x = (ndc_x - transform.offset_x) / transform.scale;
+        let quad_y = (ndc_y - transform.offset_y) / transform.scale;
+        let image_x = (quad_x / self.image_quad_scale_x.max(f32::EPSILON) + 1.0) * 0.5;
+        let image_y = (1.0 - quad_y / self.image_quad_scale_y.max(f32::EPSILON)) * 0.5;
+
+        if !(0.0..=1.0).contains(&image_x) || !(0.0..=1.0).contains(&image_y) {
+            return None;
+        }
+
+        Some((image_x, image_y))
+    }
 }
@@ -1,5 +1,12 @@
 struct AdjustmentParameters {
   exposure: vec4<f32>,
+  tone: vec4<f32>,
+  color: vec4<f32>,
+  tone_curve: vec4<f32>,
+  color_band_hues: array<vec4<f32>, 2>,
+  color_mixer_hue: array<vec4<f32>, 2>,
+  color_mixer_saturation: array<vec4<f32>, 2>,
+  color_mixer_luminance: array<vec4<f32>, 2>,
 };
 
 @group(0) @binding(0)
@@ -11,10 +18,243 @@ var output_texture: texture_storage_2d<rgba32float, write>;
 @group(0) @binding(2)
 var<uniform> adjustment_parameters: AdjustmentParameters;
 
+@group(0) @binding(3)
+var tone_curve_lut: texture_1d<f32>;
+
+const SCENE_MIDDLE_GREY: f32 = 0.18;
+const SCENE_EPSILON: f32 = 0.000001;
+
+const CONTRAST_SLOPE_RANGE: f32 = 0.6;
+const HIGHLIGHTS_RANGE_EV: f32 = 1.5;
+const SHADOWS_RANGE_EV: f32 = 1.5;
+const WHITES_RANGE_EV: f32 = 1.0;
+const BLACKS_RANGE_EV: f32 = 1.0;
+
+const TONE_CURVE_GAMMA: f32 = 2.2;
+
+const COLOR_BAND_COUNT: u32 = 8u;
+const COLOR_MIXER_HUE_RANGE: f32 = 0.5235988;
+const COLOR_MIXER_LUMINANCE_RANGE_EV: f32 = 1.0;
+const COLOR_MIXER_CHROMA_RAMP: f32 = 0.04;
+const TAU: f32 = 6.2831853;
+
+// Linear Rec.2020 to OkLab LMS, and its inverse.
+const REC2020_TO_LMS: mat3x3<f32> = mat3x3<f32>(
+  vec3<f32>(0.6166884, 0.2651402, 0.1001506),
+  vec3<f32>(0.3601591, 0.6358565, 0.2040043),
+  vec3<f32>(0.0230433, 0.0990302, 0.6963247)
+);
+const LMS_TO_REC2020: mat3x3<f32> = mat3x3<f32>(
+  vec3<f32>(2.1401406, -0.8848324, -0.0485791),
+  vec3<f32>(-1.2463560, 2.1631726, -0.4544909),
+  vec3<f32>(0.1064317, -0.2783615, 1.5023562)
+);
+
+// Cube-root LMS to OkLab, and its inverse.
+const LMS_TO_OKLAB: mat3x3<f32> = mat3x3<f32>(
+  vec3<f32>(0.2104543, 1.9779985, 0.0259040),
+  vec3<f32>(0.7936178, -2.4285922, 0.7827718),
+  vec3<f32>(-0.0040720, 0.4505937, -0.8086758)
+);
+const OKLAB_TO_LMS: mat3x3<f32> = mat3x3<f32>(
+  vec3<f32>(1.0, 1.0, 1.0),
+  vec3<f32>(0.3963378, -0.1055613, -0.0894842),
+  vec3<f32>(0.2158038, -0.0638542, -1.2914855)
+);
+
+fn rec2020_luminance(color: vec3<f32>) -> f32 {
+  return dot(color, vec3<f32>(0.2627, 0.6780, 0.0593));
+}
+
 fn apply_exposure(color: vec3<f32>, exposure_ev: f32) -> vec3<f32> {
   return color * exp2(exposure_ev);
 }
 
+// Smooth band weight that rises over [rise_start, rise_end] and falls over
+// [fall_start, fall_end], in stops relative to middle grey.
+fn band_weight(
+  stops: f32,
+  rise_start: f32,
+  rise_end: f32,
+  fall_start: f32,
+  fall_end: f32
+) -> f32 {
+  return smoothstep(rise_start, rise_end, stops) * (1.0 - smoothstep(fall_start, fall_end, stops));
+}
+
+// Returns the luminance shift in stops for a pixel `stops` away from middle grey.
+fn tone_shift_stops(stops: f32) -> f32 {
+  let contrast = adjustment_parameters.exposure.y;
+  let highlights = adjustment_parameters.tone.x;
+  let shadows = adjustment_parameters.tone.y;
+  let whites = adjustment_parameters.tone.z;
+  let blacks = adjustment_parameters.tone.w;
+
+  // Contrast scales log-luminance around middle grey so grey stays fixed.
+  var shifted = stops * (1.0 + (contrast * CONTRAST_SLOPE_RANGE));
+
+  shifted += highlights * HIGHLIGHTS_RANGE_EV * band_weight(stops, 0.0, 1.5, 2.5, 4.0);
+  shifted += shadows * SHADOWS_RANGE_EV * band_weight(-stops, 0.0, 1.5, 4.0, 6.0);
+  shifted += whites * WHITES_RANGE_EV * smoothstep(1.5, 3.5, stops);
+  shifted += blacks * BLACKS_RANGE_EV * smoothstep(3.5, 6.5, -stops);
+
+  return shifted - stops;
+}
+
+// Applies tone controls to scene luminance and rescales RGB by the same ratio,
+// preserving hue and the scene-referred channel ratios of each pixel.
+fn apply_tone(color: vec3<f32>) -> vec3<f32> {
+  let luminance = rec2020_luminance(color);
+
+  if (luminance <= SCENE_EPSILON) {
+    return color;
+  }
+
+  let stops = log2(luminance / SCENE_MIDDLE_GREY);
+
+  return color * exp2(tone_shift_stops(stops));
+}
+
+// Linearly interpolates the baked tone curve at an encoded input in [0, 1].
+fn sample_tone_curve(encoded: f32) -> vec3<f32> {
+  let size = i32(textureDimensions(tone_curve_lut));
+  let position = clamp(encoded, 0.0, 1.0) * f32(size - 1);
+  let index = i32(floor(position));
+  let next_index = min(index + 1, size - 1);
+  let weight = position - f32(index);
+
+  return mix(
+    textureLoad(tone_curve_lut, index, 0).rgb,
+    textureLoad(tone_curve_lut, next_index, 0).rgb,
+    weight
+  );
+}
+
+// Applies the tone curve per channel in a gamma-encoded domain. Values above
+// diffuse white are scaled by the curve's white point so scene highlights
+// keep their ratios, and negative values pass through unchanged.
+fn apply_tone_curve(color: vec3<f32>) -> vec3<f32> {
+  if (adjustment_parameters.tone_curve.x == 0.0) {
+    return color;
+  }
+
+  let encoded = pow(clamp(color, vec3<f32>(0.0), vec3<f32>(1.0)), vec3<f32>(1.0 / TONE_CURVE_GAMMA));
+  let curved = vec3<f32>(
+    sample_tone_curve(encoded.r).r,
+    sample_tone_curve(encoded.g).g,
+    sample_tone_curve(encoded.b).b
+  );
+  let decoded = pow(curved, vec3<f32>(TONE_CURVE_GAMMA));
+  let white = pow(sample_tone_curve(1.0), vec3<f32>(TONE_CURVE_GAMMA));
+  let above_white = select(decoded, color * white, color > vec3<f32>(1.0));
+
+  return select(above_white, color, color < vec3<f32>(0.0));
+}
+
+// Scales chroma around Rec.2020 luminance. Vibrance is weighted toward pixels
+// that are not already saturated.
+fn apply_saturation(color: vec3<f32>) -> vec3<f32> {
+  let saturation = adjustment_parameters.color.x;
+  let vibrance = adjustment_parameters.color.y;
+  let luminance = rec2020_luminance(color);
+  let max_channel = max(max(color.r, color.g), color.b);
+  let min_channel = min(min(color.r, color.g), color.b);
+
+  var chroma = 0.0;
+
+  if (max_channel > SCENE_EPSILON) {
+    chroma = clamp((max_channel - min_channel) / max_channel, 0.0, 1.0);
+  }
+
+  let vibrance_amount = vibrance * (1.0 - chroma);
+  let chroma_scale = max(1.0 + saturation + vibrance_amount, 0.0);
+
+  return vec3<f32>(luminance) + ((color - vec3<f32>(luminance)) * chroma_scale);
+}
+
+fn signed_cbrt(value: vec3<f32>) -> vec3<f32> {
+  return sign(value) * pow(abs(value), vec3<f32>(1.0 / 3.0));
+}
+
+fn rec2020_to_oklab(color: vec3<f32>) -> vec3<f32> {
+  return LMS_TO_OKLAB * signed_cbrt(REC2020_TO_LMS * color);
+}
+
+fn oklab_to_rec2020(lab: vec3<f32>) -> vec3<f32> {
+  let lms = OKLAB_TO_LMS * lab;
+
+  return LMS_TO_REC2020 * (lms * lms * lms);
+}
+
+fn color_band_hue(band: u32) -> f32 {
+  return adjustment_parameters.color_band_hues[band / 4u][band % 4u];
+}
+
+// Returns a band's hue shift, saturation, and luminance controls.
+fn color_band_controls(band: u32) -> vec3<f32> {
+  let slot = band / 4u;
+  let lane = band % 4u;
+
+  return vec3<f32>(
+    adjustment_parameters.color_mixer_hue[slot][lane],
+    adjustment_parameters.color_mixer_saturation[slot][lane],
+    adjustment_parameters.color_mixer_luminance[slot][lane]
+  );
+}
+
+// Adjusts hue, chroma, and lightness in OkLCh. Each pixel blends the two
+// bands whose centers bracket its hue, and near-neutral pixels fade out so
+// greys stay untouched.
+fn apply_color_mixer(color: vec3<f32>) -> vec3<f32> {
+  if (adjustment_parameters.color.z == 0.0) {
+    return color;
+  }
+
+  let lab = rec2020_to_oklab(color);
+  let chroma = length(lab.yz);
+  let strength = smoothstep(0.0, COLOR_MIXER_CHROMA_RAMP, chroma);
+
+  if (strength <= 0.0) {
+    return color;
+  }
+
+  var hue = atan2(lab.z, lab.y);
+
+  if (hue < 0.0) {
+    hue += TAU;
+  }
+
+  var lower = COLOR_BAND_COUNT - 1u;
+  var blend = 0.0;
+
+  for (var band = 0u; band < COLOR_BAND_COUNT; band += 1u) {
+    let next_band = (band + 1u) % COLOR_BAND_COUNT;
+    let start = color_band_hue(band);
+    let end = color_band_hue(next_band);
+    let span = (end - start + TAU) % TAU;
+    let offset = (hue - start + TAU) % TAU;
+
+    if (offset < span) {
+      lower = band;
+      blend = offset / span;
+      break;
+    }
+  }
+
+  let upper = (lower + 1u) % COLOR_BAND_COUNT;
+  let controls = mix(color_band_controls(lower), color_band_controls(upper), blend);
+
+  let mixed_hue = hue + controls.x * COLOR_MIXER_HUE_RANGE * strength;
+  let mixed_chroma = chroma * max(1.0 + controls.y * strength, 0.0);
+  // OkLab lightness follows the cube root of luminance, so a one-stop change
+  // scales it by 2^(1/3).
+  let mixed_lightness = lab.x * exp2(controls.z * COLOR_MIXER_LUMINANCE_RANGE_EV * strength / 3.0);
+
+  return oklab_to_rec2020(
+    vec3<f32>(mixed_lightness, mixed_chroma * cos(mixed_hue), mixed_chroma * sin(mixed_hue))
+  );
+}
+
 @compute @workgroup_size(16, 16)
 fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
   let output_size = textureDimensions(output_texture);
@@ -25,7 +265,11 @@ fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
 
   let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
   let source_color = textureLoad(source_texture, pixel, 0);
-  let adjusted_color = apply_exposure(source_color.rgb, adjustment_parameters.exposure.x);
+  let exposed_color = apply_exposure(source_color.rgb, adjustment_parameters.exposure.x);
+  let toned_color = apply_tone(exposed_color);
+  let curved_color = apply_tone_curve(toned_color);
+  let saturated_color = apply_saturation(curved_color);
+  let adjusted_color = apply_color_mixer(saturated_color);
 
   textureStore(output_texture, pixel, vec4<f32>(adjusted_color, source_color.a));
 }
@@ -6,6 +6,13 @@ struct DevelopmentParameters {
   camera_to_working_red: vec4<f32>,
   camera_to_working_green: vec4<f32>,
   camera_to_working_blue: vec4<f32>,
+  raw_options: vec4<u32>,
+  cfa_pattern_6x6: array<vec4<u32>, 9>,
+  lens_warp_radial: array<vec4<f32>, 3>,
+  lens_warp_tangential: array<vec4<f32>, 3>,
+  lens_warp_center: vec4<f32>,
+  noise_reduction: vec4<f32>,
+  capture_sharpening: vec4<f32>,
 };
 
 @group(0) @binding(0)
@@ -34,7 +34,8 @@ fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
   let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
   let source_color = textureLoad(source_texture, pixel, 0);
   let linear_srgb = srgb_to_linear_srgb(source_color.rgb);
-  let working_color = linear_srgb_to_linear_rec2020(linear_srgb);
+  let white_balanced_srgb = linear_srgb * development_parameters.white_balance.xyz;
+  let working_color = linear_srgb_to_linear_rec2020(white_balanced_srgb);
 
   textureStore(output_texture, pixel, vec4<f32>(working_color, source_color.a));
 }
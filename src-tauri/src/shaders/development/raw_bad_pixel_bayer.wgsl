@@ -0,0 +1,77 @@
+
+// A photosite is a hot or dead pixel when it sits outside every same-color
+// neighbour by both an absolute margin and a fraction of that neighbour.
+const BAD_PIXEL_ABSOLUTE_THRESHOLD: f32 = 0.02;
+const HOT_PIXEL_RELATIVE_THRESHOLD: f32 = 1.0;
+const DEAD_PIXEL_RELATIVE_THRESHOLD: f32 = 0.75;
+
+fn clamp_source_pixel(pixel: vec2<i32>) -> vec2<i32> {
+  let source_size = textureDimensions(source_texture);
+  let max_pixel = vec2<i32>(i32(source_size.x) - 1, i32(source_size.y) - 1);
+
+  return clamp(pixel, vec2<i32>(0, 0), max_pixel);
+}
+
+// Loads the same-color photosite at `offset`, mirroring across the image edge
+// so the neighbour keeps the center's CFA color.
+fn same_color_neighbor(pixel: vec2<i32>, offset: vec2<i32>) -> vec4<f32> {
+  let source_size = vec2<i32>(textureDimensions(source_texture));
+  var neighbor = pixel + offset;
+
+  if (neighbor.x < 0 || neighbor.x >= source_size.x) {
+    neighbor.x = pixel.x - offset.x;
+  }
+
+  if (neighbor.y < 0 || neighbor.y >= source_size.y) {
+    neighbor.y = pixel.y - offset.y;
+  }
+
+  return textureLoad(source_texture, clamp_source_pixel(neighbor), 0);
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let center = textureLoad(source_texture, pixel, 0);
+
+  let left = same_color_neighbor(pixel, vec2<i32>(-2, 0));
+  let right = same_color_neighbor(pixel, vec2<i32>(2, 0));
+  let up = same_color_neighbor(pixel, vec2<i32>(0, -2));
+  let down = same_color_neighbor(pixel, vec2<i32>(0, 2));
+  let up_left = same_color_neighbor(pixel, vec2<i32>(-2, -2)).r;
+  let up_right = same_color_neighbor(pixel, vec2<i32>(2, -2)).r;
+  let down_left = same_color_neighbor(pixel, vec2<i32>(-2, 2)).r;
+  let down_right = same_color_neighbor(pixel, vec2<i32>(2, 2)).r;
+
+  let orthogonal_max = max(max(left.r, right.r), max(up.r, down.r));
+  let orthogonal_min = min(min(left.r, right.r), min(up.r, down.r));
+  let diagonal_max = max(max(up_left, up_right), max(down_left, down_right));
+  let diagonal_min = min(min(up_left, up_right), min(down_left, down_right));
+  let neighbor_max = max(orthogonal_max, diagonal_max);
+  let neighbor_min = min(orthogonal_min, diagonal_min);
+
+  let hot = center.r - neighbor_max
+    > max(BAD_PIXEL_ABSOLUTE_THRESHOLD, neighbor_max * HOT_PIXEL_RELATIVE_THRESHOLD);
+  let dead = neighbor_min - center.r
+    > max(BAD_PIXEL_ABSOLUTE_THRESHOLD, neighbor_min * DEAD_PIXEL_RELATIVE_THRESHOLD);
+  let mapped = center.b >= 0.5;
+
+  if (!hot && !dead && !mapped) {
+    textureStore(output_texture, pixel, vec4<f32>(center.r, center.g, 0.0, 1.0));
+    return;
+  }
+
+  // Median of the four orthogonal neighbours, robust to one defective neighbour.
+  let orthogonal_sum = left.r + right.r + up.r + down.r;
+  let replacement = (orthogonal_sum - orthogonal_max - orthogonal_min) * 0.5;
+  let clipped_neighbors = left.g + right.g + up.g + down.g;
+  let clipped = select(0.0, 1.0, clipped_neighbors >= 2.0);
+
+  textureStore(output_texture, pixel, vec4<f32>(replacement, clipped, 1.0, 1.0));
+}
@@ -0,0 +1,75 @@
+
+// Restores demosaic and anti-aliasing softness with a linear-light unsharp mask.
+//
+// Luminance is blurred with a Gaussian of the configured radius, and the
+// difference from the blur is added back, scaled by amount. Relative detail
+// below the threshold is treated as noise and left unsharpened. Color is
+// preserved by scaling RGB with the luminance ratio. In mask preview the
+// stage writes the sharpening mask as greyscale instead.
+
+const SHARPENING_MAX_RADIUS: i32 = 6;
+const SHARPENING_GAIN: f32 = 2.0;
+const SHARPENING_THRESHOLD_SCALE: f32 = 0.1;
+const SHARPENING_LEVEL_FLOOR: f32 = 0.01;
+const SHARPENING_EPSILON: f32 = 0.000001;
+
+fn rec2020_luminance(color: vec3<f32>) -> f32 {
+  return dot(color, vec3<f32>(0.2627, 0.6780, 0.0593));
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let source = textureLoad(source_texture, pixel, 0);
+  let settings = development_parameters.capture_sharpening;
+  let amount = settings.x;
+  let mask_preview = settings.w >= 0.5;
+
+  if (amount <= 0.0 && !mask_preview) {
+    textureStore(output_texture, pixel, source);
+    return;
+  }
+
+  let sigma = max(settings.y, 0.1);
+  let radius = min(i32(ceil(sigma * 3.0)), SHARPENING_MAX_RADIUS);
+  let source_size = vec2<i32>(textureDimensions(source_texture));
+  let max_pixel = source_size - vec2<i32>(1, 1);
+  let luminance = max(rec2020_luminance(source.rgb), 0.0);
+
+  var blur_sum = 0.0;
+  var weight_sum = 0.0;
+
+  for (var y = -radius; y <= radius; y += 1) {
+    for (var x = -radius; x <= radius; x += 1) {
+      let offset = vec2<i32>(x, y);
+      let sample_pixel = clamp(pixel + offset, vec2<i32>(0, 0), max_pixel);
+      let weight = exp(-f32(dot(offset, offset)) / (2.0 * sigma * sigma));
+
+      blur_sum += max(rec2020_luminance(textureLoad(source_texture, sample_pixel, 0).rgb), 0.0)
+        * weight;
+      weight_sum += weight;
+    }
+  }
+
+  let blurred = blur_sum / max(weight_sum, SHARPENING_EPSILON);
+  let detail = luminance - blurred;
+  let relative_detail = abs(detail) / (blurred + SHARPENING_LEVEL_FLOOR);
+  let threshold = settings.z * SHARPENING_THRESHOLD_SCALE;
+  let mask = smoothstep(threshold, threshold * 2.0 + SHARPENING_EPSILON, relative_detail);
+
+  if (mask_preview) {
+    textureStore(output_texture, pixel, vec4<f32>(vec3<f32>(mask), source.a));
+    return;
+  }
+
+  let sharpened = max(luminance + detail * amount * SHARPENING_GAIN * mask, 0.0);
+  let ratio = select(1.0, sharpened / luminance, luminance > SHARPENING_EPSILON);
+
+  textureStore(output_texture, pixel, vec4<f32>(source.rgb * ratio, source.a));
+}
@@ -0,0 +1,87 @@
+
+const CFA_COLOR_RED: u32 = 0u;
+const CFA_COLOR_BLUE: u32 = 2u;
+
+// Green difference, in normalized units, at which a neighbour's weight halves.
+const XTRANS_CHROMA_EDGE_SOFTNESS: f32 = 0.01;
+// Every X-Trans photosite has red and blue neighbours inside a 5x5 window.
+const XTRANS_CHROMA_RADIUS: i32 = 2;
+
+fn clamp_source_pixel(pixel: vec2<i32>) -> vec2<i32> {
+  let source_size = textureDimensions(source_texture);
+  let max_pixel = vec2<i32>(i32(source_size.x) - 1, i32(source_size.y) - 1);
+
+  return clamp(pixel, vec2<i32>(0, 0), max_pixel);
+}
+
+fn xtrans_color_at(pixel: vec2<i32>) -> u32 {
+  let clamped_pixel = clamp_source_pixel(pixel);
+  let index = (u32(clamped_pixel.y % 6) * 6u) + u32(clamped_pixel.x % 6);
+
+  return development_parameters.cfa_pattern_6x6[index / 4u][index % 4u];
+}
+
+// Interpolates one missing channel as green plus the local color difference.
+//
+// Color differences are taken from photosites of that color in a 5x5 window,
+// weighted by distance and by how closely their green matches the center so
+// chroma does not bleed across edges the green pass already resolved.
+fn interpolate_channel(pixel: vec2<i32>, center_green: f32, color: u32) -> f32 {
+  var accumulated = vec2<f32>(0.0, 0.0);
+
+  let radius = XTRANS_CHROMA_RADIUS;
+
+  for (var offset_y: i32 = -radius; offset_y <= radius; offset_y = offset_y + 1) {
+    for (var offset_x: i32 = -radius; offset_x <= radius; offset_x = offset_x + 1) {
+      let neighbor = pixel + vec2<i32>(offset_x, offset_y);
+
+      if (xtrans_color_at(neighbor) != color) {
+        continue;
+      }
+
+      let neighbor_texel = textureLoad(source_texture, clamp_source_pixel(neighbor), 0);
+      let distance_squared = f32((offset_x * offset_x) + (offset_y * offset_y));
+      let green_difference = abs(neighbor_texel.g - center_green);
+      let weight = 1.0
+        / ((1.0 + distance_squared) * (green_difference + XTRANS_CHROMA_EDGE_SOFTNESS));
+      let color_difference = neighbor_texel.r - neighbor_texel.g;
+
+      accumulated = accumulated + vec2<f32>(color_difference * weight, weight);
+    }
+  }
+
+  if (accumulated.y <= 0.0) {
+    return center_green;
+  }
+
+  return max(center_green + (accumulated.x / accumulated.y), 0.0);
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let center_texel = textureLoad(source_texture, pixel, 0);
+  let center_color = xtrans_color_at(pixel);
+  let center_green = center_texel.g;
+  var camera_color = vec3<f32>(0.0, center_green, 0.0);
+
+  if (center_color == CFA_COLOR_RED) {
+    camera_color.r = center_texel.r;
+  } else {
+    camera_color.r = interpolate_channel(pixel, center_green, CFA_COLOR_RED);
+  }
+
+  if (center_color == CFA_COLOR_BLUE) {
+    camera_color.b = center_texel.r;
+  } else {
+    camera_color.b = interpolate_channel(pixel, center_green, CFA_COLOR_BLUE);
+  }
+
+  textureStore(output_texture, pixel, vec4<f32>(camera_color, 1.0));
+}
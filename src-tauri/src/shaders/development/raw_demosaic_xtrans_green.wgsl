@@ -0,0 +1,168 @@
+
+const CFA_COLOR_GREEN: u32 = 1u;
+
+const XTRANS_EPSILON: f32 = 0.000001;
+// Every X-Trans red or blue photosite has a green within three photosites
+// horizontally and vertically, and a same-color photosite within one period.
+const XTRANS_GREEN_SEARCH_DISTANCE: i32 = 3;
+const XTRANS_SAME_COLOR_SEARCH_DISTANCE: i32 = 6;
+
+fn clamp_source_pixel(pixel: vec2<i32>) -> vec2<i32> {
+  let source_size = textureDimensions(source_texture);
+  let max_pixel = vec2<i32>(i32(source_size.x) - 1, i32(source_size.y) - 1);
+
+  return clamp(pixel, vec2<i32>(0, 0), max_pixel);
+}
+
+fn xtrans_color_at(pixel: vec2<i32>) -> u32 {
+  let clamped_pixel = clamp_source_pixel(pixel);
+  let index = (u32(clamped_pixel.y % 6) * 6u) + u32(clamped_pixel.x % 6);
+
+  return development_parameters.cfa_pattern_6x6[index / 4u][index % 4u];
+}
+
+fn xtrans_sample_at(pixel: vec2<i32>) -> f32 {
+  return textureLoad(source_texture, clamp_source_pixel(pixel), 0).r;
+}
+
+// Returns the nearest sample of `color` along `step`, as (value, distance).
+// Distance is zero when no such photosite is within `max_distance`.
+fn nearest_color_along(
+  pixel: vec2<i32>,
+  step: vec2<i32>,
+  color: u32,
+  max_distance: i32
+) -> vec2<f32> {
+  for (var distance: i32 = 1; distance <= max_distance; distance = distance + 1) {
+    let neighbor = pixel + (step * distance);
+
+    if (xtrans_color_at(neighbor) == color) {
+      return vec2<f32>(xtrans_sample_at(neighbor), f32(distance));
+    }
+  }
+
+  return vec2<f32>(0.0, 0.0);
+}
+
+// Interpolates between two directional samples by their distances.
+fn interpolate_between(before: vec2<f32>, after: vec2<f32>) -> f32 {
+  return ((before.x * after.y) + (after.x * before.y)) / (before.y + after.y);
+}
+
+// Estimates green along one direction, as (value, gradient, weight).
+//
+// Like Markesteijn's directional pass, the green neighbours are interpolated,
+// corrected by the center's deviation from its own color along the same line,
+// and clamped to the neighbouring greens so the correction cannot overshoot.
+fn directional_green(
+  pixel: vec2<i32>,
+  center: f32,
+  center_color: u32,
+  step: vec2<i32>
+) -> vec3<f32> {
+  let green_before =
+    nearest_color_along(pixel, -step, CFA_COLOR_GREEN, XTRANS_GREEN_SEARCH_DISTANCE);
+  let green_after =
+    nearest_color_along(pixel, step, CFA_COLOR_GREEN, XTRANS_GREEN_SEARCH_DISTANCE);
+
+  if (green_before.y == 0.0 || green_after.y == 0.0) {
+    return vec3<f32>(0.0, 0.0, 0.0);
+  }
+
+  let green = interpolate_between(green_before, green_after);
+  var correction = 0.0;
+  var center_gradient = 0.0;
+  let same_before =
+    nearest_color_along(pixel, -step, center_color, XTRANS_SAME_COLOR_SEARCH_DISTANCE);
+  let same_after =
+    nearest_color_along(pixel, step, center_color, XTRANS_SAME_COLOR_SEARCH_DISTANCE);
+
+  if (same_before.y > 0.0 && same_after.y > 0.0) {
+    let same_color = interpolate_between(same_before, same_after);
+
+    correction = (center - same_color) * 0.5;
+    center_gradient = abs(center - same_color);
+  }
+
+  let corrected_green = clamp(
+    green + correction,
+    min(green_before.x, green_after.x),
+    max(green_before.x, green_after.x)
+  );
+  let gradient = abs(green_after.x - green_before.x) + center_gradient;
+
+  return vec3<f32>(corrected_green, gradient, 1.0);
+}
+
+fn accumulate_direction(accumulated: vec2<f32>, estimate: vec3<f32>) -> vec2<f32> {
+  let weight = estimate.z / ((estimate.y + XTRANS_EPSILON) * (estimate.y + XTRANS_EPSILON));
+
+  return accumulated + vec2<f32>(estimate.x * weight, weight);
+}
+
+// Averages every green photosite in the 3x3 neighbourhood.
+fn neighborhood_green(pixel: vec2<i32>) -> f32 {
+  var sum = 0.0;
+  var count = 0.0;
+
+  for (var offset_y: i32 = -1; offset_y <= 1; offset_y = offset_y + 1) {
+    for (var offset_x: i32 = -1; offset_x <= 1; offset_x = offset_x + 1) {
+      let neighbor = pixel + vec2<i32>(offset_x, offset_y);
+
+      if (xtrans_color_at(neighbor) == CFA_COLOR_GREEN) {
+        sum = sum + xtrans_sample_at(neighbor);
+        count = count + 1.0;
+      }
+    }
+  }
+
+  return sum / max(count, 1.0);
+}
+
+fn green_at_red_or_blue(pixel: vec2<i32>, center_color: u32) -> f32 {
+  let center = xtrans_sample_at(pixel);
+  var accumulated = vec2<f32>(0.0, 0.0);
+
+  accumulated = accumulate_direction(
+    accumulated,
+    directional_green(pixel, center, center_color, vec2<i32>(1, 0))
+  );
+  accumulated = accumulate_direction(
+    accumulated,
+    directional_green(pixel, center, center_color, vec2<i32>(0, 1))
+  );
+  accumulated = accumulate_direction(
+    accumulated,
+    directional_green(pixel, center, center_color, vec2<i32>(1, 1))
+  );
+  accumulated = accumulate_direction(
+    accumulated,
+    directional_green(pixel, center, center_color, vec2<i32>(1, -1))
+  );
+
+  if (accumulated.y <= 0.0) {
+    return neighborhood_green(pixel);
+  }
+
+  return max(accumulated.x / accumulated.y, 0.0);
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let source_texel = textureLoad(source_texture, pixel, 0);
+  let center_color = xtrans_color_at(pixel);
+  var green = source_texel.r;
+
+  if (center_color != CFA_COLOR_GREEN) {
+    green = green_at_red_or_blue(pixel, center_color);
+  }
+
+  textureStore(output_texture, pixel, vec4<f32>(source_texel.r, green, source_texel.g, 1.0));
+}
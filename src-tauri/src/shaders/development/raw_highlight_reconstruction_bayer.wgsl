@@ -0,0 +1,110 @@
+const HIGHLIGHT_RECONSTRUCTION_OFF: u32 = 0u;
+const HIGHLIGHT_RECONSTRUCTION_CLIP: u32 = 1u;
+const HIGHLIGHT_RECONSTRUCTION_BLEND: u32 = 2u;
+const HIGHLIGHT_RECONSTRUCTION_INPAINT: u32 = 3u;
+
+const HIGHLIGHT_WINDOW_RADIUS: i32 = 2;
+const HIGHLIGHT_EPSILON: f32 = 0.000001;
+
+fn clamp_source_pixel(pixel: vec2<i32>) -> vec2<i32> {
+  let source_size = textureDimensions(source_texture);
+  let max_pixel = vec2<i32>(i32(source_size.x) - 1, i32(source_size.y) - 1);
+
+  return clamp(pixel, vec2<i32>(0, 0), max_pixel);
+}
+
+fn raw_cfa_color_at(pixel: vec2<i32>) -> u32 {
+  let slot = (u32(pixel.y % 2) * 2u) + u32(pixel.x % 2);
+
+  return min(development_parameters.cfa_pattern[slot], 2u);
+}
+
+// Returns the development white-balance gain for the photosite color at `pixel`.
+fn cfa_white_balance_at(pixel: vec2<i32>) -> f32 {
+  return max(development_parameters.white_balance[raw_cfa_color_at(pixel)], HIGHLIGHT_EPSILON);
+}
+
+// Lowest white-balanced clip level across channels. Above this, at least one
+// channel has lost information and highlights can no longer stay neutral.
+fn white_balanced_clip_floor() -> f32 {
+  let white_balance = development_parameters.white_balance.xyz;
+
+  return min(white_balance.r, min(white_balance.g, white_balance.b));
+}
+
+// Repairs one clipped photosite from its 5x5 neighbourhood in white-balanced units.
+// Blend moves toward the brightest neighbour by the clipped fraction of the
+// window. Inpaint uses unclipped photosites of other colors and falls back to
+// the brightest neighbour when the whole window is clipped.
+fn reconstruct_clipped_sample(pixel: vec2<i32>, balanced_sample: f32, mode: u32) -> f32 {
+  let center_color = raw_cfa_color_at(pixel);
+
+  var window_max = balanced_sample;
+  var clipped_count = 0.0;
+  var window_count = 0.0;
+  var unclipped_sum = 0.0;
+  var unclipped_count = 0.0;
+
+  for (var offset_y = -HIGHLIGHT_WINDOW_RADIUS; offset_y <= HIGHLIGHT_WINDOW_RADIUS; offset_y += 1) {
+    for (var offset_x = -HIGHLIGHT_WINDOW_RADIUS; offset_x <= HIGHLIGHT_WINDOW_RADIUS; offset_x += 1) {
+      let neighbor = clamp_source_pixel(pixel + vec2<i32>(offset_x, offset_y));
+      let neighbor_metadata = textureLoad(source_texture, neighbor, 0);
+      let neighbor_balanced = neighbor_metadata.r * cfa_white_balance_at(neighbor);
+      let neighbor_clipped = neighbor_metadata.g >= 0.5;
+
+      window_max = max(window_max, neighbor_balanced);
+      window_count += 1.0;
+
+      if (neighbor_clipped) {
+        clipped_count += 1.0;
+      } else if (raw_cfa_color_at(neighbor) != center_color) {
+        unclipped_sum += neighbor_balanced;
+        unclipped_count += 1.0;
+      }
+    }
+  }
+
+  if (mode == HIGHLIGHT_RECONSTRUCTION_INPAINT) {
+    if (unclipped_count > 0.0) {
+      return max(balanced_sample, unclipped_sum / unclipped_count);
+    }
+
+    return window_max;
+  }
+
+  return mix(balanced_sample, window_max, clipped_count / window_count);
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let sample_metadata = textureLoad(source_texture, pixel, 0);
+  let mode = development_parameters.raw_options.x;
+
+  if (mode == HIGHLIGHT_RECONSTRUCTION_OFF) {
+    textureStore(output_texture, pixel, sample_metadata);
+    return;
+  }
+
+  let white_balance = cfa_white_balance_at(pixel);
+  let balanced_sample = sample_metadata.r * white_balance;
+  var reconstructed = balanced_sample;
+
+  if (mode == HIGHLIGHT_RECONSTRUCTION_CLIP) {
+    reconstructed = min(balanced_sample, white_balanced_clip_floor());
+  } else if (sample_metadata.g >= 0.5) {
+    reconstructed = reconstruct_clipped_sample(pixel, balanced_sample, mode);
+  }
+
+  textureStore(
+    output_texture,
+    pixel,
+    vec4<f32>(reconstructed / white_balance, sample_metadata.g, 0.0, 1.0)
+  );
+}
@@ -0,0 +1,88 @@
+
+// Reduces chroma noise in working-space RAW data.
+//
+// Pixels are square-root encoded to even out shot noise, then split into
+// luminance and opponent chroma differences. Chroma is averaged over a wide,
+// sparse window weighted by luminance and chroma similarity so color blotches
+// flatten without bleeding across edges. Luminance is left untouched.
+
+const CHROMA_RADIUS: i32 = 4;
+const CHROMA_TAP_SPACING: i32 = 2;
+const CHROMA_SPATIAL_SIGMA: f32 = 4.0;
+const CHROMA_SIGMA_MAX: f32 = 0.1;
+const CHROMA_EDGE_SIGMA: f32 = 0.08;
+const CHROMA_DETAIL_SIGMA_SCALE: f32 = 0.75;
+const NOISE_REDUCTION_EPSILON: f32 = 0.000001;
+
+fn rec2020_luminance(color: vec3<f32>) -> f32 {
+  return dot(color, vec3<f32>(0.2627, 0.6780, 0.0593));
+}
+
+fn encode_noise_domain(color: vec3<f32>) -> vec3<f32> {
+  return sqrt(max(color, vec3<f32>(0.0, 0.0, 0.0)));
+}
+
+fn decode_noise_domain(encoded: vec3<f32>) -> vec3<f32> {
+  let clamped = max(encoded, vec3<f32>(0.0, 0.0, 0.0));
+
+  return clamped * clamped;
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let source = textureLoad(source_texture, pixel, 0);
+  let amount = development_parameters.noise_reduction.z;
+
+  if (amount <= 0.0) {
+    textureStore(output_texture, pixel, source);
+    return;
+  }
+
+  let detail = development_parameters.noise_reduction.w;
+  let chroma_sigma = max(
+    CHROMA_SIGMA_MAX * amount * (1.0 - CHROMA_DETAIL_SIGMA_SCALE * detail),
+    NOISE_REDUCTION_EPSILON
+  );
+  let source_size = vec2<i32>(textureDimensions(source_texture));
+  let max_pixel = source_size - vec2<i32>(1, 1);
+
+  let center_encoded = encode_noise_domain(source.rgb);
+  let center_luminance = rec2020_luminance(center_encoded);
+  let center_chroma = center_encoded - vec3<f32>(center_luminance);
+
+  var chroma_sum = vec3<f32>(0.0, 0.0, 0.0);
+  var weight_sum = 0.0;
+
+  for (var y = -CHROMA_RADIUS; y <= CHROMA_RADIUS; y += 1) {
+    for (var x = -CHROMA_RADIUS; x <= CHROMA_RADIUS; x += 1) {
+      let offset = vec2<i32>(x, y) * CHROMA_TAP_SPACING;
+      let sample_pixel = clamp(pixel + offset, vec2<i32>(0, 0), max_pixel);
+      let encoded = encode_noise_domain(textureLoad(source_texture, sample_pixel, 0).rgb);
+      let luminance = rec2020_luminance(encoded);
+      let chroma = encoded - vec3<f32>(luminance);
+
+      let distance_squared = f32(dot(offset, offset));
+      let chroma_delta = chroma - center_chroma;
+      let luminance_delta = luminance - center_luminance;
+      let weight = exp(-distance_squared / (2.0 * CHROMA_SPATIAL_SIGMA * CHROMA_SPATIAL_SIGMA))
+        * exp(-dot(chroma_delta, chroma_delta) / (2.0 * chroma_sigma * chroma_sigma))
+        * exp(-(luminance_delta * luminance_delta) / (2.0 * CHROMA_EDGE_SIGMA * CHROMA_EDGE_SIGMA));
+
+      chroma_sum += chroma * weight;
+      weight_sum += weight;
+    }
+  }
+
+  let filtered_chroma = chroma_sum / max(weight_sum, NOISE_REDUCTION_EPSILON);
+  let chroma = mix(center_chroma, filtered_chroma, amount);
+  let color = decode_noise_domain(vec3<f32>(center_luminance) + chroma);
+
+  textureStore(output_texture, pixel, vec4<f32>(color, source.a));
+}
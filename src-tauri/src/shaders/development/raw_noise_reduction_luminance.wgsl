@@ -0,0 +1,82 @@
+
+// Reduces luminance noise in working-space RAW data with an edge-aware filter.
+//
+// Luminance is square-root encoded so noise is roughly uniform across tones,
+// then bilaterally filtered: neighbours that differ by more than the noise
+// range are ignored, which keeps edges sharp. Detail restores part of the
+// removed high-frequency signal. Chroma differences are carried through.
+
+const LUMINANCE_RADIUS: i32 = 3;
+const LUMINANCE_SPATIAL_SIGMA: f32 = 2.0;
+const LUMINANCE_SIGMA_MAX: f32 = 0.08;
+const LUMINANCE_DETAIL_RESTORE: f32 = 0.5;
+const NOISE_REDUCTION_EPSILON: f32 = 0.000001;
+
+fn rec2020_luminance(color: vec3<f32>) -> f32 {
+  return dot(color, vec3<f32>(0.2627, 0.6780, 0.0593));
+}
+
+fn encode_noise_domain(color: vec3<f32>) -> vec3<f32> {
+  return sqrt(max(color, vec3<f32>(0.0, 0.0, 0.0)));
+}
+
+fn decode_noise_domain(encoded: vec3<f32>) -> vec3<f32> {
+  let clamped = max(encoded, vec3<f32>(0.0, 0.0, 0.0));
+
+  return clamped * clamped;
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let source = textureLoad(source_texture, pixel, 0);
+  let amount = development_parameters.noise_reduction.x;
+
+  if (amount <= 0.0) {
+    textureStore(output_texture, pixel, source);
+    return;
+  }
+
+  let detail = development_parameters.noise_reduction.y;
+  let range_sigma = max(LUMINANCE_SIGMA_MAX * amount, NOISE_REDUCTION_EPSILON);
+  let source_size = vec2<i32>(textureDimensions(source_texture));
+  let max_pixel = source_size - vec2<i32>(1, 1);
+
+  let center_encoded = encode_noise_domain(source.rgb);
+  let center_luminance = rec2020_luminance(center_encoded);
+
+  var luminance_sum = 0.0;
+  var weight_sum = 0.0;
+
+  for (var y = -LUMINANCE_RADIUS; y <= LUMINANCE_RADIUS; y += 1) {
+    for (var x = -LUMINANCE_RADIUS; x <= LUMINANCE_RADIUS; x += 1) {
+      let offset = vec2<i32>(x, y);
+      let sample_pixel = clamp(pixel + offset, vec2<i32>(0, 0), max_pixel);
+      let luminance = rec2020_luminance(
+        encode_noise_domain(textureLoad(source_texture, sample_pixel, 0).rgb)
+      );
+
+      let distance_squared = f32(dot(offset, offset));
+      let luminance_delta = luminance - center_luminance;
+      let weight = exp(
+        -distance_squared / (2.0 * LUMINANCE_SPATIAL_SIGMA * LUMINANCE_SPATIAL_SIGMA)
+      ) * exp(-(luminance_delta * luminance_delta) / (2.0 * range_sigma * range_sigma));
+
+      luminance_sum += luminance * weight;
+      weight_sum += weight;
+    }
+  }
+
+  let filtered_luminance = luminance_sum / max(weight_sum, NOISE_REDUCTION_EPSILON);
+  let luminance = filtered_luminance
+    + (center_luminance - filtered_luminance) * detail * LUMINANCE_DETAIL_RESTORE;
+  let color = decode_noise_domain(center_encoded + vec3<f32>(luminance - center_luminance));
+
+  textureStore(output_texture, pixel, vec4<f32>(color, source.a));
+}
@@ -5,15 +5,19 @@ fn raw_cfa_slot(pixel: vec2<i32>) -> u32 {
 
 fn raw_sample_metadata_at(pixel: vec2<i32>) -> vec4<f32> {
   let slot = raw_cfa_slot(pixel);
-  let source_sample = textureLoad(source_texture, pixel, 0).r;
+  let source_texel = textureLoad(source_texture, pixel, 0);
+  let source_sample = source_texel.r;
   let black_level = development_parameters.black_levels[slot];
   let white_level = development_parameters.white_levels[slot];
   let sample_range = max(white_level - black_level, 0.000001);
   let clip_epsilon = max(sample_range * 0.0005, 0.000001);
-  let normalized_sample = max((source_sample - black_level) / sample_range, 0.0);
+  // DNG GainMap gains are evaluated during upload and carried in the blue channel.
+  let gain = max(source_texel.b, 0.0);
+  let normalized_sample = max((source_sample - black_level) / sample_range, 0.0) * gain;
   let clipped = select(0.0, 1.0, source_sample >= white_level - clip_epsilon);
+  let mapped_bad_pixel = select(0.0, 1.0, source_texel.g >= 0.5);
 
-  return vec4<f32>(normalized_sample, clipped, 0.0, 1.0);
+  return vec4<f32>(normalized_sample, clipped, mapped_bad_pixel, 1.0);
 }
 
 @compute @workgroup_size(16, 16)
@@ -0,0 +1,24 @@
+// Linear sources carry no CFA layout, so the level slots hold the red, green
+// and blue plane levels in x, y and z and each channel is normalized with its own.
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let source_color = textureLoad(source_texture, pixel, 0).rgb;
+  let black_levels = development_parameters.black_levels.xyz;
+  let sample_range = max(
+    development_parameters.white_levels.xyz - black_levels,
+    vec3<f32>(0.000001)
+  );
+  let camera_color = max(
+    (source_color - black_levels) / sample_range,
+    vec3<f32>(0.0, 0.0, 0.0)
+  );
+
+  textureStore(output_texture, pixel, vec4<f32>(camera_color, 1.0));
+}
@@ -0,0 +1,43 @@
+
+// X-Trans levels are reported per 2x2 slot, which does not follow the 6x6
+// color layout, so the whole mosaic is normalized with one black and one white level.
+fn xtrans_black_level() -> f32 {
+  let levels = development_parameters.black_levels;
+
+  return (levels.x + levels.y + levels.z + levels.w) * 0.25;
+}
+
+fn xtrans_white_level() -> f32 {
+  let levels = development_parameters.white_levels;
+
+  return min(min(levels.x, levels.y), min(levels.z, levels.w));
+}
+
+fn raw_sample_metadata_at(pixel: vec2<i32>) -> vec4<f32> {
+  let source_texel = textureLoad(source_texture, pixel, 0);
+  let source_sample = source_texel.r;
+  let black_level = xtrans_black_level();
+  let white_level = xtrans_white_level();
+  let sample_range = max(white_level - black_level, 0.000001);
+  let clip_epsilon = max(sample_range * 0.0005, 0.000001);
+  let gain = max(source_texel.b, 0.0);
+  let normalized_sample = max((source_sample - black_level) / sample_range, 0.0) * gain;
+  let clipped = select(0.0, 1.0, source_sample >= white_level - clip_epsilon);
+  let mapped_bad_pixel = select(0.0, 1.0, source_texel.g >= 0.5);
+
+  return vec4<f32>(normalized_sample, clipped, mapped_bad_pixel, 1.0);
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let sample_metadata = raw_sample_metadata_at(pixel);
+
+  textureStore(output_texture, pixel, sample_metadata);
+}
@@ -0,0 +1,82 @@
+
+// Applies DNG WarpRectilinear distortion correction per color plane.
+//
+// Each output pixel is mapped back into the uncorrected image with the radial
+// and tangential model from the DNG specification, then bilinearly resampled.
+// With no warp enabled the stage copies its source unchanged.
+
+fn clamp_source_pixel(pixel: vec2<i32>, source_size: vec2<i32>) -> vec2<i32> {
+  return clamp(pixel, vec2<i32>(0, 0), source_size - vec2<i32>(1, 1));
+}
+
+fn sample_source_bilinear(position: vec2<f32>, source_size: vec2<i32>) -> vec4<f32> {
+  let texel_position = position - vec2<f32>(0.5, 0.5);
+  let base = floor(texel_position);
+  let weight = texel_position - base;
+  let pixel = vec2<i32>(base);
+
+  let top_left = textureLoad(source_texture, clamp_source_pixel(pixel, source_size), 0);
+  let top_right = textureLoad(
+    source_texture,
+    clamp_source_pixel(pixel + vec2<i32>(1, 0), source_size),
+    0
+  );
+  let bottom_left = textureLoad(
+    source_texture,
+    clamp_source_pixel(pixel + vec2<i32>(0, 1), source_size),
+    0
+  );
+  let bottom_right = textureLoad(
+    source_texture,
+    clamp_source_pixel(pixel + vec2<i32>(1, 1), source_size),
+    0
+  );
+
+  return mix(mix(top_left, top_right, weight.x), mix(bottom_left, bottom_right, weight.x), weight.y);
+}
+
+fn warp_source_position(position: vec2<f32>, plane: u32) -> vec2<f32> {
+  let center = development_parameters.lens_warp_center.xy;
+  let normalization_radius = max(development_parameters.lens_warp_center.z, 0.000001);
+  let radial = development_parameters.lens_warp_radial[plane];
+  let tangential = development_parameters.lens_warp_tangential[plane].xy;
+
+  let delta = (position - center) / normalization_radius;
+  let radius_squared = dot(delta, delta);
+  let radial_ratio = radial.x
+    + (radial.y * radius_squared)
+    + (radial.z * radius_squared * radius_squared)
+    + (radial.w * radius_squared * radius_squared * radius_squared);
+  let tangential_offset = vec2<f32>(
+    (2.0 * tangential.x * delta.x * delta.y)
+      + (tangential.y * (radius_squared + (2.0 * delta.x * delta.x))),
+    (2.0 * tangential.y * delta.x * delta.y)
+      + (tangential.x * (radius_squared + (2.0 * delta.y * delta.y)))
+  );
+
+  return center + (((delta * radial_ratio) + tangential_offset) * normalization_radius);
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+
+  if (development_parameters.lens_warp_center.w < 0.5) {
+    textureStore(output_texture, pixel, textureLoad(source_texture, pixel, 0));
+    return;
+  }
+
+  let source_size = vec2<i32>(textureDimensions(source_texture));
+  let position = vec2<f32>(pixel) + vec2<f32>(0.5, 0.5);
+  let red = sample_source_bilinear(warp_source_position(position, 0u), source_size).r;
+  let green = sample_source_bilinear(warp_source_position(position, 1u), source_size).g;
+  let blue = sample_source_bilinear(warp_source_position(position, 2u), source_size).b;
+
+  textureStore(output_texture, pixel, vec4<f32>(red, green, blue, 1.0));
+}
@@ -0,0 +1,71 @@
+struct GeometryParameters {
+  source_x: vec4<f32>,
+  source_y: vec4<f32>,
+};
+
+@group(0) @binding(0)
+var source_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var output_texture: texture_storage_2d<rgba32float, write>;
+
+@group(0) @binding(2)
+var<uniform> geometry_parameters: GeometryParameters;
+
+fn clamp_source_pixel(pixel: vec2<i32>, source_size: vec2<i32>) -> vec2<i32> {
+  return clamp(pixel, vec2<i32>(0, 0), source_size - vec2<i32>(1, 1));
+}
+
+fn sample_source_bilinear(position: vec2<f32>, source_size: vec2<i32>) -> vec4<f32> {
+  let texel_position = position - vec2<f32>(0.5, 0.5);
+  let base = floor(texel_position);
+  let weight = texel_position - base;
+  let pixel = vec2<i32>(base);
+
+  let top_left = textureLoad(source_texture, clamp_source_pixel(pixel, source_size), 0);
+  let top_right = textureLoad(
+    source_texture,
+    clamp_source_pixel(pixel + vec2<i32>(1, 0), source_size),
+    0
+  );
+  let bottom_left = textureLoad(
+    source_texture,
+    clamp_source_pixel(pixel + vec2<i32>(0, 1), source_size),
+    0
+  );
+  let bottom_right = textureLoad(
+    source_texture,
+    clamp_source_pixel(pixel + vec2<i32>(1, 1), source_size),
+    0
+  );
+
+  return mix(mix(top_left, top_right, weight.x), mix(bottom_left, bottom_right, weight.x), weight.y);
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let source_size = vec2<i32>(textureDimensions(source_texture));
+  let output_position = vec3<f32>(vec2<f32>(pixel) + vec2<f32>(0.5, 0.5), 1.0);
+  let source_position = vec2<f32>(
+    dot(geometry_parameters.source_x.xyz, output_position),
+    dot(geometry_parameters.source_y.xyz, output_position)
+  );
+
+  // Quarter turns, flips, and crops land on texel centers, so only
+  // straightened output needs filtering.
+  if (geometry_parameters.source_x.w == 0.0) {
+    let source_pixel = clamp_source_pixel(vec2<i32>(floor(source_position)), source_size);
+
+    textureStore(output_texture, pixel, textureLoad(source_texture, source_pixel, 0));
+    return;
+  }
+
+  textureStore(output_texture, pixel, sample_source_bilinear(source_position, source_size));
+}
@@ -0,0 +1,136 @@
+struct LensCorrectionParameters {
+  distortion: vec4<f32>,
+  distortion_tail: vec4<f32>,
+  chromatic_aberration_red: vec4<f32>,
+  chromatic_aberration_blue: vec4<f32>,
+  vignetting: vec4<f32>,
+  options: vec4<u32>,
+};
+
+@group(0) @binding(0)
+var source_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var output_texture: texture_storage_2d<rgba32float, write>;
+
+@group(0) @binding(2)
+var<uniform> lens_correction_parameters: LensCorrectionParameters;
+
+const MIN_VIGNETTING_GAIN: f32 = 0.05;
+
+fn clamp_source_pixel(pixel: vec2<i32>, source_size: vec2<i32>) -> vec2<i32> {
+  return clamp(pixel, vec2<i32>(0, 0), source_size - vec2<i32>(1, 1));
+}
+
+fn sample_source_bilinear(position: vec2<f32>, source_size: vec2<i32>) -> vec4<f32> {
+  let texel_position = position - vec2<f32>(0.5, 0.5);
+  let base = floor(texel_position);
+  let weight = texel_position - base;
+  let pixel = vec2<i32>(base);
+
+  let top_left = textureLoad(source_texture, clamp_source_pixel(pixel, source_size), 0);
+  let top_right = textureLoad(
+    source_texture,
+    clamp_source_pixel(pixel + vec2<i32>(1, 0), source_size),
+    0
+  );
+  let bottom_left = textureLoad(
+    source_texture,
+    clamp_source_pixel(pixel + vec2<i32>(0, 1), source_size),
+    0
+  );
+  let bottom_right = textureLoad(
+    source_texture,
+    clamp_source_pixel(pixel + vec2<i32>(1, 1), source_size),
+    0
+  );
+
+  return mix(mix(top_left, top_right, weight.x), mix(bottom_left, bottom_right, weight.x), weight.y);
+}
+
+// Maps a corrected radius to the captured radius scale, with the radius
+// normalized to half the shorter image side.
+fn distortion_scale(radius: f32) -> f32 {
+  if (lens_correction_parameters.options.x == 0u) {
+    return 1.0;
+  }
+
+  let coefficients = lens_correction_parameters.distortion;
+  let c4 = lens_correction_parameters.distortion_tail.x;
+
+  return coefficients.x
+    + radius * (coefficients.y + radius * (coefficients.z + radius * (coefficients.w + radius * c4)));
+}
+
+// Radial scale of a red or blue channel relative to green at a captured radius.
+fn chromatic_aberration_scale(coefficients: vec4<f32>, radius: f32) -> f32 {
+  if (lens_correction_parameters.options.y == 0u) {
+    return 1.0;
+  }
+
+  return coefficients.x + radius * (coefficients.y + radius * coefficients.z);
+}
+
+// Gain that undoes lens falloff at a captured radius normalized to the image corner.
+fn vignetting_gain(radius: f32) -> f32 {
+  if (lens_correction_parameters.options.z == 0u) {
+    return 1.0;
+  }
+
+  let coefficients = lens_correction_parameters.vignetting;
+  let radius_squared = radius * radius;
+  let falloff = 1.0
+    + radius_squared * (coefficients.x + radius_squared * (coefficients.y + radius_squared * coefficients.z));
+  let manual_gain = max(1.0 + coefficients.w * radius_squared, 0.0);
+
+  return manual_gain / max(falloff, MIN_VIGNETTING_GAIN);
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let options = lens_correction_parameters.options;
+
+  if (options.x == 0u && options.y == 0u && options.z == 0u) {
+    textureStore(output_texture, pixel, textureLoad(source_texture, pixel, 0));
+    return;
+  }
+
+  let source_size = vec2<i32>(textureDimensions(source_texture));
+  let image_size = vec2<f32>(source_size);
+  let center = image_size * 0.5;
+  let distortion_radius = max(min(center.x, center.y), 1.0);
+  let vignetting_radius = max(length(center), 1.0);
+
+  let position = vec2<f32>(pixel) + vec2<f32>(0.5, 0.5);
+  let delta = (position - center) / distortion_radius;
+  let captured_delta = delta * distortion_scale(length(delta));
+  let captured_radius = length(captured_delta);
+
+  let green_position = center + captured_delta * distortion_radius;
+  let red_position = center
+    + captured_delta
+      * chromatic_aberration_scale(lens_correction_parameters.chromatic_aberration_red, captured_radius)
+      * distortion_radius;
+  let blue_position = center
+    + captured_delta
+      * chromatic_aberration_scale(lens_correction_parameters.chromatic_aberration_blue, captured_radius)
+      * distortion_radius;
+
+  let green_sample = sample_source_bilinear(green_position, source_size);
+  let red = sample_source_bilinear(red_position, source_size).r;
+  let blue = sample_source_bilinear(blue_position, source_size).b;
+  let gain = vignetting_gain(length(green_position - center) / vignetting_radius);
+
+  textureStore(
+    output_texture,
+    pixel,
+    vec4<f32>(vec3<f32>(red, green_sample.g, blue) * gain, green_sample.a)
+  );
+}
@@ -0,0 +1,97 @@
+struct LocalAdjustmentParameters {
+  info: vec4<f32>,
+  tone: array<vec4<f32>, 4>,
+  white_balance: array<vec4<f32>, 4>,
+};
+
+@group(0) @binding(0)
+var source_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var output_texture: texture_storage_2d<rgba32float, write>;
+
+@group(0) @binding(2)
+var<uniform> local_adjustment_parameters: LocalAdjustmentParameters;
+
+@group(0) @binding(3)
+var mask_texture: texture_2d<f32>;
+
+const ADJUSTMENT_COUNT: u32 = 4u;
+const SCENE_MIDDLE_GREY: f32 = 0.18;
+const SCENE_EPSILON: f32 = 0.000001;
+const CONTRAST_SLOPE_RANGE: f32 = 0.6;
+// Inspector mask overlay tint and its opacity at full mask strength.
+const MASK_OVERLAY_COLOR: vec3<f32> = vec3<f32>(1.0, 0.05, 0.05);
+const MASK_OVERLAY_OPACITY: f32 = 0.6;
+
+fn rec2020_luminance(color: vec3<f32>) -> f32 {
+  return dot(color, vec3<f32>(0.2627, 0.6780, 0.0593));
+}
+
+// Scales log-luminance around middle grey, preserving channel ratios.
+fn apply_contrast(color: vec3<f32>, contrast: f32) -> vec3<f32> {
+  let luminance = rec2020_luminance(color);
+
+  if (contrast == 0.0 || luminance <= SCENE_EPSILON) {
+    return color;
+  }
+
+  let stops = log2(luminance / SCENE_MIDDLE_GREY);
+
+  return color * exp2(stops * contrast * CONTRAST_SLOPE_RANGE);
+}
+
+fn apply_saturation(color: vec3<f32>, saturation: f32) -> vec3<f32> {
+  let luminance = rec2020_luminance(color);
+
+  return vec3<f32>(luminance) + ((color - vec3<f32>(luminance)) * max(1.0 + saturation, 0.0));
+}
+
+// Applies one local adjustment's exposure, white balance, contrast, and
+// saturation deltas.
+fn apply_local_adjustment(color: vec3<f32>, index: u32) -> vec3<f32> {
+  let tone = local_adjustment_parameters.tone[index];
+  let gains = local_adjustment_parameters.white_balance[index].rgb;
+  let exposed = color * exp2(tone.x) * gains;
+
+  return apply_saturation(apply_contrast(exposed, tone.y), tone.z);
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let source_color = textureLoad(source_texture, pixel, 0);
+  let count = min(u32(local_adjustment_parameters.info.x), ADJUSTMENT_COUNT);
+  var color = source_color.rgb;
+  let overlay = u32(local_adjustment_parameters.info.y);
+
+  // `info.y` selects a mask, plus one, to tint over the unadjusted input.
+  if (overlay > 0u && overlay <= count) {
+    let weight = textureLoad(mask_texture, pixel, 0)[overlay - 1u];
+
+    color = mix(color, MASK_OVERLAY_COLOR, weight * MASK_OVERLAY_OPACITY);
+    textureStore(output_texture, pixel, vec4<f32>(color, source_color.a));
+
+    return;
+  }
+
+  if (count > 0u) {
+    let masks = textureLoad(mask_texture, pixel, 0);
+
+    for (var index = 0u; index < count; index += 1u) {
+      let weight = masks[index];
+
+      if (weight > 0.0) {
+        color = mix(color, apply_local_adjustment(color, index), weight);
+      }
+    }
+  }
+
+  textureStore(output_texture, pixel, vec4<f32>(color, source_color.a));
+}
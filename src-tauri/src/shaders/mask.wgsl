@@ -0,0 +1,228 @@
+struct MaskParameters {
+  info: vec4<f32>,
+  shapes: array<vec4<f32>, 4>,
+  points: array<vec4<f32>, 4>,
+  luminance_ranges: array<vec4<f32>, 4>,
+  color_ranges: array<vec4<f32>, 4>,
+  color_options: array<vec4<f32>, 4>,
+};
+
+@group(0) @binding(0)
+var source_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var output_texture: texture_storage_2d<rgba32float, write>;
+
+@group(0) @binding(2)
+var<uniform> mask_parameters: MaskParameters;
+
+@group(0) @binding(3)
+var brush_dabs: texture_2d<f32>;
+
+const MASK_COUNT: u32 = 4u;
+const MASK_KIND_LINEAR: f32 = 1.0;
+const MASK_KIND_RADIAL: f32 = 2.0;
+const MASK_KIND_BRUSH: f32 = 3.0;
+
+// Brush dabs store `feather + ERASE_OFFSET` in `w` when they erase.
+const ERASE_OFFSET: f32 = 2.0;
+// Texels per row of the brush table. Mirrors `BRUSH_TABLE_WIDTH` in `masks.rs`.
+const BRUSH_TABLE_WIDTH: u32 = 1024u;
+const MIN_FALLOFF: f32 = 0.0001;
+// Chroma over which hue starts to count, so noisy neutral hues are ignored.
+const HUE_CHROMA_RAMP: f32 = 0.02;
+const TAU: f32 = 6.2831853;
+
+// Linear Rec.2020 to OkLab LMS.
+const REC2020_TO_LMS: mat3x3<f32> = mat3x3<f32>(
+  vec3<f32>(0.6166884, 0.2651402, 0.1001506),
+  vec3<f32>(0.3601591, 0.6358565, 0.2040043),
+  vec3<f32>(0.0230433, 0.0990302, 0.6963247)
+);
+
+// Cube-root LMS to OkLab.
+const LMS_TO_OKLAB: mat3x3<f32> = mat3x3<f32>(
+  vec3<f32>(0.2104543, 1.9779985, 0.0259040),
+  vec3<f32>(0.7936178, -2.4285922, 0.7827718),
+  vec3<f32>(-0.0040720, 0.4505937, -0.8086758)
+);
+
+fn signed_cbrt(value: vec3<f32>) -> vec3<f32> {
+  return sign(value) * pow(abs(value), vec3<f32>(1.0 / 3.0));
+}
+
+fn rec2020_to_oklab(color: vec3<f32>) -> vec3<f32> {
+  return LMS_TO_OKLAB * signed_cbrt(REC2020_TO_LMS * color);
+}
+
+// Full strength inside `1 - feather` of the unit radius, fading to zero at 1.
+fn feathered_falloff(distance: f32, feather: f32) -> f32 {
+  let inner = 1.0 - clamp(feather, 0.0, 1.0);
+
+  return 1.0 - smoothstep(inner, max(1.0, inner + MIN_FALLOFF), distance);
+}
+
+fn linear_mask(position: vec2<f32>, points: vec4<f32>, size: vec2<f32>) -> f32 {
+  let start = points.xy * size;
+  let direction = (points.zw * size) - start;
+  let length_squared = dot(direction, direction);
+
+  if (length_squared <= MIN_FALLOFF) {
+    return select(0.0, 1.0, dot(position - start, direction) <= 0.0);
+  }
+
+  let t = dot(position - start, direction) / length_squared;
+
+  return 1.0 - smoothstep(0.0, 1.0, t);
+}
+
+fn radial_mask(position: vec2<f32>, shape: vec4<f32>, points: vec4<f32>, size: vec2<f32>) -> f32 {
+  let long_edge = max(size.x, size.y);
+  let offset = position - (points.xy * size);
+  let cos_angle = cos(shape.w);
+  let sin_angle = sin(shape.w);
+  // Undo the clockwise ellipse rotation before measuring against its radii.
+  let local = vec2<f32>(
+    (cos_angle * offset.x) + (sin_angle * offset.y),
+    (-sin_angle * offset.x) + (cos_angle * offset.y)
+  );
+  let radii = max(points.zw * long_edge, vec2<f32>(MIN_FALLOFF));
+  let mask = feathered_falloff(length(local / radii), shape.z);
+
+  return select(mask, 1.0 - mask, shape.y != 0.0);
+}
+
+fn brush_texel(index: u32) -> vec4<f32> {
+  let texel = vec2<i32>(i32(index % BRUSH_TABLE_WIDTH), i32(index / BRUSH_TABLE_WIDTH));
+
+  return textureLoad(brush_dabs, texel, 0);
+}
+
+// The brush table packs four values per texel after the dab texels.
+fn brush_value(index: u32) -> u32 {
+  return u32(brush_texel(index / 4u)[index % 4u]);
+}
+
+// Only the dabs binned into this pixel's tile are visited, in stroke order.
+fn brush_mask(position: vec2<f32>, points: vec4<f32>, size: vec2<f32>) -> f32 {
+  let long_edge = max(size.x, size.y);
+  let tile_size = u32(mask_parameters.info.y);
+  let tiles_x = (u32(size.x) + tile_size - 1u) / tile_size;
+  let tile = vec2<u32>(position) / tile_size;
+  let header = u32(points.x) + (((tile.y * tiles_x) + tile.x) * 2u);
+  let first = brush_value(header);
+  let count = brush_value(header + 1u);
+  var mask = 0.0;
+
+  for (var index = first; index < first + count; index += 1u) {
+    let dab = brush_texel(brush_value(index));
+    let radius = max(dab.z * long_edge, MIN_FALLOFF);
+    let distance = length(position - (dab.xy * size)) / radius;
+
+    if (distance >= 1.0) {
+      continue;
+    }
+
+    let erase = dab.w >= ERASE_OFFSET;
+    let feather = select(dab.w, dab.w - ERASE_OFFSET, erase);
+    let strength = feathered_falloff(distance, feather);
+
+    if (erase) {
+      mask *= 1.0 - strength;
+    } else {
+      mask = max(mask, strength);
+    }
+  }
+
+  return mask;
+}
+
+fn mask_at(index: u32, position: vec2<f32>, size: vec2<f32>) -> f32 {
+  let shape = mask_parameters.shapes[index];
+  let points = mask_parameters.points[index];
+
+  if (shape.x == MASK_KIND_LINEAR) {
+    return linear_mask(position, points, size);
+  }
+
+  if (shape.x == MASK_KIND_RADIAL) {
+    return radial_mask(position, shape, points, size);
+  }
+
+  if (shape.x == MASK_KIND_BRUSH) {
+    return brush_mask(position, points, size);
+  }
+
+  return 0.0;
+}
+
+// Keeps lightness inside `min..max`, fading over `feather` beyond each end.
+fn luminance_range_weight(lightness: f32, range: vec4<f32>) -> f32 {
+  if (range.w == 0.0) {
+    return 1.0;
+  }
+
+  let feather = max(range.z, MIN_FALLOFF);
+
+  return smoothstep(range.x - feather, range.x, lightness)
+    * (1.0 - smoothstep(range.y, range.y + feather, lightness));
+}
+
+// Keeps colors near the sampled OkLCh hue and chroma, measured as an
+// elliptical distance normalized by the hue and chroma ranges.
+fn color_range_weight(lab: vec3<f32>, range: vec4<f32>, options: vec4<f32>) -> f32 {
+  if (options.y == 0.0) {
+    return 1.0;
+  }
+
+  let chroma = length(lab.yz);
+  var hue = atan2(lab.z, lab.y);
+
+  if (hue < 0.0) {
+    hue += TAU;
+  }
+
+  let hue_offset = abs(hue - range.x);
+  let hue_strength = smoothstep(0.0, HUE_CHROMA_RAMP, min(chroma, range.y));
+  let hue_distance = min(hue_offset, TAU - hue_offset) * hue_strength;
+  let distance = length(vec2<f32>(
+    hue_distance / max(range.z, MIN_FALLOFF),
+    abs(chroma - range.y) / max(range.w, MIN_FALLOFF)
+  ));
+
+  return feathered_falloff(distance, options.x);
+}
+
+fn range_weight(index: u32, lab: vec3<f32>) -> f32 {
+  return luminance_range_weight(lab.x, mask_parameters.luminance_ranges[index])
+    * color_range_weight(
+      lab,
+      mask_parameters.color_ranges[index],
+      mask_parameters.color_options[index]
+    );
+}
+
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let size = vec2<f32>(output_size);
+  let position = vec2<f32>(global_id.xy) + vec2<f32>(0.5, 0.5);
+  let count = min(u32(mask_parameters.info.x), MASK_COUNT);
+  let lab = rec2020_to_oklab(
+    textureLoad(source_texture, vec2<i32>(global_id.xy), 0).rgb
+  );
+  var masks = vec4<f32>(0.0);
+
+  for (var index = 0u; index < count; index += 1u) {
+    let mask = clamp(mask_at(index, position, size), 0.0, 1.0);
+
+    masks[index] = mask * range_weight(index, lab);
+  }
+
+  textureStore(output_texture, vec2<i32>(global_id.xy), masks);
+}
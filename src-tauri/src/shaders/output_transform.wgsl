@@ -1,6 +1,11 @@
 struct OutputTransformParameters {
   display: vec4<u32>,
   render: vec4<f32>,
+  tone_mapper: vec4<u32>,
+  display_range: vec4<f32>,
+  look_domain_min: vec4<f32>,
+  look_domain_max: vec4<f32>,
+  debug: vec4<u32>,
 };
 
 @group(0) @binding(0)
@@ -12,8 +17,28 @@ var output_texture: texture_storage_2d<rgba16float, write>;
 @group(0) @binding(2)
 var<uniform> output_transform_parameters: OutputTransformParameters;
 
+@group(0) @binding(3)
+var look_lut: texture_3d<f32>;
+
 const DISPLAY_INTENT_TONEMAP_TO_SDR: u32 = 1u;
+const DISPLAY_INTENT_TONEMAP_TO_HDR: u32 = 2u;
+const TONE_MAPPER_ACES_FITTED: u32 = 0u;
+const TONE_MAPPER_AGX: u32 = 1u;
+const TONE_MAPPER_FILMIC: u32 = 2u;
+const TONE_MAPPER_REINHARD: u32 = 3u;
+const TONE_MAPPER_CLIP: u32 = 4u;
+const MIDDLE_GREY: f32 = 0.18;
+const AGX_MIN_EV: f32 = -12.47393;
+const AGX_MAX_EV: f32 = 4.026069;
+const LOOK_PLACEMENT_WORKING: u32 = 1u;
+const LOOK_PLACEMENT_DISPLAY: u32 = 2u;
+const LOOK_INTERPOLATION_TETRAHEDRAL: u32 = 1u;
 const DISPLAY_EPSILON: f32 = 0.000001;
+const DEBUG_VISUALIZE_SPOTS: u32 = 1u;
+const SPOT_SURROUND_FRACTION: f32 = 0.01;
+const SPOT_SURROUND_MIN_RADIUS: i32 = 4;
+const SPOT_SURROUND_SAMPLES_PER_SIDE: i32 = 4;
+const SPOT_CONTRAST: f32 = 4.0;
 
 fn rec2020_luminance(color: vec3<f32>) -> f32 {
   return dot(color, vec3<f32>(0.2627, 0.6780, 0.0593));
@@ -37,18 +62,144 @@ fn aces_fitted_tone_curve(value: f32) -> f32 {
   );
 }
 
-fn tone_map_scene_color(color: vec3<f32>) -> vec3<f32> {
+// Log-logistic curve through middle grey. Contrast steepens the midtone slope
+// and the toe deepens or lifts the shadows without moving display white.
+fn filmic_tone_curve(value: f32) -> f32 {
+  let contrast = output_transform_parameters.render.z;
+  let toe = output_transform_parameters.render.w * 0.04;
+  let power = 1.0 + (0.6 * contrast);
+  let shoulder = pow(MIDDLE_GREY, power) * ((1.0 / MIDDLE_GREY) - 1.0);
+  let scaled = pow(value, power);
+  let curve = scaled / (scaled + shoulder);
+
+  if (toe > 0.0) {
+    return ((curve * curve) / (curve + toe)) * (1.0 + toe);
+  }
+
+  return curve - (toe * pow(1.0 - curve, 4.0));
+}
+
+fn reinhard_tone_curve(value: f32) -> f32 {
+  return value / (1.0 + value);
+}
+
+fn tone_map_luminance(value: f32, tone_mapper: u32) -> f32 {
+  switch tone_mapper {
+    case TONE_MAPPER_FILMIC: {
+      return filmic_tone_curve(value);
+    }
+    case TONE_MAPPER_REINHARD: {
+      return reinhard_tone_curve(value);
+    }
+    default: {
+      return aces_fitted_tone_curve(value);
+    }
+  }
+}
+
+fn agx_contrast_curve(value: vec3<f32>) -> vec3<f32> {
+  let x2 = value * value;
+  let x4 = x2 * x2;
+
+  return (15.5 * x4 * x2)
+    - (40.14 * x4 * value)
+    + (31.96 * x4)
+    - (6.868 * x2 * value)
+    + (0.4298 * x2)
+    + (0.1191 * value)
+    - 0.00232;
+}
+
+// AgX-style per-channel sigmoid. Colors are pulled into an inset gamut before
+// the log encoding so saturated highlights bleach toward white, then pushed
+// back out and decoded from the curve's 2.2 display encoding. The inset and
+// outset matrices are defined on Rec.709 primaries, so the curve runs in
+// linear Rec.709 and the result is returned to Rec.2020.
+fn agx_tone_map(color: vec3<f32>) -> vec3<f32> {
+  let inset = mat3x3<f32>(
+    0.842479062253094, 0.0423282422610123, 0.0423756549057051,
+    0.0784335999999992, 0.878468636469772, 0.0784336,
+    0.0792237451477643, 0.0791661274605434, 0.879142973793104
+  );
+  let outset = mat3x3<f32>(
+    1.19687900512017, -0.0528968517574562, -0.0529716355144438,
+    -0.0980208811401368, 1.15190312990417, -0.0980434501171241,
+    -0.0990297440797205, -0.0989611768448433, 1.15107367264116
+  );
+  let inset_color = max(
+    inset * linear_rec2020_to_linear_srgb(color),
+    vec3<f32>(DISPLAY_EPSILON)
+  );
+  let encoded = clamp(
+    (log2(inset_color / MIDDLE_GREY) - AGX_MIN_EV) / (AGX_MAX_EV - AGX_MIN_EV),
+    vec3<f32>(0.0),
+    vec3<f32>(1.0)
+  );
+  let display_encoded = clamp(
+    outset * agx_contrast_curve(encoded),
+    vec3<f32>(0.0),
+    vec3<f32>(1.0)
+  );
+
+  return linear_srgb_to_linear_rec2020(pow(display_encoded, vec3<f32>(2.2)));
+}
+
+fn tone_map_scene_color(color: vec3<f32>, tone_mapper: u32) -> vec3<f32> {
+  if (tone_mapper == TONE_MAPPER_AGX) {
+    return agx_tone_map(color);
+  }
+
+  if (tone_mapper == TONE_MAPPER_CLIP) {
+    return min(color, vec3<f32>(1.0));
+  }
+
   let scene_luminance = rec2020_luminance(color);
 
   if (scene_luminance <= DISPLAY_EPSILON) {
     return vec3<f32>(0.0);
   }
 
-  let display_luminance = aces_fitted_tone_curve(scene_luminance);
+  let display_luminance = tone_map_luminance(scene_luminance, tone_mapper);
 
   return color * (display_luminance / scene_luminance);
 }
 
+// Extends the selected tone mapper to an HDR display peak. Below diffuse white
+// the SDR curve is kept as is, so midtones match the SDR rendering. Above it
+// the response blends into the curve rescaled to the display peak, which only
+// ever brightens the shoulder and reaches the peak as the scene value does.
+fn tone_map_scene_color_to_hdr(
+  color: vec3<f32>,
+  tone_mapper: u32,
+  display_white: f32
+) -> vec3<f32> {
+  if (tone_mapper == TONE_MAPPER_CLIP) {
+    return min(color, vec3<f32>(display_white));
+  }
+
+  let sdr_color = tone_map_scene_color(color, tone_mapper);
+
+  if (display_white <= 1.0) {
+    return sdr_color;
+  }
+
+  let peak_color = max(
+    tone_map_scene_color(color / display_white, tone_mapper) * display_white,
+    sdr_color
+  );
+  let shoulder = smoothstep(1.0, display_white, rec2020_luminance(color));
+
+  return mix(sdr_color, peak_color, shoulder);
+}
+
+// AgX bleaches highlights itself and clipping should stay a hard clip, so
+// only the luminance curves get the photographic chroma rolloff.
+fn uses_highlight_chroma_rolloff(tone_mapper: u32) -> bool {
+  return tone_mapper == TONE_MAPPER_ACES_FITTED
+    || tone_mapper == TONE_MAPPER_FILMIC
+    || tone_mapper == TONE_MAPPER_REINHARD;
+}
+
 fn linear_rec2020_to_linear_srgb(color: vec3<f32>) -> vec3<f32> {
   return vec3<f32>(
     (1.6605 * color.r) + (-0.5876 * color.g) + (-0.0728 * color.b),
@@ -57,6 +208,14 @@ fn linear_rec2020_to_linear_srgb(color: vec3<f32>) -> vec3<f32> {
   );
 }
 
+fn linear_srgb_to_linear_rec2020(color: vec3<f32>) -> vec3<f32> {
+  return vec3<f32>(
+    (0.6274 * color.r) + (0.3293 * color.g) + (0.0433 * color.b),
+    (0.0691 * color.r) + (0.9195 * color.g) + (0.0114 * color.b),
+    (0.0164 * color.r) + (0.0880 * color.g) + (0.8956 * color.b)
+  );
+}
+
 fn highlight_chroma_rolloff(color: vec3<f32>) -> vec3<f32> {
   let luminance = clamp(linear_srgb_luminance(color), 0.0, 1.0);
   let neutral = vec3<f32>(luminance);
@@ -65,31 +224,168 @@ fn highlight_chroma_rolloff(color: vec3<f32>) -> vec3<f32> {
   return color + ((neutral - color) * strength);
 }
 
-fn channel_gamut_scale(channel: f32, luminance: f32) -> f32 {
+fn channel_gamut_scale(channel: f32, luminance: f32, display_white: f32) -> f32 {
   if (channel < 0.0) {
     return clamp(luminance / max(luminance - channel, DISPLAY_EPSILON), 0.0, 1.0);
   }
 
-  if (channel > 1.0) {
-    return clamp((1.0 - luminance) / max(channel - luminance, DISPLAY_EPSILON), 0.0, 1.0);
+  if (channel > display_white) {
+    return clamp(
+      (display_white - luminance) / max(channel - luminance, DISPLAY_EPSILON),
+      0.0,
+      1.0
+    );
   }
 
   return 1.0;
 }
 
-fn compress_linear_srgb_to_display_gamut(color: vec3<f32>) -> vec3<f32> {
-  let luminance = clamp(linear_srgb_luminance(color), 0.0, 1.0);
+fn compress_linear_srgb_to_display_gamut(
+  color: vec3<f32>,
+  display_white: f32
+) -> vec3<f32> {
+  let luminance = clamp(linear_srgb_luminance(color), 0.0, display_white);
   let neutral = vec3<f32>(luminance);
   let scale = min(
-    channel_gamut_scale(color.r, luminance),
+    channel_gamut_scale(color.r, luminance, display_white),
     min(
-      channel_gamut_scale(color.g, luminance),
-      channel_gamut_scale(color.b, luminance)
+      channel_gamut_scale(color.g, luminance, display_white),
+      channel_gamut_scale(color.b, luminance, display_white)
     )
   );
   let compressed_color = neutral + ((color - neutral) * scale);
 
-  return clamp(compressed_color, vec3<f32>(0.0), vec3<f32>(1.0));
+  return clamp(compressed_color, vec3<f32>(0.0), vec3<f32>(display_white));
+}
+
+fn srgb_encode_channel(value: f32) -> f32 {
+  if (value <= 0.0031308) {
+    return value * 12.92;
+  }
+
+  return (1.055 * pow(value, 1.0 / 2.4)) - 0.055;
+}
+
+fn srgb_decode_channel(value: f32) -> f32 {
+  if (value <= 0.04045) {
+    return value / 12.92;
+  }
+
+  return pow((value + 0.055) / 1.055, 2.4);
+}
+
+fn srgb_encode(color: vec3<f32>) -> vec3<f32> {
+  return vec3<f32>(
+    srgb_encode_channel(color.r),
+    srgb_encode_channel(color.g),
+    srgb_encode_channel(color.b)
+  );
+}
+
+fn srgb_decode(color: vec3<f32>) -> vec3<f32> {
+  return vec3<f32>(
+    srgb_decode_channel(color.r),
+    srgb_decode_channel(color.g),
+    srgb_decode_channel(color.b)
+  );
+}
+
+fn look_texel(index: vec3<i32>) -> vec3<f32> {
+  return textureLoad(look_lut, index, 0).rgb;
+}
+
+fn sample_look_trilinear(base: vec3<i32>, fraction: vec3<f32>) -> vec3<f32> {
+  let c000 = look_texel(base);
+  let c100 = look_texel(base + vec3<i32>(1, 0, 0));
+  let c010 = look_texel(base + vec3<i32>(0, 1, 0));
+  let c110 = look_texel(base + vec3<i32>(1, 1, 0));
+  let c001 = look_texel(base + vec3<i32>(0, 0, 1));
+  let c101 = look_texel(base + vec3<i32>(1, 0, 1));
+  let c011 = look_texel(base + vec3<i32>(0, 1, 1));
+  let c111 = look_texel(base + vec3<i32>(1, 1, 1));
+
+  let c00 = mix(c000, c100, fraction.r);
+  let c10 = mix(c010, c110, fraction.r);
+  let c01 = mix(c001, c101, fraction.r);
+  let c11 = mix(c011, c111, fraction.r);
+
+  return mix(mix(c00, c10, fraction.g), mix(c01, c11, fraction.g), fraction.b);
+}
+
+fn sample_look_tetrahedral(base: vec3<i32>, fraction: vec3<f32>) -> vec3<f32> {
+  let c000 = look_texel(base);
+  let c111 = look_texel(base + vec3<i32>(1, 1, 1));
+  let r = fraction.r;
+  let g = fraction.g;
+  let b = fraction.b;
+
+  if (r > g) {
+    if (g > b) {
+      let c100 = look_texel(base + vec3<i32>(1, 0, 0));
+      let c110 = look_texel(base + vec3<i32>(1, 1, 0));
+
+      return c000 + (r * (c100 - c000)) + (g * (c110 - c100)) + (b * (c111 - c110));
+    }
+
+    if (r > b) {
+      let c100 = look_texel(base + vec3<i32>(1, 0, 0));
+      let c101 = look_texel(base + vec3<i32>(1, 0, 1));
+
+      return c000 + (r * (c100 - c000)) + (b * (c101 - c100)) + (g * (c111 - c101));
+    }
+
+    let c001 = look_texel(base + vec3<i32>(0, 0, 1));
+    let c101 = look_texel(base + vec3<i32>(1, 0, 1));
+
+    return c000 + (b * (c001 - c000)) + (r * (c101 - c001)) + (g * (c111 - c101));
+  }
+
+  if (b > g) {
+    let c001 = look_texel(base + vec3<i32>(0, 0, 1));
+    let c011 = look_texel(base + vec3<i32>(0, 1, 1));
+
+    return c000 + (b * (c001 - c000)) + (g * (c011 - c001)) + (r * (c111 - c011));
+  }
+
+  if (b > r) {
+    let c010 = look_texel(base + vec3<i32>(0, 1, 0));
+    let c011 = look_texel(base + vec3<i32>(0, 1, 1));
+
+    return c000 + (g * (c010 - c000)) + (b * (c011 - c010)) + (r * (c111 - c011));
+  }
+
+  let c010 = look_texel(base + vec3<i32>(0, 1, 0));
+  let c110 = look_texel(base + vec3<i32>(1, 1, 0));
+
+  return c000 + (g * (c010 - c000)) + (r * (c110 - c010)) + (b * (c111 - c110));
+}
+
+fn sample_look(color: vec3<f32>) -> vec3<f32> {
+  let domain_min = output_transform_parameters.look_domain_min.rgb;
+  let domain_max = output_transform_parameters.look_domain_max.rgb;
+  let coordinate = clamp(
+    (color - domain_min) / (domain_max - domain_min),
+    vec3<f32>(0.0),
+    vec3<f32>(1.0)
+  );
+  let last_index = i32(output_transform_parameters.display.w) - 1;
+  let position = coordinate * f32(last_index);
+  let base = min(vec3<i32>(floor(position)), vec3<i32>(last_index - 1));
+  let fraction = position - vec3<f32>(base);
+
+  if (output_transform_parameters.display.z == LOOK_INTERPOLATION_TETRAHEDRAL) {
+    return sample_look_tetrahedral(base, fraction);
+  }
+
+  return sample_look_trilinear(base, fraction);
+}
+
+fn apply_look(color: vec3<f32>, placement: u32) -> vec3<f32> {
+  if (output_transform_parameters.display.y != placement) {
+    return color;
+  }
+
+  return mix(color, sample_look(color), output_transform_parameters.render.y);
 }
 
 fn render_scene_to_display(
@@ -99,21 +395,101 @@ fn render_scene_to_display(
 ) -> vec3<f32> {
   var rendered_rec2020 = max(color, vec3<f32>(0.0));
   var use_photographic_rolloff = false;
+  let display_white = output_transform_parameters.display_range.x;
 
-  if (display_render_intent == DISPLAY_INTENT_TONEMAP_TO_SDR) {
+  if (
+    display_render_intent == DISPLAY_INTENT_TONEMAP_TO_SDR
+      || display_render_intent == DISPLAY_INTENT_TONEMAP_TO_HDR
+  ) {
     rendered_rec2020 *= exp2(base_exposure_ev);
-    rendered_rec2020 = tone_map_scene_color(rendered_rec2020);
-    use_photographic_rolloff = true;
+  }
+
+  rendered_rec2020 = max(
+    apply_look(rendered_rec2020, LOOK_PLACEMENT_WORKING),
+    vec3<f32>(0.0)
+  );
+
+  if (display_render_intent == DISPLAY_INTENT_TONEMAP_TO_SDR) {
+    let tone_mapper = output_transform_parameters.tone_mapper.x;
+
+    rendered_rec2020 = tone_map_scene_color(rendered_rec2020, tone_mapper);
+    use_photographic_rolloff = uses_highlight_chroma_rolloff(tone_mapper);
+  }
+
+  if (display_render_intent == DISPLAY_INTENT_TONEMAP_TO_HDR) {
+    let tone_mapper = output_transform_parameters.tone_mapper.x;
+
+    rendered_rec2020 = tone_map_scene_color_to_hdr(
+      rendered_rec2020,
+      tone_mapper,
+      display_white
+    );
+    use_photographic_rolloff = uses_highlight_chroma_rolloff(tone_mapper);
   }
 
   let rendered_linear_srgb = linear_rec2020_to_linear_srgb(rendered_rec2020);
   var display_linear_srgb = rendered_linear_srgb;
 
   if (use_photographic_rolloff) {
-    display_linear_srgb = highlight_chroma_rolloff(display_linear_srgb);
+    display_linear_srgb = highlight_chroma_rolloff(display_linear_srgb / display_white)
+      * display_white;
+  }
+
+  let display_color = compress_linear_srgb_to_display_gamut(
+    display_linear_srgb,
+    display_white
+  );
+
+  if (output_transform_parameters.display.y != LOOK_PLACEMENT_DISPLAY) {
+    return display_color;
   }
 
-  return compress_linear_srgb_to_display_gamut(display_linear_srgb);
+  // Display looks see the signal normalized to the display peak, which is the
+  // usual sRGB range for SDR output.
+  let looked_encoded_color = apply_look(
+    srgb_encode(display_color / display_white),
+    LOOK_PLACEMENT_DISPLAY
+  );
+
+  return srgb_decode(clamp(looked_encoded_color, vec3<f32>(0.0), vec3<f32>(1.0)))
+    * display_white;
+}
+
+fn log_luminance(pixel: vec2<i32>, size: vec2<i32>) -> f32 {
+  let clamped = clamp(pixel, vec2<i32>(0), size - vec2<i32>(1));
+  let luminance = rec2020_luminance(textureLoad(source_texture, clamped, 0).rgb);
+
+  return log2(max(luminance, DISPLAY_EPSILON));
+}
+
+// Sensor dust shows as soft, slightly darker blobs that are easy to miss at
+// normal contrast. Each pixel's log luminance is compared with a sparse box
+// average of its surroundings, so only local detail survives, then the
+// difference is amplified and inverted so dark blobs read as bright spots on
+// mid grey regardless of exposure. The surround spans about 1% of the long
+// edge, with the sample step scaled so the sample count stays fixed.
+fn visualize_spots(pixel: vec2<i32>) -> vec3<f32> {
+  let size = vec2<i32>(textureDimensions(source_texture));
+  var surround = 0.0;
+  var count = 0.0;
+
+  let radius = max(
+    i32(f32(max(size.x, size.y)) * SPOT_SURROUND_FRACTION),
+    SPOT_SURROUND_MIN_RADIUS
+  );
+  let step = max(radius / SPOT_SURROUND_SAMPLES_PER_SIDE, 1);
+
+  for (var y = -radius; y <= radius; y += step) {
+    for (var x = -radius; x <= radius; x += step) {
+      surround += log_luminance(pixel + vec2<i32>(x, y), size);
+      count += 1.0;
+    }
+  }
+
+  let detail = log_luminance(pixel, size) - (surround / count);
+  let encoded = clamp(0.5 - (detail * SPOT_CONTRAST), 0.0, 1.0);
+
+  return vec3<f32>(srgb_decode_channel(encoded));
 }
 
 @compute @workgroup_size(16, 16)
@@ -126,6 +502,12 @@ fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
 
   let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
   let source_color = textureLoad(source_texture, pixel, 0);
+
+  if (output_transform_parameters.debug.x == DEBUG_VISUALIZE_SPOTS) {
+    textureStore(output_texture, pixel, vec4<f32>(visualize_spots(pixel), source_color.a));
+    return;
+  }
+
   let display_color = render_scene_to_display(
     source_color.rgb,
     output_transform_parameters.display.x,
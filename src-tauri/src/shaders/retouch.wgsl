@@ -0,0 +1,127 @@
+struct RetouchParameters {
+  info: vec4<f32>,
+  positions: array<vec4<f32>, 64>,
+  shapes: array<vec4<f32>, 64>,
+};
+
+@group(0) @binding(0)
+var source_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var output_texture: texture_storage_2d<rgba32float, write>;
+
+@group(0) @binding(2)
+var<uniform> retouch_parameters: RetouchParameters;
+
+const SPOT_COUNT: u32 = 64u;
+const RETOUCH_MODE_HEAL: f32 = 1.0;
+const BOUNDARY_SAMPLES: u32 = 32u;
+const MIN_RADIUS: f32 = 0.5;
+const MIN_FALLOFF: f32 = 0.0001;
+const TAU: f32 = 6.2831853;
+
+fn load_clamped(position: vec2<f32>) -> vec3<f32> {
+  let size = vec2<i32>(textureDimensions(source_texture));
+  let pixel = clamp(vec2<i32>(floor(position)), vec2<i32>(0), size - vec2<i32>(1));
+
+  return textureLoad(source_texture, pixel, 0).rgb;
+}
+
+// Averages a 3x3 neighborhood so single noisy pixels on the boundary do not
+// streak into the healed interior.
+fn load_boundary(position: vec2<f32>) -> vec3<f32> {
+  var sum = vec3<f32>(0.0);
+
+  for (var y = -1; y <= 1; y += 1) {
+    for (var x = -1; x <= 1; x += 1) {
+      sum += load_clamped(position + vec2<f32>(f32(x), f32(y)));
+    }
+  }
+
+  return sum / 9.0;
+}
+
+// Full strength inside `1 - feather` of the unit radius, fading to zero at 1.
+fn feathered_falloff(distance: f32, feather: f32) -> f32 {
+  let inner = 1.0 - clamp(feather, 0.0, 1.0);
+
+  return 1.0 - smoothstep(inner, max(1.0, inner + MIN_FALLOFF), distance);
+}
+
+// Harmonic membrane carrying the destination-minus-source difference on the
+// spot boundary into its interior. The discrete Poisson kernel solves
+// Laplace's equation inside the disk, so adding it to the cloned source is a
+// Poisson blend guided by the source gradients.
+fn heal_membrane(
+  local: vec2<f32>,
+  radius: f32,
+  destination: vec2<f32>,
+  offset: vec2<f32>
+) -> vec3<f32> {
+  let radius_squared = radius * radius;
+  let local_squared = min(dot(local, local), radius_squared * 0.998);
+  var sum = vec3<f32>(0.0);
+  var weight_sum = 0.0;
+
+  for (var index = 0u; index < BOUNDARY_SAMPLES; index += 1u) {
+    let angle = TAU * (f32(index) + 0.5) / f32(BOUNDARY_SAMPLES);
+    let boundary = vec2<f32>(cos(angle), sin(angle)) * radius;
+    let difference = load_boundary(destination + boundary)
+      - load_boundary(destination + boundary + offset);
+    let delta = boundary - local;
+    let weight = (radius_squared - local_squared) / max(dot(delta, delta), MIN_FALLOFF);
+
+    sum += difference * weight;
+    weight_sum += weight;
+  }
+
+  return sum / max(weight_sum, MIN_FALLOFF);
+}
+
+// Spots read the unretouched input, so overlapping spots never copy each
+// other's repairs; later spots are blended over earlier ones.
+@compute @workgroup_size(16, 16)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+  let output_size = textureDimensions(output_texture);
+
+  if (global_id.x >= output_size.x || global_id.y >= output_size.y) {
+    return;
+  }
+
+  let pixel = vec2<i32>(i32(global_id.x), i32(global_id.y));
+  let source_color = textureLoad(source_texture, pixel, 0);
+  let size = vec2<f32>(output_size);
+  let long_edge = max(size.x, size.y);
+  let position = vec2<f32>(global_id.xy) + vec2<f32>(0.5, 0.5);
+  let count = min(u32(retouch_parameters.info.x), SPOT_COUNT);
+  var color = source_color.rgb;
+
+  for (var index = 0u; index < count; index += 1u) {
+    let spot_position = retouch_parameters.positions[index];
+    let shape = retouch_parameters.shapes[index];
+    let radius = shape.x * long_edge;
+
+    if (radius < MIN_RADIUS) {
+      continue;
+    }
+
+    let destination = spot_position.xy * size;
+    let local = position - destination;
+    let distance = length(local) / radius;
+
+    if (distance >= 1.0) {
+      continue;
+    }
+
+    let offset = round((spot_position.zw - spot_position.xy) * size);
+    var repaired = load_clamped(position + offset);
+
+    if (shape.z == RETOUCH_MODE_HEAL) {
+      repaired = max(repaired + heal_membrane(local, radius, destination, offset), vec3<f32>(0.0));
+    }
+
+    color = mix(color, repaired, feathered_falloff(distance, shape.y));
+  }
+
+  textureStore(output_texture, pixel, vec4<f32>(color, source_color.a));
+}
@@ -22,6 +22,7 @@ import { useExifMetadata } from "./hooks/use-exif-metadata";
 import { SettingsModal } from "./features/settings/components/settings-modal";
 import { useCacheSize } from "./features/settings/system/hooks/use-cache-size";
 import { useActiveSidecar } from "./features/sidecar/hooks/use-active-sidecar";
+import { useEditHistory } from "./features/history/hooks/use-edit-history";
 import { useInspectionWorkspaceStore } from "./features/inspector/store/inspection-workspace-store";
 
 function App() {
@@ -29,6 +30,7 @@ function App() {
   useExifMetadata();
   useCacheSize();
   useActiveSidecar();
+  useEditHistory();
 
   const {
     activeLayout,
@@ -3,6 +3,8 @@ import { Slider, type SliderProps } from "@heroui/slider";
 
 interface CenteredSliderProps {
   range: number;
+  center?: number;
+  step?: number;
   label: string;
   fillColor?: string;
   trackColor?: string;
@@ -20,10 +22,12 @@ const TRACK_GRADIENTS: Record<string, string> = {
 
 export function CenteredSlider({
   range = 1,
+  center = 0,
+  step = 0.01,
   fillColor = "bg-white",
   trackColor,
   label,
-  defaultValue = 0,
+  defaultValue = center,
   color,
   onValueChange,
 }: CenteredSliderProps) {
@@ -50,10 +54,10 @@ export function CenteredSlider({
         value: "font-medium text-xs text-white",
       }}
       color={hasCustomTrack ? (color ?? "foreground") : color}
-      fillOffset={0}
+      fillOffset={center}
       label={label}
-      maxValue={range}
-      minValue={-range}
+      maxValue={center + range}
+      minValue={center - range}
       renderThumb={(props) => (
         <div
           {...props}
@@ -67,7 +71,7 @@ export function CenteredSlider({
         />
       )}
       size="sm"
-      step={0.01}
+      step={step}
       style={
         trackGradient
           ? ({
@@ -85,8 +89,8 @@ export function CenteredSlider({
         onValueChange?.(value);
       }}
       onDoubleClick={() => {
-        setValue(0);
-        onValueChange?.(0);
+        setValue(center);
+        onValueChange?.(center);
       }}
     />
   );
@@ -1,12 +1,55 @@
+import type { HighlightReconstruction, ToneMapper } from "@/types/sidecar";
+
 import { Accordion, AccordionItem } from "@heroui/accordion";
-import { SunDimIcon, PaletteIcon } from "@phosphor-icons/react";
+import { Button } from "@heroui/button";
+import { Select, SelectItem } from "@heroui/select";
+import {
+  SunDimIcon,
+  PaletteIcon,
+  SparkleIcon,
+  DiamondIcon,
+  ChartLineIcon,
+  SwatchesIcon,
+  FilmStripIcon,
+  SunHorizonIcon,
+} from "@phosphor-icons/react";
 
 import { CenteredSlider } from "@/components/ui/sliders/center-slider";
-import { useExposureAdjustment } from "@/features/edit-panel/basic-adjustments/hooks/use-exposure-adjustment";
+import { ColorMixerControls } from "@/features/edit-panel/basic-adjustments/components/color-mixer";
+import { LookControls } from "@/features/edit-panel/basic-adjustments/components/look-controls";
+import { ToneCurveEditor } from "@/features/edit-panel/basic-adjustments/components/tone-curve-editor";
+import { useBasicAdjustments } from "@/features/edit-panel/basic-adjustments/hooks/use-basic-adjustments";
+import {
+  HIGHLIGHT_RECONSTRUCTION_OPTIONS,
+  useHighlightReconstruction,
+} from "@/features/edit-panel/basic-adjustments/hooks/use-highlight-reconstruction";
+import { useCaptureSharpening } from "@/features/edit-panel/basic-adjustments/hooks/use-capture-sharpening";
+import { useNoiseReduction } from "@/features/edit-panel/basic-adjustments/hooks/use-noise-reduction";
+import {
+  TONE_MAPPER_OPTIONS,
+  useToneMapping,
+} from "@/features/edit-panel/basic-adjustments/hooks/use-tone-mapping";
+import {
+  NEUTRAL_TEMPERATURE_K,
+  useWhiteBalanceAdjustment,
+} from "@/features/edit-panel/basic-adjustments/hooks/use-white-balance-adjustment";
 import { EDIT_PANEL_ACCORDION_PROPS } from "@/features/edit-panel/utils/accordion";
 
 export const BasicAdjustmentsPanel = () => {
-  const { exposureEv, setExposure } = useExposureAdjustment();
+  const { getAdjustment, setAdjustment } = useBasicAdjustments();
+  const {
+    whiteBalance,
+    temperatureK,
+    tint,
+    setTemperature,
+    setTint,
+    resetToAsShot,
+  } = useWhiteBalanceAdjustment();
+  const { highlightReconstruction, setHighlightReconstruction } =
+    useHighlightReconstruction();
+  const { noiseReduction, setNoiseReduction } = useNoiseReduction();
+  const { captureSharpening, setCaptureSharpening } = useCaptureSharpening();
+  const { toneMapping, setToneMapping } = useToneMapping();
 
   return (
     <Accordion
@@ -21,40 +64,240 @@ export const BasicAdjustmentsPanel = () => {
         title="Basic Adjustments"
       >
         <CenteredSlider
-          defaultValue={exposureEv}
+          defaultValue={getAdjustment("exposure_ev")}
           label="Exposure"
           range={5}
           onValueChange={(exposureEv) => {
-            setExposure(exposureEv);
+            setAdjustment("exposure_ev", exposureEv);
+          }}
+        />
+        <CenteredSlider
+          defaultValue={getAdjustment("contrast")}
+          label="Contrast"
+          range={1}
+          onValueChange={(value) => setAdjustment("contrast", value)}
+        />
+        <CenteredSlider
+          defaultValue={getAdjustment("highlights")}
+          label="Highlights"
+          range={1}
+          onValueChange={(value) => setAdjustment("highlights", value)}
+        />
+        <CenteredSlider
+          defaultValue={getAdjustment("shadows")}
+          label="Shadows"
+          range={1}
+          onValueChange={(value) => setAdjustment("shadows", value)}
+        />
+        <CenteredSlider
+          defaultValue={getAdjustment("whites")}
+          label="Whites"
+          range={1}
+          onValueChange={(value) => setAdjustment("whites", value)}
+        />
+        <CenteredSlider
+          defaultValue={getAdjustment("blacks")}
+          label="Blacks"
+          range={1}
+          onValueChange={(value) => setAdjustment("blacks", value)}
+        />
+        <Select
+          className="mt-2"
+          items={HIGHLIGHT_RECONSTRUCTION_OPTIONS}
+          label="Highlight Recovery"
+          selectedKeys={new Set([highlightReconstruction])}
+          size="sm"
+          onSelectionChange={(keys) => {
+            const next = Array.from(keys).at(0);
+
+            if (next) {
+              setHighlightReconstruction(next as HighlightReconstruction);
+            }
           }}
+        >
+          {(option) => <SelectItem>{option.label}</SelectItem>}
+        </Select>
+        <CenteredSlider
+          defaultValue={getAdjustment("vibrance")}
+          label="Vibrance"
+          range={1}
+          onValueChange={(value) => setAdjustment("vibrance", value)}
+        />
+        <CenteredSlider
+          defaultValue={getAdjustment("saturation")}
+          label="Saturation"
+          range={1}
+          onValueChange={(value) => setAdjustment("saturation", value)}
         />
-        <CenteredSlider label="Contrast" range={2} />
-        <CenteredSlider label="Highlights" range={2} />
-        <CenteredSlider label="Shadows" range={2} />
-        <CenteredSlider label="Whites" range={1} />
-        <CenteredSlider label="Blacks" range={1} />
+      </AccordionItem>
+
+      <AccordionItem
+        key="tone-curve"
+        aria-label="Tone Curve"
+        className="w-full rounded-xl bg-zinc-800"
+        startContent={<ChartLineIcon />}
+        title="Tone Curve"
+      >
+        <ToneCurveEditor />
+      </AccordionItem>
+
+      <AccordionItem
+        key="tone-mapping"
+        aria-label="Tone Mapping"
+        className="w-full rounded-xl bg-zinc-800"
+        startContent={<SunHorizonIcon />}
+        title="Tone Mapping"
+      >
+        <Select
+          items={TONE_MAPPER_OPTIONS}
+          label="Tone Mapper"
+          selectedKeys={new Set([toneMapping.mapper])}
+          size="sm"
+          onSelectionChange={(keys) => {
+            const next = Array.from(keys).at(0);
+
+            if (next) {
+              setToneMapping("mapper", next as ToneMapper);
+            }
+          }}
+        >
+          {(option) => <SelectItem>{option.label}</SelectItem>}
+        </Select>
+        {toneMapping.mapper === "filmic" && (
+          <>
+            <CenteredSlider
+              defaultValue={toneMapping.contrast}
+              label="Contrast"
+              range={1}
+              onValueChange={(value) => setToneMapping("contrast", value)}
+            />
+            <CenteredSlider
+              defaultValue={toneMapping.toe}
+              label="Toe"
+              range={1}
+              onValueChange={(value) => setToneMapping("toe", value)}
+            />
+          </>
+        )}
       </AccordionItem>
 
       <AccordionItem
         key="color-grading"
-        isDisabled
         aria-label="Color Grading"
         className="w-full rounded-xl bg-zinc-800"
         startContent={<PaletteIcon />}
         title="Color Grading"
       >
         <CenteredSlider
-          label="White Balance"
-          range={2}
+          center={NEUTRAL_TEMPERATURE_K}
+          defaultValue={temperatureK}
+          label="Temperature"
+          range={4500}
+          step={50}
           trackColor="bg-linear-to-r from-blue-500 to-yellow-500"
+          onValueChange={setTemperature}
         />
         <CenteredSlider
+          defaultValue={tint}
           label="Tint"
-          range={2}
+          range={1}
           trackColor="bg-linear-to-r from-green-500 to-pink-500"
+          onValueChange={setTint}
+        />
+        <Button
+          className="mt-2 w-full"
+          isDisabled={whiteBalance.mode === "as_shot"}
+          size="sm"
+          variant="flat"
+          onPress={resetToAsShot}
+        >
+          As Shot
+        </Button>
+      </AccordionItem>
+
+      <AccordionItem
+        key="color-mixer"
+        aria-label="Color Mixer"
+        className="w-full rounded-xl bg-zinc-800"
+        startContent={<SwatchesIcon />}
+        title="Color Mixer"
+      >
+        <ColorMixerControls />
+      </AccordionItem>
+
+      <AccordionItem
+        key="look"
+        aria-label="Look"
+        className="w-full rounded-xl bg-zinc-800"
+        startContent={<FilmStripIcon />}
+        title="Look"
+      >
+        <LookControls />
+      </AccordionItem>
+
+      <AccordionItem
+        key="noise-reduction"
+        aria-label="Noise Reduction"
+        className="w-full rounded-xl bg-zinc-800"
+        startContent={<SparkleIcon />}
+        title="Noise Reduction"
+      >
+        <CenteredSlider
+          defaultValue={noiseReduction.luminance}
+          label="Luminance"
+          range={1}
+          onValueChange={(value) => setNoiseReduction("luminance", value)}
+        />
+        <CenteredSlider
+          defaultValue={noiseReduction.luminance_detail}
+          label="Luminance Detail"
+          range={1}
+          onValueChange={(value) =>
+            setNoiseReduction("luminance_detail", value)
+          }
+        />
+        <CenteredSlider
+          defaultValue={noiseReduction.chroma}
+          label="Color"
+          range={1}
+          onValueChange={(value) => setNoiseReduction("chroma", value)}
+        />
+        <CenteredSlider
+          defaultValue={noiseReduction.chroma_detail}
+          label="Color Detail"
+          range={1}
+          onValueChange={(value) => setNoiseReduction("chroma_detail", value)}
+        />
+      </AccordionItem>
+
+      <AccordionItem
+        key="capture-sharpening"
+        aria-label="Capture Sharpening"
+        className="w-full rounded-xl bg-zinc-800"
+        startContent={<DiamondIcon />}
+        title="Capture Sharpening"
+      >
+        <CenteredSlider
+          center={0.5}
+          defaultValue={captureSharpening.amount}
+          label="Amount"
+          range={0.5}
+          onValueChange={(value) => setCaptureSharpening("amount", value)}
+        />
+        <CenteredSlider
+          center={1.25}
+          defaultValue={captureSharpening.radius}
+          label="Radius"
+          range={0.75}
+          onValueChange={(value) => setCaptureSharpening("radius", value)}
+        />
+        <CenteredSlider
+          center={0.5}
+          defaultValue={captureSharpening.threshold}
+          label="Threshold"
+          range={0.5}
+          onValueChange={(value) => setCaptureSharpening("threshold", value)}
         />
-        <CenteredSlider label="Vibrance" range={2} />
-        <CenteredSlider label="Saturation" range={2} />
       </AccordionItem>
     </Accordion>
   );
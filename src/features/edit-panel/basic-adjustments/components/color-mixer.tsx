@@ -0,0 +1,41 @@
+import type { ColorMixerChannel } from "@/features/edit-panel/basic-adjustments/hooks/use-color-mixer";
+
+import { useState } from "react";
+import { Tab, Tabs } from "@heroui/tabs";
+
+import { CenteredSlider } from "@/components/ui/sliders/center-slider";
+import {
+  COLOR_BANDS,
+  useColorMixer,
+} from "@/features/edit-panel/basic-adjustments/hooks/use-color-mixer";
+
+export const ColorMixerControls = () => {
+  const { getBandValue, setBandValue } = useColorMixer();
+  const [channel, setChannel] = useState<ColorMixerChannel>("hue");
+
+  return (
+    <div className="flex flex-col gap-2 pb-2">
+      <Tabs
+        fullWidth
+        aria-label="Color Mixer Channel"
+        selectedKey={channel}
+        size="sm"
+        onSelectionChange={(key) => setChannel(key as ColorMixerChannel)}
+      >
+        <Tab key="hue" title="Hue" />
+        <Tab key="saturation" title="Saturation" />
+        <Tab key="luminance" title="Luminance" />
+      </Tabs>
+
+      {COLOR_BANDS.map(({ band, label }) => (
+        <CenteredSlider
+          key={`${channel}-${band}`}
+          defaultValue={getBandValue(channel, band)}
+          label={label}
+          range={1}
+          onValueChange={(value) => setBandValue(channel, band, value)}
+        />
+      ))}
+    </div>
+  );
+};
@@ -0,0 +1,103 @@
+import type { LookInterpolation, LookPlacement } from "@/types/sidecar";
+
+import { useState } from "react";
+import { Button } from "@heroui/button";
+import { Select, SelectItem } from "@heroui/select";
+import { open } from "@tauri-apps/plugin-dialog";
+
+import { CenteredSlider } from "@/components/ui/sliders/center-slider";
+import {
+  LOOK_INTERPOLATION_OPTIONS,
+  LOOK_PLACEMENT_OPTIONS,
+  useLook,
+} from "@/features/edit-panel/basic-adjustments/hooks/use-look";
+import { api } from "@/services/api";
+
+const lookFileName = (path: string) => path.split(/[\\/]/).at(-1) ?? path;
+
+export const LookControls = () => {
+  const { look, updateLook, applyLookFile, clearLook } = useLook();
+  const [error, setError] = useState<string | null>(null);
+
+  const chooseLook = async () => {
+    const path = await open({
+      multiple: false,
+      filters: [{ name: "Cube LUT", extensions: ["cube"] }],
+    });
+
+    if (!path) return;
+
+    try {
+      applyLookFile(await api.look.readLookFile({ path }));
+      setError(null);
+    } catch (readError) {
+      setError(String(readError));
+    }
+  };
+
+  return (
+    <div className="flex flex-col gap-2 pb-2">
+      <div className="flex items-center gap-2">
+        <Button
+          className="flex-1"
+          size="sm"
+          variant="flat"
+          onPress={chooseLook}
+        >
+          {look.path ? lookFileName(look.path) : "Load .cube..."}
+        </Button>
+        <Button
+          isDisabled={!look.path}
+          size="sm"
+          variant="flat"
+          onPress={() => {
+            clearLook();
+            setError(null);
+          }}
+        >
+          Clear
+        </Button>
+      </div>
+
+      {error && <p className="text-tiny text-danger">{error}</p>}
+
+      <Select
+        items={LOOK_PLACEMENT_OPTIONS}
+        label="Placement"
+        selectedKeys={new Set([look.placement])}
+        size="sm"
+        onSelectionChange={(keys) => {
+          const next = Array.from(keys).at(0);
+
+          if (next) {
+            updateLook({ placement: next as LookPlacement });
+          }
+        }}
+      >
+        {(option) => <SelectItem>{option.label}</SelectItem>}
+      </Select>
+      <Select
+        items={LOOK_INTERPOLATION_OPTIONS}
+        label="Interpolation"
+        selectedKeys={new Set([look.interpolation])}
+        size="sm"
+        onSelectionChange={(keys) => {
+          const next = Array.from(keys).at(0);
+
+          if (next) {
+            updateLook({ interpolation: next as LookInterpolation });
+          }
+        }}
+      >
+        {(option) => <SelectItem>{option.label}</SelectItem>}
+      </Select>
+      <CenteredSlider
+        center={0.5}
+        defaultValue={look.amount}
+        label="Amount"
+        range={0.5}
+        onValueChange={(value) => updateLook({ amount: value })}
+      />
+    </div>
+  );
+};
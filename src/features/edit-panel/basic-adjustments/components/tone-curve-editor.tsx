@@ -0,0 +1,325 @@
+import type { CurvePoint } from "@/types/sidecar";
+import type { ToneCurveChannel } from "@/features/edit-panel/basic-adjustments/hooks/use-tone-curve";
+
+import { useCallback, useMemo, useRef, useState } from "react";
+import { Button } from "@heroui/button";
+import { Tab, Tabs } from "@heroui/tabs";
+
+import { CenteredSlider } from "@/components/ui/sliders/center-slider";
+import {
+  NEUTRAL_CURVE_POINTS,
+  useToneCurve,
+} from "@/features/edit-panel/basic-adjustments/hooks/use-tone-curve";
+
+const CURVE_SIZE = 100;
+const POINT_HIT_RADIUS = 0.04;
+const PREVIEW_SAMPLES = 64;
+
+const CHANNEL_STROKES: Record<ToneCurveChannel, string> = {
+  rgb: "rgb(244 244 245)",
+  red: "rgb(248 113 113)",
+  green: "rgb(74 222 128)",
+  blue: "rgb(96 165 250)",
+};
+
+export const ToneCurveEditor = () => {
+  const { toneCurve, setParametric, setCurvePoints, resetCurve } =
+    useToneCurve();
+  const [channel, setChannel] = useState<ToneCurveChannel>("rgb");
+  const [dragIndex, setDragIndex] = useState<number | null>(null);
+  const svgRef = useRef<SVGSVGElement>(null);
+  const storedPoints = toneCurve[channel];
+  const points = storedPoints.length >= 2 ? storedPoints : NEUTRAL_CURVE_POINTS;
+  const path = useMemo(() => curvePath(points), [points]);
+
+  const pointerPosition = useCallback((event: React.PointerEvent) => {
+    const bounds = svgRef.current?.getBoundingClientRect();
+
+    if (!bounds) {
+      return null;
+    }
+
+    return {
+      x: clamp01((event.clientX - bounds.left) / bounds.width),
+      y: clamp01(1 - (event.clientY - bounds.top) / bounds.height),
+    };
+  }, []);
+
+  const handlePointerDown = (event: React.PointerEvent<SVGSVGElement>) => {
+    const position = pointerPosition(event);
+
+    if (!position) {
+      return;
+    }
+
+    const hitIndex = points.findIndex(
+      (point) =>
+        Math.hypot(point.x - position.x, point.y - position.y) <
+        POINT_HIT_RADIUS,
+    );
+
+    event.currentTarget.setPointerCapture(event.pointerId);
+
+    if (hitIndex >= 0) {
+      setDragIndex(hitIndex);
+
+      return;
+    }
+
+    const nextPoints = [...points, position].sort(
+      (left, right) => left.x - right.x,
+    );
+
+    setCurvePoints(channel, nextPoints);
+    setDragIndex(nextPoints.indexOf(position));
+  };
+
+  const handlePointerMove = (event: React.PointerEvent<SVGSVGElement>) => {
+    if (dragIndex === null) {
+      return;
+    }
+
+    const position = pointerPosition(event);
+
+    if (!position) {
+      return;
+    }
+
+    // Keep points ordered by clamping between their neighbours.
+    const lower = dragIndex > 0 ? points[dragIndex - 1].x + 0.01 : 0;
+    const upper =
+      dragIndex < points.length - 1 ? points[dragIndex + 1].x - 0.01 : 1;
+    const nextPoints = points.map((point, index) =>
+      index === dragIndex
+        ? { x: Math.min(Math.max(position.x, lower), upper), y: position.y }
+        : point,
+    );
+
+    setCurvePoints(channel, nextPoints);
+  };
+
+  const handleDoubleClick = (event: React.MouseEvent<SVGCircleElement>) => {
+    event.stopPropagation();
+
+    const index = Number(event.currentTarget.dataset.index);
+
+    if (index <= 0 || index >= points.length - 1) {
+      return;
+    }
+
+    setCurvePoints(
+      channel,
+      points.filter((_, pointIndex) => pointIndex !== index),
+    );
+  };
+
+  return (
+    <div className="flex flex-col gap-2 pb-2">
+      <Tabs
+        fullWidth
+        aria-label="Curve Channel"
+        selectedKey={channel}
+        size="sm"
+        onSelectionChange={(key) => setChannel(key as ToneCurveChannel)}
+      >
+        <Tab key="rgb" title="RGB" />
+        <Tab key="red" title="Red" />
+        <Tab key="green" title="Green" />
+        <Tab key="blue" title="Blue" />
+      </Tabs>
+
+      <svg
+        ref={svgRef}
+        className="aspect-square w-full cursor-crosshair touch-none rounded-lg bg-zinc-900"
+        viewBox={`0 0 ${CURVE_SIZE} ${CURVE_SIZE}`}
+        onPointerDown={handlePointerDown}
+        onPointerMove={handlePointerMove}
+        onPointerUp={() => setDragIndex(null)}
+      >
+        {[0.25, 0.5, 0.75].map((grid) => (
+          <g key={grid} className="stroke-zinc-700" strokeWidth={0.3}>
+            <line
+              x1={grid * CURVE_SIZE}
+              x2={grid * CURVE_SIZE}
+              y1={0}
+              y2={CURVE_SIZE}
+            />
+            <line
+              x1={0}
+              x2={CURVE_SIZE}
+              y1={grid * CURVE_SIZE}
+              y2={grid * CURVE_SIZE}
+            />
+          </g>
+        ))}
+        <line
+          className="stroke-zinc-600"
+          strokeDasharray="1 1"
+          strokeWidth={0.3}
+          x1={0}
+          x2={CURVE_SIZE}
+          y1={CURVE_SIZE}
+          y2={0}
+        />
+        <path
+          d={path}
+          fill="none"
+          stroke={CHANNEL_STROKES[channel]}
+          strokeWidth={0.8}
+        />
+        {points.map((point, index) => (
+          <circle
+            key={`${point.x}-${point.y}`}
+            cx={point.x * CURVE_SIZE}
+            cy={(1 - point.y) * CURVE_SIZE}
+            data-index={index}
+            fill={CHANNEL_STROKES[channel]}
+            r={1.6}
+            onDoubleClick={handleDoubleClick}
+          />
+        ))}
+      </svg>
+
+      <Button
+        isDisabled={storedPoints.length === 0}
+        size="sm"
+        variant="flat"
+        onPress={() => resetCurve(channel)}
+      >
+        Reset Curve
+      </Button>
+
+      <CenteredSlider
+        defaultValue={toneCurve.parametric.highlights}
+        label="Highlights"
+        range={1}
+        onValueChange={(value) => setParametric("highlights", value)}
+      />
+      <CenteredSlider
+        defaultValue={toneCurve.parametric.lights}
+        label="Lights"
+        range={1}
+        onValueChange={(value) => setParametric("lights", value)}
+      />
+      <CenteredSlider
+        defaultValue={toneCurve.parametric.darks}
+        label="Darks"
+        range={1}
+        onValueChange={(value) => setParametric("darks", value)}
+      />
+      <CenteredSlider
+        defaultValue={toneCurve.parametric.shadows}
+        label="Shadows"
+        range={1}
+        onValueChange={(value) => setParametric("shadows", value)}
+      />
+      <CenteredSlider
+        center={0.25}
+        defaultValue={toneCurve.parametric.shadow_split}
+        label="Shadow Split"
+        range={0.15}
+        onValueChange={(value) => setParametric("shadow_split", value)}
+      />
+      <CenteredSlider
+        center={0.5}
+        defaultValue={toneCurve.parametric.midtone_split}
+        label="Midtone Split"
+        range={0.15}
+        onValueChange={(value) => setParametric("midtone_split", value)}
+      />
+      <CenteredSlider
+        center={0.75}
+        defaultValue={toneCurve.parametric.highlight_split}
+        label="Highlight Split"
+        range={0.15}
+        onValueChange={(value) => setParametric("highlight_split", value)}
+      />
+    </div>
+  );
+};
+
+const clamp01 = (value: number) => Math.min(Math.max(value, 0), 1);
+
+// Builds an SVG path for the monotone cubic curve the renderer bakes.
+const curvePath = (points: CurvePoint[]) => {
+  const tangents = monotoneTangents(points);
+  const first = points[0];
+  const last = points[points.length - 1];
+  const commands: string[] = [];
+
+  for (let sample = 0; sample <= PREVIEW_SAMPLES; sample += 1) {
+    const x = sample / PREVIEW_SAMPLES;
+    let y: number;
+
+    if (x <= first.x) {
+      y = first.y;
+    } else if (x >= last.x) {
+      y = last.y;
+    } else {
+      const segment = points.findIndex((point) => x <= point.x) - 1;
+      const start = points[segment];
+      const end = points[segment + 1];
+      const width = end.x - start.x;
+      const t = (x - start.x) / width;
+      const t2 = t * t;
+      const t3 = t2 * t;
+
+      y =
+        (2 * t3 - 3 * t2 + 1) * start.y +
+        (t3 - 2 * t2 + t) * width * tangents[segment] +
+        (-2 * t3 + 3 * t2) * end.y +
+        (t3 - t2) * width * tangents[segment + 1];
+    }
+
+    commands.push(
+      `${sample === 0 ? "M" : "L"}${x * CURVE_SIZE},${(1 - clamp01(y)) * CURVE_SIZE}`,
+    );
+  }
+
+  return commands.join(" ");
+};
+
+const monotoneTangents = (points: CurvePoint[]) => {
+  const secants = points
+    .slice(1)
+    .map(
+      (point, index) =>
+        (point.y - points[index].y) / (point.x - points[index].x),
+    );
+  const tangents = points.map((_, index) => {
+    if (index === 0) {
+      return secants[0];
+    }
+
+    if (index === points.length - 1) {
+      return secants[secants.length - 1];
+    }
+
+    const before = secants[index - 1];
+    const after = secants[index];
+
+    return before * after <= 0 ? 0 : (before + after) / 2;
+  });
+
+  secants.forEach((secant, index) => {
+    if (secant === 0) {
+      tangents[index] = 0;
+      tangents[index + 1] = 0;
+
+      return;
+    }
+
+    const alpha = tangents[index] / secant;
+    const beta = tangents[index + 1] / secant;
+    const magnitude = alpha * alpha + beta * beta;
+
+    if (magnitude > 9) {
+      const scale = 3 / Math.sqrt(magnitude);
+
+      tangents[index] = scale * alpha * secant;
+      tangents[index + 1] = scale * beta * secant;
+    }
+  });
+
+  return tangents;
+};
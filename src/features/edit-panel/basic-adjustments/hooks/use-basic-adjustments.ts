@@ -0,0 +1,49 @@
+import type { EditRecipe } from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export type BasicAdjustmentKey =
+  | "exposure_ev"
+  | "contrast"
+  | "highlights"
+  | "shadows"
+  | "whites"
+  | "blacks"
+  | "saturation"
+  | "vibrance";
+
+export function useBasicAdjustments() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+
+  const getAdjustment = useCallback(
+    (key: BasicAdjustmentKey) => sidecar?.recipe[key] ?? 0,
+    [sidecar],
+  );
+
+  const setAdjustment = useCallback(
+    (key: BasicAdjustmentKey, value: number) => {
+      if (!sidecar) {
+        return;
+      }
+
+      const recipe: EditRecipe = {
+        ...sidecar.recipe,
+        [key]: value,
+      };
+
+      setSidecar({
+        ...sidecar,
+        recipe,
+      });
+    },
+    [setSidecar, sidecar],
+  );
+
+  return {
+    getAdjustment,
+    setAdjustment,
+  };
+}
@@ -0,0 +1,43 @@
+import type { CaptureSharpening } from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export const DEFAULT_CAPTURE_SHARPENING: CaptureSharpening = {
+  amount: 0.3,
+  radius: 0.8,
+  threshold: 0.2,
+};
+
+export function useCaptureSharpening() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+  const captureSharpening: CaptureSharpening =
+    sidecar?.recipe.capture_sharpening ?? DEFAULT_CAPTURE_SHARPENING;
+
+  const setCaptureSharpening = useCallback(
+    (key: keyof CaptureSharpening, value: number) => {
+      if (!sidecar) {
+        return;
+      }
+
+      setSidecar({
+        ...sidecar,
+        recipe: {
+          ...sidecar.recipe,
+          capture_sharpening: {
+            ...(sidecar.recipe.capture_sharpening ?? DEFAULT_CAPTURE_SHARPENING),
+            [key]: value,
+          },
+        },
+      });
+    },
+    [setSidecar, sidecar],
+  );
+
+  return {
+    captureSharpening,
+    setCaptureSharpening,
+  };
+}
@@ -0,0 +1,72 @@
+import type { ColorBand, ColorMixer } from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export type ColorMixerChannel = keyof ColorMixer;
+
+export const COLOR_BANDS: { band: ColorBand; label: string }[] = [
+  { band: "red", label: "Red" },
+  { band: "orange", label: "Orange" },
+  { band: "yellow", label: "Yellow" },
+  { band: "green", label: "Green" },
+  { band: "aqua", label: "Aqua" },
+  { band: "blue", label: "Blue" },
+  { band: "purple", label: "Purple" },
+  { band: "magenta", label: "Magenta" },
+];
+
+const NEUTRAL_BANDS = COLOR_BANDS.map(() => 0);
+
+export const DEFAULT_COLOR_MIXER: ColorMixer = {
+  hue: NEUTRAL_BANDS,
+  saturation: NEUTRAL_BANDS,
+  luminance: NEUTRAL_BANDS,
+};
+
+export function useColorMixer() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+  const colorMixer: ColorMixer =
+    sidecar?.recipe.color_mixer ?? DEFAULT_COLOR_MIXER;
+
+  const setBandValue = useCallback(
+    (channel: ColorMixerChannel, band: ColorBand, value: number) => {
+      if (!sidecar) {
+        return;
+      }
+
+      const current = sidecar.recipe.color_mixer ?? DEFAULT_COLOR_MIXER;
+      const index = COLOR_BANDS.findIndex((entry) => entry.band === band);
+
+      setSidecar({
+        ...sidecar,
+        recipe: {
+          ...sidecar.recipe,
+          color_mixer: {
+            ...current,
+            [channel]: current[channel].map((previous, bandIndex) =>
+              bandIndex === index ? value : previous,
+            ),
+          },
+        },
+      });
+    },
+    [setSidecar, sidecar],
+  );
+
+  const getBandValue = useCallback(
+    (channel: ColorMixerChannel, band: ColorBand) =>
+      colorMixer[channel][
+        COLOR_BANDS.findIndex((entry) => entry.band === band)
+      ] ?? 0,
+    [colorMixer],
+  );
+
+  return {
+    colorMixer,
+    getBandValue,
+    setBandValue,
+  };
+}
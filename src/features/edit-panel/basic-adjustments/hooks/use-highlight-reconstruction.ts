@@ -0,0 +1,44 @@
+import type { HighlightReconstruction } from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export const HIGHLIGHT_RECONSTRUCTION_OPTIONS: {
+  key: HighlightReconstruction;
+  label: string;
+}[] = [
+  { key: "off", label: "Off" },
+  { key: "clip", label: "Clip" },
+  { key: "blend", label: "Blend" },
+  { key: "inpaint", label: "Inpaint" },
+];
+
+export function useHighlightReconstruction() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+  const highlightReconstruction: HighlightReconstruction =
+    sidecar?.recipe.highlight_reconstruction ?? "blend";
+
+  const setHighlightReconstruction = useCallback(
+    (nextHighlightReconstruction: HighlightReconstruction) => {
+      if (!sidecar) {
+        return;
+      }
+
+      setSidecar({
+        ...sidecar,
+        recipe: {
+          ...sidecar.recipe,
+          highlight_reconstruction: nextHighlightReconstruction,
+        },
+      });
+    },
+    [setSidecar, sidecar],
+  );
+
+  return {
+    highlightReconstruction,
+    setHighlightReconstruction,
+  };
+}
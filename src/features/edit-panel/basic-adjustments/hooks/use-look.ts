@@ -0,0 +1,70 @@
+import type { LookFile } from "@/types/look";
+import type { Look, LookInterpolation, LookPlacement } from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export const DEFAULT_LOOK: Look = {
+  path: null,
+  hash: null,
+  placement: "display",
+  interpolation: "tetrahedral",
+  amount: 1,
+};
+
+export const LOOK_PLACEMENT_OPTIONS: { key: LookPlacement; label: string }[] = [
+  { key: "working", label: "Before Tone Mapping" },
+  { key: "display", label: "Display" },
+];
+
+export const LOOK_INTERPOLATION_OPTIONS: {
+  key: LookInterpolation;
+  label: string;
+}[] = [
+  { key: "trilinear", label: "Trilinear" },
+  { key: "tetrahedral", label: "Tetrahedral" },
+];
+
+export function useLook() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+  const look: Look = sidecar?.recipe.look ?? DEFAULT_LOOK;
+
+  const updateLook = useCallback(
+    (changes: Partial<Look>) => {
+      if (!sidecar) {
+        return;
+      }
+
+      setSidecar({
+        ...sidecar,
+        recipe: {
+          ...sidecar.recipe,
+          look: {
+            ...(sidecar.recipe.look ?? DEFAULT_LOOK),
+            ...changes,
+          },
+        },
+      });
+    },
+    [setSidecar, sidecar],
+  );
+
+  const applyLookFile = useCallback(
+    (file: LookFile) => updateLook({ path: file.path, hash: file.hash }),
+    [updateLook],
+  );
+
+  const clearLook = useCallback(
+    () => updateLook({ path: null, hash: null }),
+    [updateLook],
+  );
+
+  return {
+    look,
+    updateLook,
+    applyLookFile,
+    clearLook,
+  };
+}
@@ -0,0 +1,44 @@
+import type { NoiseReduction } from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export const DEFAULT_NOISE_REDUCTION: NoiseReduction = {
+  luminance: 0,
+  luminance_detail: 0,
+  chroma: 0,
+  chroma_detail: 0,
+};
+
+export function useNoiseReduction() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+  const noiseReduction: NoiseReduction =
+    sidecar?.recipe.noise_reduction ?? DEFAULT_NOISE_REDUCTION;
+
+  const setNoiseReduction = useCallback(
+    (key: keyof NoiseReduction, value: number) => {
+      if (!sidecar) {
+        return;
+      }
+
+      setSidecar({
+        ...sidecar,
+        recipe: {
+          ...sidecar.recipe,
+          noise_reduction: {
+            ...(sidecar.recipe.noise_reduction ?? DEFAULT_NOISE_REDUCTION),
+            [key]: value,
+          },
+        },
+      });
+    },
+    [setSidecar, sidecar],
+  );
+
+  return {
+    noiseReduction,
+    setNoiseReduction,
+  };
+}
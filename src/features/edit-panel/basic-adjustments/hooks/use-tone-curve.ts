@@ -0,0 +1,88 @@
+import type { CurvePoint, ParametricCurve, ToneCurve } from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export type ToneCurveChannel = "rgb" | "red" | "green" | "blue";
+
+export type ParametricCurveKey = keyof ParametricCurve;
+
+export const DEFAULT_PARAMETRIC_CURVE: ParametricCurve = {
+  shadows: 0,
+  darks: 0,
+  lights: 0,
+  highlights: 0,
+  shadow_split: 0.25,
+  midtone_split: 0.5,
+  highlight_split: 0.75,
+};
+
+export const DEFAULT_TONE_CURVE: ToneCurve = {
+  parametric: DEFAULT_PARAMETRIC_CURVE,
+  rgb: [],
+  red: [],
+  green: [],
+  blue: [],
+};
+
+export const NEUTRAL_CURVE_POINTS: CurvePoint[] = [
+  { x: 0, y: 0 },
+  { x: 1, y: 1 },
+];
+
+export function useToneCurve() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+  const toneCurve: ToneCurve = sidecar?.recipe.tone_curve ?? DEFAULT_TONE_CURVE;
+
+  const updateToneCurve = useCallback(
+    (update: (current: ToneCurve) => ToneCurve) => {
+      if (!sidecar) {
+        return;
+      }
+
+      setSidecar({
+        ...sidecar,
+        recipe: {
+          ...sidecar.recipe,
+          tone_curve: update(sidecar.recipe.tone_curve ?? DEFAULT_TONE_CURVE),
+        },
+      });
+    },
+    [setSidecar, sidecar],
+  );
+
+  const setParametric = useCallback(
+    (key: ParametricCurveKey, value: number) =>
+      updateToneCurve((current) => ({
+        ...current,
+        parametric: {
+          ...(current.parametric ?? DEFAULT_PARAMETRIC_CURVE),
+          [key]: value,
+        },
+      })),
+    [updateToneCurve],
+  );
+
+  const setCurvePoints = useCallback(
+    (channel: ToneCurveChannel, points: CurvePoint[]) =>
+      updateToneCurve((current) => ({
+        ...current,
+        [channel]: [...points].sort((left, right) => left.x - right.x),
+      })),
+    [updateToneCurve],
+  );
+
+  const resetCurve = useCallback(
+    (channel: ToneCurveChannel) => setCurvePoints(channel, []),
+    [setCurvePoints],
+  );
+
+  return {
+    toneCurve,
+    setParametric,
+    setCurvePoints,
+    resetCurve,
+  };
+}
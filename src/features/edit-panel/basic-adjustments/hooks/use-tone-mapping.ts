@@ -0,0 +1,51 @@
+import type { ToneMapper, ToneMapping } from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export const DEFAULT_TONE_MAPPING: ToneMapping = {
+  mapper: "aces_fitted",
+  contrast: 0,
+  toe: 0,
+};
+
+export const TONE_MAPPER_OPTIONS: { key: ToneMapper; label: string }[] = [
+  { key: "aces_fitted", label: "ACES Fitted" },
+  { key: "agx", label: "AgX" },
+  { key: "filmic", label: "Filmic" },
+  { key: "reinhard", label: "Reinhard" },
+  { key: "clip", label: "None (Clip)" },
+];
+
+export function useToneMapping() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+  const toneMapping: ToneMapping =
+    sidecar?.recipe.tone_mapping ?? DEFAULT_TONE_MAPPING;
+
+  const setToneMapping = useCallback(
+    <K extends keyof ToneMapping>(key: K, value: ToneMapping[K]) => {
+      if (!sidecar) {
+        return;
+      }
+
+      setSidecar({
+        ...sidecar,
+        recipe: {
+          ...sidecar.recipe,
+          tone_mapping: {
+            ...(sidecar.recipe.tone_mapping ?? DEFAULT_TONE_MAPPING),
+            [key]: value,
+          },
+        },
+      });
+    },
+    [setSidecar, sidecar],
+  );
+
+  return {
+    toneMapping,
+    setToneMapping,
+  };
+}
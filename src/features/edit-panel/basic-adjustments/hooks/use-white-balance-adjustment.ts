@@ -0,0 +1,73 @@
+import type { WhiteBalance } from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export const NEUTRAL_TEMPERATURE_K = 6500;
+
+export function useWhiteBalanceAdjustment() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+  const whiteBalance: WhiteBalance = sidecar?.recipe.white_balance ?? {
+    mode: "as_shot",
+  };
+
+  const temperatureK =
+    whiteBalance.mode === "temperature"
+      ? whiteBalance.temperature_k
+      : NEUTRAL_TEMPERATURE_K;
+  const tint = whiteBalance.mode === "temperature" ? whiteBalance.tint : 0;
+
+  const setWhiteBalance = useCallback(
+    (nextWhiteBalance: WhiteBalance) => {
+      if (!sidecar) {
+        return;
+      }
+
+      setSidecar({
+        ...sidecar,
+        recipe: {
+          ...sidecar.recipe,
+          white_balance: nextWhiteBalance,
+        },
+      });
+    },
+    [setSidecar, sidecar],
+  );
+
+  const setTemperature = useCallback(
+    (nextTemperatureK: number) => {
+      setWhiteBalance({
+        mode: "temperature",
+        temperature_k: nextTemperatureK,
+        tint,
+      });
+    },
+    [setWhiteBalance, tint],
+  );
+
+  const setTint = useCallback(
+    (nextTint: number) => {
+      setWhiteBalance({
+        mode: "temperature",
+        temperature_k: temperatureK,
+        tint: nextTint,
+      });
+    },
+    [setWhiteBalance, temperatureK],
+  );
+
+  const resetToAsShot = useCallback(() => {
+    setWhiteBalance({ mode: "as_shot" });
+  }, [setWhiteBalance]);
+
+  return {
+    whiteBalance,
+    temperatureK,
+    tint,
+    setTemperature,
+    setTint,
+    resetToAsShot,
+  };
+}
@@ -8,6 +8,7 @@ import { RetouchPanel } from "@/features/edit-panel/retouch/components/retouch-p
 import { AiPanel } from "@/features/edit-panel/ai/components/ai-panel";
 import { PresetsPanel } from "@/features/edit-panel/presets/components/presets-panel";
 import { Histogram } from "@/features/histogram/components/histogram";
+import { HistoryControls } from "@/features/history/components/history-controls";
 import { useLayoutStore } from "@/store/layout-store";
 
 export function EditPanel() {
@@ -46,6 +47,7 @@ export function EditPanel() {
       </div>
 
       <div className="flex flex-row gap-2 pt-2 flex-shrink-0">
+        <HistoryControls />
         <Button
           isDisabled
           className="rounded-md w-full shadow-md"
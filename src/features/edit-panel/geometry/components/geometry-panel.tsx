@@ -1,24 +1,208 @@
 import { Accordion, AccordionItem } from "@heroui/accordion";
-import { ExportIcon } from "@phosphor-icons/react";
+import { Button, ButtonGroup } from "@heroui/button";
+import { Select, SelectItem } from "@heroui/select";
+import { Slider } from "@heroui/slider";
+import { Switch } from "@heroui/switch";
+import {
+  ApertureIcon,
+  ArrowClockwiseIcon,
+  ArrowCounterClockwiseIcon,
+  CropIcon,
+  FlipHorizontalIcon,
+  FlipVerticalIcon,
+} from "@phosphor-icons/react";
 
+import { CenteredSlider } from "@/components/ui/sliders/center-slider";
+import {
+  CROP_ASPECT_RATIO_OPTIONS,
+  type CropInsets,
+  MAX_STRAIGHTEN_DEGREES,
+  cropAspectRatioKey,
+  cropInsets,
+  useGeometry,
+} from "@/features/edit-panel/geometry/hooks/use-geometry";
+import { useLensCorrection } from "@/features/edit-panel/geometry/hooks/use-lens-correction";
 import { EDIT_PANEL_ACCORDION_PROPS } from "@/features/edit-panel/utils/accordion";
 
+const CROP_INSET_SIDES: { side: keyof CropInsets; label: string }[] = [
+  { side: "left", label: "Left" },
+  { side: "top", label: "Top" },
+  { side: "right", label: "Right" },
+  { side: "bottom", label: "Bottom" },
+];
+
 export const GeometryPanel = () => {
+  const { lensCorrection, setLensCorrection } = useLensCorrection();
+  const { geometry, updateGeometry, rotate, setCropInset, resetCrop } =
+    useGeometry();
+  const insets = cropInsets(geometry.crop);
+
   return (
     <Accordion
       {...EDIT_PANEL_ACCORDION_PROPS}
-      defaultExpandedKeys={["geometry-crop"]}
+      defaultExpandedKeys={["geometry-crop", "geometry-lens"]}
     >
       <AccordionItem
         key="geometry-crop"
         aria-label="Crop & Transform"
         className="w-full rounded-xl bg-zinc-800"
-        startContent={<ExportIcon />}
+        startContent={<CropIcon />}
         title="Crop & Transform"
       >
-        <div className="p-4 text-center text-sm text-zinc-500">
-          Crop tools coming soon...
+        <div className="flex flex-col gap-2 pb-2">
+          <div className="flex items-center justify-between gap-2">
+            <ButtonGroup size="sm" variant="flat">
+              <Button
+                isIconOnly
+                aria-label="Rotate left"
+                onPress={() => rotate(-1)}
+              >
+                <ArrowCounterClockwiseIcon />
+              </Button>
+              <Button
+                isIconOnly
+                aria-label="Rotate right"
+                onPress={() => rotate(1)}
+              >
+                <ArrowClockwiseIcon />
+              </Button>
+            </ButtonGroup>
+            <ButtonGroup size="sm" variant="flat">
+              <Button
+                isIconOnly
+                aria-label="Flip horizontal"
+                color={geometry.flip_horizontal ? "primary" : "default"}
+                onPress={() =>
+                  updateGeometry({ flip_horizontal: !geometry.flip_horizontal })
+                }
+              >
+                <FlipHorizontalIcon />
+              </Button>
+              <Button
+                isIconOnly
+                aria-label="Flip vertical"
+                color={geometry.flip_vertical ? "primary" : "default"}
+                onPress={() =>
+                  updateGeometry({ flip_vertical: !geometry.flip_vertical })
+                }
+              >
+                <FlipVerticalIcon />
+              </Button>
+            </ButtonGroup>
+            <Button size="sm" variant="flat" onPress={resetCrop}>
+              Reset
+            </Button>
+          </div>
+          <Select
+            items={CROP_ASPECT_RATIO_OPTIONS}
+            label="Aspect Ratio"
+            selectedKeys={new Set([cropAspectRatioKey(geometry.aspect_ratio)])}
+            size="sm"
+            onSelectionChange={(keys) => {
+              const next = CROP_ASPECT_RATIO_OPTIONS.find(
+                (option) => option.key === Array.from(keys).at(0),
+              );
+
+              if (next) {
+                updateGeometry({ aspect_ratio: next.aspectRatio });
+              }
+            }}
+          >
+            {(option) => <SelectItem>{option.label}</SelectItem>}
+          </Select>
+        </div>
+        <CenteredSlider
+          defaultValue={geometry.straighten_degrees}
+          label="Straighten"
+          range={MAX_STRAIGHTEN_DEGREES}
+          step={0.1}
+          onValueChange={(value) =>
+            updateGeometry({ straighten_degrees: value })
+          }
+        />
+        {CROP_INSET_SIDES.map(({ side, label }) => (
+          <Slider
+            key={side}
+            getValueLabel={(value) => `${Math.round(Number(value) * 100)}%`}
+            label={`Crop ${label}`}
+            maxValue={0.45}
+            minValue={0}
+            size="sm"
+            step={0.005}
+            value={insets[side]}
+            onChange={(value) => {
+              if (typeof value === "number") {
+                setCropInset(side, value);
+              }
+            }}
+          />
+        ))}
+      </AccordionItem>
+
+      <AccordionItem
+        key="geometry-lens"
+        aria-label="Lens Corrections"
+        className="w-full rounded-xl bg-zinc-800"
+        startContent={<ApertureIcon />}
+        title="Lens Corrections"
+      >
+        <div className="flex flex-col gap-2 pb-2">
+          <Switch
+            isSelected={lensCorrection.distortion}
+            size="sm"
+            onValueChange={(value) => setLensCorrection("distortion", value)}
+          >
+            Profile Distortion
+          </Switch>
+          <Switch
+            isSelected={lensCorrection.chromatic_aberration}
+            size="sm"
+            onValueChange={(value) =>
+              setLensCorrection("chromatic_aberration", value)
+            }
+          >
+            Profile Chromatic Aberration
+          </Switch>
+          <Switch
+            isSelected={lensCorrection.vignetting}
+            size="sm"
+            onValueChange={(value) => setLensCorrection("vignetting", value)}
+          >
+            Profile Vignetting
+          </Switch>
         </div>
+        <CenteredSlider
+          defaultValue={lensCorrection.distortion_amount}
+          label="Distortion"
+          range={1}
+          onValueChange={(value) =>
+            setLensCorrection("distortion_amount", value)
+          }
+        />
+        <CenteredSlider
+          defaultValue={lensCorrection.chromatic_aberration_red}
+          label="Red Fringe"
+          range={1}
+          onValueChange={(value) =>
+            setLensCorrection("chromatic_aberration_red", value)
+          }
+        />
+        <CenteredSlider
+          defaultValue={lensCorrection.chromatic_aberration_blue}
+          label="Blue Fringe"
+          range={1}
+          onValueChange={(value) =>
+            setLensCorrection("chromatic_aberration_blue", value)
+          }
+        />
+        <CenteredSlider
+          defaultValue={lensCorrection.vignetting_amount}
+          label="Vignetting"
+          range={1}
+          onValueChange={(value) =>
+            setLensCorrection("vignetting_amount", value)
+          }
+        />
       </AccordionItem>
     </Accordion>
   );
@@ -0,0 +1,142 @@
+import type { CropAspectRatio, CropRect, Geometry } from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export const DEFAULT_GEOMETRY: Geometry = {
+  crop: null,
+  aspect_ratio: { mode: "free" },
+  straighten_degrees: 0,
+  quarter_turns: 0,
+  flip_horizontal: false,
+  flip_vertical: false,
+};
+
+export const MAX_STRAIGHTEN_DEGREES = 45;
+
+export const CROP_ASPECT_RATIO_OPTIONS: {
+  key: string;
+  label: string;
+  aspectRatio: CropAspectRatio;
+}[] = [
+  { key: "free", label: "Free", aspectRatio: { mode: "free" } },
+  { key: "original", label: "Original", aspectRatio: { mode: "original" } },
+  {
+    key: "1:1",
+    label: "1:1",
+    aspectRatio: { mode: "fixed", width: 1, height: 1 },
+  },
+  {
+    key: "5:4",
+    label: "5:4",
+    aspectRatio: { mode: "fixed", width: 5, height: 4 },
+  },
+  {
+    key: "4:3",
+    label: "4:3",
+    aspectRatio: { mode: "fixed", width: 4, height: 3 },
+  },
+  {
+    key: "3:2",
+    label: "3:2",
+    aspectRatio: { mode: "fixed", width: 3, height: 2 },
+  },
+  {
+    key: "16:9",
+    label: "16:9",
+    aspectRatio: { mode: "fixed", width: 16, height: 9 },
+  },
+];
+
+export type CropInsets = {
+  left: number;
+  top: number;
+  right: number;
+  bottom: number;
+};
+
+export const cropAspectRatioKey = (aspectRatio: CropAspectRatio) =>
+  aspectRatio.mode === "fixed"
+    ? `${aspectRatio.width}:${aspectRatio.height}`
+    : aspectRatio.mode;
+
+export const cropInsets = (crop: CropRect | null): CropInsets => {
+  if (!crop) {
+    return { left: 0, top: 0, right: 0, bottom: 0 };
+  }
+
+  return {
+    left: crop.x,
+    top: crop.y,
+    right: 1 - crop.x - crop.width,
+    bottom: 1 - crop.y - crop.height,
+  };
+};
+
+const cropFromInsets = (insets: CropInsets): CropRect => ({
+  x: insets.left,
+  y: insets.top,
+  width: Math.max(1 - insets.left - insets.right, 0.01),
+  height: Math.max(1 - insets.top - insets.bottom, 0.01),
+});
+
+export function useGeometry() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+  const geometry: Geometry = sidecar?.recipe.geometry ?? DEFAULT_GEOMETRY;
+
+  const updateGeometry = useCallback(
+    (changes: Partial<Geometry>) => {
+      if (!sidecar) {
+        return;
+      }
+
+      setSidecar({
+        ...sidecar,
+        recipe: {
+          ...sidecar.recipe,
+          geometry: {
+            ...(sidecar.recipe.geometry ?? DEFAULT_GEOMETRY),
+            ...changes,
+          },
+        },
+      });
+    },
+    [setSidecar, sidecar],
+  );
+
+  const rotate = useCallback(
+    (quarterTurns: number) =>
+      updateGeometry({
+        quarter_turns: (((geometry.quarter_turns + quarterTurns) % 4) + 4) % 4,
+      }),
+    [geometry.quarter_turns, updateGeometry],
+  );
+
+  const setCropInset = useCallback(
+    (side: keyof CropInsets, value: number) =>
+      updateGeometry({
+        crop: cropFromInsets({ ...cropInsets(geometry.crop), [side]: value }),
+      }),
+    [geometry.crop, updateGeometry],
+  );
+
+  const resetCrop = useCallback(
+    () =>
+      updateGeometry({
+        crop: null,
+        aspect_ratio: { mode: "free" },
+        straighten_degrees: 0,
+      }),
+    [updateGeometry],
+  );
+
+  return {
+    geometry,
+    updateGeometry,
+    rotate,
+    setCropInset,
+    resetCrop,
+  };
+}
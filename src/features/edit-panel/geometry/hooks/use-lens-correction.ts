@@ -0,0 +1,47 @@
+import type { LensCorrection } from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export const DEFAULT_LENS_CORRECTION: LensCorrection = {
+  distortion: false,
+  chromatic_aberration: false,
+  vignetting: false,
+  distortion_amount: 0,
+  chromatic_aberration_red: 0,
+  chromatic_aberration_blue: 0,
+  vignetting_amount: 0,
+};
+
+export function useLensCorrection() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+  const lensCorrection: LensCorrection =
+    sidecar?.recipe.lens_correction ?? DEFAULT_LENS_CORRECTION;
+
+  const setLensCorrection = useCallback(
+    <K extends keyof LensCorrection>(key: K, value: LensCorrection[K]) => {
+      if (!sidecar) {
+        return;
+      }
+
+      setSidecar({
+        ...sidecar,
+        recipe: {
+          ...sidecar.recipe,
+          lens_correction: {
+            ...(sidecar.recipe.lens_correction ?? DEFAULT_LENS_CORRECTION),
+            [key]: value,
+          },
+        },
+      });
+    },
+    [setSidecar, sidecar],
+  );
+
+  return {
+    lensCorrection,
+    setLensCorrection,
+  };
+}
@@ -0,0 +1,353 @@
+import type {
+  LocalAdjustment,
+  Mask,
+  MaskPoint,
+  MaskRange,
+} from "@/types/sidecar";
+
+import { Button } from "@heroui/button";
+import { Slider } from "@heroui/slider";
+import { Switch } from "@heroui/switch";
+
+import { CenteredSlider } from "@/components/ui/sliders/center-slider";
+import { useMaskingStore } from "@/features/edit-panel/masking/store/masking-store";
+
+type MaskControlsProps = {
+  mask: Mask;
+  onMaskChange: (mask: Mask) => void;
+};
+
+type UnitSliderProps = {
+  label: string;
+  value: number;
+  maxValue?: number;
+  minValue?: number;
+  step?: number;
+  formatValue?: (value: number) => string;
+  onValueChange: (value: number) => void;
+};
+
+const formatPercent = (value: number) => `${Math.round(value * 100)}%`;
+
+const UnitSlider = ({
+  label,
+  value,
+  maxValue = 1,
+  minValue = 0,
+  step = 0.005,
+  formatValue = formatPercent,
+  onValueChange,
+}: UnitSliderProps) => (
+  <Slider
+    getValueLabel={(sliderValue) => formatValue(Number(sliderValue))}
+    label={label}
+    maxValue={maxValue}
+    minValue={minValue}
+    size="sm"
+    step={step}
+    value={value}
+    onChange={(sliderValue) => {
+      if (typeof sliderValue === "number") {
+        onValueChange(sliderValue);
+      }
+    }}
+  />
+);
+
+type PointSlidersProps = {
+  label: string;
+  point: MaskPoint;
+  onPointChange: (point: MaskPoint) => void;
+};
+
+const PointSliders = ({ label, point, onPointChange }: PointSlidersProps) => (
+  <>
+    <UnitSlider
+      label={`${label} X`}
+      value={point.x}
+      onValueChange={(x) => onPointChange({ ...point, x })}
+    />
+    <UnitSlider
+      label={`${label} Y`}
+      value={point.y}
+      onValueChange={(y) => onPointChange({ ...point, y })}
+    />
+  </>
+);
+
+const BrushControls = ({
+  mask,
+  onMaskChange,
+}: {
+  mask: Extract<Mask, { mode: "brush" }>;
+  onMaskChange: (mask: Mask) => void;
+}) => {
+  const isBrushActive = useMaskingStore((state) => state.isBrushActive);
+  const brushRadius = useMaskingStore((state) => state.brushRadius);
+  const brushFeather = useMaskingStore((state) => state.brushFeather);
+  const brushErase = useMaskingStore((state) => state.brushErase);
+  const setBrushActive = useMaskingStore((state) => state.setBrushActive);
+  const setBrushRadius = useMaskingStore((state) => state.setBrushRadius);
+  const setBrushFeather = useMaskingStore((state) => state.setBrushFeather);
+  const setBrushErase = useMaskingStore((state) => state.setBrushErase);
+
+  return (
+    <>
+      <div className="flex items-center justify-between gap-2">
+        <Switch isSelected={isBrushActive} onValueChange={setBrushActive}>
+          <span className="text-xs">Paint</span>
+        </Switch>
+        <Switch isSelected={brushErase} onValueChange={setBrushErase}>
+          <span className="text-xs">Erase</span>
+        </Switch>
+        <Button
+          isDisabled={mask.strokes.length === 0}
+          size="sm"
+          variant="flat"
+          onPress={() => onMaskChange({ ...mask, strokes: [] })}
+        >
+          Clear
+        </Button>
+      </div>
+      <UnitSlider
+        label="Size"
+        maxValue={0.2}
+        minValue={0.002}
+        step={0.001}
+        value={brushRadius}
+        onValueChange={setBrushRadius}
+      />
+      <UnitSlider
+        label="Feather"
+        value={brushFeather}
+        onValueChange={setBrushFeather}
+      />
+    </>
+  );
+};
+
+export const MaskControls = ({ mask, onMaskChange }: MaskControlsProps) => {
+  switch (mask.mode) {
+    case "linear":
+      return (
+        <>
+          <PointSliders
+            label="Start"
+            point={mask.start}
+            onPointChange={(start) => onMaskChange({ ...mask, start })}
+          />
+          <PointSliders
+            label="End"
+            point={mask.end}
+            onPointChange={(end) => onMaskChange({ ...mask, end })}
+          />
+        </>
+      );
+    case "radial":
+      return (
+        <>
+          <PointSliders
+            label="Center"
+            point={mask.center}
+            onPointChange={(center) => onMaskChange({ ...mask, center })}
+          />
+          <UnitSlider
+            label="Width"
+            value={mask.radius_x}
+            onValueChange={(radius_x) => onMaskChange({ ...mask, radius_x })}
+          />
+          <UnitSlider
+            label="Height"
+            value={mask.radius_y}
+            onValueChange={(radius_y) => onMaskChange({ ...mask, radius_y })}
+          />
+          <CenteredSlider
+            defaultValue={mask.rotation_degrees}
+            label="Rotation"
+            range={180}
+            step={1}
+            onValueChange={(rotation_degrees) =>
+              onMaskChange({ ...mask, rotation_degrees })
+            }
+          />
+          <UnitSlider
+            label="Feather"
+            value={mask.feather}
+            onValueChange={(feather) => onMaskChange({ ...mask, feather })}
+          />
+          <Switch
+            isSelected={mask.invert}
+            onValueChange={(invert) => onMaskChange({ ...mask, invert })}
+          >
+            <span className="text-xs">Invert</span>
+          </Switch>
+        </>
+      );
+    case "brush":
+      return <BrushControls mask={mask} onMaskChange={onMaskChange} />;
+  }
+};
+
+const DEFAULT_LUMINANCE_RANGE = { min: 0, max: 1, feather: 0.1 };
+
+type MaskRangeControlsProps = {
+  range: MaskRange;
+  onRangeChange: (range: MaskRange) => void;
+};
+
+export const MaskRangeControls = ({
+  range,
+  onRangeChange,
+}: MaskRangeControlsProps) => {
+  const isColorPickerActive = useMaskingStore(
+    (state) => state.isColorPickerActive,
+  );
+  const setColorPickerActive = useMaskingStore(
+    (state) => state.setColorPickerActive,
+  );
+  const { luminance, color } = range;
+
+  return (
+    <>
+      <Switch
+        isSelected={luminance !== null}
+        onValueChange={(isSelected) =>
+          onRangeChange({
+            ...range,
+            luminance: isSelected ? DEFAULT_LUMINANCE_RANGE : null,
+          })
+        }
+      >
+        <span className="text-xs">Luminance Range</span>
+      </Switch>
+      {luminance && (
+        <>
+          <UnitSlider
+            label="Min"
+            value={luminance.min}
+            onValueChange={(min) =>
+              onRangeChange({ ...range, luminance: { ...luminance, min } })
+            }
+          />
+          <UnitSlider
+            label="Max"
+            value={luminance.max}
+            onValueChange={(max) =>
+              onRangeChange({ ...range, luminance: { ...luminance, max } })
+            }
+          />
+          <UnitSlider
+            label="Luminance Feather"
+            maxValue={0.5}
+            value={luminance.feather}
+            onValueChange={(feather) =>
+              onRangeChange({ ...range, luminance: { ...luminance, feather } })
+            }
+          />
+        </>
+      )}
+      <div className="flex items-center justify-between gap-2">
+        <span className="text-xs">Color Range</span>
+        <div className="flex gap-2">
+          <Button
+            color={isColorPickerActive ? "primary" : "default"}
+            size="sm"
+            variant="flat"
+            onPress={() => setColorPickerActive(!isColorPickerActive)}
+          >
+            {color ? "Repick" : "Pick Color"}
+          </Button>
+          <Button
+            isDisabled={color === null}
+            size="sm"
+            variant="flat"
+            onPress={() => onRangeChange({ ...range, color: null })}
+          >
+            Clear
+          </Button>
+        </div>
+      </div>
+      {color && (
+        <>
+          <UnitSlider
+            formatValue={(value) => `${Math.round(value)}°`}
+            label="Hue Range"
+            maxValue={180}
+            minValue={1}
+            step={1}
+            value={color.hue_range_degrees}
+            onValueChange={(hue_range_degrees) =>
+              onRangeChange({
+                ...range,
+                color: { ...color, hue_range_degrees },
+              })
+            }
+          />
+          <UnitSlider
+            formatValue={(value) => value.toFixed(3)}
+            label="Chroma Range"
+            maxValue={0.3}
+            minValue={0.005}
+            step={0.005}
+            value={color.chroma_range}
+            onValueChange={(chroma_range) =>
+              onRangeChange({ ...range, color: { ...color, chroma_range } })
+            }
+          />
+          <UnitSlider
+            label="Color Feather"
+            value={color.feather}
+            onValueChange={(feather) =>
+              onRangeChange({ ...range, color: { ...color, feather } })
+            }
+          />
+        </>
+      )}
+    </>
+  );
+};
+
+type LocalAdjustmentSlidersProps = {
+  adjustment: LocalAdjustment;
+  onChange: (changes: Partial<LocalAdjustment>) => void;
+};
+
+export const LocalAdjustmentSliders = ({
+  adjustment,
+  onChange,
+}: LocalAdjustmentSlidersProps) => (
+  <>
+    <CenteredSlider
+      defaultValue={adjustment.exposure_ev}
+      label="Exposure"
+      range={5}
+      onValueChange={(exposure_ev) => onChange({ exposure_ev })}
+    />
+    <CenteredSlider
+      defaultValue={adjustment.contrast}
+      label="Contrast"
+      range={1}
+      onValueChange={(contrast) => onChange({ contrast })}
+    />
+    <CenteredSlider
+      defaultValue={adjustment.temperature}
+      label="Temperature"
+      range={1}
+      trackColor="bg-linear-to-r from-blue-500 to-yellow-500"
+      onValueChange={(temperature) => onChange({ temperature })}
+    />
+    <CenteredSlider
+      defaultValue={adjustment.tint}
+      label="Tint"
+      range={1}
+      trackColor="bg-linear-to-r from-green-500 to-pink-500"
+      onValueChange={(tint) => onChange({ tint })}
+    />
+    <CenteredSlider
+      defaultValue={adjustment.saturation}
+      label="Saturation"
+      range={1}
+      onValueChange={(saturation) => onChange({ saturation })}
+    />
+  </>
+);
@@ -1,9 +1,64 @@
 import { Accordion, AccordionItem } from "@heroui/accordion";
-import { PaletteIcon } from "@phosphor-icons/react";
+import { Button, ButtonGroup } from "@heroui/button";
+import { Switch } from "@heroui/switch";
+import { PaletteIcon, TrashIcon } from "@phosphor-icons/react";
+import { useEffect } from "react";
 
+import {
+  LocalAdjustmentSliders,
+  MaskControls,
+  MaskRangeControls,
+} from "@/features/edit-panel/masking/components/mask-controls";
+import {
+  MASK_MODE_LABELS,
+  MAX_LOCAL_ADJUSTMENTS,
+  type MaskMode,
+  useLocalAdjustments,
+} from "@/features/edit-panel/masking/hooks/use-local-adjustments";
+import { useMaskingStore } from "@/features/edit-panel/masking/store/masking-store";
 import { EDIT_PANEL_ACCORDION_PROPS } from "@/features/edit-panel/utils/accordion";
 
+const MASK_MODES: MaskMode[] = ["linear", "radial", "brush"];
+
 export const MaskingPanel = () => {
+  const {
+    localAdjustments,
+    addLocalAdjustment,
+    updateLocalAdjustment,
+    removeLocalAdjustment,
+  } = useLocalAdjustments();
+  const selectedIndex = useMaskingStore((state) => state.selectedIndex);
+  const setSelectedIndex = useMaskingStore((state) => state.setSelectedIndex);
+  const setBrushActive = useMaskingStore((state) => state.setBrushActive);
+  const setColorPickerActive = useMaskingStore(
+    (state) => state.setColorPickerActive,
+  );
+  const selected =
+    selectedIndex !== null ? localAdjustments[selectedIndex] : undefined;
+
+  useEffect(() => {
+    if (selected?.mask.mode !== "brush") {
+      setBrushActive(false);
+    }
+  }, [selected?.mask.mode, setBrushActive]);
+
+  useEffect(() => {
+    setColorPickerActive(false);
+  }, [selectedIndex, setColorPickerActive]);
+
+  useEffect(
+    () => () => {
+      setBrushActive(false);
+      setColorPickerActive(false);
+    },
+    [setBrushActive, setColorPickerActive],
+  );
+
+  const handleRemove = (index: number) => {
+    removeLocalAdjustment(index);
+    setSelectedIndex(null);
+  };
+
   return (
     <Accordion
       {...EDIT_PANEL_ACCORDION_PROPS}
@@ -16,9 +71,85 @@ export const MaskingPanel = () => {
         startContent={<PaletteIcon />}
         title="Masking"
       >
-        <div className="p-4 text-center text-sm text-zinc-500">
-          Masking tools coming soon...
+        <div className="flex flex-col gap-2 pb-2">
+          <ButtonGroup fullWidth size="sm" variant="flat">
+            {MASK_MODES.map((mode) => (
+              <Button
+                key={mode}
+                isDisabled={localAdjustments.length >= MAX_LOCAL_ADJUSTMENTS}
+                onPress={() => {
+                  const index = addLocalAdjustment(mode);
+
+                  if (index !== null) {
+                    setSelectedIndex(index);
+                  }
+                }}
+              >
+                {MASK_MODE_LABELS[mode]}
+              </Button>
+            ))}
+          </ButtonGroup>
+          {localAdjustments.length === 0 && (
+            <div className="p-4 text-center text-sm text-zinc-500">
+              Add a mask to adjust part of the image.
+            </div>
+          )}
+          {localAdjustments.map((adjustment, index) => (
+            <div
+              key={`${adjustment.name}-${index}`}
+              className="flex items-center gap-2"
+            >
+              <Button
+                className="flex-1 justify-start"
+                color={index === selectedIndex ? "primary" : "default"}
+                size="sm"
+                variant="flat"
+                onPress={() => setSelectedIndex(index)}
+              >
+                {adjustment.name}
+              </Button>
+              <Switch
+                aria-label={`Enable ${adjustment.name}`}
+                isSelected={adjustment.enabled}
+                size="sm"
+                onValueChange={(enabled) =>
+                  updateLocalAdjustment(index, { enabled })
+                }
+              />
+              <Button
+                isIconOnly
+                aria-label={`Remove ${adjustment.name}`}
+                size="sm"
+                variant="light"
+                onPress={() => handleRemove(index)}
+              >
+                <TrashIcon />
+              </Button>
+            </div>
+          ))}
         </div>
+        {selected && selectedIndex !== null && (
+          <div key={selectedIndex} className="flex flex-col gap-2 pb-2">
+            <MaskControls
+              mask={selected.mask}
+              onMaskChange={(mask) =>
+                updateLocalAdjustment(selectedIndex, { mask })
+              }
+            />
+            <MaskRangeControls
+              range={selected.range}
+              onRangeChange={(range) =>
+                updateLocalAdjustment(selectedIndex, { range })
+              }
+            />
+            <LocalAdjustmentSliders
+              adjustment={selected}
+              onChange={(changes) =>
+                updateLocalAdjustment(selectedIndex, changes)
+              }
+            />
+          </div>
+        )}
       </AccordionItem>
     </Accordion>
   );
@@ -0,0 +1,103 @@
+import type { MaskPoint } from "@/types/sidecar";
+
+import { useEffect, useRef } from "react";
+
+import { useLocalAdjustments } from "@/features/edit-panel/masking/hooks/use-local-adjustments";
+import { useMaskingStore } from "@/features/edit-panel/masking/store/masking-store";
+import { api } from "@/services/api";
+
+const sourcePointAt = (event: PointerEvent) => {
+  const devicePixelRatio = window.devicePixelRatio || 1;
+
+  return api.renderer
+    .sourcePositionAtViewport({
+      x: event.clientX * devicePixelRatio,
+      y: event.clientY * devicePixelRatio,
+    })
+    .catch((error) => {
+      console.error("[useBrushPainting] source_position_at_viewport:", error);
+
+      return null;
+    });
+};
+
+// Records pointer strokes over the viewport while the brush is active and
+// appends each finished stroke to the selected brush mask.
+export function useBrushPainting(
+  viewportRef: React.RefObject<HTMLDivElement>,
+  enabled: boolean,
+) {
+  const isBrushActive = useMaskingStore((state) => state.isBrushActive);
+  const { appendBrushStroke } = useLocalAdjustments();
+  const appendBrushStrokeRef = useRef(appendBrushStroke);
+
+  useEffect(() => {
+    appendBrushStrokeRef.current = appendBrushStroke;
+  }, [appendBrushStroke]);
+
+  useEffect(() => {
+    const viewer = viewportRef.current;
+
+    if (!viewer || !enabled || !isBrushActive) return;
+
+    let pendingPoints: Promise<MaskPoint | null>[] | null = null;
+
+    const handlePointerDown = (event: PointerEvent) => {
+      if (
+        event.button !== 0 ||
+        (event.target instanceof HTMLElement &&
+          event.target.closest('[data-filter-ui="true"]'))
+      ) {
+        return;
+      }
+
+      event.preventDefault();
+      pendingPoints = [sourcePointAt(event)];
+      viewer.setPointerCapture?.(event.pointerId);
+    };
+
+    const handlePointerMove = (event: PointerEvent) => {
+      pendingPoints?.push(sourcePointAt(event));
+    };
+
+    const handlePointerUp = (event: PointerEvent) => {
+      if (!pendingPoints) return;
+
+      const strokePoints = pendingPoints;
+
+      pendingPoints = null;
+      viewer.releasePointerCapture?.(event.pointerId);
+
+      Promise.all(strokePoints).then((points) => {
+        const { selectedIndex, brushRadius, brushFeather, brushErase } =
+          useMaskingStore.getState();
+        const insidePoints = points.filter(
+          (point): point is MaskPoint => point !== null,
+        );
+
+        if (selectedIndex === null || insidePoints.length === 0) return;
+
+        appendBrushStrokeRef.current(selectedIndex, {
+          points: insidePoints,
+          radius: brushRadius,
+          feather: brushFeather,
+          erase: brushErase,
+        });
+      });
+    };
+
+    viewer.style.cursor = "crosshair";
+    viewer.addEventListener("pointerdown", handlePointerDown);
+    viewer.addEventListener("pointermove", handlePointerMove);
+    viewer.addEventListener("pointerup", handlePointerUp);
+    viewer.addEventListener("pointercancel", handlePointerUp);
+
+    return () => {
+      viewer.style.removeProperty("cursor");
+      viewer.removeEventListener("pointerdown", handlePointerDown);
+      viewer.removeEventListener("pointermove", handlePointerMove);
+      viewer.removeEventListener("pointerup", handlePointerUp);
+      viewer.removeEventListener("pointercancel", handlePointerUp);
+    };
+  }, [enabled, isBrushActive, viewportRef]);
+}
@@ -0,0 +1,149 @@
+import type {
+  BrushStroke,
+  LocalAdjustment,
+  Mask,
+  Sidecar,
+} from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export const MAX_LOCAL_ADJUSTMENTS = 4;
+
+export type MaskMode = Mask["mode"];
+
+export const MASK_MODE_LABELS: Record<MaskMode, string> = {
+  linear: "Linear Gradient",
+  radial: "Radial Gradient",
+  brush: "Brush",
+};
+
+const DEFAULT_MASKS: Record<MaskMode, Mask> = {
+  linear: {
+    mode: "linear",
+    start: { x: 0.5, y: 0 },
+    end: { x: 0.5, y: 0.5 },
+  },
+  radial: {
+    mode: "radial",
+    center: { x: 0.5, y: 0.5 },
+    radius_x: 0.25,
+    radius_y: 0.25,
+    rotation_degrees: 0,
+    feather: 0.5,
+    invert: false,
+  },
+  brush: { mode: "brush", strokes: [] },
+};
+
+export const createLocalAdjustment = (
+  mode: MaskMode,
+  name: string,
+): LocalAdjustment => ({
+  name,
+  enabled: true,
+  mask: DEFAULT_MASKS[mode],
+  range: { luminance: null, color: null },
+  exposure_ev: 0,
+  contrast: 0,
+  temperature: 0,
+  tint: 0,
+  saturation: 0,
+});
+
+const withLocalAdjustments = (
+  sidecar: Sidecar,
+  localAdjustments: LocalAdjustment[],
+): Sidecar => ({
+  ...sidecar,
+  recipe: { ...sidecar.recipe, local_adjustments: localAdjustments },
+});
+
+export function useLocalAdjustments() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+  const localAdjustments: LocalAdjustment[] =
+    sidecar?.recipe.local_adjustments ?? [];
+
+  const addLocalAdjustment = useCallback(
+    (mode: MaskMode) => {
+      if (!sidecar || localAdjustments.length >= MAX_LOCAL_ADJUSTMENTS) {
+        return null;
+      }
+
+      const name = `${MASK_MODE_LABELS[mode]} ${localAdjustments.length + 1}`;
+
+      setSidecar(
+        withLocalAdjustments(sidecar, [
+          ...localAdjustments,
+          createLocalAdjustment(mode, name),
+        ]),
+      );
+
+      return localAdjustments.length;
+    },
+    [localAdjustments, setSidecar, sidecar],
+  );
+
+  const updateLocalAdjustment = useCallback(
+    (index: number, changes: Partial<LocalAdjustment>) => {
+      if (!sidecar || !localAdjustments[index]) {
+        return;
+      }
+
+      setSidecar(
+        withLocalAdjustments(
+          sidecar,
+          localAdjustments.map((adjustment, adjustmentIndex) =>
+            adjustmentIndex === index
+              ? { ...adjustment, ...changes }
+              : adjustment,
+          ),
+        ),
+      );
+    },
+    [localAdjustments, setSidecar, sidecar],
+  );
+
+  const removeLocalAdjustment = useCallback(
+    (index: number) => {
+      if (!sidecar) {
+        return;
+      }
+
+      setSidecar(
+        withLocalAdjustments(
+          sidecar,
+          localAdjustments.filter(
+            (_, adjustmentIndex) => adjustmentIndex !== index,
+          ),
+        ),
+      );
+    },
+    [localAdjustments, setSidecar, sidecar],
+  );
+
+  const appendBrushStroke = useCallback(
+    (index: number, stroke: BrushStroke) => {
+      const mask = localAdjustments[index]?.mask;
+
+      if (mask?.mode !== "brush") {
+        return;
+      }
+
+      updateLocalAdjustment(index, {
+        mask: { ...mask, strokes: [...mask.strokes, stroke] },
+      });
+    },
+    [localAdjustments, updateLocalAdjustment],
+  );
+
+  return {
+    localAdjustments,
+    addLocalAdjustment,
+    updateLocalAdjustment,
+    removeLocalAdjustment,
+    appendBrushStroke,
+  };
+}
@@ -0,0 +1,86 @@
+import { useEffect, useRef } from "react";
+
+import { useLocalAdjustments } from "@/features/edit-panel/masking/hooks/use-local-adjustments";
+import { useMaskingStore } from "@/features/edit-panel/masking/store/masking-store";
+import { api } from "@/services/api";
+
+// Samples the clicked image color into the selected mask's color range while
+// the picker is active, keeping any tolerances already set on the range.
+export function useMaskColorPicker(
+  viewportRef: React.RefObject<HTMLDivElement>,
+  enabled: boolean,
+) {
+  const isColorPickerActive = useMaskingStore(
+    (state) => state.isColorPickerActive,
+  );
+  const { localAdjustments, updateLocalAdjustment } = useLocalAdjustments();
+  const latestRef = useRef({ localAdjustments, updateLocalAdjustment });
+
+  useEffect(() => {
+    latestRef.current = { localAdjustments, updateLocalAdjustment };
+  }, [localAdjustments, updateLocalAdjustment]);
+
+  useEffect(() => {
+    const viewer = viewportRef.current;
+
+    if (!viewer || !enabled || !isColorPickerActive) return;
+
+    const handlePointerDown = async (event: PointerEvent) => {
+      if (
+        event.button !== 0 ||
+        (event.target instanceof HTMLElement &&
+          event.target.closest('[data-filter-ui="true"]'))
+      ) {
+        return;
+      }
+
+      event.preventDefault();
+
+      const { selectedIndex, setColorPickerActive } =
+        useMaskingStore.getState();
+      const devicePixelRatio = window.devicePixelRatio || 1;
+
+      try {
+        const sample = await api.renderer.sourcePositionAtViewport({
+          x: event.clientX * devicePixelRatio,
+          y: event.clientY * devicePixelRatio,
+        });
+
+        if (!sample || selectedIndex === null) return;
+
+        const sampled = await api.renderer.sampleMaskColor(sample);
+        const { localAdjustments, updateLocalAdjustment } = latestRef.current;
+        const adjustment = localAdjustments[selectedIndex];
+
+        if (!adjustment) return;
+
+        const current = adjustment.range.color;
+
+        updateLocalAdjustment(selectedIndex, {
+          range: {
+            ...adjustment.range,
+            color: current
+              ? {
+                  ...current,
+                  sample: sampled.sample,
+                  hue_degrees: sampled.hue_degrees,
+                  chroma: sampled.chroma,
+                }
+              : sampled,
+          },
+        });
+        setColorPickerActive(false);
+      } catch (error) {
+        console.error("[useMaskColorPicker] sample_mask_color:", error);
+      }
+    };
+
+    viewer.style.cursor = "crosshair";
+    viewer.addEventListener("pointerdown", handlePointerDown);
+
+    return () => {
+      viewer.style.removeProperty("cursor");
+      viewer.removeEventListener("pointerdown", handlePointerDown);
+    };
+  }, [enabled, isColorPickerActive, viewportRef]);
+}
@@ -0,0 +1,44 @@
+import { create } from "zustand";
+
+export const DEFAULT_BRUSH_RADIUS = 0.02;
+export const DEFAULT_BRUSH_FEATHER = 0.5;
+
+interface MaskingState {
+  selectedIndex: number | null;
+  isBrushActive: boolean;
+  brushRadius: number;
+  brushFeather: number;
+  brushErase: boolean;
+  isColorPickerActive: boolean;
+  setSelectedIndex: (index: number | null) => void;
+  setBrushActive: (value: boolean) => void;
+  setBrushRadius: (value: number) => void;
+  setBrushFeather: (value: number) => void;
+  setBrushErase: (value: boolean) => void;
+  setColorPickerActive: (value: boolean) => void;
+}
+
+export const useMaskingStore = create<MaskingState>((set) => ({
+  selectedIndex: null,
+  isBrushActive: false,
+  brushRadius: DEFAULT_BRUSH_RADIUS,
+  brushFeather: DEFAULT_BRUSH_FEATHER,
+  brushErase: false,
+  isColorPickerActive: false,
+  setSelectedIndex: (index) => set({ selectedIndex: index }),
+  setBrushActive: (value) =>
+    set(
+      value
+        ? { isBrushActive: true, isColorPickerActive: false }
+        : { isBrushActive: false },
+    ),
+  setBrushRadius: (value) => set({ brushRadius: value }),
+  setBrushFeather: (value) => set({ brushFeather: value }),
+  setBrushErase: (value) => set({ brushErase: value }),
+  setColorPickerActive: (value) =>
+    set(
+      value
+        ? { isColorPickerActive: true, isBrushActive: false }
+        : { isColorPickerActive: false },
+    ),
+}));
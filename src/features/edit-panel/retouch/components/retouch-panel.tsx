@@ -1,9 +1,164 @@
+import type { RetouchMode } from "@/types/sidecar";
+
 import { Accordion, AccordionItem } from "@heroui/accordion";
-import { SunDimIcon } from "@phosphor-icons/react";
+import { Button, ButtonGroup } from "@heroui/button";
+import { Slider } from "@heroui/slider";
+import { Switch } from "@heroui/switch";
+import { SunDimIcon, TrashIcon } from "@phosphor-icons/react";
+import { useQueryClient } from "@tanstack/react-query";
+import { useEffect } from "react";
 
+import { useMaskingStore } from "@/features/edit-panel/masking/store/masking-store";
+import {
+  MAX_RETOUCH_SPOTS,
+  useRetouchSpots,
+} from "@/features/edit-panel/retouch/hooks/use-retouch-spots";
+import { useRetouchStore } from "@/features/edit-panel/retouch/store/retouch-store";
 import { EDIT_PANEL_ACCORDION_PROPS } from "@/features/edit-panel/utils/accordion";
+import { api } from "@/services/api";
+
+const RETOUCH_MODES: RetouchMode[] = ["heal", "clone"];
+
+const RETOUCH_MODE_LABELS: Record<RetouchMode, string> = {
+  heal: "Heal",
+  clone: "Clone",
+};
+
+const formatPercent = (value: number) => `${Math.round(value * 100)}%`;
+
+type SpotSliderProps = {
+  label: string;
+  value: number;
+  maxValue?: number;
+  minValue?: number;
+  step?: number;
+  onValueChange: (value: number) => void;
+};
+
+const SpotSlider = ({
+  label,
+  value,
+  maxValue = 1,
+  minValue = 0,
+  step = 0.005,
+  onValueChange,
+}: SpotSliderProps) => (
+  <Slider
+    getValueLabel={(sliderValue) => formatPercent(Number(sliderValue))}
+    label={label}
+    maxValue={maxValue}
+    minValue={minValue}
+    size="sm"
+    step={step}
+    value={value}
+    onChange={(sliderValue) => {
+      if (typeof sliderValue === "number") {
+        onValueChange(sliderValue);
+      }
+    }}
+  />
+);
+
+type ModeButtonsProps = {
+  mode: RetouchMode;
+  onModeChange: (mode: RetouchMode) => void;
+};
+
+const ModeButtons = ({ mode, onModeChange }: ModeButtonsProps) => (
+  <ButtonGroup fullWidth size="sm" variant="flat">
+    {RETOUCH_MODES.map((retouchMode) => (
+      <Button
+        key={retouchMode}
+        color={retouchMode === mode ? "primary" : "default"}
+        onPress={() => onModeChange(retouchMode)}
+      >
+        {RETOUCH_MODE_LABELS[retouchMode]}
+      </Button>
+    ))}
+  </ButtonGroup>
+);
 
 export const RetouchPanel = () => {
+  const {
+    retouchSpots,
+    updateRetouchSpot,
+    removeRetouchSpot,
+    clearRetouchSpots,
+  } = useRetouchSpots();
+  const selectedIndex = useRetouchStore((state) => state.selectedIndex);
+  const isSpotToolActive = useRetouchStore((state) => state.isSpotToolActive);
+  const spotRadius = useRetouchStore((state) => state.spotRadius);
+  const spotFeather = useRetouchStore((state) => state.spotFeather);
+  const spotMode = useRetouchStore((state) => state.spotMode);
+  const setSelectedIndex = useRetouchStore((state) => state.setSelectedIndex);
+  const setSpotToolActive = useRetouchStore(
+    (state) => state.setSpotToolActive,
+  );
+  const setSpotRadius = useRetouchStore((state) => state.setSpotRadius);
+  const setSpotFeather = useRetouchStore((state) => state.setSpotFeather);
+  const setSpotMode = useRetouchStore((state) => state.setSpotMode);
+  const isSpotVisualizationActive = useRetouchStore(
+    (state) => state.isSpotVisualizationActive,
+  );
+  const setSpotVisualizationActive = useRetouchStore(
+    (state) => state.setSpotVisualizationActive,
+  );
+  const queryClient = useQueryClient();
+  const isMaskToolActive = useMaskingStore(
+    (state) => state.isBrushActive || state.isColorPickerActive,
+  );
+  const selected =
+    selectedIndex !== null ? retouchSpots[selectedIndex] : undefined;
+
+  useEffect(() => {
+    if (isMaskToolActive) {
+      setSpotToolActive(false);
+    }
+  }, [isMaskToolActive, setSpotToolActive]);
+
+  useEffect(
+    () => () => {
+      setSpotToolActive(false);
+    },
+    [setSpotToolActive],
+  );
+
+  const handleRemove = (index: number) => {
+    removeRetouchSpot(index);
+    setSelectedIndex(null);
+  };
+
+  const handleSpotVisualization = async (enabled: boolean) => {
+    try {
+      await api.renderer.setSpotVisualization({ enabled });
+      setSpotVisualizationActive(enabled);
+      await queryClient.invalidateQueries({
+        queryKey: ["renderer-inspection"],
+      });
+    } catch (error) {
+      console.error("[RetouchPanel] set_spot_visualization:", error);
+    }
+  };
+
+  const handleFindSource = async (index: number) => {
+    const spot = retouchSpots[index];
+
+    if (!spot) return;
+
+    try {
+      const source = await api.renderer.findSpotSource({
+        ...spot.destination,
+        radius: spot.radius,
+      });
+
+      if (source) {
+        updateRetouchSpot(index, { source });
+      }
+    } catch (error) {
+      console.error("[RetouchPanel] find_spot_source:", error);
+    }
+  };
+
   return (
     <Accordion
       {...EDIT_PANEL_ACCORDION_PROPS}
@@ -16,9 +171,127 @@ export const RetouchPanel = () => {
         startContent={<SunDimIcon />}
         title="Detail & Retouch"
       >
-        <div className="p-4 text-center text-sm text-zinc-500">
-          Detail tools coming soon...
+        <div className="flex flex-col gap-2 pb-2">
+          <div className="flex items-center justify-between gap-2">
+            <Switch
+              isDisabled={retouchSpots.length >= MAX_RETOUCH_SPOTS}
+              isSelected={isSpotToolActive}
+              onValueChange={setSpotToolActive}
+            >
+              <span className="text-xs">Place Spots</span>
+            </Switch>
+            <Button
+              isDisabled={retouchSpots.length === 0}
+              size="sm"
+              variant="flat"
+              onPress={() => {
+                clearRetouchSpots();
+                setSelectedIndex(null);
+              }}
+            >
+              Clear
+            </Button>
+          </div>
+          <Switch
+            isSelected={isSpotVisualizationActive}
+            onValueChange={handleSpotVisualization}
+          >
+            <span className="text-xs">Visualize Spots</span>
+          </Switch>
+          <ModeButtons mode={spotMode} onModeChange={setSpotMode} />
+          <SpotSlider
+            label="Size"
+            maxValue={0.2}
+            minValue={0.002}
+            step={0.001}
+            value={spotRadius}
+            onValueChange={setSpotRadius}
+          />
+          <SpotSlider
+            label="Feather"
+            value={spotFeather}
+            onValueChange={setSpotFeather}
+          />
+          {retouchSpots.length === 0 && (
+            <div className="p-4 text-center text-sm text-zinc-500">
+              Click the image to remove a spot.
+            </div>
+          )}
+          {retouchSpots.map((spot, index) => (
+            <div key={index} className="flex items-center gap-2">
+              <Button
+                className="flex-1 justify-start"
+                color={index === selectedIndex ? "primary" : "default"}
+                size="sm"
+                variant="flat"
+                onPress={() => setSelectedIndex(index)}
+              >
+                {`${RETOUCH_MODE_LABELS[spot.mode]} ${index + 1}`}
+              </Button>
+              <Button
+                isIconOnly
+                aria-label={`Remove spot ${index + 1}`}
+                size="sm"
+                variant="light"
+                onPress={() => handleRemove(index)}
+              >
+                <TrashIcon />
+              </Button>
+            </div>
+          ))}
         </div>
+        {selected && selectedIndex !== null && (
+          <div key={selectedIndex} className="flex flex-col gap-2 pb-2">
+            <ModeButtons
+              mode={selected.mode}
+              onModeChange={(mode) =>
+                updateRetouchSpot(selectedIndex, { mode })
+              }
+            />
+            <SpotSlider
+              label="Spot Size"
+              maxValue={0.2}
+              minValue={0.002}
+              step={0.001}
+              value={selected.radius}
+              onValueChange={(radius) =>
+                updateRetouchSpot(selectedIndex, { radius })
+              }
+            />
+            <SpotSlider
+              label="Spot Feather"
+              value={selected.feather}
+              onValueChange={(feather) =>
+                updateRetouchSpot(selectedIndex, { feather })
+              }
+            />
+            <SpotSlider
+              label="Source X"
+              value={selected.source.x}
+              onValueChange={(x) =>
+                updateRetouchSpot(selectedIndex, {
+                  source: { ...selected.source, x },
+                })
+              }
+            />
+            <SpotSlider
+              label="Source Y"
+              value={selected.source.y}
+              onValueChange={(y) =>
+                updateRetouchSpot(selectedIndex, {
+                  source: { ...selected.source, y },
+                })
+              }
+            />
+            <Button
+              size="sm"
+              variant="flat"
+              onPress={() => handleFindSource(selectedIndex)}
+            >
+              Find Source
+            </Button>
+          </div>
+        )}
       </AccordionItem>
     </Accordion>
   );
@@ -0,0 +1,84 @@
+import type { RetouchSpot, Sidecar } from "@/types/sidecar";
+
+import { useCallback } from "react";
+
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+export const MAX_RETOUCH_SPOTS = 64;
+
+const withRetouchSpots = (
+  sidecar: Sidecar,
+  retouchSpots: RetouchSpot[],
+): Sidecar => ({
+  ...sidecar,
+  recipe: { ...sidecar.recipe, retouch_spots: retouchSpots },
+});
+
+export function useRetouchSpots() {
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const setSidecar = useActiveSidecarStore((state) => state.setSidecar);
+  const retouchSpots: RetouchSpot[] = sidecar?.recipe.retouch_spots ?? [];
+
+  const addRetouchSpot = useCallback(
+    (spot: RetouchSpot) => {
+      if (!sidecar || retouchSpots.length >= MAX_RETOUCH_SPOTS) {
+        return null;
+      }
+
+      setSidecar(withRetouchSpots(sidecar, [...retouchSpots, spot]));
+
+      return retouchSpots.length;
+    },
+    [retouchSpots, setSidecar, sidecar],
+  );
+
+  const updateRetouchSpot = useCallback(
+    (index: number, changes: Partial<RetouchSpot>) => {
+      if (!sidecar || !retouchSpots[index]) {
+        return;
+      }
+
+      setSidecar(
+        withRetouchSpots(
+          sidecar,
+          retouchSpots.map((spot, spotIndex) =>
+            spotIndex === index ? { ...spot, ...changes } : spot,
+          ),
+        ),
+      );
+    },
+    [retouchSpots, setSidecar, sidecar],
+  );
+
+  const removeRetouchSpot = useCallback(
+    (index: number) => {
+      if (!sidecar) {
+        return;
+      }
+
+      setSidecar(
+        withRetouchSpots(
+          sidecar,
+          retouchSpots.filter((_, spotIndex) => spotIndex !== index),
+        ),
+      );
+    },
+    [retouchSpots, setSidecar, sidecar],
+  );
+
+  const clearRetouchSpots = useCallback(() => {
+    if (!sidecar) {
+      return;
+    }
+
+    setSidecar(withRetouchSpots(sidecar, []));
+  }, [setSidecar, sidecar]);
+
+  return {
+    retouchSpots,
+    addRetouchSpot,
+    updateRetouchSpot,
+    removeRetouchSpot,
+    clearRetouchSpots,
+  };
+}
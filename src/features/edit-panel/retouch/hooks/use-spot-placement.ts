@@ -0,0 +1,80 @@
+import { useEffect, useRef } from "react";
+
+import { useRetouchSpots } from "@/features/edit-panel/retouch/hooks/use-retouch-spots";
+import { useRetouchStore } from "@/features/edit-panel/retouch/store/retouch-store";
+import { api } from "@/services/api";
+
+// Places a retouch spot where the viewport is clicked while the spot tool is
+// active, taking its source from the best-matching nearby patch.
+export function useSpotPlacement(
+  viewportRef: React.RefObject<HTMLDivElement>,
+  enabled: boolean,
+) {
+  const isSpotToolActive = useRetouchStore((state) => state.isSpotToolActive);
+  const { addRetouchSpot } = useRetouchSpots();
+  const addRetouchSpotRef = useRef(addRetouchSpot);
+
+  useEffect(() => {
+    addRetouchSpotRef.current = addRetouchSpot;
+  }, [addRetouchSpot]);
+
+  useEffect(() => {
+    const viewer = viewportRef.current;
+
+    if (!viewer || !enabled || !isSpotToolActive) return;
+
+    const handlePointerDown = async (event: PointerEvent) => {
+      if (
+        event.button !== 0 ||
+        (event.target instanceof HTMLElement &&
+          event.target.closest('[data-filter-ui="true"]'))
+      ) {
+        return;
+      }
+
+      event.preventDefault();
+
+      const { spotRadius, spotFeather, spotMode, setSelectedIndex } =
+        useRetouchStore.getState();
+      const devicePixelRatio = window.devicePixelRatio || 1;
+
+      try {
+        const destination = await api.renderer.sourcePositionAtViewport({
+          x: event.clientX * devicePixelRatio,
+          y: event.clientY * devicePixelRatio,
+        });
+
+        if (!destination) return;
+
+        const source = await api.renderer.findSpotSource({
+          ...destination,
+          radius: spotRadius,
+        });
+
+        if (!source) return;
+
+        const index = addRetouchSpotRef.current({
+          source,
+          destination,
+          radius: spotRadius,
+          feather: spotFeather,
+          mode: spotMode,
+        });
+
+        if (index !== null) {
+          setSelectedIndex(index);
+        }
+      } catch (error) {
+        console.error("[useSpotPlacement] find_spot_source:", error);
+      }
+    };
+
+    viewer.style.cursor = "crosshair";
+    viewer.addEventListener("pointerdown", handlePointerDown);
+
+    return () => {
+      viewer.style.removeProperty("cursor");
+      viewer.removeEventListener("pointerdown", handlePointerDown);
+    };
+  }, [enabled, isSpotToolActive, viewportRef]);
+}
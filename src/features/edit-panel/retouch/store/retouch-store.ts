@@ -0,0 +1,49 @@
+import type { RetouchMode } from "@/types/sidecar";
+
+import { create } from "zustand";
+
+import { useMaskingStore } from "@/features/edit-panel/masking/store/masking-store";
+
+export const DEFAULT_SPOT_RADIUS = 0.02;
+export const DEFAULT_SPOT_FEATHER = 0.5;
+
+interface RetouchState {
+  selectedIndex: number | null;
+  isSpotToolActive: boolean;
+  spotRadius: number;
+  spotFeather: number;
+  spotMode: RetouchMode;
+  isSpotVisualizationActive: boolean;
+  setSelectedIndex: (index: number | null) => void;
+  setSpotToolActive: (value: boolean) => void;
+  setSpotRadius: (value: number) => void;
+  setSpotFeather: (value: number) => void;
+  setSpotMode: (value: RetouchMode) => void;
+  setSpotVisualizationActive: (value: boolean) => void;
+}
+
+export const useRetouchStore = create<RetouchState>((set) => ({
+  selectedIndex: null,
+  isSpotToolActive: false,
+  spotRadius: DEFAULT_SPOT_RADIUS,
+  spotFeather: DEFAULT_SPOT_FEATHER,
+  spotMode: "heal",
+  isSpotVisualizationActive: false,
+  setSelectedIndex: (index) => set({ selectedIndex: index }),
+  setSpotToolActive: (value) => {
+    if (value) {
+      const { setBrushActive, setColorPickerActive } =
+        useMaskingStore.getState();
+
+      setBrushActive(false);
+      setColorPickerActive(false);
+    }
+
+    set({ isSpotToolActive: value });
+  },
+  setSpotRadius: (value) => set({ spotRadius: value }),
+  setSpotFeather: (value) => set({ spotFeather: value }),
+  setSpotMode: (value) => set({ spotMode: value }),
+  setSpotVisualizationActive: (value) =>
+    set({ isSpotVisualizationActive: value }),
+}));
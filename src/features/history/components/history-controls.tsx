@@ -0,0 +1,46 @@
+import { Button } from "@heroui/button";
+import { Tooltip } from "@heroui/tooltip";
+import {
+  ArrowCounterClockwiseIcon,
+  ArrowClockwiseIcon,
+} from "@phosphor-icons/react";
+
+import { useEditHistoryStore } from "@/features/history/store/edit-history-store";
+import { redoEdit, undoEdit } from "@/features/history/utils/edit-history";
+
+export function HistoryControls() {
+  const summary = useEditHistoryStore((state) => state.summary);
+  const position = summary?.position ?? 0;
+  const entries = summary?.entries ?? [];
+  const undoLabel = entries[position - 1]?.label;
+  const redoLabel = entries[position]?.label;
+
+  return (
+    <>
+      <Tooltip content={undoLabel ? `Undo ${undoLabel}` : "Undo"}>
+        <Button
+          isIconOnly
+          aria-label="Undo"
+          className="rounded-md shadow-md"
+          color="default"
+          isDisabled={!undoLabel}
+          size="sm"
+          startContent={<ArrowCounterClockwiseIcon />}
+          onPress={() => void undoEdit()}
+        />
+      </Tooltip>
+      <Tooltip content={redoLabel ? `Redo ${redoLabel}` : "Redo"}>
+        <Button
+          isIconOnly
+          aria-label="Redo"
+          className="rounded-md shadow-md"
+          color="default"
+          isDisabled={!redoLabel}
+          size="sm"
+          startContent={<ArrowClockwiseIcon />}
+          onPress={() => void redoEdit()}
+        />
+      </Tooltip>
+    </>
+  );
+}
@@ -0,0 +1,81 @@
+import { useEffect } from "react";
+
+import { useEditHistoryStore } from "@/features/history/store/edit-history-store";
+import {
+  recordActiveRecipe,
+  redoEdit,
+  undoEdit,
+} from "@/features/history/utils/edit-history";
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+
+const RECORD_DEBOUNCE_MS = 500;
+
+// Text fields keep their own undo; sliders do not, so a slider that still has
+// focus after a drag does not block the shortcut.
+const isTextEntryTarget = (target: EventTarget | null) =>
+  target instanceof HTMLElement &&
+  (target.isContentEditable ||
+    target.closest('textarea, input:not([type="range"])') !== null);
+
+const recordOrLog = () =>
+  recordActiveRecipe().catch((error) =>
+    console.error("[edit-history] Failed to record edit:", error),
+  );
+
+// Records the active recipe into the image's persisted undo history and binds
+// the undo/redo shortcuts. A newly opened image is recorded immediately so its
+// loaded recipe is the baseline; later edits are recorded once they settle.
+export function useEditHistory() {
+  const imagePath = useActiveSidecarStore((state) => state.imagePath);
+  const sidecar = useActiveSidecarStore((state) => state.sidecar);
+  const hasSidecar = sidecar !== null;
+  const clearSummary = useEditHistoryStore((state) => state.clearSummary);
+
+  useEffect(() => {
+    if (!imagePath || !hasSidecar) {
+      clearSummary();
+
+      return;
+    }
+
+    void recordOrLog();
+  }, [clearSummary, hasSidecar, imagePath]);
+
+  useEffect(() => {
+    if (!imagePath || !sidecar) {
+      return;
+    }
+
+    const timeout = window.setTimeout(() => {
+      void recordOrLog();
+    }, RECORD_DEBOUNCE_MS);
+
+    return () => clearTimeout(timeout);
+  }, [imagePath, sidecar]);
+
+  useEffect(() => {
+    const handleKeyDown = (event: KeyboardEvent) => {
+      if (
+        !(event.metaKey || event.ctrlKey) ||
+        event.altKey ||
+        isTextEntryTarget(event.target)
+      ) {
+        return;
+      }
+
+      const key = event.key.toLowerCase();
+
+      if (key === "z" && !event.shiftKey) {
+        event.preventDefault();
+        void undoEdit();
+      } else if ((key === "z" && event.shiftKey) || key === "y") {
+        event.preventDefault();
+        void redoEdit();
+      }
+    };
+
+    window.addEventListener("keydown", handleKeyDown);
+
+    return () => window.removeEventListener("keydown", handleKeyDown);
+  }, []);
+}
@@ -0,0 +1,19 @@
+import type { EditHistorySummary } from "@/types/history";
+
+import { create } from "zustand";
+
+type EditHistoryState = {
+  imagePath: string | null;
+  summary: EditHistorySummary | null;
+  setSummary: (imagePath: string, summary: EditHistorySummary) => void;
+  clearSummary: () => void;
+};
+
+export const useEditHistoryStore = create<EditHistoryState>((set) => ({
+  imagePath: null,
+  summary: null,
+
+  setSummary: (imagePath, summary) => set({ imagePath, summary }),
+
+  clearSummary: () => set({ imagePath: null, summary: null }),
+}));
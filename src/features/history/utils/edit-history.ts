@@ -0,0 +1,80 @@
+import type { EditRecipe } from "@/types/sidecar";
+
+import { useEditHistoryStore } from "@/features/history/store/edit-history-store";
+import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+import { api } from "@/services/api";
+
+let pendingStep: Promise<void> = Promise.resolve();
+
+const updateSummary = async (imagePath: string) => {
+  const summary = await api.history.getEditHistorySummary({ path: imagePath });
+
+  if (useActiveSidecarStore.getState().imagePath === imagePath) {
+    useEditHistoryStore.getState().setSummary(imagePath, summary);
+  }
+};
+
+// Records the active recipe as the latest history state. Recording an
+// unchanged recipe is a no-op, so this is safe to call before every step.
+export const recordActiveRecipe = async () => {
+  const { imagePath, sidecar } = useActiveSidecarStore.getState();
+
+  if (!imagePath || !sidecar) {
+    return;
+  }
+
+  const summary = await api.history.recordEditHistory({
+    path: imagePath,
+    recipe: sidecar.recipe,
+  });
+
+  if (useActiveSidecarStore.getState().imagePath === imagePath) {
+    useEditHistoryStore.getState().setSummary(imagePath, summary);
+  }
+};
+
+const applyRecipe = (imagePath: string, recipe: EditRecipe) => {
+  const { imagePath: activePath, sidecar, setSidecar } =
+    useActiveSidecarStore.getState();
+
+  if (activePath !== imagePath || !sidecar) {
+    return;
+  }
+
+  setSidecar({ ...sidecar, recipe });
+};
+
+// Steps are queued so repeated shortcuts apply one at a time, and each step
+// first records any edit the debounced recorder has not stored yet.
+const stepHistory = (direction: "undo" | "redo") => {
+  pendingStep = pendingStep.then(async () => {
+    const { imagePath } = useActiveSidecarStore.getState();
+
+    if (!imagePath) {
+      return;
+    }
+
+    try {
+      await recordActiveRecipe();
+
+      const recipe =
+        direction === "undo"
+          ? await api.history.undoEditHistory({ path: imagePath })
+          : await api.history.redoEditHistory({ path: imagePath });
+
+      if (recipe) {
+        applyRecipe(imagePath, recipe);
+      }
+
+      await updateSummary(imagePath);
+    } catch (error) {
+      console.error(`[edit-history] Failed to ${direction}:`, error);
+    }
+  });
+
+  return pendingStep;
+};
+
+export const undoEdit = () => stepHistory("undo");
+
+export const redoEdit = () => stepHistory("redo");
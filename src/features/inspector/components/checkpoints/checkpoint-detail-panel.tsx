@@ -186,7 +186,7 @@ export function CheckpointDetailPanel({
             )}
           />
           <InspectorRow
-            label="Crop"
+            label="Active Area"
             value={`${raw.cropArea.width} x ${raw.cropArea.height} @ ${raw.cropArea.x}, ${raw.cropArea.y}`}
           />
           <InspectorRow label="Bit Depth" value={`${raw.bitsPerSample} bits`} />
@@ -211,10 +211,30 @@ export function CheckpointDetailPanel({
           label="Development"
           value={formatTexture(textures.developmentOutput)}
         />
+        <InspectorRow
+          label="Lens Correction"
+          value={formatTexture(textures.lensCorrectionOutput)}
+        />
+        <InspectorRow
+          label="Retouch"
+          value={formatTexture(textures.retouchOutput)}
+        />
         <InspectorRow
           label="Adjustment"
           value={formatTexture(textures.adjustmentOutput)}
         />
+        <InspectorRow
+          label="Mask"
+          value={formatTexture(textures.mask)}
+        />
+        <InspectorRow
+          label="Local Adjustment"
+          value={formatTexture(textures.localAdjustmentOutput)}
+        />
+        <InspectorRow
+          label="Geometry"
+          value={formatTexture(textures.geometryOutput)}
+        />
         <InspectorRow
           label="Display"
           value={formatTexture(textures.displayOutput)}
@@ -330,5 +350,7 @@ const formatArtifactLabel = (
   artifact: InspectionCheckpointArtifact | null | undefined,
 ) => artifact?.label || formatArtifactKind(artifact?.kind);
 
-const formatTexture = (texture: TextureResourceInspection) =>
-  `${texture.format} / ${formatDimensions(texture.width, texture.height)}`;
+const formatTexture = (texture: TextureResourceInspection | undefined) =>
+  texture
+    ? `${texture.format} / ${formatDimensions(texture.width, texture.height)}`
+    : "-";
@@ -16,10 +16,30 @@ export const GraphInspectionSection = ({
       label="Development"
       value={formatTexture(textures?.developmentOutput)}
     />
+    <InspectorRow
+      label="Lens Correction"
+      value={formatTexture(textures?.lensCorrectionOutput)}
+    />
+    <InspectorRow
+      label="Retouch"
+      value={formatTexture(textures?.retouchOutput)}
+    />
     <InspectorRow
       label="Adjustment"
       value={formatTexture(textures?.adjustmentOutput)}
     />
+    <InspectorRow
+      label="Mask"
+      value={formatTexture(textures?.mask)}
+    />
+    <InspectorRow
+      label="Local Adjustment"
+      value={formatTexture(textures?.localAdjustmentOutput)}
+    />
+    <InspectorRow
+      label="Geometry"
+      value={formatTexture(textures?.geometryOutput)}
+    />
     <InspectorRow
       label="Display"
       value={formatTexture(textures?.displayOutput)}
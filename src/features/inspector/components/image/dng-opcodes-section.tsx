@@ -0,0 +1,34 @@
+import type { DngOpcodeInspection } from "@/types/inspection";
+
+import { InspectorRow } from "../shared/inspector-row";
+import { InspectorSection } from "../shared/inspector-section";
+
+export const DngOpcodesSection = ({
+  opcodes,
+}: {
+  opcodes: DngOpcodeInspection[];
+}) => {
+  if (opcodes.length === 0) {
+    return null;
+  }
+
+  return (
+    <InspectorSection title="DNG Opcodes">
+      {opcodes.map((opcode, index) => (
+        <InspectorRow
+          key={`${opcode.list}-${index}`}
+          label={`${opcode.list} ${opcode.name}`}
+          value={formatOpcodeStatus(opcode)}
+        />
+      ))}
+    </InspectorSection>
+  );
+};
+
+const formatOpcodeStatus = (opcode: DngOpcodeInspection) => {
+  if (opcode.applied) {
+    return "Applied";
+  }
+
+  return opcode.optional ? "Skipped (optional)" : "Skipped";
+};
@@ -25,8 +25,8 @@ export const RawMetadataSection = ({
           raw.sensorDimensions.height,
         )}
       />
-      <InspectorRow label="Crop" value={formatRect(raw.cropArea)} />
-      <InspectorRow label="CFA" value={raw.cfa.name} />
+      <InspectorRow label="Active Area" value={formatRect(raw.cropArea)} />
+      <InspectorRow label="CFA" value={raw.cfa?.name ?? "Linear RGB"} />
       <InspectorRow
         label="Black"
         value={formatNumberList(raw.sourceBlackLevels, 0)}
@@ -51,6 +51,10 @@ export const RawMetadataSection = ({
         label="WB Headroom"
         value={formatNumberList(raw.headroomWhiteBalance, 3)}
       />
+      <InspectorRow
+        label="Mapped Bad Pixels"
+        value={raw.mappedBadPixels.toString()}
+      />
     </InspectorSection>
   );
 };
@@ -1,6 +1,7 @@
 import { GraphInspectionSection } from "./graph/graph-inspection-section";
 import { CheckpointDetailPanel } from "./checkpoints/checkpoint-detail-panel";
 import { CheckpointSection } from "./checkpoints/checkpoint-section";
+import { DngOpcodesSection } from "./image/dng-opcodes-section";
 import { ImageInspectionSection } from "./image/image-inspection-section";
 import { RawMetadataSection } from "./image/raw-metadata-section";
 import { PipelineInspectionSection } from "./pipeline/pipeline-inspection-section";
@@ -47,6 +48,7 @@ export function InspectorPanel() {
             <>
               <ImageInspectionSection image={image} />
               <RawMetadataSection raw={image?.raw ?? null} />
+              <DngOpcodesSection opcodes={image?.dngOpcodes ?? []} />
               <PipelineInspectionSection pipeline={pipeline} />
               <GraphInspectionSection textures={textures} />
               <TimingInspectionSection timings={timings} />
@@ -1,31 +1,131 @@
 import type { PipelineInspection } from "@/types/inspection";
 
+import { Select, SelectItem } from "@heroui/select";
+import { Switch } from "@heroui/switch";
+import { useQueryClient } from "@tanstack/react-query";
+
 import { InspectorRow } from "../shared/inspector-row";
 import { InspectorSection } from "../shared/inspector-section";
 
+import { useRetouchStore } from "@/features/edit-panel/retouch/store/retouch-store";
 import { formatEv } from "@/lib/formatters";
+import { api } from "@/services/api";
+
+const MASK_OVERLAY_OPTIONS = [
+  { key: "off", label: "Off", index: null },
+  { key: "0", label: "Mask 1", index: 0 },
+  { key: "1", label: "Mask 2", index: 1 },
+  { key: "2", label: "Mask 3", index: 2 },
+  { key: "3", label: "Mask 4", index: 3 },
+];
 
 export const PipelineInspectionSection = ({
   pipeline,
 }: {
   pipeline: PipelineInspection | null;
-}) => (
-  <InspectorSection title="Pipeline">
-    <InspectorRow
-      label="Development"
-      value={pipeline?.developmentSource ?? "-"}
-    />
-    <InspectorRow
-      label="Display Intent"
-      value={pipeline?.displayIntent ?? "-"}
-    />
-    <InspectorRow
-      label="Base Exposure"
-      value={formatEv(pipeline?.baseExposureEv)}
-    />
-    <InspectorRow
-      label="User Exposure"
-      value={formatEv(pipeline?.userExposureEv)}
-    />
-  </InspectorSection>
-);
+}) => {
+  const queryClient = useQueryClient();
+
+  const setSharpeningMaskPreview = async (enabled: boolean) => {
+    await api.renderer.setSharpeningMaskPreview({ enabled });
+    await queryClient.invalidateQueries({ queryKey: ["renderer-inspection"] });
+  };
+
+  const setLocalMaskOverlay = async (index: number | null) => {
+    await api.renderer.setLocalMaskOverlay({ index });
+    await queryClient.invalidateQueries({ queryKey: ["renderer-inspection"] });
+  };
+
+  const setSpotVisualization = async (enabled: boolean) => {
+    await api.renderer.setSpotVisualization({ enabled });
+    useRetouchStore.getState().setSpotVisualizationActive(enabled);
+    await queryClient.invalidateQueries({ queryKey: ["renderer-inspection"] });
+  };
+
+  return (
+    <InspectorSection title="Pipeline">
+      <InspectorRow
+        label="Development"
+        value={pipeline?.developmentSource ?? "-"}
+      />
+      <InspectorRow
+        label="Display Intent"
+        value={pipeline?.displayIntent ?? "-"}
+      />
+      <InspectorRow
+        label="Base Exposure"
+        value={formatEv(pipeline?.baseExposureEv)}
+      />
+      <InspectorRow
+        label="User Exposure"
+        value={formatEv(pipeline?.userExposureEv)}
+      />
+      <InspectorRow
+        label="White Balance"
+        value={pipeline?.whiteBalance ?? "-"}
+      />
+      <InspectorRow
+        label="WB Multipliers"
+        value={formatMultipliers(pipeline?.whiteBalanceMultipliers)}
+      />
+      <InspectorRow
+        label="Highlights"
+        value={pipeline?.highlightReconstruction ?? "-"}
+      />
+      <InspectorRow
+        label="Noise Reduction"
+        value={pipeline?.noiseReduction ?? "-"}
+      />
+      <InspectorRow
+        label="Sharpening"
+        value={pipeline?.captureSharpening ?? "-"}
+      />
+      <Switch
+        isDisabled={!pipeline || pipeline.captureSharpening === "-"}
+        isSelected={pipeline?.sharpeningMaskPreview ?? false}
+        size="sm"
+        onValueChange={setSharpeningMaskPreview}
+      >
+        <span className="text-xs text-zinc-300">Sharpening Mask</span>
+      </Switch>
+      <Select
+        isDisabled={!pipeline}
+        items={MASK_OVERLAY_OPTIONS}
+        label="Mask Overlay"
+        selectedKeys={new Set([String(pipeline?.localMaskOverlay ?? "off")])}
+        size="sm"
+        onSelectionChange={(keys) => {
+          const next = MASK_OVERLAY_OPTIONS.find(
+            (option) => option.key === Array.from(keys).at(0),
+          );
+
+          if (next) {
+            setLocalMaskOverlay(next.index);
+          }
+        }}
+      >
+        {(option) => <SelectItem>{option.label}</SelectItem>}
+      </Select>
+      <Switch
+        isDisabled={!pipeline}
+        isSelected={pipeline?.spotVisualization ?? false}
+        size="sm"
+        onValueChange={setSpotVisualization}
+      >
+        <span className="text-xs text-zinc-300">Visualize Spots</span>
+      </Switch>
+      <InspectorRow
+        label="Lens Profile"
+        value={pipeline?.lensProfile ?? "-"}
+      />
+      <InspectorRow
+        label="Tone Mapper"
+        value={pipeline?.toneMapper ?? "-"}
+      />
+      <InspectorRow label="Look" value={pipeline?.look ?? "-"} />
+    </InspectorSection>
+  );
+};
+
+const formatMultipliers = (values: number[] | null | undefined) =>
+  values ? values.map((value) => value.toFixed(3)).join(", ") : "-";
@@ -5,13 +5,40 @@ import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 
 import { api } from "@/services/api";
 import { useActiveSidecarStore } from "@/features/sidecar/store/active-sidecar-store";
+import { DEFAULT_GEOMETRY } from "@/features/edit-panel/geometry/hooks/use-geometry";
+import { DEFAULT_LENS_CORRECTION } from "@/features/edit-panel/geometry/hooks/use-lens-correction";
+import { DEFAULT_CAPTURE_SHARPENING } from "@/features/edit-panel/basic-adjustments/hooks/use-capture-sharpening";
+import { DEFAULT_COLOR_MIXER } from "@/features/edit-panel/basic-adjustments/hooks/use-color-mixer";
+import { DEFAULT_LOOK } from "@/features/edit-panel/basic-adjustments/hooks/use-look";
+import { DEFAULT_NOISE_REDUCTION } from "@/features/edit-panel/basic-adjustments/hooks/use-noise-reduction";
+import { DEFAULT_TONE_CURVE } from "@/features/edit-panel/basic-adjustments/hooks/use-tone-curve";
+import { DEFAULT_TONE_MAPPING } from "@/features/edit-panel/basic-adjustments/hooks/use-tone-mapping";
 import { useInspectionWorkspaceStore } from "@/features/inspector/store/inspection-workspace-store";
 import { useImageStore } from "@/store/image-store";
 import { useImageTransformStore } from "@/store/transform-store";
 import { useLayoutStore } from "@/store/layout-store";
 
 const DEFAULT_RECIPE: EditRecipe = {
+  white_balance: { mode: "as_shot" },
+  highlight_reconstruction: "blend",
+  lens_correction: DEFAULT_LENS_CORRECTION,
+  noise_reduction: DEFAULT_NOISE_REDUCTION,
+  capture_sharpening: DEFAULT_CAPTURE_SHARPENING,
   exposure_ev: 0,
+  contrast: 0,
+  highlights: 0,
+  shadows: 0,
+  whites: 0,
+  blacks: 0,
+  saturation: 0,
+  vibrance: 0,
+  tone_curve: DEFAULT_TONE_CURVE,
+  color_mixer: DEFAULT_COLOR_MIXER,
+  look: DEFAULT_LOOK,
+  tone_mapping: DEFAULT_TONE_MAPPING,
+  geometry: DEFAULT_GEOMETRY,
+  local_adjustments: [],
+  retouch_spots: [],
 };
 
 const EXPOSURE_REFERENCE_VALUES = [-2, -1, 0, 1, 2];
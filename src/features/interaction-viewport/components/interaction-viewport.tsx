@@ -7,6 +7,9 @@ import { useViewportSync } from "../hooks/use-viewport-sync";
 import { useInteractionHandlers } from "../hooks/use-interaction-handlers";
 
 import { useImageStore } from "@/store/image-store";
+import { useBrushPainting } from "@/features/edit-panel/masking/hooks/use-brush-painting";
+import { useMaskColorPicker } from "@/features/edit-panel/masking/hooks/use-mask-color-picker";
+import { useSpotPlacement } from "@/features/edit-panel/retouch/hooks/use-spot-placement";
 import { FilterMenuBar } from "@/features/filter/components/menu-bar/menu-bar";
 import { useFilterStore } from "@/features/filter/stores/filter-store";
 import { useFilteredImages } from "@/features/filter/hooks/use-filtered-files";
@@ -66,6 +69,10 @@ export function InteractionViewport({
     rendererActive && Boolean(imagePath),
   );
 
+  useBrushPainting(viewportRef, rendererActive && Boolean(imagePath));
+  useMaskColorPicker(viewportRef, rendererActive && Boolean(imagePath));
+  useSpotPlacement(viewportRef, rendererActive && Boolean(imagePath));
+
   const showEmptyState = !imagePath && !isLoading && !isPreviewLoading;
   const showFilteredEmpty =
     !isLoading &&
@@ -1,11 +1,23 @@
 import { useEffect, useRef, useCallback } from "react";
 
+import { useMaskingStore } from "@/features/edit-panel/masking/store/masking-store";
+import { useRetouchStore } from "@/features/edit-panel/retouch/store/retouch-store";
 import { useImageTransformStore } from "@/store/transform-store";
 
 const MIN_SCALE = 0.01;
 const MAX_SCALE = 30;
 const PAN_SPEED = 1.5;
 
+const isToolPointerActive = () => {
+  const { isBrushActive, isColorPickerActive } = useMaskingStore.getState();
+
+  return (
+    isBrushActive ||
+    isColorPickerActive ||
+    useRetouchStore.getState().isSpotToolActive
+  );
+};
+
 export function useInteractionHandlers(
   viewportRef: React.RefObject<HTMLDivElement>,
   scale: number,
@@ -102,6 +114,10 @@ export function useInteractionHandlers(
 
       if (pointerDownEvent.button !== 0) return;
 
+      // Mask painting, color picking and spot placement own primary-button
+      // input while active.
+      if (isToolPointerActive()) return;
+
       const viewer = viewportRef.current;
 
       if (!viewer) return;
@@ -219,12 +235,14 @@ export function useInteractionHandlers(
   const handlePointerEnter = useCallback(() => {
     isHoveringRef.current = true;
     if (isDraggingRef.current) return;
+    if (isToolPointerActive()) return;
     setCursor("grab");
   }, [setCursor]);
 
   const handlePointerLeave = useCallback(() => {
     isHoveringRef.current = false;
     if (isDraggingRef.current) return;
+    if (isToolPointerActive()) return;
     setCursor("");
   }, [setCursor]);
 
@@ -0,0 +1,45 @@
+import { Slider } from "@heroui/slider";
+import { Switch } from "@heroui/switch";
+
+import { useHdrOutput } from "../hooks/use-hdr-output";
+
+export function DisplaySettings() {
+  const { hdrEnabled, peakNits, hdrActive, setHdrEnabled, setPeakNits } =
+    useHdrOutput();
+
+  return (
+    <div className="flex flex-col gap-2">
+      <h3 className="text-md font-semibold">Display</h3>
+      <div className="flex items-center justify-between gap-4 p-3 rounded-lg">
+        <Switch isSelected={hdrEnabled} size="sm" onValueChange={setHdrEnabled}>
+          <span className="text-sm text-zinc-300">HDR Output</span>
+        </Switch>
+        {hdrEnabled && (
+          <span className="text-xs text-zinc-400">
+            {hdrActive ? "Active" : "Unsupported, using SDR"}
+          </span>
+        )}
+      </div>
+      <Slider
+        className="px-3"
+        defaultValue={peakNits}
+        getValueLabel={(value) => `${value} nits`}
+        isDisabled={!hdrEnabled}
+        label="Peak Brightness"
+        maxValue={4000}
+        minValue={400}
+        size="sm"
+        step={100}
+        onChangeEnd={(value) => {
+          if (typeof value === "number") {
+            setPeakNits(value);
+          }
+        }}
+      />
+      <p className="text-xs text-zinc-400 flex justify-end">
+        Scene-referred images use the extra range up to this peak. Other images
+        are shown as SDR.
+      </p>
+    </div>
+  );
+}
@@ -0,0 +1,51 @@
+import { useDisplayPreferencesStore } from "../stores/display-preferences-store";
+
+import { api } from "@/services/api";
+
+/**
+ * Sends the stored HDR preference to the renderer and records whether the
+ * surface accepted it or fell back to SDR.
+ */
+export async function applyHdrOutput() {
+  const { hdrEnabled, peakNits, setHdrActive } =
+    useDisplayPreferencesStore.getState();
+
+  try {
+    const hdrActive = await api.renderer.setHdrOutput({
+      enabled: hdrEnabled,
+      peakNits,
+    });
+
+    setHdrActive(hdrActive);
+  } catch (error) {
+    console.error("Failed to set HDR output", error);
+  }
+}
+
+export function useHdrOutput() {
+  const hdrEnabled = useDisplayPreferencesStore((state) => state.hdrEnabled);
+  const peakNits = useDisplayPreferencesStore((state) => state.peakNits);
+  const hdrActive = useDisplayPreferencesStore((state) => state.hdrActive);
+  const setHdrEnabled = useDisplayPreferencesStore(
+    (state) => state.setHdrEnabled,
+  );
+  const setPeakNits = useDisplayPreferencesStore((state) => state.setPeakNits);
+
+  const updateHdrEnabled = (value: boolean) => {
+    setHdrEnabled(value);
+    void applyHdrOutput();
+  };
+
+  const updatePeakNits = (value: number) => {
+    setPeakNits(value);
+    void applyHdrOutput();
+  };
+
+  return {
+    hdrEnabled,
+    peakNits,
+    hdrActive,
+    setHdrEnabled: updateHdrEnabled,
+    setPeakNits: updatePeakNits,
+  };
+}
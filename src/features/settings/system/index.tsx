@@ -1,8 +1,10 @@
 import { CacheSettings } from "./components/cache-settings";
+import { DisplaySettings } from "./components/display-settings";
 
 export function SystemTab() {
   return (
     <div className="flex flex-col gap-4">
+      <DisplaySettings />
       <CacheSettings />
     </div>
   );
@@ -0,0 +1,34 @@
+import { create } from "zustand";
+import { persist } from "zustand/middleware";
+
+export const DEFAULT_HDR_PEAK_NITS = 1000;
+
+interface DisplayPreferencesState {
+  hdrEnabled: boolean;
+  peakNits: number;
+  hdrActive: boolean;
+  setHdrEnabled: (value: boolean) => void;
+  setPeakNits: (value: number) => void;
+  setHdrActive: (value: boolean) => void;
+}
+
+export const useDisplayPreferencesStore = create<DisplayPreferencesState>()(
+  persist(
+    (set) => ({
+      hdrEnabled: false,
+      peakNits: DEFAULT_HDR_PEAK_NITS,
+      hdrActive: false,
+      setHdrEnabled: (value) => set({ hdrEnabled: value }),
+      setPeakNits: (value) => set({ peakNits: value }),
+      setHdrActive: (value) => set({ hdrActive: value }),
+    }),
+    {
+      name: "extents-display-preferences",
+      version: 1,
+      partialize: (state) => ({
+        hdrEnabled: state.hdrEnabled,
+        peakNits: state.peakNits,
+      }),
+    },
+  ),
+);
@@ -2,6 +2,8 @@ import React from "react";
 import ReactDOM from "react-dom/client";
 import { BrowserRouter } from "react-router-dom";
 
+import { applyHdrOutput } from "@/features/settings/system/hooks/use-hdr-output";
+import { useDisplayPreferencesStore } from "@/features/settings/system/stores/display-preferences-store";
 import { api } from "@/services/api";
 import App from "./App.tsx";
 import { Provider } from "./provider.tsx";
@@ -28,6 +30,13 @@ ReactDOM.createRoot(document.getElementById("root")!).render(
   </React.StrictMode>,
 );
 
-api.renderer.initRenderer().catch((err) => {
-  console.error("[main.tsx] Failed to init renderer:", err);
-});
+api.renderer
+  .initRenderer()
+  .then(() => {
+    if (useDisplayPreferencesStore.getState().hdrEnabled) {
+      return applyHdrOutput();
+    }
+  })
+  .catch((err) => {
+    console.error("[main.tsx] Failed to init renderer:", err);
+  });
@@ -0,0 +1,9 @@
+import type { CommandArgs } from "@/types/commands";
+
+import { invokeTauri } from "./_client";
+
+export const getBadPixelMap = (args: CommandArgs["get_bad_pixel_map"]) =>
+  invokeTauri("get_bad_pixel_map", args);
+
+export const setBadPixelMap = (args: CommandArgs["set_bad_pixel_map"]) =>
+  invokeTauri("set_bad_pixel_map", args);
@@ -0,0 +1,6 @@
+import type { ExportImagesRequest } from "@/types/export";
+
+import { invokeTauri } from "./_client";
+
+export const exportImages = (request: ExportImagesRequest) =>
+  invokeTauri("export_images", { request });
@@ -0,0 +1,16 @@
+import type { CommandArgs } from "@/types/commands";
+
+import { invokeTauri } from "./_client";
+
+export const getEditHistorySummary = (
+  args: CommandArgs["get_edit_history_summary"],
+) => invokeTauri("get_edit_history_summary", args);
+
+export const recordEditHistory = (args: CommandArgs["record_edit_history"]) =>
+  invokeTauri("record_edit_history", args);
+
+export const undoEditHistory = (args: CommandArgs["undo_edit_history"]) =>
+  invokeTauri("undo_edit_history", args);
+
+export const redoEditHistory = (args: CommandArgs["redo_edit_history"]) =>
+  invokeTauri("redo_edit_history", args);
@@ -1,10 +1,14 @@
 import * as annotations from "./annotations";
+import * as badPixels from "./bad-pixels";
 import * as fs from "./file-system";
 import * as image from "./image";
 import * as thumbnails from "./thumbnails";
 import * as renderer from "./renderer";
 import * as exif from "./exif";
+import * as exportImages from "./export";
+import * as history from "./history";
 import * as inspection from "./inspection";
+import * as look from "./look";
 import * as settings from "./settings";
 import * as sidecar from "./sidecar";
 
@@ -12,12 +16,16 @@ export type { PreviewInfo } from "./image";
 
 export const api = {
   annotations,
+  badPixels,
   fs,
   image,
   thumbnails,
   renderer,
   exif,
+  export: exportImages,
+  history,
   inspection,
+  look,
   settings,
   sidecar,
 };
@@ -0,0 +1,6 @@
+import type { CommandArgs } from "@/types/commands";
+
+import { invokeTauri } from "./_client";
+
+export const readLookFile = (args: CommandArgs["read_look_file"]) =>
+  invokeTauri("read_look_file", args);
@@ -38,4 +38,35 @@ export const shouldRenderFrame = () => invokeTauri("should_render_frame", null);
 
 export const getInspection = () => invokeTauri("get_renderer_inspection", null);
 
+export const sampleWhiteBalance = (args: CommandArgs["sample_white_balance"]) =>
+  invokeTauri("sample_white_balance", args);
+
+export const sampleColorBand = (args: CommandArgs["sample_color_band"]) =>
+  invokeTauri("sample_color_band", args);
+
+export const sourcePositionAtViewport = (
+  args: CommandArgs["source_position_at_viewport"],
+) => invokeTauri("source_position_at_viewport", args);
+
+export const sampleMaskColor = (args: CommandArgs["sample_mask_color"]) =>
+  invokeTauri("sample_mask_color", args);
+
+export const findSpotSource = (args: CommandArgs["find_spot_source"]) =>
+  invokeTauri("find_spot_source", args);
+
+export const setSharpeningMaskPreview = (
+  args: CommandArgs["set_sharpening_mask_preview"],
+) => invokeTauri("set_sharpening_mask_preview", args);
+
+export const setLocalMaskOverlay = (
+  args: CommandArgs["set_local_mask_overlay"],
+) => invokeTauri("set_local_mask_overlay", args);
+
+export const setSpotVisualization = (
+  args: CommandArgs["set_spot_visualization"],
+) => invokeTauri("set_spot_visualization", args);
+
+export const setHdrOutput = (args: CommandArgs["set_hdr_output"]) =>
+  invokeTauri("set_hdr_output", args);
+
 export const clearRenderer = () => invokeTauri("clear_renderer", null);
@@ -0,0 +1,4 @@
+export type BadPixel = {
+  x: number;
+  y: number;
+};
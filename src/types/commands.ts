@@ -1,5 +1,8 @@
 import { FileAnnotation, FlagEntry, RatingEntry } from "./file-annotations";
+import { BadPixel } from "./bad-pixels";
 import { ImageExifEntry } from "./exif";
+import { ExportImagesRequest } from "./export";
+import { LookFile } from "./look";
 import { HistogramData } from "./histogram";
 import { InspectionSnapshot } from "./inspection";
 import {
@@ -10,7 +13,15 @@ import {
 } from "./inspection-checkpoint";
 import { TreeNode } from "./file-system";
 import { CacheType } from "./settings";
-import { Sidecar } from "./sidecar";
+import {
+  ColorBand,
+  ColorRange,
+  EditRecipe,
+  MaskPoint,
+  Sidecar,
+  WhiteBalance,
+} from "./sidecar";
+import { EditHistorySummary } from "./history";
 
 export interface CommandArgs {
   get_home_dir: null;
@@ -38,6 +49,15 @@ export interface CommandArgs {
   render_frame: null;
   should_render_frame: null;
   get_renderer_inspection: null;
+  sample_white_balance: { x: number; y: number };
+  sample_color_band: { x: number; y: number };
+  source_position_at_viewport: { x: number; y: number };
+  sample_mask_color: { x: number; y: number };
+  find_spot_source: { x: number; y: number; radius: number };
+  set_sharpening_mask_preview: { enabled: boolean };
+  set_local_mask_overlay: { index: number | null };
+  set_spot_visualization: { enabled: boolean };
+  set_hdr_output: { enabled: boolean; peakNits: number };
   create_inspection_checkpoint_set: {
     request: CreateInspectionCheckpointSetRequest;
   };
@@ -64,11 +84,23 @@ export interface CommandArgs {
   set_flags: { entries: FlagEntry[] };
   get_annotations: { paths: string[] };
   get_exif_metadata: { paths: string[] };
+  get_bad_pixel_map: { cameraMake: string; cameraModel: string };
+  set_bad_pixel_map: {
+    cameraMake: string;
+    cameraModel: string;
+    pixels: BadPixel[];
+  };
+  export_images: { request: ExportImagesRequest };
+  read_look_file: { path: string };
   get_cache_size: { cacheType: CacheType };
   clear_cache: { cacheType: CacheType };
   load_sidecar: { path: string };
   save_sidecar: { path: string; sidecar: Sidecar };
   sync_sidecar: { sidecar: Sidecar };
+  get_edit_history_summary: { path: string };
+  record_edit_history: { path: string; recipe: EditRecipe };
+  undo_edit_history: { path: string };
+  redo_edit_history: { path: string };
 }
 
 export interface CommandReturn {
@@ -89,6 +121,15 @@ export interface CommandReturn {
   render_frame: void;
   should_render_frame: boolean;
   get_renderer_inspection: InspectionSnapshot | null;
+  sample_white_balance: WhiteBalance;
+  sample_color_band: ColorBand | null;
+  source_position_at_viewport: MaskPoint | null;
+  sample_mask_color: ColorRange;
+  find_spot_source: MaskPoint | null;
+  set_sharpening_mask_preview: void;
+  set_local_mask_overlay: void;
+  set_spot_visualization: void;
+  set_hdr_output: boolean;
   create_inspection_checkpoint_set: InspectionCheckpoint;
   capture_inspection_checkpoint_artifact: InspectionCheckpointArtifact;
   list_inspection_checkpoints: InspectionCheckpoint[];
@@ -102,9 +143,17 @@ export interface CommandReturn {
   set_flags: void;
   get_annotations: FileAnnotation[];
   get_exif_metadata: ImageExifEntry[];
+  get_bad_pixel_map: BadPixel[];
+  set_bad_pixel_map: void;
+  export_images: void;
+  read_look_file: LookFile;
   get_cache_size: number;
   clear_cache: void;
   load_sidecar: Sidecar;
   save_sidecar: void;
   sync_sidecar: void;
+  get_edit_history_summary: EditHistorySummary;
+  record_edit_history: EditHistorySummary;
+  undo_edit_history: EditRecipe | null;
+  redo_edit_history: EditRecipe | null;
 }
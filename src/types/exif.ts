@@ -7,6 +7,7 @@ export type ExifMetadata = {
   shutterSpeed: number | null;
   aperture: number | null;
   focalLength: number | null;
+  focalLength35mm: number | null;
   exposureBias: number | null;
   whiteBalance: number | null;
   meteringMode: number | null;
@@ -0,0 +1,25 @@
+export type ExportFormat =
+  | { kind: "jpeg"; quality: number }
+  | { kind: "png" }
+  | { kind: "tiff16" }
+  | { kind: "exr"; peakNits: number }
+  | { kind: "avif"; peakNits: number };
+
+export type ExportImagesRequest = {
+  paths: string[];
+  outputDir: string;
+  format: ExportFormat;
+};
+
+export type ExportProgress = {
+  index: number;
+  total: number;
+  sourcePath: string;
+  outputPath: string | null;
+  error: string | null;
+};
+
+export type ExportSummary = {
+  exported: number;
+  failed: number;
+};
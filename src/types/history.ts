@@ -0,0 +1,9 @@
+export type EditHistoryEntry = {
+  label: string;
+  recordedAt: number;
+};
+
+export type EditHistorySummary = {
+  entries: EditHistoryEntry[];
+  position: number;
+};
@@ -12,6 +12,14 @@ export type ImageInspection = {
   height: number;
   hasTransparency: boolean;
   raw: RawImageInspection | null;
+  dngOpcodes: DngOpcodeInspection[];
+};
+
+export type DngOpcodeInspection = {
+  list: string;
+  name: string;
+  optional: boolean;
+  applied: boolean;
 };
 
 export type RawImageInspection = {
@@ -20,13 +28,14 @@ export type RawImageInspection = {
   bitsPerSample: number;
   sensorDimensions: DimensionsInspection;
   cropArea: RectInspection;
-  cfa: CfaPatternInspection;
+  cfa: CfaPatternInspection | null;
   sourceBlackLevels: number[];
   sourceWhiteLevels: number[];
   normalizedBlackLevels: number[];
   normalizedWhiteLevels: number[];
   asShotWhiteBalance: number[];
   headroomWhiteBalance: number[];
+  mappedBadPixels: number;
 };
 
 export type DimensionsInspection = {
@@ -58,12 +67,28 @@ export type PipelineInspection = {
   displayIntent: string;
   baseExposureEv: number | null;
   userExposureEv: number;
+  whiteBalance: string;
+  whiteBalanceMultipliers: number[] | null;
+  highlightReconstruction: string;
+  noiseReduction: string;
+  captureSharpening: string;
+  sharpeningMaskPreview: boolean;
+  localMaskOverlay: number | null;
+  spotVisualization: boolean;
+  lensProfile: string;
+  toneMapper: string;
+  look: string;
 };
 
 export type TextureInspection = {
   source: TextureResourceInspection;
   developmentOutput: TextureResourceInspection;
+  lensCorrectionOutput: TextureResourceInspection;
+  retouchOutput: TextureResourceInspection;
   adjustmentOutput: TextureResourceInspection;
+  mask: TextureResourceInspection;
+  localAdjustmentOutput: TextureResourceInspection;
+  geometryOutput: TextureResourceInspection;
   displayOutput: TextureResourceInspection;
   surface: TextureResourceInspection;
 };
@@ -0,0 +1,6 @@
+export type LookFile = {
+  path: string;
+  hash: string;
+  title: string | null;
+  size: number;
+};
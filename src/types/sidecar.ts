@@ -1,5 +1,206 @@
+export type WhiteBalance =
+  | { mode: "as_shot" }
+  | { mode: "temperature"; temperature_k: number; tint: number }
+  | { mode: "custom"; multipliers: [number, number, number] };
+
+export type HighlightReconstruction = "off" | "clip" | "blend" | "inpaint";
+
+export type LensCorrection = {
+  distortion: boolean;
+  chromatic_aberration: boolean;
+  vignetting: boolean;
+  distortion_amount: number;
+  chromatic_aberration_red: number;
+  chromatic_aberration_blue: number;
+  vignetting_amount: number;
+};
+
+export type NoiseReduction = {
+  luminance: number;
+  luminance_detail: number;
+  chroma: number;
+  chroma_detail: number;
+};
+
+export type CaptureSharpening = {
+  amount: number;
+  radius: number;
+  threshold: number;
+};
+
+export type CurvePoint = {
+  x: number;
+  y: number;
+};
+
+export type ParametricCurve = {
+  shadows: number;
+  darks: number;
+  lights: number;
+  highlights: number;
+  shadow_split: number;
+  midtone_split: number;
+  highlight_split: number;
+};
+
+export type ToneCurve = {
+  parametric: ParametricCurve;
+  rgb: CurvePoint[];
+  red: CurvePoint[];
+  green: CurvePoint[];
+  blue: CurvePoint[];
+};
+
+export type ColorBand =
+  | "red"
+  | "orange"
+  | "yellow"
+  | "green"
+  | "aqua"
+  | "blue"
+  | "purple"
+  | "magenta";
+
+export type ColorMixer = {
+  hue: number[];
+  saturation: number[];
+  luminance: number[];
+};
+
+export type LookPlacement = "working" | "display";
+
+export type LookInterpolation = "trilinear" | "tetrahedral";
+
+export type Look = {
+  path: string | null;
+  hash: string | null;
+  placement: LookPlacement;
+  interpolation: LookInterpolation;
+  amount: number;
+};
+
+export type ToneMapper =
+  | "aces_fitted"
+  | "agx"
+  | "filmic"
+  | "reinhard"
+  | "clip";
+
+export type ToneMapping = {
+  mapper: ToneMapper;
+  contrast: number;
+  toe: number;
+};
+
+export type CropRect = {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+};
+
+export type CropAspectRatio =
+  | { mode: "free" }
+  | { mode: "original" }
+  | { mode: "fixed"; width: number; height: number };
+
+export type Geometry = {
+  crop: CropRect | null;
+  aspect_ratio: CropAspectRatio;
+  straighten_degrees: number;
+  quarter_turns: number;
+  flip_horizontal: boolean;
+  flip_vertical: boolean;
+};
+
+export type MaskPoint = {
+  x: number;
+  y: number;
+};
+
+export type BrushStroke = {
+  points: MaskPoint[];
+  radius: number;
+  feather: number;
+  erase: boolean;
+};
+
+export type Mask =
+  | { mode: "linear"; start: MaskPoint; end: MaskPoint }
+  | {
+      mode: "radial";
+      center: MaskPoint;
+      radius_x: number;
+      radius_y: number;
+      rotation_degrees: number;
+      feather: number;
+      invert: boolean;
+    }
+  | { mode: "brush"; strokes: BrushStroke[] };
+
+export type LuminanceRange = {
+  min: number;
+  max: number;
+  feather: number;
+};
+
+export type ColorRange = {
+  sample: MaskPoint;
+  hue_degrees: number;
+  chroma: number;
+  hue_range_degrees: number;
+  chroma_range: number;
+  feather: number;
+};
+
+export type MaskRange = {
+  luminance: LuminanceRange | null;
+  color: ColorRange | null;
+};
+
+export type LocalAdjustment = {
+  name: string;
+  enabled: boolean;
+  mask: Mask;
+  range: MaskRange;
+  exposure_ev: number;
+  contrast: number;
+  temperature: number;
+  tint: number;
+  saturation: number;
+};
+
+export type RetouchMode = "clone" | "heal";
+
+export type RetouchSpot = {
+  source: MaskPoint;
+  destination: MaskPoint;
+  radius: number;
+  feather: number;
+  mode: RetouchMode;
+};
+
 export type EditRecipe = {
+  white_balance: WhiteBalance;
+  highlight_reconstruction: HighlightReconstruction;
+  lens_correction: LensCorrection;
+  noise_reduction: NoiseReduction;
+  capture_sharpening: CaptureSharpening;
   exposure_ev: number;
+  contrast: number;
+  highlights: number;
+  shadows: number;
+  whites: number;
+  blacks: number;
+  saturation: number;
+  vibrance: number;
+  tone_curve: ToneCurve;
+  color_mixer: ColorMixer;
+  look: Look;
+  tone_mapping: ToneMapping;
+  geometry: Geometry;
+  local_adjustments: LocalAdjustment[];
+  retouch_spots: RetouchSpot[];
 };
 
 export type SidecarAppInfo = {